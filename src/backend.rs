@@ -1,30 +1,26 @@
-mod asm;
-mod codegen;
-mod emit;
-mod fixup;
+mod aarch64;
+mod riscv64;
+mod x86_64;
 
-use crate::Stage;
 use crate::frontend::ir;
+use crate::{Artifact, Stage};
 
-use codegen::generate;
-use emit::emit;
+pub use aarch64::AArch64;
+pub use riscv64::RiscV64;
+pub use x86_64::{ObjectFormat, X86_64};
 
-pub fn compile(ir_program: ir::Program, stage: Stage) -> Option<String> {
-    // Code Generation
-    let assembly = generate(ir_program);
-    if stage == Stage::Codegen {
-        dbg!(assembly);
-        println!("Code Generation OK!");
-        return None;
-    }
+// Everything the compiler needs from a backend. Each target lowers the IR
+// through its own instruction types and passes.
+pub trait Target {
+    // What clang is told to assemble and link for
+    fn triple(&self) -> &'static str;
 
-    // Code Emission
-    let assembly_code = emit(assembly);
-    if stage == Stage::Emit {
-        println!("{}", assembly_code);
-        println!("Code Emission OK!");
-        return None;
-    }
+    // Stops at the instructions with `Stage::Codegen`, and otherwise goes
+    // as far as the target can towards `stage`
+    fn compile(&self, ir_program: ir::Program, stage: Stage) -> Result<Artifact, String>;
 
-    return Some(assembly_code);
+    // Compiles the program into memory and returns the exit status of `main`
+    fn run(&self, _ir_program: ir::Program) -> Result<i32, String> {
+        return Err(format!("Cannot run {} code in-process", self.triple()));
+    }
 }
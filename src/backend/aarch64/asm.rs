@@ -0,0 +1,89 @@
+#[derive(Debug)]
+pub struct Program {
+    pub function: Function,
+}
+
+#[derive(Debug)]
+pub struct Function {
+    pub name: String,
+    pub instructions: Vec<Instruction>,
+}
+
+// Three-operand instructions take registers, except where noted. Selection
+// puts pseudos and immediates anywhere; `fixup` makes them legal.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Mov {
+        dst: Operand,
+        src: Operand,
+    },
+    Unary(UnaryOperator, Operand, Operand),
+    // `add` and `sub` also take a 12-bit immediate as the last operand
+    Binary(BinaryOperator, Operand, Operand, Operand),
+    // dst = minuend - lhs * rhs
+    MultiplySubtract {
+        dst: Operand,
+        lhs: Operand,
+        rhs: Operand,
+        minuend: Operand,
+    },
+    // Also takes a 12-bit immediate on the right
+    Compare(Operand, Operand),
+    SetCondition(Condition, Operand),
+    Branch(String),
+    BranchZero(Operand, String),
+    BranchNotZero(Operand, String),
+    Label(String),
+    Load(Reg, i32),
+    Store(Reg, i32),
+    AllocateStack(i32),
+    Return,
+}
+
+#[derive(Debug, Clone)]
+pub enum UnaryOperator {
+    Mvn,
+    Neg,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    Sdiv,
+    And,
+    Orr,
+    Eor,
+    Lsl,
+    Asr,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Condition {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Immediate(i32),
+    Register(Reg),
+    Pseudo(String),
+    // Offset from `sp`
+    Stack(i32),
+}
+
+// W0 holds the return value. W9 to W11 are the fixup scratch registers and
+// X16 addresses stack slots that are out of reach of an immediate offset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reg {
+    W0,
+    W9,
+    W10,
+    W11,
+}
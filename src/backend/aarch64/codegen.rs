@@ -0,0 +1,167 @@
+use super::asm;
+use super::fixup;
+use crate::frontend::ir;
+
+pub fn generate(ir_program: ir::Program) -> asm::Program {
+    let function = generate_function(ir_program.function);
+    return asm::Program { function };
+}
+
+// The instructions chosen for the IR, before registers are allocated and
+// fixup makes them legal, for `--codegen`
+pub fn select(ir_func: ir::Function) -> Vec<asm::Instruction> {
+    return generate_instruction(ir_func.body);
+}
+
+fn generate_function(ir_func: ir::Function) -> asm::Function {
+    let name = ir_func.name;
+    let mut instructions = generate_instruction(ir_func.body);
+    fixup::fixup(&mut instructions);
+    return asm::Function { name, instructions };
+}
+
+fn map_src_operand(src: &ir::Value) -> asm::Operand {
+    match src {
+        ir::Value::Constant(val) => asm::Operand::Immediate(*val),
+        ir::Value::Variable(var) => asm::Operand::Pseudo(var.clone()),
+    }
+}
+
+fn map_binary(op: &ir::BinaryOperator) -> asm::BinaryOperator {
+    match op {
+        ir::BinaryOperator::Add => asm::BinaryOperator::Add,
+        ir::BinaryOperator::Subtract => asm::BinaryOperator::Sub,
+        ir::BinaryOperator::Multiply => asm::BinaryOperator::Mul,
+        ir::BinaryOperator::Divide => asm::BinaryOperator::Sdiv,
+        ir::BinaryOperator::BitwiseAnd => asm::BinaryOperator::And,
+        ir::BinaryOperator::BitwiseOr => asm::BinaryOperator::Orr,
+        ir::BinaryOperator::BitwiseXor => asm::BinaryOperator::Eor,
+        ir::BinaryOperator::LeftShift => asm::BinaryOperator::Lsl,
+        ir::BinaryOperator::RightShift => asm::BinaryOperator::Asr,
+        _ => unreachable!(),
+    }
+}
+
+fn map_binary_relational(op: &ir::BinaryOperator) -> Option<asm::Condition> {
+    match op {
+        ir::BinaryOperator::Equal => Some(asm::Condition::Eq),
+        ir::BinaryOperator::NotEqual => Some(asm::Condition::Ne),
+
+        ir::BinaryOperator::GreaterThan => Some(asm::Condition::Gt),
+        ir::BinaryOperator::GreaterOrEqual => Some(asm::Condition::Ge),
+
+        ir::BinaryOperator::LessThan => Some(asm::Condition::Lt),
+        ir::BinaryOperator::LessOrEqual => Some(asm::Condition::Le),
+        _ => None,
+    }
+}
+
+fn generate_instruction(ir_instructions: Vec<ir::Instruction>) -> Vec<asm::Instruction> {
+    let mut instructions = Vec::new();
+
+    for inst in ir_instructions {
+        match inst {
+            ir::Instruction::Return(val) => {
+                instructions.push(asm::Instruction::Mov {
+                    dst: asm::Operand::Register(asm::Reg::W0),
+                    src: map_src_operand(&val),
+                });
+                instructions.push(asm::Instruction::Return);
+            }
+
+            ir::Instruction::Unary { op, dst, src } => {
+                let dst = asm::Operand::Pseudo(dst);
+                let src = map_src_operand(&src);
+
+                match op {
+                    ir::UnaryOperator::Complement => instructions.push(asm::Instruction::Unary(
+                        asm::UnaryOperator::Mvn,
+                        dst,
+                        src,
+                    )),
+                    ir::UnaryOperator::Negate => instructions.push(asm::Instruction::Unary(
+                        asm::UnaryOperator::Neg,
+                        dst,
+                        src,
+                    )),
+                    ir::UnaryOperator::Not => {
+                        instructions
+                            .push(asm::Instruction::Compare(src, asm::Operand::Immediate(0)));
+                        instructions.push(asm::Instruction::SetCondition(asm::Condition::Eq, dst));
+                    }
+                }
+            }
+
+            ir::Instruction::Binary {
+                op,
+                dst,
+                src1,
+                src2,
+            } => {
+                let dst = asm::Operand::Pseudo(dst);
+                let src1 = map_src_operand(&src1);
+                let src2 = map_src_operand(&src2);
+
+                if let Some(condition) = map_binary_relational(&op) {
+                    instructions.push(asm::Instruction::Compare(src1, src2));
+                    instructions.push(asm::Instruction::SetCondition(condition, dst));
+                    continue;
+                }
+
+                // There is no remainder instruction: a % b = a - (a / b) * b
+                if op == ir::BinaryOperator::Modulo {
+                    let quotient = asm::Operand::Register(asm::Reg::W11);
+                    instructions.push(asm::Instruction::Binary(
+                        asm::BinaryOperator::Sdiv,
+                        quotient.clone(),
+                        src1.clone(),
+                        src2.clone(),
+                    ));
+                    instructions.push(asm::Instruction::MultiplySubtract {
+                        dst,
+                        lhs: quotient,
+                        rhs: src2,
+                        minuend: src1,
+                    });
+                    continue;
+                }
+
+                instructions.push(asm::Instruction::Binary(map_binary(&op), dst, src1, src2));
+            }
+
+            ir::Instruction::Copy { src, dst } => {
+                instructions.push(asm::Instruction::Mov {
+                    dst: asm::Operand::Pseudo(dst),
+                    src: map_src_operand(&src),
+                });
+            }
+
+            ir::Instruction::Jump { target } => {
+                instructions.push(asm::Instruction::Branch(target));
+            }
+
+            ir::Instruction::JumpIfZero { condition, target } => {
+                instructions.push(asm::Instruction::BranchZero(
+                    map_src_operand(&condition),
+                    target,
+                ));
+            }
+
+            ir::Instruction::JumpIfNotZero { condition, target } => {
+                instructions.push(asm::Instruction::BranchNotZero(
+                    map_src_operand(&condition),
+                    target,
+                ));
+            }
+
+            ir::Instruction::Label(name) => {
+                instructions.push(asm::Instruction::Label(name));
+            }
+
+            // Debug info is only emitted for x86-64
+            ir::Instruction::Location(_) => {}
+        }
+    }
+
+    return instructions;
+}
@@ -0,0 +1,200 @@
+use super::asm::*;
+use std::fmt::Write;
+
+pub fn emit(program: Program) -> String {
+    let mut output = String::new();
+    emit_program(&program, &mut output);
+    return output;
+}
+
+fn emit_program(program: &Program, output: &mut String) {
+    writeln!(output, "\t.text").unwrap();
+    emit_function(&program.function, output);
+    writeln!(output, "\t.section .note.GNU-stack,\"\",%progbits").unwrap();
+}
+
+fn emit_function(function: &Function, output: &mut String) {
+    writeln!(output, "\t.globl {}", function.name).unwrap();
+    writeln!(output, "\t.type {}, %function", function.name).unwrap();
+    writeln!(output, "{}:", function.name).unwrap();
+
+    // The frame record: the caller's frame pointer and the return address
+    writeln!(output, "\tstp x29, x30, [sp, #-16]!").unwrap();
+    writeln!(output, "\tmov x29, sp").unwrap();
+
+    for instruction in &function.instructions {
+        emit_instruction(instruction, output);
+    }
+
+    writeln!(output, "\t.size {}, .-{}", function.name, function.name).unwrap();
+}
+
+fn emit_instruction(instruction: &Instruction, output: &mut String) {
+    match instruction {
+        Instruction::Mov {
+            dst,
+            src: Operand::Immediate(value),
+        } => emit_immediate(&emit_operand(dst), *value, output),
+
+        Instruction::Mov { dst, src } => {
+            writeln!(output, "\tmov {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+        }
+
+        Instruction::Return => {
+            writeln!(output).unwrap();
+            writeln!(output, "\tmov sp, x29").unwrap();
+            writeln!(output, "\tldp x29, x30, [sp], #16").unwrap();
+            writeln!(output, "\tret").unwrap();
+        }
+
+        Instruction::Unary(op, dst, src) => {
+            let mnemonic = match op {
+                UnaryOperator::Mvn => "mvn",
+                UnaryOperator::Neg => "neg",
+            };
+            writeln!(
+                output,
+                "\t{} {}, {}",
+                mnemonic,
+                emit_operand(dst),
+                emit_operand(src)
+            )
+            .unwrap();
+        }
+
+        Instruction::AllocateStack(bytes) => {
+            if *bytes < 4096 {
+                writeln!(output, "\tsub sp, sp, #{}", bytes).unwrap();
+            } else {
+                emit_immediate("x16", *bytes, output);
+                writeln!(output, "\tsub sp, sp, x16").unwrap();
+            }
+            writeln!(output).unwrap();
+        }
+
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let mnemonic = match op {
+                BinaryOperator::Add => "add",
+                BinaryOperator::Sub => "sub",
+                BinaryOperator::Mul => "mul",
+                BinaryOperator::Sdiv => "sdiv",
+                BinaryOperator::And => "and",
+                BinaryOperator::Orr => "orr",
+                BinaryOperator::Eor => "eor",
+                BinaryOperator::Lsl => "lsl",
+                BinaryOperator::Asr => "asr",
+            };
+            writeln!(
+                output,
+                "\t{} {}, {}, {}",
+                mnemonic,
+                emit_operand(dst),
+                emit_operand(lhs),
+                emit_operand(rhs)
+            )
+            .unwrap();
+        }
+
+        Instruction::MultiplySubtract {
+            dst,
+            lhs,
+            rhs,
+            minuend,
+        } => writeln!(
+            output,
+            "\tmsub {}, {}, {}, {}",
+            emit_operand(dst),
+            emit_operand(lhs),
+            emit_operand(rhs),
+            emit_operand(minuend)
+        )
+        .unwrap(),
+
+        Instruction::Compare(lhs, rhs) => {
+            writeln!(output, "\tcmp {}, {}", emit_operand(lhs), emit_operand(rhs)).unwrap()
+        }
+
+        Instruction::SetCondition(condition, dst) => writeln!(
+            output,
+            "\tcset {}, {}",
+            emit_operand(dst),
+            emit_condition(condition)
+        )
+        .unwrap(),
+
+        Instruction::Branch(label) => writeln!(output, "\tb .L{}", label).unwrap(),
+
+        Instruction::BranchZero(op, label) => {
+            writeln!(output, "\tcbz {}, .L{}", emit_operand(op), label).unwrap()
+        }
+
+        Instruction::BranchNotZero(op, label) => {
+            writeln!(output, "\tcbnz {}, .L{}", emit_operand(op), label).unwrap()
+        }
+
+        Instruction::Label(label) => {
+            writeln!(output).unwrap();
+            writeln!(output, ".L{}:", label).unwrap()
+        }
+
+        Instruction::Load(reg, offset) => emit_memory("ldr", reg, *offset, output),
+        Instruction::Store(reg, offset) => emit_memory("str", reg, *offset, output),
+    }
+}
+
+// `mov` only takes immediates that fit one `movz` or `movn`; anything else
+// is built from its two halves
+fn emit_immediate(dst: &str, value: i32, output: &mut String) {
+    if (-65536..65536).contains(&value) {
+        writeln!(output, "\tmov {}, #{}", dst, value).unwrap();
+        return;
+    }
+
+    let bits = value as u32;
+    writeln!(output, "\tmovz {}, #{}", dst, bits & 0xFFFF).unwrap();
+    writeln!(output, "\tmovk {}, #{}, lsl #16", dst, bits >> 16).unwrap();
+}
+
+// The scaled 12-bit offset of `ldr` and `str` reaches 16380 bytes; further
+// slots are addressed through x16
+fn emit_memory(mnemonic: &str, reg: &Reg, offset: i32, output: &mut String) {
+    let reg = emit_register(reg);
+    if offset < 16384 {
+        writeln!(output, "\t{} {}, [sp, #{}]", mnemonic, reg, offset).unwrap();
+    } else {
+        emit_immediate("x16", offset, output);
+        writeln!(output, "\t{} {}, [sp, x16]", mnemonic, reg).unwrap();
+    }
+}
+
+fn emit_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => format!("#{}", value),
+        Operand::Register(reg) => emit_register(reg),
+        Operand::Stack(_) | Operand::Pseudo(_) => unreachable!("operand must be a register"),
+    }
+}
+
+pub fn emit_register(reg: &Reg) -> String {
+    match reg {
+        Reg::W0 => "w0",
+        Reg::W9 => "w9",
+        Reg::W10 => "w10",
+        Reg::W11 => "w11",
+    }
+    .to_string()
+}
+
+pub fn emit_condition(condition: &Condition) -> String {
+    match condition {
+        Condition::Eq => "eq",
+        Condition::Ne => "ne",
+
+        Condition::Lt => "lt",
+        Condition::Le => "le",
+
+        Condition::Gt => "gt",
+        Condition::Ge => "ge",
+    }
+    .to_string()
+}
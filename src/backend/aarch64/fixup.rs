@@ -0,0 +1,205 @@
+use super::asm::*;
+use std::collections::HashMap;
+
+const SCRATCH: [Reg; 3] = [Reg::W9, Reg::W10, Reg::W11];
+
+pub fn fixup(instructions: &mut Vec<Instruction>) {
+    let stack_size = replace_pseudos(instructions);
+
+    // AAPCS64 requires `sp` to stay 16-byte aligned
+    let aligned = (stack_size + 15) & !15;
+    instructions.insert(0, Instruction::AllocateStack(aligned));
+
+    let mut legal = Vec::with_capacity(instructions.len());
+    for inst in instructions.drain(..) {
+        legalize(inst, &mut legal);
+    }
+    *instructions = legal;
+}
+
+// Every pseudo gets its own 4-byte slot above `sp`
+fn replace_pseudos(instructions: &mut [Instruction]) -> i32 {
+    let mut offsets: HashMap<String, i32> = HashMap::new();
+    let mut next = 0;
+
+    for inst in instructions.iter_mut() {
+        for operand in operands_mut(inst) {
+            if let Operand::Pseudo(name) = operand {
+                let offset = *offsets.entry(name.clone()).or_insert_with(|| {
+                    next += 4;
+                    next - 4
+                });
+                *operand = Operand::Stack(offset);
+            }
+        }
+    }
+
+    return next;
+}
+
+fn operands_mut(inst: &mut Instruction) -> Vec<&mut Operand> {
+    match inst {
+        Instruction::Mov { dst, src } => vec![dst, src],
+        Instruction::Unary(_, dst, src) => vec![dst, src],
+        Instruction::Binary(_, dst, lhs, rhs) => vec![dst, lhs, rhs],
+        Instruction::MultiplySubtract {
+            dst,
+            lhs,
+            rhs,
+            minuend,
+        } => vec![dst, lhs, rhs, minuend],
+        Instruction::Compare(lhs, rhs) => vec![lhs, rhs],
+        Instruction::SetCondition(_, dst) => vec![dst],
+        Instruction::BranchZero(op, _) | Instruction::BranchNotZero(op, _) => vec![op],
+        _ => vec![],
+    }
+}
+
+fn is_arithmetic_immediate(value: i32) -> bool {
+    return (0..4096).contains(&value);
+}
+
+// AArch64 is a load/store architecture: operands in stack slots are loaded
+// into scratch registers first, results are computed into W9 and stored, and
+// immediates that the instruction cannot encode are moved into a register.
+fn legalize(mut inst: Instruction, out: &mut Vec<Instruction>) {
+    // The scratch registers that the instruction does not already use
+    let taken: Vec<Reg> = operands_mut(&mut inst)
+        .into_iter()
+        .filter_map(|op| match op {
+            Operand::Register(reg) => Some(*reg),
+            _ => None,
+        })
+        .collect();
+    let mut scratch = SCRATCH.into_iter().filter(|reg| !taken.contains(reg));
+
+    let mut load = |operand: Operand, out: &mut Vec<Instruction>| -> Operand {
+        match operand {
+            Operand::Stack(offset) => {
+                let reg = scratch.next().unwrap();
+                out.push(Instruction::Load(reg, offset));
+                return Operand::Register(reg);
+            }
+            Operand::Immediate(_) => {
+                let reg = scratch.next().unwrap();
+                out.push(Instruction::Mov {
+                    dst: Operand::Register(reg),
+                    src: operand,
+                });
+                return Operand::Register(reg);
+            }
+            _ => return operand,
+        }
+    };
+
+    // Where the result goes, and the store that puts it in its slot
+    let result = |operand: Operand| -> (Operand, Option<Instruction>) {
+        match operand {
+            Operand::Stack(offset) => (
+                Operand::Register(Reg::W9),
+                Some(Instruction::Store(Reg::W9, offset)),
+            ),
+            _ => (operand, None),
+        }
+    };
+
+    let store = match inst {
+        Instruction::Mov { dst, src } => match (dst, src) {
+            (Operand::Stack(offset), src) => {
+                let src = load(src, out);
+                let Operand::Register(reg) = src else {
+                    unreachable!()
+                };
+                out.push(Instruction::Store(reg, offset));
+                None
+            }
+            (Operand::Register(reg), Operand::Stack(offset)) => {
+                out.push(Instruction::Load(reg, offset));
+                None
+            }
+            (dst, src) => {
+                out.push(Instruction::Mov { dst, src });
+                None
+            }
+        },
+
+        Instruction::Unary(op, dst, src) => {
+            let src = load(src, out);
+            let (dst, store) = result(dst);
+            out.push(Instruction::Unary(op, dst, src));
+            store
+        }
+
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let lhs = load(lhs, out);
+            let rhs = match rhs {
+                Operand::Immediate(value)
+                    if matches!(op, BinaryOperator::Add | BinaryOperator::Sub)
+                        && is_arithmetic_immediate(value) =>
+                {
+                    rhs
+                }
+                _ => load(rhs, out),
+            };
+            let (dst, store) = result(dst);
+            out.push(Instruction::Binary(op, dst, lhs, rhs));
+            store
+        }
+
+        Instruction::MultiplySubtract {
+            dst,
+            lhs,
+            rhs,
+            minuend,
+        } => {
+            let lhs = load(lhs, out);
+            let rhs = load(rhs, out);
+            let minuend = load(minuend, out);
+            let (dst, store) = result(dst);
+            out.push(Instruction::MultiplySubtract {
+                dst,
+                lhs,
+                rhs,
+                minuend,
+            });
+            store
+        }
+
+        Instruction::Compare(lhs, rhs) => {
+            let lhs = load(lhs, out);
+            let rhs = match rhs {
+                Operand::Immediate(value) if is_arithmetic_immediate(value) => rhs,
+                _ => load(rhs, out),
+            };
+            out.push(Instruction::Compare(lhs, rhs));
+            None
+        }
+
+        Instruction::SetCondition(condition, dst) => {
+            let (dst, store) = result(dst);
+            out.push(Instruction::SetCondition(condition, dst));
+            store
+        }
+
+        Instruction::BranchZero(op, target) => {
+            let op = load(op, out);
+            out.push(Instruction::BranchZero(op, target));
+            None
+        }
+
+        Instruction::BranchNotZero(op, target) => {
+            let op = load(op, out);
+            out.push(Instruction::BranchNotZero(op, target));
+            None
+        }
+
+        other => {
+            out.push(other);
+            None
+        }
+    };
+
+    if let Some(store) = store {
+        out.push(store);
+    }
+}
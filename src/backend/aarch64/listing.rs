@@ -0,0 +1,91 @@
+// The selected instructions as a listing, with the mnemonics and registers
+// that emission prints for them
+
+use super::asm::*;
+use super::emit::{emit_condition, emit_register};
+use crate::dump::Dump;
+use crate::dump::listing::{self, Line, Operand as Item};
+
+pub fn dump(function: &str, instructions: &[Instruction]) -> Dump {
+    let lines: Vec<Line> = instructions.iter().map(line).collect();
+    return listing::listing(function, &lines);
+}
+
+fn line(instruction: &Instruction) -> Line {
+    let (opcode, operands) = match instruction {
+        Instruction::Label(label) => return Line::Label(label.clone()),
+
+        Instruction::Mov { dst, src } => ("mov", vec![item(dst), item(src)]),
+        Instruction::Unary(op, dst, src) => {
+            let opcode = match op {
+                UnaryOperator::Mvn => "mvn",
+                UnaryOperator::Neg => "neg",
+            };
+            (opcode, vec![item(dst), item(src)])
+        }
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let opcode = match op {
+                BinaryOperator::Add => "add",
+                BinaryOperator::Sub => "sub",
+                BinaryOperator::Mul => "mul",
+                BinaryOperator::Sdiv => "sdiv",
+                BinaryOperator::And => "and",
+                BinaryOperator::Orr => "orr",
+                BinaryOperator::Eor => "eor",
+                BinaryOperator::Lsl => "lsl",
+                BinaryOperator::Asr => "asr",
+            };
+            (opcode, vec![item(dst), item(lhs), item(rhs)])
+        }
+        Instruction::MultiplySubtract {
+            dst,
+            lhs,
+            rhs,
+            minuend,
+        } => ("msub", vec![item(dst), item(lhs), item(rhs), item(minuend)]),
+        Instruction::Compare(lhs, rhs) => ("cmp", vec![item(lhs), item(rhs)]),
+        Instruction::SetCondition(condition, dst) => (
+            "cset",
+            vec![item(dst), Item::Symbol(emit_condition(condition))],
+        ),
+        Instruction::Branch(label) => ("b", vec![Item::Symbol(label.clone())]),
+        Instruction::BranchZero(operand, label) => {
+            ("cbz", vec![item(operand), Item::Symbol(label.clone())])
+        }
+        Instruction::BranchNotZero(operand, label) => {
+            ("cbnz", vec![item(operand), Item::Symbol(label.clone())])
+        }
+        Instruction::Load(reg, offset) => ("ldr", vec![register(reg), slot(*offset)]),
+        Instruction::Store(reg, offset) => ("str", vec![register(reg), slot(*offset)]),
+        Instruction::AllocateStack(bytes) => (
+            "sub",
+            vec![
+                Item::Register("sp".to_string()),
+                Item::Register("sp".to_string()),
+                Item::Immediate((*bytes).into()),
+            ],
+        ),
+        Instruction::Return => ("ret", Vec::new()),
+    };
+    return Line::Instruction(opcode.to_string(), operands);
+}
+
+fn item(operand: &Operand) -> Item {
+    return match operand {
+        Operand::Immediate(value) => Item::Immediate((*value).into()),
+        Operand::Register(reg) => register(reg),
+        Operand::Pseudo(name) => Item::Pseudo(name.clone()),
+        Operand::Stack(offset) => slot(*offset),
+    };
+}
+
+fn register(reg: &Reg) -> Item {
+    return Item::Register(emit_register(reg));
+}
+
+fn slot(offset: i32) -> Item {
+    return Item::Memory {
+        base: "sp",
+        offset: offset.into(),
+    };
+}
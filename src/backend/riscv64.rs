@@ -0,0 +1,35 @@
+mod asm;
+mod codegen;
+mod emit;
+mod fixup;
+mod listing;
+
+use super::Target;
+use crate::frontend::ir;
+use crate::{Artifact, Stage};
+
+use codegen::{generate, select};
+use emit::emit;
+
+// 64-bit RISC-V Linux with the M extension, following the LP64 ABI
+pub struct RiscV64;
+
+impl Target for RiscV64 {
+    fn triple(&self) -> &'static str {
+        return "riscv64-linux-gnu";
+    }
+
+    fn compile(&self, ir_program: ir::Program, stage: Stage) -> Result<Artifact, String> {
+        if stage == Stage::Codegen {
+            let name = ir_program.function.name.clone();
+            let instructions = select(ir_program.function);
+            return Ok(Artifact::Instructions(listing::dump(&name, &instructions)));
+        }
+
+        // Code Generation
+        let assembly = generate(ir_program);
+
+        // Code Emission
+        return Ok(Artifact::Assembly(emit(assembly)));
+    }
+}
@@ -0,0 +1,73 @@
+#[derive(Debug)]
+pub struct Program {
+    pub function: Function,
+}
+
+#[derive(Debug)]
+pub struct Function {
+    pub name: String,
+    pub instructions: Vec<Instruction>,
+}
+
+// Register operands only, except that the right operand of some binary
+// instructions may be a 12-bit immediate. Selection puts pseudos and
+// immediates anywhere; `fixup` makes them legal.
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Mov { dst: Operand, src: Operand },
+    Unary(UnaryOperator, Operand, Operand),
+    Binary(BinaryOperator, Operand, Operand, Operand),
+    Jump(String),
+    BranchZero(Operand, String),
+    BranchNotZero(Operand, String),
+    Label(String),
+    Load(Reg, i32),
+    Store(Reg, i32),
+    AllocateStack(i32),
+    Return,
+}
+
+#[derive(Debug, Clone)]
+pub enum UnaryOperator {
+    Not,
+    Negw,
+    // Set to 1 if zero or not zero, and to 0 otherwise
+    Seqz,
+    Snez,
+}
+
+// The `w` forms work on the low 32 bits and sign-extend the result. The
+// bitwise operators and `slt` keep values that are already sign-extended
+// that way, so they need no `w` form.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryOperator {
+    Addw,
+    Subw,
+    Mulw,
+    Divw,
+    Remw,
+    And,
+    Or,
+    Xor,
+    Sllw,
+    Sraw,
+    Slt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Immediate(i32),
+    Register(Reg),
+    Pseudo(String),
+    // Offset from `sp`
+    Stack(i32),
+}
+
+// A0 holds the return value, and T0 and T1 are the fixup scratch registers.
+// Emission uses T2 for stack slots that are out of reach of an immediate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reg {
+    A0,
+    T0,
+    T1,
+}
@@ -0,0 +1,194 @@
+use super::asm;
+use super::fixup;
+use crate::frontend::ir;
+
+pub fn generate(ir_program: ir::Program) -> asm::Program {
+    let function = generate_function(ir_program.function);
+    return asm::Program { function };
+}
+
+// The instructions chosen for the IR, before registers are allocated and
+// fixup makes them legal, for `--codegen`
+pub fn select(ir_func: ir::Function) -> Vec<asm::Instruction> {
+    return generate_instruction(ir_func.body);
+}
+
+fn generate_function(ir_func: ir::Function) -> asm::Function {
+    let name = ir_func.name;
+    let mut instructions = generate_instruction(ir_func.body);
+    fixup::fixup(&mut instructions);
+    return asm::Function { name, instructions };
+}
+
+fn map_src_operand(src: &ir::Value) -> asm::Operand {
+    match src {
+        ir::Value::Constant(val) => asm::Operand::Immediate(*val),
+        ir::Value::Variable(var) => asm::Operand::Pseudo(var.clone()),
+    }
+}
+
+fn map_binary(op: &ir::BinaryOperator) -> asm::BinaryOperator {
+    match op {
+        ir::BinaryOperator::Add => asm::BinaryOperator::Addw,
+        ir::BinaryOperator::Subtract => asm::BinaryOperator::Subw,
+        ir::BinaryOperator::Multiply => asm::BinaryOperator::Mulw,
+        ir::BinaryOperator::Divide => asm::BinaryOperator::Divw,
+        ir::BinaryOperator::Modulo => asm::BinaryOperator::Remw,
+        ir::BinaryOperator::BitwiseAnd => asm::BinaryOperator::And,
+        ir::BinaryOperator::BitwiseOr => asm::BinaryOperator::Or,
+        ir::BinaryOperator::BitwiseXor => asm::BinaryOperator::Xor,
+        ir::BinaryOperator::LeftShift => asm::BinaryOperator::Sllw,
+        ir::BinaryOperator::RightShift => asm::BinaryOperator::Sraw,
+        _ => unreachable!(),
+    }
+}
+
+// There are no condition flags. `slt` answers less-than, the other orderings
+// swap its operands or flip its result with `xori`, and equality tests
+// whether the exclusive or of the operands is zero.
+fn generate_relational(
+    op: &ir::BinaryOperator,
+    dst: asm::Operand,
+    src1: asm::Operand,
+    src2: asm::Operand,
+    instructions: &mut Vec<asm::Instruction>,
+) {
+    let (lhs, rhs) = match op {
+        ir::BinaryOperator::GreaterThan | ir::BinaryOperator::LessOrEqual => (src2, src1),
+        _ => (src1, src2),
+    };
+
+    match op {
+        ir::BinaryOperator::LessThan | ir::BinaryOperator::GreaterThan => {
+            instructions.push(asm::Instruction::Binary(
+                asm::BinaryOperator::Slt,
+                dst,
+                lhs,
+                rhs,
+            ));
+        }
+
+        ir::BinaryOperator::GreaterOrEqual | ir::BinaryOperator::LessOrEqual => {
+            instructions.push(asm::Instruction::Binary(
+                asm::BinaryOperator::Slt,
+                dst.clone(),
+                lhs,
+                rhs,
+            ));
+            instructions.push(asm::Instruction::Binary(
+                asm::BinaryOperator::Xor,
+                dst.clone(),
+                dst,
+                asm::Operand::Immediate(1),
+            ));
+        }
+
+        ir::BinaryOperator::Equal | ir::BinaryOperator::NotEqual => {
+            let test = match op {
+                ir::BinaryOperator::Equal => asm::UnaryOperator::Seqz,
+                _ => asm::UnaryOperator::Snez,
+            };
+            instructions.push(asm::Instruction::Binary(
+                asm::BinaryOperator::Xor,
+                dst.clone(),
+                lhs,
+                rhs,
+            ));
+            instructions.push(asm::Instruction::Unary(test, dst.clone(), dst));
+        }
+
+        _ => unreachable!(),
+    }
+}
+
+fn is_relational(op: &ir::BinaryOperator) -> bool {
+    matches!(
+        op,
+        ir::BinaryOperator::Equal
+            | ir::BinaryOperator::NotEqual
+            | ir::BinaryOperator::GreaterThan
+            | ir::BinaryOperator::GreaterOrEqual
+            | ir::BinaryOperator::LessThan
+            | ir::BinaryOperator::LessOrEqual
+    )
+}
+
+fn generate_instruction(ir_instructions: Vec<ir::Instruction>) -> Vec<asm::Instruction> {
+    let mut instructions = Vec::new();
+
+    for inst in ir_instructions {
+        match inst {
+            ir::Instruction::Return(val) => {
+                instructions.push(asm::Instruction::Mov {
+                    dst: asm::Operand::Register(asm::Reg::A0),
+                    src: map_src_operand(&val),
+                });
+                instructions.push(asm::Instruction::Return);
+            }
+
+            ir::Instruction::Unary { op, dst, src } => {
+                let op = match op {
+                    ir::UnaryOperator::Complement => asm::UnaryOperator::Not,
+                    ir::UnaryOperator::Negate => asm::UnaryOperator::Negw,
+                    ir::UnaryOperator::Not => asm::UnaryOperator::Seqz,
+                };
+                instructions.push(asm::Instruction::Unary(
+                    op,
+                    asm::Operand::Pseudo(dst),
+                    map_src_operand(&src),
+                ));
+            }
+
+            ir::Instruction::Binary {
+                op,
+                dst,
+                src1,
+                src2,
+            } => {
+                let dst = asm::Operand::Pseudo(dst);
+                let src1 = map_src_operand(&src1);
+                let src2 = map_src_operand(&src2);
+
+                if is_relational(&op) {
+                    generate_relational(&op, dst, src1, src2, &mut instructions);
+                } else {
+                    instructions.push(asm::Instruction::Binary(map_binary(&op), dst, src1, src2));
+                }
+            }
+
+            ir::Instruction::Copy { src, dst } => {
+                instructions.push(asm::Instruction::Mov {
+                    dst: asm::Operand::Pseudo(dst),
+                    src: map_src_operand(&src),
+                });
+            }
+
+            ir::Instruction::Jump { target } => {
+                instructions.push(asm::Instruction::Jump(target));
+            }
+
+            ir::Instruction::JumpIfZero { condition, target } => {
+                instructions.push(asm::Instruction::BranchZero(
+                    map_src_operand(&condition),
+                    target,
+                ));
+            }
+
+            ir::Instruction::JumpIfNotZero { condition, target } => {
+                instructions.push(asm::Instruction::BranchNotZero(
+                    map_src_operand(&condition),
+                    target,
+                ));
+            }
+
+            ir::Instruction::Label(name) => {
+                instructions.push(asm::Instruction::Label(name));
+            }
+
+            // Debug info is only emitted for x86-64
+            ir::Instruction::Location(_) => {}
+        }
+    }
+
+    return instructions;
+}
@@ -0,0 +1,231 @@
+use super::asm::*;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+pub fn emit(program: Program) -> String {
+    let mut output = String::new();
+    emit_program(&program, &mut output);
+    return output;
+}
+
+fn emit_program(program: &Program, output: &mut String) {
+    writeln!(output, "\t.text").unwrap();
+    emit_function(&program.function, output);
+    writeln!(output, "\t.section .note.GNU-stack,\"\",@progbits").unwrap();
+}
+
+fn emit_function(function: &Function, output: &mut String) {
+    writeln!(output, "\t.globl {}", function.name).unwrap();
+    writeln!(output, "\t.type {}, @function", function.name).unwrap();
+    writeln!(output, "{}:", function.name).unwrap();
+
+    // Save the return address and the caller's frame pointer, and point
+    // `s0` at the top of the frame
+    writeln!(output, "\taddi sp, sp, -16").unwrap();
+    writeln!(output, "\tsd ra, 8(sp)").unwrap();
+    writeln!(output, "\tsd s0, 0(sp)").unwrap();
+    writeln!(output, "\taddi s0, sp, 16").unwrap();
+
+    let far = far_branches(&function.instructions);
+    for (instruction, far) in function.instructions.iter().zip(far) {
+        emit_instruction(instruction, far, output);
+    }
+
+    writeln!(output, "\t.size {}, .-{}", function.name, function.name).unwrap();
+}
+
+// `beqz` and `bnez` reach 4 KiB either way. Every instruction is given the
+// most bytes it can assemble to, so a branch whose target is in range here
+// is in range in the object too; the others are inverted around a `j`.
+fn far_branches(instructions: &[Instruction]) -> Vec<bool> {
+    let mut offsets = Vec::new();
+    let mut labels = HashMap::new();
+    let mut offset = 0;
+    for instruction in instructions {
+        offsets.push(offset);
+        if let Instruction::Label(label) = instruction {
+            labels.insert(label.as_str(), offset);
+        }
+        offset += size(instruction);
+    }
+
+    let mut far = Vec::new();
+    for (instruction, offset) in instructions.iter().zip(offsets) {
+        let target = match instruction {
+            Instruction::BranchZero(_, label) | Instruction::BranchNotZero(_, label) => label,
+            _ => {
+                far.push(false);
+                continue;
+            }
+        };
+        let distance = labels[target.as_str()] - offset;
+        far.push(!(-4096..4096).contains(&distance));
+    }
+    return far;
+}
+
+// The most bytes `instruction` is emitted as
+fn size(instruction: &Instruction) -> i64 {
+    match instruction {
+        Instruction::Label(_) => 0,
+        Instruction::Mov {
+            src: Operand::Immediate(value),
+            ..
+        } => load_immediate_size(*value),
+        Instruction::AllocateStack(bytes) if *bytes <= 2048 => 4,
+        Instruction::AllocateStack(bytes) => load_immediate_size(*bytes) + 4,
+        Instruction::Load(_, offset) | Instruction::Store(_, offset) if *offset < 2048 => 4,
+        Instruction::Load(_, offset) | Instruction::Store(_, offset) => {
+            load_immediate_size(*offset) + 8
+        }
+        Instruction::BranchZero(..) | Instruction::BranchNotZero(..) => 8,
+        Instruction::Return => 20,
+        _ => 4,
+    }
+}
+
+// `li` is `addi`, or `lui` and `addiw` for values beyond 12 bits
+fn load_immediate_size(value: i32) -> i64 {
+    if (-2048..2048).contains(&value) {
+        return 4;
+    }
+    return 8;
+}
+
+fn emit_instruction(instruction: &Instruction, far: bool, output: &mut String) {
+    match instruction {
+        Instruction::Mov {
+            dst,
+            src: Operand::Immediate(value),
+        } => writeln!(output, "\tli {}, {}", emit_operand(dst), value).unwrap(),
+
+        Instruction::Mov { dst, src } => {
+            writeln!(output, "\tmv {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+        }
+
+        Instruction::Return => {
+            writeln!(output).unwrap();
+            writeln!(output, "\taddi sp, s0, -16").unwrap();
+            writeln!(output, "\tld ra, 8(sp)").unwrap();
+            writeln!(output, "\tld s0, 0(sp)").unwrap();
+            writeln!(output, "\taddi sp, sp, 16").unwrap();
+            writeln!(output, "\tret").unwrap();
+        }
+
+        Instruction::Unary(op, dst, src) => {
+            let mnemonic = match op {
+                UnaryOperator::Not => "not",
+                UnaryOperator::Negw => "negw",
+                UnaryOperator::Seqz => "seqz",
+                UnaryOperator::Snez => "snez",
+            };
+            writeln!(
+                output,
+                "\t{} {}, {}",
+                mnemonic,
+                emit_operand(dst),
+                emit_operand(src)
+            )
+            .unwrap();
+        }
+
+        Instruction::AllocateStack(bytes) => {
+            if *bytes <= 2048 {
+                writeln!(output, "\taddi sp, sp, -{}", bytes).unwrap();
+            } else {
+                writeln!(output, "\tli t2, {}", bytes).unwrap();
+                writeln!(output, "\tsub sp, sp, t2").unwrap();
+            }
+            writeln!(output).unwrap();
+        }
+
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let mnemonic = match (op, rhs) {
+                (BinaryOperator::Addw, Operand::Immediate(_)) => "addiw",
+                (BinaryOperator::Addw, _) => "addw",
+                (BinaryOperator::Subw, _) => "subw",
+                (BinaryOperator::Mulw, _) => "mulw",
+                (BinaryOperator::Divw, _) => "divw",
+                (BinaryOperator::Remw, _) => "remw",
+                (BinaryOperator::And, Operand::Immediate(_)) => "andi",
+                (BinaryOperator::And, _) => "and",
+                (BinaryOperator::Or, Operand::Immediate(_)) => "ori",
+                (BinaryOperator::Or, _) => "or",
+                (BinaryOperator::Xor, Operand::Immediate(_)) => "xori",
+                (BinaryOperator::Xor, _) => "xor",
+                (BinaryOperator::Sllw, Operand::Immediate(_)) => "slliw",
+                (BinaryOperator::Sllw, _) => "sllw",
+                (BinaryOperator::Sraw, Operand::Immediate(_)) => "sraiw",
+                (BinaryOperator::Sraw, _) => "sraw",
+                (BinaryOperator::Slt, Operand::Immediate(_)) => "slti",
+                (BinaryOperator::Slt, _) => "slt",
+            };
+            writeln!(
+                output,
+                "\t{} {}, {}, {}",
+                mnemonic,
+                emit_operand(dst),
+                emit_operand(lhs),
+                emit_operand(rhs)
+            )
+            .unwrap();
+        }
+
+        Instruction::Jump(label) => writeln!(output, "\tj .L{}", label).unwrap(),
+
+        Instruction::BranchZero(op, label) if far => emit_far_branch("bnez", op, label, output),
+        Instruction::BranchZero(op, label) => {
+            writeln!(output, "\tbeqz {}, .L{}", emit_operand(op), label).unwrap()
+        }
+
+        Instruction::BranchNotZero(op, label) if far => emit_far_branch("beqz", op, label, output),
+        Instruction::BranchNotZero(op, label) => {
+            writeln!(output, "\tbnez {}, .L{}", emit_operand(op), label).unwrap()
+        }
+
+        Instruction::Label(label) => {
+            writeln!(output).unwrap();
+            writeln!(output, ".L{}:", label).unwrap()
+        }
+
+        Instruction::Load(reg, offset) => emit_memory("lw", reg, *offset, output),
+        Instruction::Store(reg, offset) => emit_memory("sw", reg, *offset, output),
+    }
+}
+
+// The inverted branch skips over a `j`, which reaches 1 MiB
+fn emit_far_branch(inverted: &str, op: &Operand, label: &str, output: &mut String) {
+    writeln!(output, "\t{} {}, 1f", inverted, emit_operand(op)).unwrap();
+    writeln!(output, "\tj .L{}", label).unwrap();
+    writeln!(output, "1:").unwrap();
+}
+
+// Loads and stores take a signed 12-bit offset; slots further up are
+// addressed through t2
+fn emit_memory(mnemonic: &str, reg: &Reg, offset: i32, output: &mut String) {
+    let reg = emit_register(reg);
+    if offset < 2048 {
+        writeln!(output, "\t{} {}, {}(sp)", mnemonic, reg, offset).unwrap();
+    } else {
+        writeln!(output, "\tli t2, {}", offset).unwrap();
+        writeln!(output, "\tadd t2, sp, t2").unwrap();
+        writeln!(output, "\t{} {}, 0(t2)", mnemonic, reg).unwrap();
+    }
+}
+
+fn emit_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => value.to_string(),
+        Operand::Register(reg) => emit_register(reg),
+        Operand::Stack(_) | Operand::Pseudo(_) => unreachable!("operand must be a register"),
+    }
+}
+
+pub fn emit_register(reg: &Reg) -> String {
+    match reg {
+        Reg::A0 => "a0",
+        Reg::T0 => "t0",
+        Reg::T1 => "t1",
+    }
+    .to_string()
+}
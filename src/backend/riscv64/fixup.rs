@@ -0,0 +1,173 @@
+use super::asm::*;
+use std::collections::HashMap;
+
+pub fn fixup(instructions: &mut Vec<Instruction>) {
+    let stack_size = replace_pseudos(instructions);
+
+    // The LP64 ABI keeps `sp` 16-byte aligned
+    let aligned = (stack_size + 15) & !15;
+    instructions.insert(0, Instruction::AllocateStack(aligned));
+
+    let mut legal = Vec::with_capacity(instructions.len());
+    for inst in instructions.drain(..) {
+        legalize(inst, &mut legal);
+    }
+    *instructions = legal;
+}
+
+// Every pseudo gets its own 4-byte slot above `sp`
+fn replace_pseudos(instructions: &mut [Instruction]) -> i32 {
+    let mut offsets: HashMap<String, i32> = HashMap::new();
+    let mut next = 0;
+
+    for inst in instructions.iter_mut() {
+        for operand in operands_mut(inst) {
+            if let Operand::Pseudo(name) = operand {
+                let offset = *offsets.entry(name.clone()).or_insert_with(|| {
+                    next += 4;
+                    next - 4
+                });
+                *operand = Operand::Stack(offset);
+            }
+        }
+    }
+
+    return next;
+}
+
+fn operands_mut(inst: &mut Instruction) -> Vec<&mut Operand> {
+    match inst {
+        Instruction::Mov { dst, src } => vec![dst, src],
+        Instruction::Unary(_, dst, src) => vec![dst, src],
+        Instruction::Binary(_, dst, lhs, rhs) => vec![dst, lhs, rhs],
+        Instruction::BranchZero(op, _) | Instruction::BranchNotZero(op, _) => vec![op],
+        _ => vec![],
+    }
+}
+
+fn is_small_immediate(value: i32) -> bool {
+    return (-2048..2048).contains(&value);
+}
+
+// Turns the right operand into the immediate form of the instruction when
+// there is one that fits. `sub` has none, but adding the negation does.
+fn immediate_form(op: BinaryOperator, rhs: &Operand) -> Option<(BinaryOperator, Operand)> {
+    let Operand::Immediate(value) = *rhs else {
+        return None;
+    };
+
+    match op {
+        BinaryOperator::Subw if value != i32::MIN && is_small_immediate(-value) => {
+            Some((BinaryOperator::Addw, Operand::Immediate(-value)))
+        }
+        BinaryOperator::Addw
+        | BinaryOperator::And
+        | BinaryOperator::Or
+        | BinaryOperator::Xor
+        | BinaryOperator::Slt
+            if is_small_immediate(value) =>
+        {
+            Some((op, Operand::Immediate(value)))
+        }
+        BinaryOperator::Sllw | BinaryOperator::Sraw if (0..32).contains(&value) => {
+            Some((op, Operand::Immediate(value)))
+        }
+        _ => None,
+    }
+}
+
+// RISC-V is a load/store architecture: operands in stack slots are loaded
+// into T0 and T1 first, results are computed into T0 and stored, and
+// immediates without a matching instruction form are loaded with `li`.
+fn legalize(inst: Instruction, out: &mut Vec<Instruction>) {
+    let mut scratch = [Reg::T0, Reg::T1].into_iter();
+
+    let mut load = |operand: Operand, out: &mut Vec<Instruction>| -> Operand {
+        match operand {
+            Operand::Stack(offset) => {
+                let reg = scratch.next().unwrap();
+                out.push(Instruction::Load(reg, offset));
+                return Operand::Register(reg);
+            }
+            Operand::Immediate(_) => {
+                let reg = scratch.next().unwrap();
+                out.push(Instruction::Mov {
+                    dst: Operand::Register(reg),
+                    src: operand,
+                });
+                return Operand::Register(reg);
+            }
+            _ => return operand,
+        }
+    };
+
+    // Where the result goes, and the store that puts it in its slot
+    let result = |operand: Operand| -> (Operand, Option<Instruction>) {
+        match operand {
+            Operand::Stack(offset) => (
+                Operand::Register(Reg::T0),
+                Some(Instruction::Store(Reg::T0, offset)),
+            ),
+            _ => (operand, None),
+        }
+    };
+
+    let store = match inst {
+        Instruction::Mov { dst, src } => match (dst, src) {
+            (Operand::Stack(offset), src) => {
+                let Operand::Register(reg) = load(src, out) else {
+                    unreachable!()
+                };
+                out.push(Instruction::Store(reg, offset));
+                None
+            }
+            (Operand::Register(reg), Operand::Stack(offset)) => {
+                out.push(Instruction::Load(reg, offset));
+                None
+            }
+            (dst, src) => {
+                out.push(Instruction::Mov { dst, src });
+                None
+            }
+        },
+
+        Instruction::Unary(op, dst, src) => {
+            let src = load(src, out);
+            let (dst, store) = result(dst);
+            out.push(Instruction::Unary(op, dst, src));
+            store
+        }
+
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let lhs = load(lhs, out);
+            let (op, rhs) = match immediate_form(op.clone(), &rhs) {
+                Some(form) => form,
+                None => (op, load(rhs, out)),
+            };
+            let (dst, store) = result(dst);
+            out.push(Instruction::Binary(op, dst, lhs, rhs));
+            store
+        }
+
+        Instruction::BranchZero(op, target) => {
+            let op = load(op, out);
+            out.push(Instruction::BranchZero(op, target));
+            None
+        }
+
+        Instruction::BranchNotZero(op, target) => {
+            let op = load(op, out);
+            out.push(Instruction::BranchNotZero(op, target));
+            None
+        }
+
+        other => {
+            out.push(other);
+            None
+        }
+    };
+
+    if let Some(store) = store {
+        out.push(store);
+    }
+}
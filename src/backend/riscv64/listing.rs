@@ -0,0 +1,95 @@
+// The selected instructions as a listing, with the mnemonics and registers
+// that emission prints for them
+
+use super::asm::*;
+use super::emit::emit_register;
+use crate::dump::Dump;
+use crate::dump::listing::{self, Line, Operand as Item};
+
+pub fn dump(function: &str, instructions: &[Instruction]) -> Dump {
+    let lines: Vec<Line> = instructions.iter().map(line).collect();
+    return listing::listing(function, &lines);
+}
+
+fn line(instruction: &Instruction) -> Line {
+    let (opcode, operands) = match instruction {
+        Instruction::Label(label) => return Line::Label(label.clone()),
+
+        Instruction::Mov {
+            dst,
+            src: src @ Operand::Immediate(_),
+        } => ("li", vec![item(dst), item(src)]),
+        Instruction::Mov { dst, src } => ("mv", vec![item(dst), item(src)]),
+        Instruction::Unary(op, dst, src) => {
+            let opcode = match op {
+                UnaryOperator::Not => "not",
+                UnaryOperator::Negw => "negw",
+                UnaryOperator::Seqz => "seqz",
+                UnaryOperator::Snez => "snez",
+            };
+            (opcode, vec![item(dst), item(src)])
+        }
+        Instruction::Binary(op, dst, lhs, rhs) => {
+            let opcode = match (op, rhs) {
+                (BinaryOperator::Addw, Operand::Immediate(_)) => "addiw",
+                (BinaryOperator::Addw, _) => "addw",
+                (BinaryOperator::Subw, _) => "subw",
+                (BinaryOperator::Mulw, _) => "mulw",
+                (BinaryOperator::Divw, _) => "divw",
+                (BinaryOperator::Remw, _) => "remw",
+                (BinaryOperator::And, Operand::Immediate(_)) => "andi",
+                (BinaryOperator::And, _) => "and",
+                (BinaryOperator::Or, Operand::Immediate(_)) => "ori",
+                (BinaryOperator::Or, _) => "or",
+                (BinaryOperator::Xor, Operand::Immediate(_)) => "xori",
+                (BinaryOperator::Xor, _) => "xor",
+                (BinaryOperator::Sllw, Operand::Immediate(_)) => "slliw",
+                (BinaryOperator::Sllw, _) => "sllw",
+                (BinaryOperator::Sraw, Operand::Immediate(_)) => "sraiw",
+                (BinaryOperator::Sraw, _) => "sraw",
+                (BinaryOperator::Slt, Operand::Immediate(_)) => "slti",
+                (BinaryOperator::Slt, _) => "slt",
+            };
+            (opcode, vec![item(dst), item(lhs), item(rhs)])
+        }
+        Instruction::Jump(label) => ("j", vec![Item::Symbol(label.clone())]),
+        Instruction::BranchZero(operand, label) => {
+            ("beqz", vec![item(operand), Item::Symbol(label.clone())])
+        }
+        Instruction::BranchNotZero(operand, label) => {
+            ("bnez", vec![item(operand), Item::Symbol(label.clone())])
+        }
+        Instruction::Load(reg, offset) => ("lw", vec![register(reg), slot(*offset)]),
+        Instruction::Store(reg, offset) => ("sw", vec![register(reg), slot(*offset)]),
+        Instruction::AllocateStack(bytes) => (
+            "addi",
+            vec![
+                Item::Register("sp".to_string()),
+                Item::Register("sp".to_string()),
+                Item::Immediate(-i64::from(*bytes)),
+            ],
+        ),
+        Instruction::Return => ("ret", Vec::new()),
+    };
+    return Line::Instruction(opcode.to_string(), operands);
+}
+
+fn item(operand: &Operand) -> Item {
+    return match operand {
+        Operand::Immediate(value) => Item::Immediate((*value).into()),
+        Operand::Register(reg) => register(reg),
+        Operand::Pseudo(name) => Item::Pseudo(name.clone()),
+        Operand::Stack(offset) => slot(*offset),
+    };
+}
+
+fn register(reg: &Reg) -> Item {
+    return Item::Register(emit_register(reg));
+}
+
+fn slot(offset: i32) -> Item {
+    return Item::Memory {
+        base: "sp",
+        offset: offset.into(),
+    };
+}
@@ -0,0 +1,145 @@
+mod asm;
+mod att;
+mod cfi;
+mod codegen;
+mod debug;
+mod elf;
+mod emit;
+mod encode;
+mod fixup;
+mod jit;
+mod link;
+mod listing;
+mod peephole;
+mod regalloc;
+mod strength;
+
+use super::Target;
+use crate::frontend::ir;
+use crate::{Artifact, Linker, RegAllocator, Stage, Syntax};
+
+use codegen::generate;
+use emit::emit;
+
+// What the triple's object format needs from the assembly. Mach-O puts an
+// underscore in front of C names and keeps labels local with `L`, while ELF
+// takes names as written and uses `.L`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectFormat {
+    MachO,
+    Elf,
+}
+
+impl ObjectFormat {
+    pub fn symbol(self, name: &str) -> String {
+        return match self {
+            ObjectFormat::MachO => format!("_{}", name),
+            ObjectFormat::Elf => name.to_string(),
+        };
+    }
+
+    pub fn local(self) -> &'static str {
+        return match self {
+            ObjectFormat::MachO => "L",
+            ObjectFormat::Elf => ".L",
+        };
+    }
+}
+
+pub struct X86_64 {
+    pub format: ObjectFormat,
+    pub allocator: RegAllocator,
+    pub peephole: bool,
+    pub linker: Linker,
+    pub syntax: Syntax,
+    // The source file to describe in debug info, with `-g`
+    pub debug: Option<String>,
+    pub frame_pointer: bool,
+}
+
+impl Target for X86_64 {
+    fn triple(&self) -> &'static str {
+        return match self.format {
+            ObjectFormat::MachO => "x86_64-apple-darwin",
+            ObjectFormat::Elf => "x86_64-linux-gnu",
+        };
+    }
+
+    fn compile(&self, ir_program: ir::Program, stage: Stage) -> Result<Artifact, String> {
+        return compile(self, ir_program, stage);
+    }
+
+    fn run(&self, ir_program: ir::Program) -> Result<i32, String> {
+        return run(self, ir_program);
+    }
+}
+
+fn compile(target: &X86_64, ir_program: ir::Program, stage: Stage) -> Result<Artifact, String> {
+    if stage == Stage::Codegen {
+        let name = ir_program.function.name.clone();
+        let instructions = codegen::select(ir_program.function);
+        return Ok(Artifact::Instructions(listing::dump(&name, &instructions)));
+    }
+
+    let assembly = select(target, ir_program);
+
+    // ELF objects are encoded directly, without going through assembly.
+    // Mach-O ones, and any with debug info, which the encoder cannot
+    // describe, are left to the system assembler
+    let integrated = target.format == ObjectFormat::Elf;
+    if stage == Stage::Object && integrated && target.debug.is_none() {
+        let object = encode::assemble(&assembly, target.format);
+        return Ok(Artifact::Object(elf::write(&object)));
+    }
+    if stage == Stage::Full && target.linker == Linker::Builtin {
+        if !integrated {
+            return Err(format!(
+                "The built-in linker makes Linux executables, not {} ones",
+                target.triple()
+            ));
+        }
+        let object = encode::assemble(&assembly, target.format);
+        return match link::link(&[object]) {
+            Ok(executable) => Ok(Artifact::Executable(executable)),
+            Err(e) => Err(format!("Link error: {}", e)),
+        };
+    }
+
+    // Debug info is gathered before emission consumes the function
+    let debug_info = target.debug.as_deref().map(|source| {
+        let mut sections = String::new();
+        debug::emit(&assembly.function, source, target.format, &mut sections);
+        return (debug::file(source), sections);
+    });
+
+    // Code Emission
+    let mut assembly_code = match target.syntax {
+        Syntax::Intel => emit(assembly, target.format),
+        Syntax::Att => att::emit(assembly, target.format),
+    };
+    if let Some((file, sections)) = debug_info {
+        assembly_code = file + &assembly_code + &sections;
+    }
+    // Without the note, the GNU linker makes the stack executable
+    if target.format == ObjectFormat::Elf {
+        assembly_code += "\t.section .note.GNU-stack,\"\",@progbits\n";
+    }
+    return Ok(Artifact::Assembly(assembly_code));
+}
+
+// Compiles the program into memory and runs it, returning the exit status
+fn run(target: &X86_64, ir_program: ir::Program) -> Result<i32, String> {
+    let assembly = select(target, ir_program);
+    let object = encode::assemble(&assembly, target.format);
+    let entry = target.format.symbol("main");
+    return jit::run(&object, &entry).map_err(|e| format!("Run error: {}", e));
+}
+
+// Code Generation
+fn select(target: &X86_64, ir_program: ir::Program) -> asm::Program {
+    let mut assembly = generate(ir_program, target.allocator, target.frame_pointer);
+    if target.peephole {
+        peephole::optimize(&mut assembly.function.instructions);
+    }
+    return assembly;
+}
@@ -0,0 +1,115 @@
+use crate::frontend::ir::Span;
+
+#[derive(Debug)]
+pub struct Program {
+    pub function: Function,
+}
+
+#[derive(Debug)]
+pub struct Function {
+    pub name: String,
+    pub instructions: Vec<Instruction>,
+    pub span: Span,
+    pub variables: Vec<Variable>,
+    // Whether the frame is kept in `rbp`, or stack slots are addressed from
+    // `rsp` with `-fomit-frame-pointer`
+    pub frame_pointer: bool,
+}
+
+// A source variable that lives in a stack slot, for debug info
+#[derive(Debug)]
+pub struct Variable {
+    pub name: String,
+    pub span: Span,
+    pub offset: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Move {
+        dst: Operand,
+        src: Operand,
+    },
+    Unary(UnaryOperator, Operand),
+    Binary(BinaryOperator, Operand, Operand),
+    Compare(Operand, Operand),
+    Jump(String),
+    JumpCondition(Condition, String),
+    SetCondition(Condition, Operand),
+    Label(String),
+    Division(Operand),
+    Multiply(Operand),
+    Lea {
+        dst: Operand,
+        base: Operand,
+        index: Operand,
+        scale: i32,
+    },
+    ConvertDQ,
+    AllocateStack(i32),
+    DeallocateStack(i32),
+    Push(Reg),
+    Pop(Reg),
+    Return,
+    // Source position of the instructions that follow, for `.loc`
+    Location(Span),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnaryOperator {
+    Not,
+    Neg,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryOperator {
+    Add,
+    Sub,
+    Mul,
+    And,
+    Or,
+    Xor,
+    Sal,
+    Sar,
+    Shr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Equal,
+    NotEqual,
+
+    Greater,
+    GreaterEqual,
+
+    Less,
+    LessEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Immediate(i32),
+    Register(Reg),
+    Pseudo(String),
+    Stack(i32),
+    // `[rsp + offset]`, for slots in a frame without a frame pointer
+    StackPointer(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Reg {
+    AX,
+    BX,
+    CX,
+    DX,
+    SI,
+    DI,
+    R8,
+    R9,
+    R10,
+    R11,
+    R12,
+    R13,
+    R14,
+    R15,
+}
@@ -0,0 +1,273 @@
+use super::ObjectFormat;
+use super::asm::*;
+use super::cfi::Frame;
+use std::fmt::Write;
+
+// AT&T syntax: source before destination, `%` registers, `$` immediates and
+// a size suffix on every mnemonic whose operands could be ambiguous
+pub fn emit(program: Program, format: ObjectFormat) -> String {
+    let mut output = String::new();
+    emit_function(&program.function, format, &mut output);
+    return output;
+}
+
+fn emit_function(function: &Function, format: ObjectFormat, output: &mut String) {
+    let symbol = format.symbol(&function.name);
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.text").unwrap();
+    }
+    writeln!(output, "\t.globl {}", symbol).unwrap();
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.type {}, @function", symbol).unwrap();
+    }
+    writeln!(output, "{}:", symbol).unwrap();
+
+    writeln!(output, "\t.cfi_startproc").unwrap();
+
+    if function.frame_pointer {
+        writeln!(output, "\tpushq %rbp").unwrap();
+        writeln!(output, "\t.cfi_def_cfa_offset 16").unwrap();
+        writeln!(output, "\t.cfi_offset %rbp, -16").unwrap();
+        writeln!(output, "\tmovq %rsp, %rbp").unwrap();
+        writeln!(output, "\t.cfi_def_cfa_register %rbp").unwrap();
+    }
+
+    let mut frame = Frame::new(function.frame_pointer);
+    for instruction in &function.instructions {
+        frame.before(instruction, output);
+        emit_instruction(instruction, &mut frame, format, output);
+    }
+
+    writeln!(output, "\t.cfi_endproc").unwrap();
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.size {}, .-{}", symbol, symbol).unwrap();
+    }
+}
+
+fn emit_instruction(
+    instruction: &Instruction,
+    frame: &mut Frame,
+    format: ObjectFormat,
+    output: &mut String,
+) {
+    let local = format.local();
+    write!(output, "\t").unwrap();
+
+    match instruction {
+        Instruction::Move { dst, src } => {
+            writeln!(output, "movl {}, {}", emit_operand(src), emit_operand(dst)).unwrap();
+        }
+
+        Instruction::Push(reg) => {
+            writeln!(output, "pushq {}", emit_quad_register(reg)).unwrap();
+            frame.push(&emit_quad_register(reg), output);
+        }
+        Instruction::Pop(reg) => {
+            writeln!(output, "popq {}", emit_quad_register(reg)).unwrap();
+            frame.pop(output);
+        }
+
+        Instruction::Return => {
+            writeln!(output).unwrap();
+            if frame.frame_pointer {
+                writeln!(output, "\tmovq %rbp, %rsp").unwrap();
+                writeln!(output, "\tpopq %rbp").unwrap();
+                writeln!(output, "\t.cfi_def_cfa %rsp, 8").unwrap();
+            }
+            writeln!(output, "\tret").unwrap();
+            frame.ret(output);
+        }
+
+        Instruction::Unary(unop, oper) => match unop {
+            UnaryOperator::Not => writeln!(output, "notl {}", emit_operand(oper)).unwrap(),
+            UnaryOperator::Neg => writeln!(output, "negl {}", emit_operand(oper)).unwrap(),
+        },
+
+        Instruction::AllocateStack(bytes) => {
+            writeln!(output, "subq ${}, %rsp", bytes).unwrap();
+            frame.allocate(*bytes, output);
+            writeln!(output).unwrap();
+        }
+
+        Instruction::DeallocateStack(bytes) => {
+            writeln!(output, "addq ${}, %rsp", bytes).unwrap();
+            frame.deallocate(*bytes, output);
+        }
+
+        Instruction::Binary(op, dst, src) => {
+            let mnemonic = match op {
+                BinaryOperator::Add => "addl",
+                BinaryOperator::Sub => "subl",
+                BinaryOperator::Mul => "imull",
+                BinaryOperator::And => "andl",
+                BinaryOperator::Or => "orl",
+                BinaryOperator::Xor => "xorl",
+                BinaryOperator::Sal => "sall",
+                BinaryOperator::Sar => "sarl",
+                BinaryOperator::Shr => "shrl",
+            };
+            let src = match op {
+                BinaryOperator::Sal | BinaryOperator::Sar | BinaryOperator::Shr => {
+                    emit_shift_count(src)
+                }
+                _ => emit_operand(src),
+            };
+            writeln!(output, "{} {}, {}", mnemonic, src, emit_operand(dst)).unwrap();
+        }
+
+        Instruction::Division(divisor) => {
+            writeln!(output, "idivl {}", emit_operand(divisor)).unwrap()
+        }
+
+        Instruction::Multiply(src) => writeln!(output, "imull {}", emit_operand(src)).unwrap(),
+
+        Instruction::Lea {
+            dst,
+            base,
+            index,
+            scale,
+        } => writeln!(
+            output,
+            "leal ({},{},{}), {}",
+            emit_address_register(base),
+            emit_address_register(index),
+            scale,
+            emit_operand(dst)
+        )
+        .unwrap(),
+
+        Instruction::ConvertDQ => writeln!(output, "cltd").unwrap(),
+
+        Instruction::Compare(dst, src) => {
+            writeln!(output, "cmpl {}, {}", emit_operand(src), emit_operand(dst)).unwrap()
+        }
+
+        Instruction::Jump(label) => writeln!(output, "jmp {}{}", local, label).unwrap(),
+
+        Instruction::JumpCondition(condition, label) => {
+            writeln!(output, "j{} {}{}", emit_condition(condition), local, label).unwrap()
+        }
+
+        Instruction::SetCondition(condition, dst) => {
+            writeln!(
+                output,
+                "set{} {}",
+                emit_condition(condition),
+                emit_one_byte_operand(dst)
+            )
+            .unwrap();
+        }
+
+        Instruction::Label(label) => {
+            writeln!(output).unwrap();
+            writeln!(output, "{}{}:", local, label).unwrap()
+        }
+
+        Instruction::Location(span) => {
+            writeln!(output, ".loc 1 {} {}", span.line, span.column).unwrap()
+        }
+    }
+}
+
+fn emit_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => format!("${}", value),
+
+        Operand::Register(reg) => match reg {
+            Reg::AX => "%eax",
+            Reg::BX => "%ebx",
+            Reg::CX => "%ecx",
+            Reg::DX => "%edx",
+            Reg::SI => "%esi",
+            Reg::DI => "%edi",
+            Reg::R8 => "%r8d",
+            Reg::R9 => "%r9d",
+            Reg::R10 => "%r10d",
+            Reg::R11 => "%r11d",
+            Reg::R12 => "%r12d",
+            Reg::R13 => "%r13d",
+            Reg::R14 => "%r14d",
+            Reg::R15 => "%r15d",
+        }
+        .to_string(),
+
+        Operand::Stack(value) => format!("{}(%rbp)", -value),
+        Operand::StackPointer(value) => format!("{}(%rsp)", value),
+        Operand::Pseudo(_value) => unreachable!(),
+    }
+}
+
+fn emit_one_byte_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => format!("${}", value),
+        Operand::Register(reg) => match reg {
+            Reg::AX => "%al",
+            Reg::BX => "%bl",
+            Reg::CX => "%cl",
+            Reg::DX => "%dl",
+            Reg::SI => "%sil",
+            Reg::DI => "%dil",
+            Reg::R8 => "%r8b",
+            Reg::R9 => "%r9b",
+            Reg::R10 => "%r10b",
+            Reg::R11 => "%r11b",
+            Reg::R12 => "%r12b",
+            Reg::R13 => "%r13b",
+            Reg::R14 => "%r14b",
+            Reg::R15 => "%r15b",
+        }
+        .to_string(),
+        Operand::Stack(value) => format!("{}(%rbp)", -value),
+        Operand::StackPointer(value) => format!("{}(%rsp)", value),
+        Operand::Pseudo(_value) => unreachable!(),
+    }
+}
+
+fn emit_address_register(operand: &Operand) -> String {
+    match operand {
+        Operand::Register(reg) => emit_quad_register(reg),
+        _ => unreachable!("address must be a register"),
+    }
+}
+
+fn emit_quad_register(reg: &Reg) -> String {
+    match reg {
+        Reg::AX => "%rax",
+        Reg::BX => "%rbx",
+        Reg::CX => "%rcx",
+        Reg::DX => "%rdx",
+        Reg::SI => "%rsi",
+        Reg::DI => "%rdi",
+        Reg::R8 => "%r8",
+        Reg::R9 => "%r9",
+        Reg::R10 => "%r10",
+        Reg::R11 => "%r11",
+        Reg::R12 => "%r12",
+        Reg::R13 => "%r13",
+        Reg::R14 => "%r14",
+        Reg::R15 => "%r15",
+    }
+    .to_string()
+}
+
+fn emit_shift_count(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => format!("${}", value),
+        Operand::Register(Reg::CX) => "%cl".to_string(),
+        _ => unreachable!("shift count must be immediate or cl"),
+    }
+}
+
+fn emit_condition(condition: &Condition) -> String {
+    match condition {
+        Condition::Equal => "e",
+        Condition::NotEqual => "ne",
+
+        Condition::Less => "l",
+        Condition::LessEqual => "le",
+
+        Condition::Greater => "g",
+        Condition::GreaterEqual => "ge",
+    }
+    .to_string()
+}
@@ -0,0 +1,71 @@
+// Call frame information, so debuggers, profilers and backtraces can unwind
+// through our functions. The CFA is the value `rsp` had before the call; the
+// `.cfi_*` directives say how to find it, and where the registers the
+// prologue pushed are saved, after each instruction that changes either.
+
+use super::asm::Instruction;
+use std::fmt::Write;
+
+pub struct Frame {
+    pub frame_pointer: bool,
+    // How far below the CFA `rsp` is
+    depth: i32,
+    // The depth an epilogue started from, restored after its `ret`
+    remembered: Option<i32>,
+}
+
+impl Frame {
+    // The state after the part of the prologue the emitter writes itself
+    pub fn new(frame_pointer: bool) -> Frame {
+        return Frame {
+            frame_pointer,
+            depth: if frame_pointer { 16 } else { 8 },
+            remembered: None,
+        };
+    }
+
+    // A `return` in the middle of a function is followed by code that still
+    // has the whole frame, so the state is saved before each epilogue
+    pub fn before(&mut self, instruction: &Instruction, output: &mut String) {
+        let epilogue = matches!(
+            instruction,
+            Instruction::Pop(_) | Instruction::DeallocateStack(_) | Instruction::Return
+        );
+        if epilogue && self.remembered.is_none() {
+            writeln!(output, "\t.cfi_remember_state").unwrap();
+            self.remembered = Some(self.depth);
+        }
+    }
+
+    pub fn allocate(&mut self, bytes: i32, output: &mut String) {
+        self.adjust(bytes, output);
+    }
+
+    pub fn deallocate(&mut self, bytes: i32, output: &mut String) {
+        self.adjust(-bytes, output);
+    }
+
+    pub fn push(&mut self, register: &str, output: &mut String) {
+        self.adjust(8, output);
+        writeln!(output, "\t.cfi_offset {}, {}", register, -self.depth).unwrap();
+    }
+
+    pub fn pop(&mut self, output: &mut String) {
+        self.adjust(-8, output);
+    }
+
+    pub fn ret(&mut self, output: &mut String) {
+        if let Some(depth) = self.remembered.take() {
+            writeln!(output, "\t.cfi_restore_state").unwrap();
+            self.depth = depth;
+        }
+    }
+
+    // With a frame pointer the CFA is found from `rbp`, which stays put
+    fn adjust(&mut self, bytes: i32, output: &mut String) {
+        self.depth += bytes;
+        if !self.frame_pointer {
+            writeln!(output, "\t.cfi_def_cfa_offset {}", self.depth).unwrap();
+        }
+    }
+}
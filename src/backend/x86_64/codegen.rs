@@ -0,0 +1,429 @@
+use super::asm;
+use super::fixup;
+use super::regalloc;
+use super::strength;
+use crate::RegAllocator;
+use crate::frontend::ir;
+use std::collections::{HashMap, HashSet};
+
+pub fn generate(
+    ir_program: ir::Program,
+    allocator: RegAllocator,
+    frame_pointer: bool,
+) -> asm::Program {
+    let function = generate_function(ir_program.function, allocator, frame_pointer);
+    return asm::Program { function };
+}
+
+// The instructions chosen for the IR, before registers are allocated and
+// fixup makes them legal, for `--codegen`
+pub fn select(ir_func: ir::Function) -> Vec<asm::Instruction> {
+    return generate_instruction(ir_func.body);
+}
+
+fn generate_function(
+    ir_func: ir::Function,
+    allocator: RegAllocator,
+    frame_pointer: bool,
+) -> asm::Function {
+    let name = ir_func.name;
+    let mut instructions = generate_instruction(ir_func.body);
+    let (callee_saved, registered) = regalloc::allocate(&mut instructions, allocator);
+    let slots = fixup::fixup(&mut instructions, &callee_saved, frame_pointer);
+
+    // Only variables that stay in their stack slot are described; the ones
+    // that spend time in a register, or were optimized away, are not
+    let variables = ir_func
+        .locals
+        .into_iter()
+        .filter(|local| !registered.contains(&local.name))
+        .filter_map(|local| {
+            let offset = *slots.get(&local.name)?;
+            let (name, _) = local.name.rsplit_once('.')?;
+            return Some(asm::Variable {
+                name: name.to_string(),
+                span: local.span,
+                offset,
+            });
+        })
+        .collect();
+
+    return asm::Function {
+        name,
+        instructions,
+        span: ir_func.span,
+        variables,
+        frame_pointer,
+    };
+}
+
+fn map_src_operand(src: &ir::Value) -> asm::Operand {
+    match src {
+        ir::Value::Constant(val) => asm::Operand::Immediate(*val),
+        ir::Value::Variable(var) => asm::Operand::Pseudo(var.clone()),
+    }
+}
+
+fn map_unary(op: &ir::UnaryOperator) -> asm::UnaryOperator {
+    match op {
+        ir::UnaryOperator::Complement => asm::UnaryOperator::Not,
+        ir::UnaryOperator::Negate => asm::UnaryOperator::Neg,
+        ir::UnaryOperator::Not => unreachable!(),
+    }
+}
+
+fn map_binary(op: &ir::BinaryOperator) -> asm::BinaryOperator {
+    match op {
+        ir::BinaryOperator::Add => asm::BinaryOperator::Add,
+        ir::BinaryOperator::Subtract => asm::BinaryOperator::Sub,
+        ir::BinaryOperator::Multiply => asm::BinaryOperator::Mul,
+        ir::BinaryOperator::BitwiseAnd => asm::BinaryOperator::And,
+        ir::BinaryOperator::BitwiseOr => asm::BinaryOperator::Or,
+        ir::BinaryOperator::BitwiseXor => asm::BinaryOperator::Xor,
+        ir::BinaryOperator::LeftShift => asm::BinaryOperator::Sal,
+        ir::BinaryOperator::RightShift => asm::BinaryOperator::Sar,
+        _ => unreachable!(),
+    }
+}
+
+fn map_binary_relational(op: &ir::BinaryOperator) -> asm::Condition {
+    match op {
+        ir::BinaryOperator::Equal => asm::Condition::Equal,
+        ir::BinaryOperator::NotEqual => asm::Condition::NotEqual,
+
+        ir::BinaryOperator::GreaterThan => asm::Condition::Greater,
+        ir::BinaryOperator::GreaterOrEqual => asm::Condition::GreaterEqual,
+
+        ir::BinaryOperator::LessThan => asm::Condition::Less,
+        ir::BinaryOperator::LessOrEqual => asm::Condition::LessEqual,
+        _ => unreachable!(),
+    }
+}
+
+fn invert_condition(condition: asm::Condition) -> asm::Condition {
+    match condition {
+        asm::Condition::Equal => asm::Condition::NotEqual,
+        asm::Condition::NotEqual => asm::Condition::Equal,
+
+        asm::Condition::Greater => asm::Condition::LessEqual,
+        asm::Condition::GreaterEqual => asm::Condition::Less,
+
+        asm::Condition::Less => asm::Condition::GreaterEqual,
+        asm::Condition::LessEqual => asm::Condition::Greater,
+    }
+}
+
+fn is_relational(op: &ir::BinaryOperator) -> bool {
+    matches!(
+        op,
+        ir::BinaryOperator::Equal
+            | ir::BinaryOperator::NotEqual
+            | ir::BinaryOperator::GreaterThan
+            | ir::BinaryOperator::GreaterOrEqual
+            | ir::BinaryOperator::LessThan
+            | ir::BinaryOperator::LessOrEqual
+    )
+}
+
+// A value is true when `cmp lhs, rhs` sets `condition`
+struct Test {
+    condition: asm::Condition,
+    lhs: ir::Value,
+    rhs: ir::Value,
+}
+
+fn count_uses(instructions: &[ir::Instruction]) -> HashMap<&String, usize> {
+    let mut uses: HashMap<&String, usize> = HashMap::new();
+
+    for inst in instructions {
+        let values = match inst {
+            ir::Instruction::Return(val) => vec![val],
+            ir::Instruction::Unary { src, .. } => vec![src],
+            ir::Instruction::Binary { src1, src2, .. } => vec![src1, src2],
+            ir::Instruction::Copy { src, .. } => vec![src],
+            ir::Instruction::JumpIfZero { condition, .. }
+            | ir::Instruction::JumpIfNotZero { condition, .. } => vec![condition],
+            _ => vec![],
+        };
+
+        for val in values {
+            if let ir::Value::Variable(name) = val {
+                *uses.entry(name).or_default() += 1;
+            }
+        }
+    }
+
+    return uses;
+}
+
+// Looks through the instructions right before `at` for the comparison that
+// produced `value`. A relational result or `!` that is used only there does
+// not need to be materialized with `setcc`; the comparison moves to the use,
+// flipping its condition for every `!` on the way.
+fn fold_condition(
+    instructions: &[ir::Instruction],
+    uses: &HashMap<&String, usize>,
+    at: usize,
+    value: &ir::Value,
+    folded: &mut HashSet<usize>,
+) -> Test {
+    let mut value = value.clone();
+    let mut negated = false;
+    let mut at = at;
+
+    while let ir::Value::Variable(name) = &value
+        && uses.get(name) == Some(&1)
+        && at > 0
+    {
+        match &instructions[at - 1] {
+            ir::Instruction::Binary {
+                op,
+                src1,
+                src2,
+                dst,
+            } if dst == name && is_relational(op) => {
+                folded.insert(at - 1);
+                let condition = map_binary_relational(op);
+                return Test {
+                    condition: if negated {
+                        invert_condition(condition)
+                    } else {
+                        condition
+                    },
+                    lhs: src1.clone(),
+                    rhs: src2.clone(),
+                };
+            }
+
+            ir::Instruction::Unary {
+                op: ir::UnaryOperator::Not,
+                src,
+                dst,
+            } if dst == name => {
+                folded.insert(at - 1);
+                negated = !negated;
+                value = src.clone();
+                at -= 1;
+            }
+
+            _ => break,
+        }
+    }
+
+    return Test {
+        condition: if negated {
+            asm::Condition::Equal
+        } else {
+            asm::Condition::NotEqual
+        },
+        lhs: value,
+        rhs: ir::Value::Constant(0),
+    };
+}
+
+// `cmp` cannot take an immediate as its first operand
+fn generate_compare(out: &mut Vec<asm::Instruction>, lhs: &ir::Value, rhs: &ir::Value) {
+    match map_src_operand(lhs) {
+        asm::Operand::Immediate(val) => {
+            out.push(asm::Instruction::Move {
+                dst: asm::Operand::Register(asm::Reg::R11),
+                src: asm::Operand::Immediate(val),
+            });
+
+            out.push(asm::Instruction::Compare(
+                asm::Operand::Register(asm::Reg::R11),
+                map_src_operand(rhs),
+            ));
+        }
+        lhs => {
+            out.push(asm::Instruction::Compare(lhs, map_src_operand(rhs)));
+        }
+    }
+}
+
+fn generate_instruction(instructions: Vec<ir::Instruction>) -> Vec<asm::Instruction> {
+    let mut out: Vec<asm::Instruction> = Vec::new();
+
+    // Conditional jumps and `!` test their operand directly; the comparisons
+    // folded into them are skipped
+    let uses = count_uses(&instructions);
+    let mut folded: HashSet<usize> = HashSet::new();
+    let mut tests: HashMap<usize, Test> = HashMap::new();
+    for (i, inst) in instructions.iter().enumerate() {
+        let value = match inst {
+            ir::Instruction::JumpIfZero { condition, .. }
+            | ir::Instruction::JumpIfNotZero { condition, .. } => condition,
+            ir::Instruction::Unary {
+                op: ir::UnaryOperator::Not,
+                src,
+                ..
+            } => src,
+            _ => continue,
+        };
+        tests.insert(
+            i,
+            fold_condition(&instructions, &uses, i, value, &mut folded),
+        );
+    }
+
+    for (i, inst) in instructions.iter().enumerate() {
+        if folded.contains(&i) {
+            continue;
+        }
+
+        match inst {
+            ir::Instruction::Return(value) => {
+                match value {
+                    ir::Value::Constant(val) => {
+                        out.push(asm::Instruction::Move {
+                            dst: asm::Operand::Register(asm::Reg::AX),
+                            src: asm::Operand::Immediate(*val),
+                        });
+                    }
+
+                    ir::Value::Variable(val) => {
+                        out.push(asm::Instruction::Move {
+                            dst: asm::Operand::Register(asm::Reg::AX),
+                            src: asm::Operand::Pseudo(val.clone()),
+                        });
+                    }
+                }
+
+                out.push(asm::Instruction::Return);
+            }
+
+            ir::Instruction::Unary { op, src, dst } => match op {
+                ir::UnaryOperator::Not => {
+                    let test = &tests[&i];
+                    generate_compare(&mut out, &test.lhs, &test.rhs);
+
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Pseudo(dst.clone()),
+                        src: asm::Operand::Immediate(0),
+                    });
+
+                    out.push(asm::Instruction::SetCondition(
+                        invert_condition(test.condition),
+                        asm::Operand::Pseudo(dst.clone()),
+                    ));
+                }
+                _ => {
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Pseudo(dst.clone()),
+                        src: map_src_operand(src),
+                    });
+
+                    out.push(asm::Instruction::Unary(
+                        map_unary(op),
+                        asm::Operand::Pseudo(dst.clone()),
+                    ));
+                }
+            },
+
+            ir::Instruction::Binary {
+                op,
+                src1,
+                src2,
+                dst,
+            } => match op {
+                // Multiply, Divide and Modulo by a constant
+                _ if let Some(reduced) = strength::reduce(
+                    op,
+                    map_src_operand(src1),
+                    map_src_operand(src2),
+                    asm::Operand::Pseudo(dst.clone()),
+                ) =>
+                {
+                    out.extend(reduced);
+                }
+
+                // Divide (/) and Modulo (%)
+                ir::BinaryOperator::Divide | ir::BinaryOperator::Modulo => {
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Register(asm::Reg::AX),
+                        src: map_src_operand(src1),
+                    });
+
+                    out.push(asm::Instruction::ConvertDQ);
+                    out.push(asm::Instruction::Division(map_src_operand(src2)));
+
+                    let result_reg = if matches!(op, ir::BinaryOperator::Divide) {
+                        asm::Reg::AX
+                    } else {
+                        asm::Reg::DX
+                    };
+
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Pseudo(dst.clone()),
+                        src: asm::Operand::Register(result_reg),
+                    });
+                }
+
+                // Eq, NotEq, Greater, Less. etc.
+                ir::BinaryOperator::Equal
+                | ir::BinaryOperator::NotEqual
+                | ir::BinaryOperator::GreaterThan
+                | ir::BinaryOperator::GreaterOrEqual
+                | ir::BinaryOperator::LessThan
+                | ir::BinaryOperator::LessOrEqual => {
+                    generate_compare(&mut out, src1, src2);
+
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Pseudo(dst.clone()),
+                        src: asm::Operand::Immediate(0),
+                    });
+
+                    out.push(asm::Instruction::SetCondition(
+                        map_binary_relational(op),
+                        asm::Operand::Pseudo(dst.clone()),
+                    ));
+                }
+
+                // Add, Sub, Mul, Bitwise
+                _ => {
+                    out.push(asm::Instruction::Move {
+                        dst: asm::Operand::Pseudo(dst.clone()),
+                        src: map_src_operand(src1),
+                    });
+
+                    out.push(asm::Instruction::Binary(
+                        map_binary(op),
+                        asm::Operand::Pseudo(dst.clone()),
+                        map_src_operand(src2),
+                    ));
+                }
+            },
+
+            ir::Instruction::JumpIfZero { target, .. } => {
+                let test = &tests[&i];
+                generate_compare(&mut out, &test.lhs, &test.rhs);
+
+                out.push(asm::Instruction::JumpCondition(
+                    invert_condition(test.condition),
+                    target.clone(),
+                ));
+            }
+
+            ir::Instruction::JumpIfNotZero { target, .. } => {
+                let test = &tests[&i];
+                generate_compare(&mut out, &test.lhs, &test.rhs);
+
+                out.push(asm::Instruction::JumpCondition(
+                    test.condition,
+                    target.clone(),
+                ));
+            }
+
+            ir::Instruction::Copy { src, dst } => out.push(asm::Instruction::Move {
+                dst: asm::Operand::Pseudo(dst.clone()),
+                src: map_src_operand(src),
+            }),
+
+            ir::Instruction::Label(ident) => out.push(asm::Instruction::Label(ident.clone())),
+            ir::Instruction::Jump { target } => out.push(asm::Instruction::Jump(target.clone())),
+            ir::Instruction::Location(span) => out.push(asm::Instruction::Location(*span)),
+        }
+    }
+
+    return out;
+}
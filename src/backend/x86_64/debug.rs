@@ -0,0 +1,234 @@
+// DWARF debug info for `-g`. The assembler builds the line table from the
+// `.file` and `.loc` directives; this adds the compile unit that points at
+// it, the function, and the variables that live in stack slots. Sections are
+// named for the target's object format, like the rest of the assembly.
+
+use super::ObjectFormat;
+use super::asm::*;
+use std::env;
+use std::fmt::Write;
+
+const TAG_COMPILE_UNIT: u8 = 0x11;
+const TAG_SUBPROGRAM: u8 = 0x2e;
+const TAG_VARIABLE: u8 = 0x34;
+const TAG_BASE_TYPE: u8 = 0x24;
+
+const AT_LOCATION: u8 = 0x02;
+const AT_NAME: u8 = 0x03;
+const AT_BYTE_SIZE: u8 = 0x0b;
+const AT_STMT_LIST: u8 = 0x10;
+const AT_LOW_PC: u8 = 0x11;
+const AT_HIGH_PC: u8 = 0x12;
+const AT_LANGUAGE: u8 = 0x13;
+const AT_COMP_DIR: u8 = 0x1b;
+const AT_PRODUCER: u8 = 0x25;
+const AT_DECL_FILE: u8 = 0x3a;
+const AT_DECL_LINE: u8 = 0x3b;
+const AT_ENCODING: u8 = 0x3e;
+const AT_EXTERNAL: u8 = 0x3f;
+const AT_FRAME_BASE: u8 = 0x40;
+const AT_TYPE: u8 = 0x49;
+
+const FORM_ADDR: u8 = 0x01;
+const FORM_DATA2: u8 = 0x05;
+const FORM_DATA4: u8 = 0x06;
+const FORM_STRING: u8 = 0x08;
+const FORM_DATA1: u8 = 0x0b;
+const FORM_UDATA: u8 = 0x0f;
+const FORM_REF4: u8 = 0x13;
+const FORM_SEC_OFFSET: u8 = 0x17;
+const FORM_EXPRLOC: u8 = 0x18;
+const FORM_FLAG_PRESENT: u8 = 0x19;
+
+const LANG_C99: u16 = 0x0c;
+const ATE_SIGNED: u8 = 0x05;
+const OP_FBREG: u8 = 0x91;
+const OP_REG6: u8 = 0x56; // rbp
+const OP_CALL_FRAME_CFA: u8 = 0x9c;
+
+// Abbreviation codes are the position in this table plus one. Each entry is
+// a tag, whether it has children, and its attributes with their forms.
+type Abbreviation = (u8, bool, &'static [(u8, u8)]);
+
+const ABBREVIATIONS: &[Abbreviation] = &[
+    (
+        TAG_COMPILE_UNIT,
+        true,
+        &[
+            (AT_PRODUCER, FORM_STRING),
+            (AT_LANGUAGE, FORM_DATA2),
+            (AT_NAME, FORM_STRING),
+            (AT_STMT_LIST, FORM_SEC_OFFSET),
+            (AT_COMP_DIR, FORM_STRING),
+            (AT_LOW_PC, FORM_ADDR),
+            (AT_HIGH_PC, FORM_DATA4),
+        ],
+    ),
+    (
+        TAG_SUBPROGRAM,
+        true,
+        &[
+            (AT_NAME, FORM_STRING),
+            (AT_DECL_FILE, FORM_DATA1),
+            (AT_DECL_LINE, FORM_UDATA),
+            (AT_TYPE, FORM_REF4),
+            (AT_EXTERNAL, FORM_FLAG_PRESENT),
+            (AT_LOW_PC, FORM_ADDR),
+            (AT_HIGH_PC, FORM_DATA4),
+            (AT_FRAME_BASE, FORM_EXPRLOC),
+        ],
+    ),
+    (
+        TAG_VARIABLE,
+        false,
+        &[
+            (AT_NAME, FORM_STRING),
+            (AT_DECL_FILE, FORM_DATA1),
+            (AT_DECL_LINE, FORM_UDATA),
+            (AT_TYPE, FORM_REF4),
+            (AT_LOCATION, FORM_EXPRLOC),
+        ],
+    ),
+    (
+        TAG_BASE_TYPE,
+        false,
+        &[
+            (AT_NAME, FORM_STRING),
+            (AT_ENCODING, FORM_DATA1),
+            (AT_BYTE_SIZE, FORM_DATA1),
+        ],
+    ),
+];
+
+fn abbreviation(tag: u8) -> usize {
+    return ABBREVIATIONS.iter().position(|a| a.0 == tag).unwrap() + 1;
+}
+
+// Names the source file for the `.loc` directives that follow
+pub fn file(source: &str) -> String {
+    return format!("\t.file 1 {}\n", quote(source));
+}
+
+// Appended after the function: marks its end and adds the debug sections
+pub fn emit(function: &Function, source: &str, format: ObjectFormat, output: &mut String) {
+    let symbol = format.symbol(&function.name);
+    let local = format.local();
+    writeln!(output, "{}func_end:", local).unwrap();
+
+    writeln!(output, "{}", section(format, "debug_abbrev")).unwrap();
+    writeln!(output, "{}abbrev_start:", local).unwrap();
+    for (code, (tag, children, attributes)) in ABBREVIATIONS.iter().enumerate() {
+        writeln!(output, "\t.uleb128 {}", code + 1).unwrap();
+        writeln!(output, "\t.uleb128 {}", tag).unwrap();
+        writeln!(output, "\t.byte {}", *children as u8).unwrap();
+        for (attribute, form) in attributes.iter() {
+            writeln!(output, "\t.uleb128 {}", attribute).unwrap();
+            writeln!(output, "\t.uleb128 {}", form).unwrap();
+        }
+        writeln!(output, "\t.byte 0, 0").unwrap();
+    }
+    writeln!(output, "\t.byte 0").unwrap();
+
+    let comp_dir = env::current_dir()
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_default();
+
+    // ELF refers to the abbreviations and the line table with
+    // section-relative relocations. Mach-O has none, but each is the only
+    // one in its section, so both are at 0 there
+    let (abbreviations, line_table) = match format {
+        ObjectFormat::Elf => {
+            writeln!(output, "{}", section(format, "debug_line")).unwrap();
+            writeln!(output, "{}line_table_start:", local).unwrap();
+            (
+                format!("{}abbrev_start", local),
+                format!("{}line_table_start", local),
+            )
+        }
+        ObjectFormat::MachO => ("0".to_string(), "0".to_string()),
+    };
+
+    writeln!(output, "{}", section(format, "debug_info")).unwrap();
+    writeln!(output, "{}info_start:", local).unwrap();
+    writeln!(output, "\t.long {0}info_end-{0}info_version", local).unwrap();
+    writeln!(output, "{}info_version:", local).unwrap();
+    writeln!(output, "\t.short 4").unwrap();
+    writeln!(output, "\t.long {}", abbreviations).unwrap();
+    writeln!(output, "\t.byte 8").unwrap();
+
+    writeln!(output, "\t.uleb128 {}", abbreviation(TAG_COMPILE_UNIT)).unwrap();
+    writeln!(output, "\t.asciz \"crucible 0.1.0\"").unwrap();
+    writeln!(output, "\t.short {}", LANG_C99).unwrap();
+    writeln!(output, "\t.asciz {}", quote(source)).unwrap();
+    writeln!(output, "\t.long {}", line_table).unwrap();
+    writeln!(output, "\t.asciz {}", quote(&comp_dir)).unwrap();
+    writeln!(output, "\t.quad {}", symbol).unwrap();
+    writeln!(output, "\t.long {}func_end-{}", local, symbol).unwrap();
+
+    writeln!(output, "\t.uleb128 {}", abbreviation(TAG_SUBPROGRAM)).unwrap();
+    writeln!(output, "\t.asciz {}", quote(&function.name)).unwrap();
+    writeln!(output, "\t.byte 1").unwrap();
+    writeln!(output, "\t.uleb128 {}", function.span.line).unwrap();
+    writeln!(output, "\t.long {0}type_int-{0}info_start", local).unwrap();
+    writeln!(output, "\t.quad {}", symbol).unwrap();
+    writeln!(output, "\t.long {}func_end-{}", local, symbol).unwrap();
+    writeln!(output, "\t.uleb128 1").unwrap();
+
+    // The frame base is `rbp`, so a slot is at a negative offset from it.
+    // Without a frame pointer it is the CFA, and slots are counted from
+    // the return address just below it.
+    let below_base = if function.frame_pointer {
+        writeln!(output, "\t.byte {}", OP_REG6).unwrap();
+        0
+    } else {
+        writeln!(output, "\t.byte {}", OP_CALL_FRAME_CFA).unwrap();
+        8
+    };
+
+    for variable in &function.variables {
+        let mut location = vec![OP_FBREG];
+        sleb128(-(variable.offset + below_base) as i64, &mut location);
+
+        writeln!(output, "\t.uleb128 {}", abbreviation(TAG_VARIABLE)).unwrap();
+        writeln!(output, "\t.asciz {}", quote(&variable.name)).unwrap();
+        writeln!(output, "\t.byte 1").unwrap();
+        writeln!(output, "\t.uleb128 {}", variable.span.line).unwrap();
+        writeln!(output, "\t.long {0}type_int-{0}info_start", local).unwrap();
+        writeln!(output, "\t.uleb128 {}", location.len()).unwrap();
+        let bytes: Vec<String> = location.iter().map(|b| b.to_string()).collect();
+        writeln!(output, "\t.byte {}", bytes.join(", ")).unwrap();
+    }
+    writeln!(output, "\t.byte 0").unwrap();
+
+    writeln!(output, "{}type_int:", local).unwrap();
+    writeln!(output, "\t.uleb128 {}", abbreviation(TAG_BASE_TYPE)).unwrap();
+    writeln!(output, "\t.asciz \"int\"").unwrap();
+    writeln!(output, "\t.byte {}", ATE_SIGNED).unwrap();
+    writeln!(output, "\t.byte 4").unwrap();
+
+    writeln!(output, "\t.byte 0").unwrap();
+    writeln!(output, "{}info_end:", local).unwrap();
+}
+
+fn section(format: ObjectFormat, name: &str) -> String {
+    return match format {
+        ObjectFormat::MachO => format!("\t.section __DWARF,__{},regular,debug", name),
+        ObjectFormat::Elf => format!("\t.section .{},\"\",@progbits", name),
+    };
+}
+
+fn sleb128(mut value: i64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        out.push(if done { byte } else { byte | 0x80 });
+        if done {
+            return;
+        }
+    }
+}
+
+fn quote(text: &str) -> String {
+    return format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+}
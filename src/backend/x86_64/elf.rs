@@ -0,0 +1,296 @@
+// Relocatable ELF64 object files for x86-64
+
+pub struct Object {
+    pub text: Vec<u8>,
+    pub symbols: Vec<Symbol>,
+    pub relocations: Vec<Relocation>,
+}
+
+// A global function defined in `.text`
+pub struct Symbol {
+    pub name: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+// A reference from `.text` to a symbol that may be defined elsewhere. The
+// symbols that no object defines become undefined symbols.
+pub struct Relocation {
+    pub offset: u64,
+    pub symbol: String,
+    pub kind: RelocationKind,
+    pub addend: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelocationKind {
+    // The 32-bit displacement of a `call`
+    Plt32,
+}
+
+impl RelocationKind {
+    fn code(self) -> u32 {
+        match self {
+            RelocationKind::Plt32 => 4,
+        }
+    }
+}
+
+// Executables are a single segment, headers included, loaded at a fixed
+// address. The code starts right after the ELF and program headers.
+pub const BASE_ADDRESS: u64 = 0x400000;
+pub const TEXT_OFFSET: u64 = 64 + 2 * 56;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const ET_REL: u16 = 1;
+const ET_EXEC: u16 = 2;
+
+const PT_LOAD: u32 = 1;
+const PT_GNU_STACK: u32 = 0x6474e551;
+const PF_X: u32 = 0x1;
+const PF_W: u32 = 0x2;
+const PF_R: u32 = 0x4;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+
+// Section header indices
+const TEXT: u16 = 1;
+const SYMTAB: u32 = 3;
+const STRTAB: u32 = 4;
+const SHSTRTAB: u16 = 5;
+
+struct StringTable {
+    bytes: Vec<u8>,
+}
+
+impl StringTable {
+    fn new() -> Self {
+        return StringTable { bytes: vec![0] };
+    }
+
+    fn add(&mut self, name: &str) -> u32 {
+        let offset = self.bytes.len() as u32;
+        self.bytes.extend(name.as_bytes());
+        self.bytes.push(0);
+        return offset;
+    }
+}
+
+#[derive(Default)]
+struct Section {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entry_size: u64,
+}
+
+// Layout: ELF header, section contents, then the section header table
+pub fn write(object: &Object) -> Vec<u8> {
+    let mut shstrtab = StringTable::new();
+    let mut strtab = StringTable::new();
+
+    // Locals come first: the null symbol and the `.text` section symbol
+    let mut symtab: Vec<u8> = Vec::new();
+    symbol(&mut symtab, 0, STB_LOCAL, STT_NOTYPE, 0, 0, 0);
+    symbol(&mut symtab, 0, STB_LOCAL, STT_SECTION, TEXT, 0, 0);
+    let first_global = 2;
+
+    let mut indices: Vec<String> = Vec::new();
+    for sym in &object.symbols {
+        let name = strtab.add(&sym.name);
+        symbol(
+            &mut symtab,
+            name,
+            STB_GLOBAL,
+            STT_FUNC,
+            TEXT,
+            sym.offset,
+            sym.size,
+        );
+        indices.push(sym.name.clone());
+    }
+    for reloc in &object.relocations {
+        if !indices.contains(&reloc.symbol) {
+            let name = strtab.add(&reloc.symbol);
+            symbol(&mut symtab, name, STB_GLOBAL, STT_NOTYPE, 0, 0, 0);
+            indices.push(reloc.symbol.clone());
+        }
+    }
+
+    let mut rela: Vec<u8> = Vec::new();
+    for reloc in &object.relocations {
+        let index = first_global + indices.iter().position(|s| *s == reloc.symbol).unwrap();
+        rela.extend(reloc.offset.to_le_bytes());
+        rela.extend(((index as u64) << 32 | reloc.kind.code() as u64).to_le_bytes());
+        rela.extend(reloc.addend.to_le_bytes());
+    }
+
+    let text = Section {
+        name: shstrtab.add(".text"),
+        kind: SHT_PROGBITS,
+        flags: SHF_ALLOC | SHF_EXECINSTR,
+        align: 16,
+        ..Default::default()
+    };
+    let rela_text = Section {
+        name: shstrtab.add(".rela.text"),
+        kind: SHT_RELA,
+        flags: SHF_INFO_LINK,
+        link: SYMTAB,
+        info: TEXT as u32,
+        align: 8,
+        entry_size: 24,
+        ..Default::default()
+    };
+    let symtab_section = Section {
+        name: shstrtab.add(".symtab"),
+        kind: SHT_SYMTAB,
+        link: STRTAB,
+        info: first_global as u32,
+        align: 8,
+        entry_size: 24,
+        ..Default::default()
+    };
+    let strtab_section = Section {
+        name: shstrtab.add(".strtab"),
+        kind: SHT_STRTAB,
+        align: 1,
+        ..Default::default()
+    };
+    let shstrtab_section = Section {
+        name: shstrtab.add(".shstrtab"),
+        kind: SHT_STRTAB,
+        align: 1,
+        ..Default::default()
+    };
+    // Marks the stack as non-executable
+    let note_gnu_stack = Section {
+        name: shstrtab.add(".note.GNU-stack"),
+        kind: SHT_PROGBITS,
+        align: 1,
+        ..Default::default()
+    };
+
+    let contents: [(Section, &[u8]); 6] = [
+        (text, &object.text),
+        (rela_text, &rela),
+        (symtab_section, &symtab),
+        (strtab_section, &strtab.bytes),
+        (shstrtab_section, &shstrtab.bytes),
+        (note_gnu_stack, &[]),
+    ];
+
+    let mut out = vec![0; 64];
+    let mut sections = vec![Section::default()];
+
+    for (mut section, bytes) in contents {
+        while !out.len().is_multiple_of(section.align as usize) {
+            out.push(0);
+        }
+        section.offset = out.len() as u64;
+        section.size = bytes.len() as u64;
+        out.extend(bytes);
+        sections.push(section);
+    }
+
+    while !out.len().is_multiple_of(8) {
+        out.push(0);
+    }
+    let section_headers = out.len() as u64;
+    for section in &sections {
+        out.extend(section.name.to_le_bytes());
+        out.extend(section.kind.to_le_bytes());
+        out.extend(section.flags.to_le_bytes());
+        out.extend(0u64.to_le_bytes()); // address
+        out.extend(section.offset.to_le_bytes());
+        out.extend(section.size.to_le_bytes());
+        out.extend(section.link.to_le_bytes());
+        out.extend(section.info.to_le_bytes());
+        out.extend(section.align.to_le_bytes());
+        out.extend(section.entry_size.to_le_bytes());
+    }
+
+    let header = header(ET_REL, 0, 0, section_headers, sections.len() as u16);
+    out[..64].copy_from_slice(&header);
+
+    return out;
+}
+
+// Layout: ELF header, a loadable segment and a non-executable stack, then
+// the code. There are no section headers.
+pub fn write_executable(text: &[u8], entry: u64) -> Vec<u8> {
+    let mut out = header(ET_EXEC, entry, 2, 0, 0);
+    let size = TEXT_OFFSET + text.len() as u64;
+    program_header(&mut out, PT_LOAD, PF_R | PF_X, BASE_ADDRESS, size, 0x1000);
+    program_header(&mut out, PT_GNU_STACK, PF_R | PF_W, 0, 0, 16);
+    debug_assert_eq!(out.len() as u64, TEXT_OFFSET);
+    out.extend(text);
+    return out;
+}
+
+fn header(
+    kind: u16,
+    entry: u64,
+    program_headers: u16,
+    section_headers: u64,
+    sections: u16,
+) -> Vec<u8> {
+    let mut header = Vec::with_capacity(64);
+    header.extend(b"\x7fELF");
+    header.extend([2, 1, 1, 0]); // 64-bit, little-endian, version 1, System V
+    header.extend([0; 8]);
+    header.extend(kind.to_le_bytes());
+    header.extend(62u16.to_le_bytes()); // x86-64
+    header.extend(1u32.to_le_bytes());
+    header.extend(entry.to_le_bytes());
+    let program_header_offset: u64 = if program_headers > 0 { 64 } else { 0 };
+    header.extend(program_header_offset.to_le_bytes());
+    header.extend(section_headers.to_le_bytes());
+    header.extend(0u32.to_le_bytes()); // flags
+    header.extend(64u16.to_le_bytes()); // header size
+    let program_header_size: u16 = if program_headers > 0 { 56 } else { 0 };
+    header.extend(program_header_size.to_le_bytes());
+    header.extend(program_headers.to_le_bytes());
+    header.extend(64u16.to_le_bytes()); // section header entry size
+    header.extend(sections.to_le_bytes());
+    let names: u16 = if sections > 0 { SHSTRTAB } else { 0 };
+    header.extend(names.to_le_bytes());
+    return header;
+}
+
+fn program_header(out: &mut Vec<u8>, kind: u32, flags: u32, address: u64, size: u64, align: u64) {
+    out.extend(kind.to_le_bytes());
+    out.extend(flags.to_le_bytes());
+    out.extend(0u64.to_le_bytes()); // from the start of the file
+    out.extend(address.to_le_bytes()); // virtual
+    out.extend(address.to_le_bytes()); // physical
+    out.extend(size.to_le_bytes()); // in the file
+    out.extend(size.to_le_bytes()); // in memory
+    out.extend(align.to_le_bytes());
+}
+
+fn symbol(out: &mut Vec<u8>, name: u32, bind: u8, kind: u8, section: u16, value: u64, size: u64) {
+    out.extend(name.to_le_bytes());
+    out.push(bind << 4 | kind);
+    out.push(0); // default visibility
+    out.extend(section.to_le_bytes());
+    out.extend(value.to_le_bytes());
+    out.extend(size.to_le_bytes());
+}
@@ -0,0 +1,297 @@
+use super::ObjectFormat;
+use super::asm::*;
+use super::cfi::Frame;
+use std::fmt::Write;
+
+pub fn emit(program: Program, format: ObjectFormat) -> String {
+    let mut output = String::new();
+    emit_program(&program, format, &mut output);
+    return output;
+}
+
+fn emit_program(program: &Program, format: ObjectFormat, output: &mut String) {
+    writeln!(output, "\t.intel_syntax noprefix").unwrap();
+    emit_function(&program.function, format, output);
+}
+
+fn emit_function(function: &Function, format: ObjectFormat, output: &mut String) {
+    let symbol = format.symbol(&function.name);
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.text").unwrap();
+    }
+    writeln!(output, "\t.globl {}", symbol).unwrap();
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.type {}, @function", symbol).unwrap();
+    }
+    writeln!(output, "{}:", symbol).unwrap();
+
+    writeln!(output, "\t.cfi_startproc").unwrap();
+
+    if function.frame_pointer {
+        writeln!(output, "\tpush rbp").unwrap();
+        writeln!(output, "\t.cfi_def_cfa_offset 16").unwrap();
+        writeln!(output, "\t.cfi_offset rbp, -16").unwrap();
+        writeln!(output, "\tmov rbp, rsp").unwrap();
+        writeln!(output, "\t.cfi_def_cfa_register rbp").unwrap();
+    }
+
+    let mut frame = Frame::new(function.frame_pointer);
+    for instruction in &function.instructions {
+        frame.before(instruction, output);
+        emit_instruction(instruction, &mut frame, format, output);
+    }
+
+    writeln!(output, "\t.cfi_endproc").unwrap();
+    if format == ObjectFormat::Elf {
+        writeln!(output, "\t.size {}, .-{}", symbol, symbol).unwrap();
+    }
+}
+
+fn emit_instruction(
+    instruction: &Instruction,
+    frame: &mut Frame,
+    format: ObjectFormat,
+    output: &mut String,
+) {
+    let local = format.local();
+    write!(output, "\t").unwrap();
+
+    match instruction {
+        Instruction::Move { dst, src } => {
+            writeln!(output, "mov {}, {}", emit_operand(dst), emit_operand(src)).unwrap();
+        }
+
+        Instruction::Push(reg) => {
+            writeln!(output, "push {}", emit_quad_register(reg)).unwrap();
+            frame.push(&emit_quad_register(reg), output);
+        }
+        Instruction::Pop(reg) => {
+            writeln!(output, "pop {}", emit_quad_register(reg)).unwrap();
+            frame.pop(output);
+        }
+
+        Instruction::Return => {
+            writeln!(output).unwrap();
+            if frame.frame_pointer {
+                writeln!(output, "\tmov rsp, rbp").unwrap();
+                writeln!(output, "\tpop rbp").unwrap();
+                writeln!(output, "\t.cfi_def_cfa rsp, 8").unwrap();
+            }
+            writeln!(output, "\tret").unwrap();
+            frame.ret(output);
+        }
+
+        Instruction::Unary(unop, oper) => match unop {
+            UnaryOperator::Not => writeln!(output, "not {}", emit_operand(oper)).unwrap(),
+            UnaryOperator::Neg => writeln!(output, "neg {}", emit_operand(oper)).unwrap(),
+        },
+
+        Instruction::AllocateStack(bytes) => {
+            writeln!(output, "sub rsp, {}", bytes).unwrap();
+            frame.allocate(*bytes, output);
+            writeln!(output).unwrap();
+        }
+
+        Instruction::DeallocateStack(bytes) => {
+            writeln!(output, "add rsp, {}", bytes).unwrap();
+            frame.deallocate(*bytes, output);
+        }
+
+        Instruction::Binary(op, dst, src) => match op {
+            BinaryOperator::Add => {
+                writeln!(output, "add {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::Sub => {
+                writeln!(output, "sub {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::Mul => {
+                writeln!(output, "imul {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::And => {
+                writeln!(output, "and {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::Or => {
+                writeln!(output, "or {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::Xor => {
+                writeln!(output, "xor {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+            }
+            BinaryOperator::Sal => writeln!(
+                output,
+                "sal {}, {}",
+                emit_operand(dst),
+                emit_shift_count(src)
+            )
+            .unwrap(),
+            BinaryOperator::Sar => writeln!(
+                output,
+                "sar {}, {}",
+                emit_operand(dst),
+                emit_shift_count(src)
+            )
+            .unwrap(),
+            BinaryOperator::Shr => writeln!(
+                output,
+                "shr {}, {}",
+                emit_operand(dst),
+                emit_shift_count(src)
+            )
+            .unwrap(),
+        },
+
+        Instruction::Division(divisor) => {
+            writeln!(output, "idiv {}", emit_operand(divisor)).unwrap()
+        }
+
+        Instruction::Multiply(src) => writeln!(output, "imul {}", emit_operand(src)).unwrap(),
+
+        Instruction::Lea {
+            dst,
+            base,
+            index,
+            scale,
+        } => writeln!(
+            output,
+            "lea {}, [{} + {}*{}]",
+            emit_operand(dst),
+            emit_address_register(base),
+            emit_address_register(index),
+            scale
+        )
+        .unwrap(),
+
+        Instruction::ConvertDQ => writeln!(output, "cdq").unwrap(),
+
+        Instruction::Compare(dst, src) => {
+            writeln!(output, "cmp {}, {}", emit_operand(dst), emit_operand(src)).unwrap()
+        }
+
+        Instruction::Jump(label) => writeln!(output, "jmp {}{}", local, label).unwrap(),
+
+        Instruction::JumpCondition(condition, label) => {
+            writeln!(output, "j{} {}{}", emit_condition(condition), local, label).unwrap()
+        }
+
+        Instruction::SetCondition(condition, dst) => {
+            writeln!(
+                output,
+                "set{} {}",
+                emit_condition(condition),
+                emit_one_byte_operand(dst)
+            )
+            .unwrap();
+        }
+
+        Instruction::Label(label) => {
+            writeln!(output).unwrap();
+            writeln!(output, "{}{}:", local, label).unwrap()
+        }
+
+        Instruction::Location(span) => {
+            writeln!(output, ".loc 1 {} {}", span.line, span.column).unwrap()
+        }
+    }
+}
+
+pub fn emit_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => value.to_string(),
+
+        Operand::Register(reg) => match reg {
+            Reg::AX => "eax",
+            Reg::BX => "ebx",
+            Reg::CX => "ecx",
+            Reg::DX => "edx",
+            Reg::SI => "esi",
+            Reg::DI => "edi",
+            Reg::R8 => "r8d",
+            Reg::R9 => "r9d",
+            Reg::R10 => "r10d",
+            Reg::R11 => "r11d",
+            Reg::R12 => "r12d",
+            Reg::R13 => "r13d",
+            Reg::R14 => "r14d",
+            Reg::R15 => "r15d",
+        }
+        .to_string(),
+
+        Operand::Stack(value) => format!("dword ptr [rbp - {}]", value),
+        Operand::StackPointer(value) => format!("dword ptr [rsp + {}]", value),
+        Operand::Pseudo(_value) => unreachable!(),
+    }
+}
+
+fn emit_one_byte_operand(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => value.to_string(),
+        Operand::Register(reg) => match reg {
+            Reg::AX => "al",
+            Reg::BX => "bl",
+            Reg::CX => "cl",
+            Reg::DX => "dl",
+            Reg::SI => "sil",
+            Reg::DI => "dil",
+            Reg::R8 => "r8b",
+            Reg::R9 => "r9b",
+            Reg::R10 => "r10b",
+            Reg::R11 => "r11b",
+            Reg::R12 => "r12b",
+            Reg::R13 => "r13b",
+            Reg::R14 => "r14b",
+            Reg::R15 => "r15b",
+        }
+        .to_string(),
+        Operand::Stack(value) => format!("byte ptr [rbp - {}]", value),
+        Operand::StackPointer(value) => format!("byte ptr [rsp + {}]", value),
+        Operand::Pseudo(_value) => unreachable!(),
+    }
+}
+
+fn emit_address_register(operand: &Operand) -> String {
+    match operand {
+        Operand::Register(reg) => emit_quad_register(reg),
+        _ => unreachable!("address must be a register"),
+    }
+}
+
+pub fn emit_quad_register(reg: &Reg) -> String {
+    match reg {
+        Reg::AX => "rax",
+        Reg::BX => "rbx",
+        Reg::CX => "rcx",
+        Reg::DX => "rdx",
+        Reg::SI => "rsi",
+        Reg::DI => "rdi",
+        Reg::R8 => "r8",
+        Reg::R9 => "r9",
+        Reg::R10 => "r10",
+        Reg::R11 => "r11",
+        Reg::R12 => "r12",
+        Reg::R13 => "r13",
+        Reg::R14 => "r14",
+        Reg::R15 => "r15",
+    }
+    .to_string()
+}
+
+fn emit_shift_count(operand: &Operand) -> String {
+    match operand {
+        Operand::Immediate(value) => value.to_string(),
+        Operand::Register(Reg::CX) => "cl".to_string(),
+        _ => unreachable!("shift count must be immediate or cl"),
+    }
+}
+
+pub fn emit_condition(condition: &Condition) -> String {
+    match condition {
+        Condition::Equal => "e",
+        Condition::NotEqual => "ne",
+
+        Condition::Less => "l",
+        Condition::LessEqual => "le",
+
+        Condition::Greater => "g",
+        Condition::GreaterEqual => "ge",
+    }
+    .to_string()
+}
@@ -0,0 +1,414 @@
+// x86-64 machine code encoding
+
+use super::ObjectFormat;
+use super::asm::*;
+use super::elf::{Object, Symbol};
+use std::collections::HashMap;
+
+const RSP: u8 = 4;
+const RBP: u8 = 5;
+
+// The symbol is spelled as the emitters spell it for the same format
+pub fn assemble(program: &Program, format: ObjectFormat) -> Object {
+    let function = &program.function;
+    let text = encode_function(function);
+
+    return Object {
+        symbols: vec![Symbol {
+            name: format.symbol(&function.name),
+            offset: 0,
+            size: text.len() as u64,
+        }],
+        text,
+        relocations: Vec::new(),
+    };
+}
+
+pub fn encode_function(function: &Function) -> Vec<u8> {
+    let instructions = &function.instructions;
+
+    // Everything but the jumps has a fixed encoding
+    let encoded: Vec<Vec<u8>> = instructions
+        .iter()
+        .map(|inst| encode(inst, function.frame_pointer))
+        .collect();
+
+    let labels: HashMap<&String, usize> = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, inst)| match inst {
+            Instruction::Label(label) => Some((label, i)),
+            _ => None,
+        })
+        .collect();
+
+    let target = |inst: &Instruction| -> Option<usize> {
+        match inst {
+            Instruction::Jump(label) | Instruction::JumpCondition(_, label) => Some(labels[label]),
+            _ => None,
+        }
+    };
+
+    // Branch relaxation: every jump starts out with a rel8 displacement and
+    // grows to rel32 when its target is out of reach. Growing a jump can only
+    // push other targets further away, so this settles.
+    let mut long = vec![false; instructions.len()];
+    let offsets = loop {
+        let mut offsets = Vec::with_capacity(instructions.len() + 1);
+        let mut offset = 0;
+        for (i, inst) in instructions.iter().enumerate() {
+            offsets.push(offset);
+            offset += match inst {
+                Instruction::Jump(_) if long[i] => 5,
+                Instruction::JumpCondition(..) if long[i] => 6,
+                Instruction::Jump(_) | Instruction::JumpCondition(..) => 2,
+                _ => encoded[i].len(),
+            };
+        }
+        offsets.push(offset);
+
+        let mut changed = false;
+        for (i, inst) in instructions.iter().enumerate() {
+            if let Some(t) = target(inst)
+                && !long[i]
+            {
+                let displacement = offsets[t] as i64 - offsets[i + 1] as i64;
+                if i8::try_from(displacement).is_err() {
+                    long[i] = true;
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            break offsets;
+        }
+    };
+
+    // push rbp; mov rbp, rsp
+    let mut out = if function.frame_pointer {
+        vec![0x55, 0x48, 0x89, 0xE5]
+    } else {
+        Vec::new()
+    };
+
+    for (i, inst) in instructions.iter().enumerate() {
+        let Some(t) = target(inst) else {
+            out.extend(&encoded[i]);
+            continue;
+        };
+
+        let displacement = offsets[t] as i64 - offsets[i + 1] as i64;
+        match (inst, long[i]) {
+            (Instruction::Jump(_), false) => out.push(0xEB),
+            (Instruction::Jump(_), true) => out.push(0xE9),
+            (Instruction::JumpCondition(condition, _), false) => {
+                out.push(0x70 | condition_code(condition))
+            }
+            (Instruction::JumpCondition(condition, _), true) => {
+                out.extend([0x0F, 0x80 | condition_code(condition)])
+            }
+            _ => unreachable!(),
+        }
+
+        if long[i] {
+            out.extend((displacement as i32).to_le_bytes());
+        } else {
+            out.push(displacement as i8 as u8);
+        }
+    }
+
+    return out;
+}
+
+fn encode(inst: &Instruction, frame_pointer: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    match inst {
+        Instruction::Move { dst, src } => match (dst, src) {
+            (Operand::Register(reg), Operand::Immediate(value)) => {
+                let n = number(*reg);
+                if n >= 8 {
+                    out.push(0x41);
+                }
+                out.push(0xB8 | (n & 7));
+                out.extend(value.to_le_bytes());
+            }
+            (_, Operand::Immediate(value)) => {
+                with_modrm(&mut out, &[0xC7], 0, dst, false);
+                out.extend(value.to_le_bytes());
+            }
+            (_, Operand::Register(reg)) => with_modrm(&mut out, &[0x89], number(*reg), dst, false),
+            (Operand::Register(reg), _) => with_modrm(&mut out, &[0x8B], number(*reg), src, false),
+            _ => unreachable!("move between two memory operands"),
+        },
+
+        Instruction::Unary(op, operand) => {
+            let extension = match op {
+                UnaryOperator::Not => 2,
+                UnaryOperator::Neg => 3,
+            };
+            with_modrm(&mut out, &[0xF7], extension, operand, false);
+        }
+
+        Instruction::Binary(BinaryOperator::Mul, dst, src) => {
+            let Operand::Register(reg) = dst else {
+                unreachable!("imul can only write to a register");
+            };
+            match src {
+                Operand::Immediate(value) => {
+                    immediate(&mut out, [0x6B, 0x69], number(*reg), dst, *value)
+                }
+                _ => with_modrm(&mut out, &[0x0F, 0xAF], number(*reg), src, false),
+            }
+        }
+
+        Instruction::Binary(
+            op @ (BinaryOperator::Sal | BinaryOperator::Sar | BinaryOperator::Shr),
+            dst,
+            count,
+        ) => {
+            let extension = match op {
+                BinaryOperator::Sal => 4,
+                BinaryOperator::Shr => 5,
+                _ => 7,
+            };
+            match count {
+                Operand::Immediate(1) => with_modrm(&mut out, &[0xD1], extension, dst, false),
+                Operand::Immediate(count) => {
+                    with_modrm(&mut out, &[0xC1], extension, dst, false);
+                    out.push(*count as u8);
+                }
+                _ => with_modrm(&mut out, &[0xD3], extension, dst, false),
+            }
+        }
+
+        Instruction::Binary(op, dst, src) => {
+            // The /digit extension of the immediate forms, and the opcodes of
+            // the two register forms
+            let (extension, to_rm, to_reg) = match op {
+                BinaryOperator::Add => (0, 0x01, 0x03),
+                BinaryOperator::Or => (1, 0x09, 0x0B),
+                BinaryOperator::And => (4, 0x21, 0x23),
+                BinaryOperator::Sub => (5, 0x29, 0x2B),
+                BinaryOperator::Xor => (6, 0x31, 0x33),
+                _ => unreachable!(),
+            };
+            arithmetic(&mut out, extension, to_rm, to_reg, dst, src);
+        }
+
+        Instruction::Compare(a, b) => arithmetic(&mut out, 7, 0x39, 0x3B, a, b),
+
+        Instruction::SetCondition(condition, dst) => {
+            with_modrm(
+                &mut out,
+                &[0x0F, 0x90 | condition_code(condition)],
+                0,
+                dst,
+                true,
+            );
+        }
+
+        Instruction::Division(operand) => with_modrm(&mut out, &[0xF7], 7, operand, false),
+        Instruction::Multiply(operand) => with_modrm(&mut out, &[0xF7], 5, operand, false),
+        Instruction::ConvertDQ => out.push(0x99),
+
+        Instruction::Lea {
+            dst,
+            base,
+            index,
+            scale,
+        } => {
+            let (Operand::Register(dst), Operand::Register(base), Operand::Register(index)) =
+                (dst, base, index)
+            else {
+                unreachable!("lea operands must be registers");
+            };
+            let (dst, base, index) = (number(*dst), number(*base), number(*index));
+
+            let rex = 0x40 | (dst >> 3) << 2 | (index >> 3) << 1 | base >> 3;
+            if rex != 0x40 {
+                out.push(rex);
+            }
+            out.push(0x8D);
+
+            // A base of rbp or r13 without displacement would mean "no base"
+            let needs_displacement = base & 7 == RBP;
+            let mode = if needs_displacement { 0x40 } else { 0x00 };
+            out.push(mode | (dst & 7) << 3 | 0b100);
+            out.push(scale_bits(*scale) << 6 | (index & 7) << 3 | base & 7);
+            if needs_displacement {
+                out.push(0);
+            }
+        }
+
+        // sub rsp, bytes
+        Instruction::AllocateStack(bytes) => adjust_rsp(&mut out, 5, *bytes),
+        // add rsp, bytes
+        Instruction::DeallocateStack(bytes) => adjust_rsp(&mut out, 0, *bytes),
+
+        Instruction::Push(reg) | Instruction::Pop(reg) => {
+            let n = number(*reg);
+            if n >= 8 {
+                out.push(0x41);
+            }
+            let opcode = if matches!(inst, Instruction::Push(_)) {
+                0x50
+            } else {
+                0x58
+            };
+            out.push(opcode | (n & 7));
+        }
+
+        // mov rsp, rbp; pop rbp; ret
+        Instruction::Return if frame_pointer => out.extend([0x48, 0x89, 0xEC, 0x5D, 0xC3]),
+        Instruction::Return => out.push(0xC3),
+
+        Instruction::Label(_) | Instruction::Jump(_) | Instruction::JumpCondition(..) => {}
+
+        // Only objects without debug info are encoded here
+        Instruction::Location(_) => {}
+    }
+
+    return out;
+}
+
+// Instructions in the add/or/and/sub/xor/cmp family
+fn arithmetic(
+    out: &mut Vec<u8>,
+    extension: u8,
+    to_rm: u8,
+    to_reg: u8,
+    dst: &Operand,
+    src: &Operand,
+) {
+    match (dst, src) {
+        (_, Operand::Immediate(value)) => immediate(out, [0x83, 0x81], extension, dst, *value),
+        (_, Operand::Register(reg)) => with_modrm(out, &[to_rm], number(*reg), dst, false),
+        (Operand::Register(reg), _) => with_modrm(out, &[to_reg], number(*reg), src, false),
+        _ => unreachable!("two memory operands"),
+    }
+}
+
+// Picks the sign-extended 8-bit immediate form when the value fits
+fn immediate(out: &mut Vec<u8>, opcodes: [u8; 2], reg: u8, rm: &Operand, value: i32) {
+    match i8::try_from(value) {
+        Ok(byte) => {
+            with_modrm(out, &[opcodes[0]], reg, rm, false);
+            out.push(byte as u8);
+        }
+        Err(_) => {
+            with_modrm(out, &[opcodes[1]], reg, rm, false);
+            out.extend(value.to_le_bytes());
+        }
+    }
+}
+
+fn adjust_rsp(out: &mut Vec<u8>, extension: u8, bytes: i32) {
+    let modrm = 0xC0 | extension << 3 | RSP;
+    match i8::try_from(bytes) {
+        Ok(byte) => out.extend([0x48, 0x83, modrm, byte as u8]),
+        Err(_) => {
+            out.extend([0x48, 0x81, modrm]);
+            out.extend(bytes.to_le_bytes());
+        }
+    }
+}
+
+// Emits the REX prefix, opcode and ModRM byte (plus displacement) for an
+// instruction whose r/m operand is `rm`. `reg` is a register number or the
+// opcode extension. Byte operations need a REX prefix to reach sil and dil.
+fn with_modrm(out: &mut Vec<u8>, opcode: &[u8], reg: u8, rm: &Operand, byte: bool) {
+    let mut rex = (reg >> 3) << 2;
+    if let Operand::Register(r) = rm {
+        let n = number(*r);
+        rex |= n >> 3;
+        if byte && (4..8).contains(&n) {
+            rex |= 0x40;
+        }
+    }
+    if rex != 0 {
+        out.push(0x40 | rex);
+    }
+
+    out.extend(opcode);
+
+    match rm {
+        Operand::Register(r) => out.push(0xC0 | (reg & 7) << 3 | number(*r) & 7),
+
+        // [rbp - offset]
+        Operand::Stack(offset) => match i8::try_from(-offset) {
+            Ok(displacement) => {
+                out.push(0x40 | (reg & 7) << 3 | RBP);
+                out.push(displacement as u8);
+            }
+            Err(_) => {
+                out.push(0x80 | (reg & 7) << 3 | RBP);
+                out.extend((-offset).to_le_bytes());
+            }
+        },
+
+        // [rsp + offset], which always needs a SIB byte. Unlike with rbp, a
+        // zero displacement can be left out.
+        Operand::StackPointer(0) => {
+            out.push((reg & 7) << 3 | 0b100);
+            out.push(0x24);
+        }
+        Operand::StackPointer(offset) => match i8::try_from(*offset) {
+            Ok(displacement) => {
+                out.push(0x40 | (reg & 7) << 3 | 0b100);
+                out.push(0x24);
+                out.push(displacement as u8);
+            }
+            Err(_) => {
+                out.push(0x80 | (reg & 7) << 3 | 0b100);
+                out.push(0x24);
+                out.extend(offset.to_le_bytes());
+            }
+        },
+
+        _ => unreachable!("operand cannot be addressed"),
+    }
+}
+
+fn number(reg: Reg) -> u8 {
+    match reg {
+        Reg::AX => 0,
+        Reg::CX => 1,
+        Reg::DX => 2,
+        Reg::BX => 3,
+        Reg::SI => 6,
+        Reg::DI => 7,
+        Reg::R8 => 8,
+        Reg::R9 => 9,
+        Reg::R10 => 10,
+        Reg::R11 => 11,
+        Reg::R12 => 12,
+        Reg::R13 => 13,
+        Reg::R14 => 14,
+        Reg::R15 => 15,
+    }
+}
+
+fn condition_code(condition: &Condition) -> u8 {
+    match condition {
+        Condition::Equal => 0x4,
+        Condition::NotEqual => 0x5,
+
+        Condition::Less => 0xC,
+        Condition::GreaterEqual => 0xD,
+
+        Condition::LessEqual => 0xE,
+        Condition::Greater => 0xF,
+    }
+}
+
+fn scale_bits(scale: i32) -> u8 {
+    match scale {
+        1 => 0,
+        2 => 1,
+        4 => 2,
+        8 => 3,
+        _ => unreachable!("invalid lea scale {}", scale),
+    }
+}
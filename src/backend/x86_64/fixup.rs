@@ -1,21 +1,98 @@
 use super::asm;
 use std::collections::HashMap;
 
-pub fn fixup(instructions: &mut Vec<asm::Instruction>) {
-    let stack_size = replace_pseudos(instructions);
+// Returns the stack slot of every pseudo that did not get a register
+pub fn fixup(
+    instructions: &mut Vec<asm::Instruction>,
+    callee_saved: &[asm::Reg],
+    frame_pointer: bool,
+) -> HashMap<String, i32> {
+    let (stack_size, stack_map) = replace_pseudos(instructions);
 
     fix_moves(instructions);
     fix_div_imm(instructions);
+    fix_multiply_imm(instructions);
+    fix_lea(instructions);
     fix_binary(instructions);
     fix_shifts(instructions);
     fix_multiply(instructions);
     fix_compares(instructions);
 
-    let aligned = (stack_size + 15) & !15;
+    // The saved registers are pushed below the locals. Together with the
+    // return address and the saved `rbp`, if any, they keep `rsp` 16-byte
+    // aligned.
+    let saved_size = 8 * callee_saved.len() as i32;
+    let linkage = if frame_pointer { 16 } else { 8 };
+    let aligned = ((stack_size + saved_size + linkage + 15) & !15) - saved_size - linkage;
     instructions.insert(0, asm::Instruction::AllocateStack(aligned));
+    let release = (!frame_pointer).then_some(aligned);
+    save_registers(instructions, callee_saved, release);
+
+    // Without `rbp`, a slot sits below where `rsp` was on entry, which is
+    // above everything the prologue pushed
+    if !frame_pointer {
+        address_from_rsp(instructions, aligned + saved_size);
+    }
+
+    return stack_map;
+}
+
+// `release` is the stack the epilogue frees after restoring the registers,
+// when there is no `mov rsp, rbp` to drop it
+fn save_registers(
+    instructions: &mut Vec<asm::Instruction>,
+    callee_saved: &[asm::Reg],
+    release: Option<i32>,
+) {
+    let mut out = Vec::with_capacity(instructions.len());
+
+    for inst in instructions.drain(..) {
+        if matches!(inst, asm::Instruction::Return) {
+            out.extend(
+                callee_saved
+                    .iter()
+                    .rev()
+                    .map(|reg| asm::Instruction::Pop(*reg)),
+            );
+            out.extend(release.map(asm::Instruction::DeallocateStack));
+        }
+
+        let is_prologue = matches!(inst, asm::Instruction::AllocateStack(_));
+        out.push(inst);
+
+        if is_prologue {
+            out.extend(callee_saved.iter().map(|reg| asm::Instruction::Push(*reg)));
+        }
+    }
+
+    *instructions = out;
+}
+
+fn address_from_rsp(instructions: &mut [asm::Instruction], frame_size: i32) {
+    for inst in instructions.iter_mut() {
+        let operands = match inst {
+            asm::Instruction::Move { dst, src }
+            | asm::Instruction::Binary(_, dst, src)
+            | asm::Instruction::Compare(dst, src) => vec![dst, src],
+
+            asm::Instruction::Unary(_, op)
+            | asm::Instruction::Division(op)
+            | asm::Instruction::Multiply(op)
+            | asm::Instruction::SetCondition(_, op)
+            | asm::Instruction::Lea { dst: op, .. } => vec![op],
+
+            _ => vec![],
+        };
+
+        for op in operands {
+            if let asm::Operand::Stack(offset) = op {
+                *op = asm::Operand::StackPointer(frame_size - *offset);
+            }
+        }
+    }
 }
 
-fn replace_pseudos(instructions: &mut Vec<asm::Instruction>) -> i32 {
+fn replace_pseudos(instructions: &mut [asm::Instruction]) -> (i32, HashMap<String, i32>) {
     let mut stack_map: HashMap<String, i32> = HashMap::new();
     let mut next_stack: i32 = 4;
 
@@ -35,10 +112,18 @@ fn replace_pseudos(instructions: &mut Vec<asm::Instruction>) -> i32 {
                 replace_operand(src2, &mut stack_map, &mut next_stack);
             }
 
-            asm::Instruction::Division(op) => {
+            asm::Instruction::Division(op) | asm::Instruction::Multiply(op) => {
                 replace_operand(op, &mut stack_map, &mut next_stack);
             }
 
+            asm::Instruction::Lea {
+                dst, base, index, ..
+            } => {
+                replace_operand(dst, &mut stack_map, &mut next_stack);
+                replace_operand(base, &mut stack_map, &mut next_stack);
+                replace_operand(index, &mut stack_map, &mut next_stack);
+            }
+
             asm::Instruction::Compare(dst, src) => {
                 replace_operand(dst, &mut stack_map, &mut next_stack);
                 replace_operand(src, &mut stack_map, &mut next_stack);
@@ -52,7 +137,7 @@ fn replace_pseudos(instructions: &mut Vec<asm::Instruction>) -> i32 {
         }
     }
 
-    next_stack - 4
+    (next_stack - 4, stack_map)
 }
 
 fn replace_operand(
@@ -141,6 +226,115 @@ fn fix_div_imm(instructions: &mut Vec<asm::Instruction>) {
     }
 }
 
+fn fix_multiply_imm(instructions: &mut Vec<asm::Instruction>) {
+    let mut i = 0;
+    while i < instructions.len() {
+        let needs_fix = if let asm::Instruction::Multiply(operand) = &instructions[i] {
+            matches!(operand, asm::Operand::Immediate(_))
+        } else {
+            false
+        };
+
+        if needs_fix {
+            let immediate = match &instructions[i] {
+                asm::Instruction::Multiply(asm::Operand::Immediate(val)) => *val,
+                _ => unreachable!(),
+            };
+
+            instructions[i] = asm::Instruction::Move {
+                dst: asm::Operand::Register(asm::Reg::R10),
+                src: asm::Operand::Immediate(immediate),
+            };
+
+            instructions.insert(
+                i + 1,
+                asm::Instruction::Multiply(asm::Operand::Register(asm::Reg::R10)),
+            );
+
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+// `lea` addresses through registers and can only write to a register
+fn fix_lea(instructions: &mut Vec<asm::Instruction>) {
+    let mut i = 0;
+    while i < instructions.len() {
+        let needs_fix = if let asm::Instruction::Lea {
+            dst, base, index, ..
+        } = &instructions[i]
+        {
+            !matches!(dst, asm::Operand::Register(_))
+                || !matches!(base, asm::Operand::Register(_))
+                || !matches!(index, asm::Operand::Register(_))
+        } else {
+            false
+        };
+
+        if needs_fix {
+            let (dst, base, index, scale) = match &instructions[i] {
+                asm::Instruction::Lea {
+                    dst,
+                    base,
+                    index,
+                    scale,
+                } => (dst.clone(), base.clone(), index.clone(), *scale),
+                _ => unreachable!(),
+            };
+
+            let mut fixed = Vec::new();
+
+            let base = if matches!(base, asm::Operand::Register(_)) {
+                base
+            } else {
+                fixed.push(asm::Instruction::Move {
+                    dst: asm::Operand::Register(asm::Reg::R10),
+                    src: base,
+                });
+                asm::Operand::Register(asm::Reg::R10)
+            };
+
+            let index = if matches!(index, asm::Operand::Register(_)) {
+                index
+            } else {
+                fixed.push(asm::Instruction::Move {
+                    dst: asm::Operand::Register(asm::Reg::R11),
+                    src: index,
+                });
+                asm::Operand::Register(asm::Reg::R11)
+            };
+
+            if matches!(dst, asm::Operand::Register(_)) {
+                fixed.push(asm::Instruction::Lea {
+                    dst,
+                    base,
+                    index,
+                    scale,
+                });
+            } else {
+                fixed.push(asm::Instruction::Lea {
+                    dst: asm::Operand::Register(asm::Reg::R11),
+                    base,
+                    index,
+                    scale,
+                });
+                fixed.push(asm::Instruction::Move {
+                    dst,
+                    src: asm::Operand::Register(asm::Reg::R11),
+                });
+            }
+
+            let count = fixed.len();
+            instructions.splice(i..i + 1, fixed);
+            i += count;
+        } else {
+            i += 1;
+        }
+    }
+}
+
 fn fix_binary(instructions: &mut Vec<asm::Instruction>) {
     let mut i = 0;
     while i < instructions.len() {
@@ -177,8 +371,10 @@ fn fix_shifts(instructions: &mut Vec<asm::Instruction>) {
     let mut i = 0;
     while i < instructions.len() {
         let needs_fix = if let asm::Instruction::Binary(op, _, src) = &instructions[i] {
-            matches!(op, asm::BinaryOperator::Sal | asm::BinaryOperator::Sar)
-                && !matches!(src, asm::Operand::Immediate(_))
+            matches!(
+                op,
+                asm::BinaryOperator::Sal | asm::BinaryOperator::Sar | asm::BinaryOperator::Shr
+            ) && !matches!(src, asm::Operand::Immediate(_))
         } else {
             false
         };
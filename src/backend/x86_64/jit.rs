@@ -0,0 +1,74 @@
+// Running encoded code in this process
+
+use super::elf::Object;
+
+// Copies the code into fresh pages, makes them executable instead of
+// writable, and calls `entry`, the symbol of `main`, on the current thread
+#[cfg(target_arch = "x86_64")]
+pub fn run(object: &Object, entry: &str) -> Result<i32, String> {
+    let size = object.text.len().max(1);
+
+    // SAFETY: a fresh anonymous mapping, owned by nothing else
+    let memory = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANON,
+            -1,
+            0,
+        )
+    };
+    if memory == libc::MAP_FAILED {
+        return Err(format!("mmap failed: {}", std::io::Error::last_os_error()));
+    }
+
+    let result = place_and_call(object, entry, memory as *mut u8, size);
+
+    // SAFETY: the mapping from above, which nothing refers to any more
+    unsafe { libc::munmap(memory, size) };
+    return result;
+}
+
+#[cfg(target_arch = "x86_64")]
+fn place_and_call(
+    object: &Object,
+    entry: &str,
+    memory: *mut u8,
+    size: usize,
+) -> Result<i32, String> {
+    let (text, addresses) = super::link::place(&[object], memory as u64)?;
+    let Some(&main) = addresses.get(entry) else {
+        return Err(format!("undefined reference to `{}`", entry));
+    };
+
+    // SAFETY: `text` is exactly as long as the code that the mapping was
+    // sized for, and the mapping is still writable
+    unsafe {
+        std::ptr::copy_nonoverlapping(text.as_ptr(), memory, text.len());
+        if libc::mprotect(
+            memory as *mut libc::c_void,
+            size,
+            libc::PROT_READ | libc::PROT_EXEC,
+        ) != 0
+        {
+            return Err(format!(
+                "mprotect failed: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+    }
+
+    // SAFETY: `main` follows the System V calling convention, saves the
+    // callee-saved registers it uses, and touches no memory but its frame
+    let status = unsafe {
+        let main: extern "C" fn() -> i32 = std::mem::transmute(main as usize);
+        main()
+    };
+    return Ok(status);
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+pub fn run(_object: &Object, _entry: &str) -> Result<i32, String> {
+    return Err("running in-process needs an x86-64 host".to_string());
+}
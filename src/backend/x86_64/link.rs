@@ -0,0 +1,165 @@
+// Static linking of our own objects into an executable, without libc
+
+use super::elf::{self, Object, Relocation, RelocationKind, Symbol};
+use std::collections::HashMap;
+
+const SYS_EXIT: u8 = 60;
+
+// The entry point calls `main` and passes its result to the `exit` system
+// call. The kernel starts `_start` with a 16-byte aligned stack, so the
+// `call` leaves it the way the ABI promises `main`.
+fn startup() -> Object {
+    let mut text = vec![0xE8, 0, 0, 0, 0]; // call main
+    text.extend([0x89, 0xC7]); // mov edi, eax
+    text.extend([0xB8, SYS_EXIT, 0, 0, 0]); // mov eax, SYS_EXIT
+    text.extend([0x0F, 0x05]); // syscall
+
+    return Object {
+        symbols: vec![Symbol {
+            name: "_start".to_string(),
+            offset: 0,
+            size: text.len() as u64,
+        }],
+        relocations: vec![Relocation {
+            offset: 1,
+            symbol: "main".to_string(),
+            kind: RelocationKind::Plt32,
+            addend: -4,
+        }],
+        text,
+    };
+}
+
+pub fn link(objects: &[Object]) -> Result<Vec<u8>, String> {
+    let startup = startup();
+    let objects: Vec<&Object> = std::iter::once(&startup).chain(objects).collect();
+
+    let (text, addresses) = place(&objects, elf::BASE_ADDRESS + elf::TEXT_OFFSET)?;
+    return Ok(elf::write_executable(&text, addresses["_start"]));
+}
+
+// Lays out the code of every object one after the other, as it will be
+// loaded at `address`, and patches the relocations. Returns the code and the
+// address of every global symbol.
+pub fn place<'a>(
+    objects: &[&'a Object],
+    address: u64,
+) -> Result<(Vec<u8>, HashMap<&'a str, u64>), String> {
+    let mut text: Vec<u8> = Vec::new();
+    let mut bases = Vec::new();
+    for object in objects {
+        while !text.len().is_multiple_of(16) {
+            text.push(0xCC); // int3
+        }
+        bases.push(address + text.len() as u64);
+        text.extend(&object.text);
+    }
+
+    let mut addresses: HashMap<&str, u64> = HashMap::new();
+    let mut errors = Vec::new();
+    for (object, base) in objects.iter().zip(&bases) {
+        for symbol in &object.symbols {
+            if addresses
+                .insert(&symbol.name, base + symbol.offset)
+                .is_some()
+            {
+                errors.push(format!("multiple definition of `{}`", symbol.name));
+            }
+        }
+    }
+
+    for (object, base) in objects.iter().zip(&bases) {
+        for reloc in &object.relocations {
+            let Some(target) = addresses.get(reloc.symbol.as_str()) else {
+                errors.push(format!(
+                    "undefined reference to `{}` in `{}`",
+                    reloc.symbol,
+                    containing(object, reloc.offset)
+                ));
+                continue;
+            };
+
+            let place = base + reloc.offset;
+            let value = match reloc.kind {
+                RelocationKind::Plt32 => *target as i64 + reloc.addend - place as i64,
+            };
+            let Ok(value) = i32::try_from(value) else {
+                errors.push(format!("relocation to `{}` out of range", reloc.symbol));
+                continue;
+            };
+
+            let at = (place - address) as usize;
+            text[at..at + 4].copy_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors.join("\n"));
+    }
+
+    return Ok((text, addresses));
+}
+
+// The name of the function a relocation is in, for error messages
+fn containing(object: &Object, offset: u64) -> &str {
+    return object
+        .symbols
+        .iter()
+        .find(|symbol| (symbol.offset..symbol.offset + symbol.size).contains(&offset))
+        .map_or("<unknown>", |symbol| symbol.name.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+
+    // `mov eax, 42` / `ret`
+    fn main() -> Object {
+        let text = vec![0xB8, 42, 0, 0, 0, 0xC3];
+        return Object {
+            symbols: vec![Symbol {
+                name: "main".to_string(),
+                offset: 0,
+                size: text.len() as u64,
+            }],
+            relocations: Vec::new(),
+            text,
+        };
+    }
+
+    // The startup object is the only one with a relocation, so writing it as
+    // a relocatable object and linking that with the system linker checks
+    // `.rela.text`
+    #[test]
+    fn relocations_in_objects() {
+        let directory = std::env::temp_dir().join(format!("crucible-link-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let (start, main_object) = (directory.join("start.o"), directory.join("main.o"));
+        fs::write(&start, elf::write(&startup())).unwrap();
+        fs::write(&main_object, elf::write(&main())).unwrap();
+
+        let executable = directory.join("program");
+        let linked = Command::new("ld")
+            .arg("-o")
+            .arg(&executable)
+            .args([&start, &main_object])
+            .status()
+            .expect("failed to run ld");
+        assert!(linked.success());
+        let status = Command::new(&executable).status().unwrap();
+        assert_eq!(status.code(), Some(42));
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn undefined_reference() {
+        assert_eq!(
+            link(&[]).unwrap_err(),
+            "undefined reference to `main` in `_start`"
+        );
+        assert!(link(&[main()]).unwrap().starts_with(b"\x7fELF"));
+    }
+}
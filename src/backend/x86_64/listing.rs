@@ -0,0 +1,111 @@
+// The selected instructions as a listing, with the mnemonics and registers
+// that emission prints for them
+
+use super::asm::*;
+use super::emit::{emit_condition, emit_operand, emit_quad_register};
+use crate::dump::Dump;
+use crate::dump::listing::{self, Line, Operand as Item};
+
+pub fn dump(function: &str, instructions: &[Instruction]) -> Dump {
+    let lines: Vec<Line> = instructions.iter().map(line).collect();
+    return listing::listing(function, &lines);
+}
+
+fn line(instruction: &Instruction) -> Line {
+    let (opcode, operands) = match instruction {
+        Instruction::Label(label) => return Line::Label(label.clone()),
+
+        Instruction::Move { dst, src } => ("mov".to_string(), vec![item(dst), item(src)]),
+        Instruction::Unary(op, operand) => {
+            let opcode = match op {
+                UnaryOperator::Not => "not",
+                UnaryOperator::Neg => "neg",
+            };
+            (opcode.to_string(), vec![item(operand)])
+        }
+        Instruction::Binary(op, dst, src) => {
+            let opcode = match op {
+                BinaryOperator::Add => "add",
+                BinaryOperator::Sub => "sub",
+                BinaryOperator::Mul => "imul",
+                BinaryOperator::And => "and",
+                BinaryOperator::Or => "or",
+                BinaryOperator::Xor => "xor",
+                BinaryOperator::Sal => "sal",
+                BinaryOperator::Sar => "sar",
+                BinaryOperator::Shr => "shr",
+            };
+            (opcode.to_string(), vec![item(dst), item(src)])
+        }
+        Instruction::Compare(lhs, rhs) => ("cmp".to_string(), vec![item(lhs), item(rhs)]),
+        Instruction::Jump(label) => ("jmp".to_string(), vec![Item::Symbol(label.clone())]),
+        Instruction::JumpCondition(condition, label) => (
+            format!("j{}", emit_condition(condition)),
+            vec![Item::Symbol(label.clone())],
+        ),
+        Instruction::SetCondition(condition, dst) => {
+            (format!("set{}", emit_condition(condition)), vec![item(dst)])
+        }
+        Instruction::Division(divisor) => ("idiv".to_string(), vec![item(divisor)]),
+        Instruction::Multiply(src) => ("imul".to_string(), vec![item(src)]),
+        Instruction::Lea {
+            dst,
+            base,
+            index,
+            scale,
+        } => {
+            let address = Item::Address {
+                base: listing::spell(&item(base)),
+                index: listing::spell(&item(index)),
+                scale: (*scale).into(),
+            };
+            ("lea".to_string(), vec![item(dst), address])
+        }
+        Instruction::ConvertDQ => ("cdq".to_string(), Vec::new()),
+        Instruction::AllocateStack(bytes) => (
+            "sub".to_string(),
+            vec![
+                Item::Register("rsp".to_string()),
+                Item::Immediate((*bytes).into()),
+            ],
+        ),
+        Instruction::DeallocateStack(bytes) => (
+            "add".to_string(),
+            vec![
+                Item::Register("rsp".to_string()),
+                Item::Immediate((*bytes).into()),
+            ],
+        ),
+        Instruction::Push(reg) => ("push".to_string(), vec![quad(reg)]),
+        Instruction::Pop(reg) => ("pop".to_string(), vec![quad(reg)]),
+        Instruction::Return => ("ret".to_string(), Vec::new()),
+        Instruction::Location(span) => (
+            ".loc".to_string(),
+            vec![
+                Item::Immediate(span.line.into()),
+                Item::Immediate(span.column.into()),
+            ],
+        ),
+    };
+    return Line::Instruction(opcode, operands);
+}
+
+fn item(operand: &Operand) -> Item {
+    return match operand {
+        Operand::Immediate(value) => Item::Immediate((*value).into()),
+        Operand::Register(_) => Item::Register(emit_operand(operand)),
+        Operand::Pseudo(name) => Item::Pseudo(name.clone()),
+        Operand::Stack(offset) => Item::Memory {
+            base: "rbp",
+            offset: -i64::from(*offset),
+        },
+        Operand::StackPointer(offset) => Item::Memory {
+            base: "rsp",
+            offset: (*offset).into(),
+        },
+    };
+}
+
+fn quad(reg: &Reg) -> Item {
+    return Item::Register(emit_quad_register(reg));
+}
@@ -0,0 +1,536 @@
+// Peephole optimization over the final instruction stream
+
+use super::asm::*;
+
+// A pattern looks at the instructions from the current position onwards and
+// may replace the first `window` of them. The rest is only there so that a
+// pattern can check that a register or the flags are dead afterwards.
+struct Pattern {
+    window: usize,
+    rewrite: fn(&[Instruction]) -> Option<Vec<Instruction>>,
+}
+
+// Tried in order at every position, until none of them applies anywhere
+const PATTERNS: &[Pattern] = &[
+    // mov x, x
+    Pattern {
+        window: 1,
+        rewrite: self_move,
+    },
+    // jmp L / L:
+    Pattern {
+        window: 1,
+        rewrite: jump_to_next,
+    },
+    // mov [s], r / mov r2, [s]
+    Pattern {
+        window: 2,
+        rewrite: store_reload,
+    },
+    // mov r, [s] / mov [s], r
+    Pattern {
+        window: 2,
+        rewrite: reload_store,
+    },
+    // mov [s], x / mov [s], y
+    Pattern {
+        window: 2,
+        rewrite: dead_store,
+    },
+    // mov r, [s] / add d, r
+    Pattern {
+        window: 2,
+        rewrite: fold_load,
+    },
+    // mov r, 0
+    Pattern {
+        window: 1,
+        rewrite: zero_with_xor,
+    },
+];
+
+pub fn optimize(instructions: &mut Vec<Instruction>) {
+    // Debug locations are set aside so that they never come between the
+    // instructions of a pattern. `locations[i]` holds the ones in front of
+    // instruction `i`, and the last entry the ones at the very end.
+    let mut locations: Vec<Vec<Instruction>> = vec![Vec::new()];
+    let mut code = Vec::with_capacity(instructions.len());
+    for inst in instructions.drain(..) {
+        if matches!(inst, Instruction::Location(_)) {
+            locations.last_mut().unwrap().push(inst);
+        } else {
+            code.push(inst);
+            locations.push(Vec::new());
+        }
+    }
+
+    rewrite(&mut code, &mut locations);
+
+    for (before, inst) in locations.iter_mut().zip(code) {
+        instructions.append(before);
+        instructions.push(inst);
+    }
+    instructions.append(locations.last_mut().unwrap());
+}
+
+fn rewrite(instructions: &mut Vec<Instruction>, locations: &mut Vec<Vec<Instruction>>) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        let mut i = 0;
+        while i < instructions.len() {
+            let rewritten = PATTERNS.iter().find_map(|pattern| {
+                if i + pattern.window > instructions.len() {
+                    return None;
+                }
+                let replacement = (pattern.rewrite)(&instructions[i..])?;
+                return Some((pattern.window, replacement));
+            });
+
+            match rewritten {
+                Some((window, replacement)) => {
+                    // The locations of a rewritten window go in front of
+                    // whatever takes its place
+                    let empty = std::iter::repeat_with(Vec::new).take(replacement.len());
+                    let moved: Vec<Instruction> =
+                        locations.splice(i..i + window, empty).flatten().collect();
+                    locations[i].splice(0..0, moved);
+
+                    instructions.splice(i..i + window, replacement);
+                    changed = true;
+                }
+                None => i += 1,
+            }
+        }
+    }
+}
+
+fn self_move(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    match &code[0] {
+        Instruction::Move { dst, src } if dst == src => Some(vec![]),
+        _ => None,
+    }
+}
+
+fn jump_to_next(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    let target = match &code[0] {
+        Instruction::Jump(target) | Instruction::JumpCondition(_, target) => target,
+        _ => return None,
+    };
+
+    let falls_into_target = code[1..]
+        .iter()
+        .map_while(|inst| match inst {
+            Instruction::Label(label) => Some(label),
+            _ => None,
+        })
+        .any(|label| label == target);
+
+    return falls_into_target.then(Vec::new);
+}
+
+// The value just stored is still in the register
+fn store_reload(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    match (&code[0], &code[1]) {
+        (
+            Instruction::Move {
+                dst: slot @ Operand::Stack(_),
+                src: stored @ Operand::Register(_),
+            },
+            Instruction::Move {
+                dst: loaded @ Operand::Register(_),
+                src: reloaded,
+            },
+        ) if reloaded == slot => Some(vec![
+            code[0].clone(),
+            Instruction::Move {
+                dst: loaded.clone(),
+                src: stored.clone(),
+            },
+        ]),
+        _ => None,
+    }
+}
+
+// The slot already holds the value that was loaded from it
+fn reload_store(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    match (&code[0], &code[1]) {
+        (
+            Instruction::Move {
+                dst: loaded @ Operand::Register(_),
+                src: slot @ Operand::Stack(_),
+            },
+            Instruction::Move { dst, src },
+        ) if dst == slot && src == loaded => Some(vec![code[0].clone()]),
+        _ => None,
+    }
+}
+
+fn dead_store(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    match (&code[0], &code[1]) {
+        (
+            Instruction::Move {
+                dst: first @ Operand::Stack(_),
+                ..
+            },
+            Instruction::Move { dst, src },
+        ) if dst == first && src != first => Some(vec![code[1].clone()]),
+        _ => None,
+    }
+}
+
+// Uses a stack slot or immediate directly as the source operand instead of
+// loading it into a register first, when nothing else reads that register
+fn fold_load(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    let Instruction::Move {
+        dst: Operand::Register(reg),
+        src: value,
+    } = &code[0]
+    else {
+        return None;
+    };
+
+    let in_register = Operand::Register(*reg);
+    let is_memory = matches!(value, Operand::Stack(_));
+    let is_immediate = matches!(value, Operand::Immediate(_));
+
+    // Only one of the two operands of an instruction may be in memory
+    let fits = |other: &Operand| match other {
+        Operand::Register(r) => r != reg,
+        Operand::Stack(_) => is_immediate,
+        _ => false,
+    };
+
+    let folded = match &code[1] {
+        Instruction::Binary(
+            op @ (BinaryOperator::Add
+            | BinaryOperator::Sub
+            | BinaryOperator::And
+            | BinaryOperator::Or
+            | BinaryOperator::Xor),
+            dst,
+            src,
+        ) if *src == in_register && fits(dst) => {
+            Instruction::Binary(op.clone(), dst.clone(), value.clone())
+        }
+
+        // `imul` can only write to a register
+        Instruction::Binary(BinaryOperator::Mul, dst @ Operand::Register(r), src)
+            if *src == in_register && r != reg =>
+        {
+            Instruction::Binary(BinaryOperator::Mul, dst.clone(), value.clone())
+        }
+
+        Instruction::Compare(lhs, rhs) if *rhs == in_register && fits(lhs) => {
+            Instruction::Compare(lhs.clone(), value.clone())
+        }
+
+        // `cmp` cannot take an immediate first operand
+        Instruction::Compare(lhs, rhs)
+            if *lhs == in_register
+                && is_memory
+                && !matches!(rhs, Operand::Stack(_))
+                && rhs != lhs =>
+        {
+            Instruction::Compare(value.clone(), rhs.clone())
+        }
+
+        Instruction::Division(src) if *src == in_register && is_memory => {
+            Instruction::Division(value.clone())
+        }
+
+        Instruction::Multiply(src) if *src == in_register && is_memory => {
+            Instruction::Multiply(value.clone())
+        }
+
+        _ => return None,
+    };
+
+    if !is_dead_after(&code[2..], *reg) {
+        return None;
+    }
+
+    return Some(vec![folded]);
+}
+
+// `xor` is shorter than `mov` with an immediate, but it clobbers the flags
+fn zero_with_xor(code: &[Instruction]) -> Option<Vec<Instruction>> {
+    match &code[0] {
+        Instruction::Move {
+            dst: reg @ Operand::Register(_),
+            src: Operand::Immediate(0),
+        } if flags_dead_after(&code[1..]) => Some(vec![Instruction::Binary(
+            BinaryOperator::Xor,
+            reg.clone(),
+            reg.clone(),
+        )]),
+        _ => None,
+    }
+}
+
+// Scans forward for the next read or write of `reg`. The fixup scratch
+// registers never carry a value from one block into another; any other
+// register is assumed live at the end of the block.
+fn is_dead_after(code: &[Instruction], reg: Reg) -> bool {
+    for inst in code {
+        if reads(inst, reg) {
+            return false;
+        }
+        if writes(inst, reg) {
+            return true;
+        }
+        match inst {
+            Instruction::Return => return true,
+            Instruction::Label(_) | Instruction::Jump(_) | Instruction::JumpCondition(..) => {
+                return matches!(reg, Reg::R10 | Reg::R11);
+            }
+            _ => {}
+        }
+    }
+    return true;
+}
+
+// The flags never live across a label or jump: every `setcc` and `jcc` comes
+// right after the `cmp` that sets them
+fn flags_dead_after(code: &[Instruction]) -> bool {
+    for inst in code {
+        match inst {
+            Instruction::SetCondition(..) | Instruction::JumpCondition(..) => return false,
+
+            // A shift by a count of zero leaves the flags alone
+            Instruction::Binary(
+                BinaryOperator::Sal | BinaryOperator::Sar | BinaryOperator::Shr,
+                _,
+                count,
+            ) => {
+                if matches!(count, Operand::Immediate(c) if c & 31 != 0) {
+                    return true;
+                }
+            }
+
+            Instruction::Compare(..)
+            | Instruction::Binary(..)
+            | Instruction::Unary(UnaryOperator::Neg, _)
+            | Instruction::Division(_)
+            | Instruction::Multiply(_)
+            | Instruction::Label(_)
+            | Instruction::Jump(_)
+            | Instruction::Return => return true,
+
+            _ => {}
+        }
+    }
+    return true;
+}
+
+fn reads(inst: &Instruction, reg: Reg) -> bool {
+    let operand = |op: &Operand| *op == Operand::Register(reg);
+
+    match inst {
+        Instruction::Move { src, .. } => operand(src),
+        Instruction::Unary(_, op) => operand(op),
+        Instruction::Binary(op, dst, src) => {
+            let count = matches!(
+                op,
+                BinaryOperator::Sal | BinaryOperator::Sar | BinaryOperator::Shr
+            ) && !matches!(src, Operand::Immediate(_))
+                && reg == Reg::CX;
+            operand(dst) || operand(src) || count
+        }
+        Instruction::Compare(a, b) => operand(a) || operand(b),
+        Instruction::SetCondition(_, dst) => operand(dst),
+        Instruction::Division(op) => operand(op) || matches!(reg, Reg::AX | Reg::DX),
+        Instruction::Multiply(op) => operand(op) || reg == Reg::AX,
+        Instruction::ConvertDQ => reg == Reg::AX,
+        Instruction::Lea { base, index, .. } => operand(base) || operand(index),
+        Instruction::Push(r) => *r == reg,
+        Instruction::Return => reg == Reg::AX,
+        _ => false,
+    }
+}
+
+fn writes(inst: &Instruction, reg: Reg) -> bool {
+    match inst {
+        Instruction::Move { dst, .. } | Instruction::Lea { dst, .. } => {
+            *dst == Operand::Register(reg)
+        }
+        Instruction::Division(_) | Instruction::Multiply(_) => matches!(reg, Reg::AX | Reg::DX),
+        Instruction::ConvertDQ => reg == Reg::DX,
+        Instruction::Pop(r) => *r == reg,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frontend::ir::Span;
+
+    fn mov(dst: Operand, src: Operand) -> Instruction {
+        return Instruction::Move { dst, src };
+    }
+
+    fn binary(op: BinaryOperator, dst: Operand, src: Operand) -> Instruction {
+        return Instruction::Binary(op, dst, src);
+    }
+
+    const EAX: Operand = Operand::Register(Reg::AX);
+    const ECX: Operand = Operand::Register(Reg::CX);
+    const EDX: Operand = Operand::Register(Reg::DX);
+    const R10: Operand = Operand::Register(Reg::R10);
+    const SLOT: Operand = Operand::Stack(-4);
+    const OTHER: Operand = Operand::Stack(-8);
+
+    #[test]
+    fn self_move() {
+        assert_eq!(super::self_move(&[mov(EAX, EAX)]), Some(vec![]));
+        assert_eq!(super::self_move(&[mov(EAX, ECX)]), None);
+    }
+
+    #[test]
+    fn jump_to_next() {
+        let label = |name: &str| Instruction::Label(name.to_string());
+        let jump = Instruction::Jump("end".to_string());
+        let branch = Instruction::JumpCondition(Condition::Equal, "end".to_string());
+
+        assert_eq!(
+            super::jump_to_next(&[jump.clone(), label("end")]),
+            Some(vec![])
+        );
+        // Through other labels, which jump nowhere
+        assert_eq!(
+            super::jump_to_next(&[branch, label("other"), label("end")]),
+            Some(vec![])
+        );
+        assert_eq!(
+            super::jump_to_next(&[jump.clone(), mov(EAX, ECX), label("end")]),
+            None
+        );
+        assert_eq!(super::jump_to_next(&[jump, label("other")]), None);
+    }
+
+    #[test]
+    fn store_reload() {
+        assert_eq!(
+            super::store_reload(&[mov(SLOT, EAX), mov(ECX, SLOT)]),
+            Some(vec![mov(SLOT, EAX), mov(ECX, EAX)])
+        );
+        assert_eq!(
+            super::store_reload(&[mov(SLOT, EAX), mov(ECX, OTHER)]),
+            None
+        );
+    }
+
+    #[test]
+    fn reload_store() {
+        assert_eq!(
+            super::reload_store(&[mov(EAX, SLOT), mov(SLOT, EAX)]),
+            Some(vec![mov(EAX, SLOT)])
+        );
+        assert_eq!(
+            super::reload_store(&[mov(EAX, SLOT), mov(OTHER, EAX)]),
+            None
+        );
+        assert_eq!(super::reload_store(&[mov(EAX, SLOT), mov(SLOT, ECX)]), None);
+    }
+
+    #[test]
+    fn dead_store() {
+        assert_eq!(
+            super::dead_store(&[mov(SLOT, EAX), mov(SLOT, ECX)]),
+            Some(vec![mov(SLOT, ECX)])
+        );
+        assert_eq!(super::dead_store(&[mov(SLOT, EAX), mov(OTHER, ECX)]), None);
+    }
+
+    #[test]
+    fn fold_load() {
+        assert_eq!(
+            super::fold_load(&[
+                mov(R10, SLOT),
+                binary(BinaryOperator::Add, EAX, R10),
+                Instruction::Return,
+            ]),
+            Some(vec![binary(BinaryOperator::Add, EAX, SLOT)])
+        );
+        assert_eq!(
+            super::fold_load(&[
+                mov(ECX, Operand::Immediate(3)),
+                Instruction::Compare(SLOT, ECX),
+                mov(ECX, EAX),
+            ]),
+            Some(vec![Instruction::Compare(SLOT, Operand::Immediate(3))])
+        );
+
+        // The register is still read afterwards
+        assert_eq!(
+            super::fold_load(&[
+                mov(ECX, SLOT),
+                binary(BinaryOperator::Add, EAX, ECX),
+                mov(EDX, ECX),
+            ]),
+            None
+        );
+        // Or it may be, past the end of the block
+        assert_eq!(
+            super::fold_load(&[
+                mov(ECX, SLOT),
+                binary(BinaryOperator::Add, EAX, ECX),
+                Instruction::Jump("end".to_string()),
+            ]),
+            None
+        );
+        // Two memory operands
+        assert_eq!(
+            super::fold_load(&[
+                mov(R10, SLOT),
+                binary(BinaryOperator::Add, OTHER, R10),
+                Instruction::Return,
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn zero_with_xor() {
+        let zero = mov(EAX, Operand::Immediate(0));
+        let compare = Instruction::Compare(ECX, Operand::Immediate(1));
+        assert_eq!(
+            super::zero_with_xor(&[zero.clone(), Instruction::Return]),
+            Some(vec![binary(BinaryOperator::Xor, EAX, EAX)])
+        );
+        // The flags are overwritten before anything reads them
+        assert_eq!(
+            super::zero_with_xor(&[zero.clone(), compare.clone()]),
+            Some(vec![binary(BinaryOperator::Xor, EAX, EAX)])
+        );
+
+        // The flags of an earlier `cmp` are still to be read
+        let set = Instruction::SetCondition(Condition::Equal, EAX);
+        assert_eq!(super::zero_with_xor(&[zero.clone(), set]), None);
+        let branch = Instruction::JumpCondition(Condition::Less, "end".to_string());
+        assert_eq!(super::zero_with_xor(&[zero.clone(), branch]), None);
+        // Only zero
+        assert_eq!(
+            super::zero_with_xor(&[mov(EAX, Operand::Immediate(1)), Instruction::Return]),
+            None
+        );
+    }
+
+    // The table runs to a fixed point, and debug locations stay in front of
+    // what replaced their window
+    #[test]
+    fn optimize() {
+        let location = Instruction::Location(Span { line: 2, column: 5 });
+        let mut code = vec![
+            location.clone(),
+            mov(SLOT, EAX),
+            mov(EAX, SLOT),
+            mov(ECX, ECX),
+            Instruction::Return,
+        ];
+        super::optimize(&mut code);
+        assert_eq!(code, [location, mov(SLOT, EAX), Instruction::Return]);
+    }
+}
@@ -0,0 +1,85 @@
+mod graph;
+mod linear;
+mod liveness;
+
+use super::asm::*;
+use crate::RegAllocator;
+use std::collections::HashSet;
+
+// AX, CX and DX are taken by `idiv`, `imul`, shifts and the return value,
+// and R10 and R11 are the fixup scratch registers. The caller-saved
+// registers come first so that small functions need no saves.
+const ALLOCATABLE: [Reg; 9] = [
+    Reg::SI,
+    Reg::DI,
+    Reg::R8,
+    Reg::R9,
+    Reg::BX,
+    Reg::R12,
+    Reg::R13,
+    Reg::R14,
+    Reg::R15,
+];
+
+const CALLEE_SAVED: [Reg; 5] = [Reg::BX, Reg::R12, Reg::R13, Reg::R14, Reg::R15];
+
+// Replaces the pseudos that got a register and returns the callee-saved
+// registers that were used, along with the pseudos that were in a register
+// anywhere. Spilled pseudos are left for `fixup` to place on the stack.
+pub fn allocate(
+    instructions: &mut Vec<Instruction>,
+    allocator: RegAllocator,
+) -> (Vec<Reg>, HashSet<String>) {
+    let registered = match allocator {
+        RegAllocator::None => return (Vec::new(), HashSet::new()),
+        RegAllocator::Linear => linear::allocate(instructions, &ALLOCATABLE),
+        RegAllocator::Graph => {
+            let assignment = graph::allocate(instructions, &ALLOCATABLE);
+            for inst in instructions.iter_mut() {
+                for operand in operands_mut(inst) {
+                    if let Operand::Pseudo(name) = operand
+                        && let Some(reg) = assignment.get(name)
+                    {
+                        *operand = Operand::Register(*reg);
+                    }
+                }
+            }
+            assignment.into_keys().collect()
+        }
+    };
+
+    // Coalesced moves are now moves of a register to itself
+    instructions.retain(|inst| {
+        !matches!(inst, Instruction::Move {
+            dst: Operand::Register(dst),
+            src: Operand::Register(src),
+        } if dst == src)
+    });
+
+    let mut used: Vec<Reg> = instructions
+        .iter_mut()
+        .flat_map(operands_mut)
+        .filter_map(|operand| match operand {
+            Operand::Register(reg) if CALLEE_SAVED.contains(reg) => Some(*reg),
+            _ => None,
+        })
+        .collect();
+    used.sort();
+    used.dedup();
+    return (used, registered);
+}
+
+fn operands_mut(inst: &mut Instruction) -> Vec<&mut Operand> {
+    match inst {
+        Instruction::Move { dst, src } => vec![dst, src],
+        Instruction::Unary(_, op) => vec![op],
+        Instruction::Binary(_, dst, src) => vec![dst, src],
+        Instruction::Compare(a, b) => vec![a, b],
+        Instruction::SetCondition(_, dst) => vec![dst],
+        Instruction::Division(op) | Instruction::Multiply(op) => vec![op],
+        Instruction::Lea {
+            dst, base, index, ..
+        } => vec![dst, base, index],
+        _ => vec![],
+    }
+}
@@ -0,0 +1,189 @@
+// Graph-coloring register allocation (Chaitin & Briggs)
+
+use super::liveness::{self, Liveness};
+use crate::backend::x86_64::asm::*;
+use std::collections::{HashMap, HashSet};
+
+struct Graph {
+    adjacent: Vec<HashSet<usize>>,
+    alias: Vec<usize>,
+    cost: Vec<f64>,
+    moves: Vec<(usize, usize)>,
+}
+
+pub fn allocate(instructions: &[Instruction], registers: &[Reg]) -> HashMap<String, Reg> {
+    let liveness = Liveness::analyze(instructions);
+    let mut graph = Graph::build(instructions, &liveness);
+    let k = registers.len();
+
+    graph.coalesce(k);
+    let colors = graph.color(registers);
+
+    let mut assignment = HashMap::new();
+    for (id, name) in liveness.names.iter().enumerate() {
+        if let Some(reg) = colors[graph.find(id)] {
+            assignment.insert(name.clone(), reg);
+        }
+    }
+
+    return assignment;
+}
+
+impl Graph {
+    // A definition interferes with everything live after it, except for the
+    // source of a move, which may share its register
+    fn build(instructions: &[Instruction], liveness: &Liveness) -> Self {
+        let count = liveness.names.len();
+        let mut graph = Graph {
+            adjacent: vec![HashSet::new(); count],
+            alias: (0..count).collect(),
+            cost: vec![0.0; count],
+            moves: Vec::new(),
+        };
+
+        for (i, inst) in instructions.iter().enumerate() {
+            let source = match inst {
+                Instruction::Move {
+                    dst: Operand::Pseudo(dst),
+                    src: Operand::Pseudo(src),
+                } => {
+                    let (dst, src) = (liveness.ids[dst], liveness.ids[src]);
+                    graph.moves.push((dst, src));
+                    Some(src)
+                }
+                _ => None,
+            };
+
+            for &d in &liveness.defs[i] {
+                for &l in &liveness.live_out[i] {
+                    if Some(l) != source {
+                        graph.add_edge(d, l);
+                    }
+                }
+            }
+        }
+
+        // Pseudos read before any definition all hold their values on entry
+        if let Some(entry) = liveness.live_in.first() {
+            for &a in entry {
+                for &b in entry {
+                    graph.add_edge(a, b);
+                }
+            }
+        }
+
+        // Each access costs more the deeper the loop it sits in
+        let depth = liveness::loop_depth(instructions);
+        for (i, depth) in depth.iter().enumerate() {
+            let weight = 10f64.powi((*depth).min(8) as i32);
+            for &v in liveness.uses[i].iter().chain(&liveness.defs[i]) {
+                graph.cost[v] += weight;
+            }
+        }
+
+        return graph;
+    }
+
+    fn add_edge(&mut self, a: usize, b: usize) {
+        if a != b {
+            self.adjacent[a].insert(b);
+            self.adjacent[b].insert(a);
+        }
+    }
+
+    fn find(&self, mut v: usize) -> usize {
+        while self.alias[v] != v {
+            v = self.alias[v];
+        }
+        return v;
+    }
+
+    // Briggs' conservative test: merging is safe when the combined node has
+    // fewer than k neighbors of significant degree, because it can then
+    // always be simplified
+    fn coalesce(&mut self, k: usize) {
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for m in 0..self.moves.len() {
+                let (a, b) = self.moves[m];
+                let (a, b) = (self.find(a), self.find(b));
+                if a == b || self.adjacent[a].contains(&b) {
+                    continue;
+                }
+
+                let significant = self.adjacent[a]
+                    .union(&self.adjacent[b])
+                    .filter(|n| self.adjacent[**n].len() >= k)
+                    .count();
+                if significant >= k {
+                    continue;
+                }
+
+                for n in std::mem::take(&mut self.adjacent[b]) {
+                    self.adjacent[n].remove(&b);
+                    self.add_edge(a, n);
+                }
+                self.alias[b] = a;
+                self.cost[a] += self.cost[b];
+                changed = true;
+            }
+        }
+    }
+
+    // Simplify nodes of degree below k onto a stack, picking the cheapest
+    // node to spill when none is left, then pop them and hand out registers.
+    // Spill candidates still get a register if their neighbors left one free.
+    fn color(&self, registers: &[Reg]) -> Vec<Option<Reg>> {
+        let count = self.adjacent.len();
+        let k = registers.len();
+
+        let mut degree: Vec<usize> = self.adjacent.iter().map(|adj| adj.len()).collect();
+        let mut removed: Vec<bool> = (0..count).map(|v| self.alias[v] != v).collect();
+        let mut stack = Vec::new();
+
+        while let Some(v) = (0..count)
+            .filter(|v| !removed[*v])
+            .find(|v| degree[*v] < k)
+            .or_else(|| {
+                (0..count).filter(|v| !removed[*v]).min_by(|a, b| {
+                    let a = self.cost[*a] / degree[*a] as f64;
+                    let b = self.cost[*b] / degree[*b] as f64;
+                    a.total_cmp(&b)
+                })
+            })
+        {
+            removed[v] = true;
+            stack.push(v);
+            for &n in &self.adjacent[v] {
+                degree[n] = degree[n].saturating_sub(1);
+            }
+        }
+
+        let mut colors: Vec<Option<Reg>> = vec![None; count];
+        while let Some(v) = stack.pop() {
+            let taken: HashSet<Reg> = self.adjacent[v].iter().filter_map(|n| colors[*n]).collect();
+
+            // Prefer the register of a move partner, so the move disappears
+            let partner = self
+                .moves
+                .iter()
+                .filter_map(|(a, b)| {
+                    let (a, b) = (self.find(*a), self.find(*b));
+                    if a == v {
+                        colors[b]
+                    } else if b == v {
+                        colors[a]
+                    } else {
+                        None
+                    }
+                })
+                .find(|reg| !taken.contains(reg));
+
+            colors[v] = partner.or_else(|| registers.iter().copied().find(|r| !taken.contains(r)));
+        }
+
+        return colors;
+    }
+}
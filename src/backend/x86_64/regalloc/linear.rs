@@ -0,0 +1,298 @@
+// Linear-scan register allocation with interval splitting (Poletto & Sarkar,
+// splitting and resolution after Wimmer & Mössenböck)
+
+use super::liveness::{self, Liveness};
+use crate::backend::x86_64::asm::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+// A piece of a pseudo's lifetime, either in a register or in its stack slot
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    start: usize,
+    end: usize,
+    reg: Option<Reg>,
+}
+
+struct Active {
+    var: usize,
+    start: usize,
+    end: usize,
+    reg: Reg,
+}
+
+// Returns the pseudos that spend at least part of their lifetime in a
+// register
+pub fn allocate(instructions: &mut Vec<Instruction>, registers: &[Reg]) -> HashSet<String> {
+    let liveness = Liveness::analyze(instructions);
+    let count = liveness.names.len();
+
+    // Debug markers take the position of the instruction after them, so
+    // the distances that pick what to spill, and with them the registers,
+    // are the same with `-g`
+    let mut positions = Vec::with_capacity(instructions.len());
+    let mut position = 0;
+    for inst in instructions.iter() {
+        positions.push(position);
+        if !matches!(inst, Instruction::Location(_)) {
+            position += 1;
+        }
+    }
+
+    // Lifetimes are the hull of the positions where a pseudo is live, and
+    // occurrences are the positions that read or write it
+    let mut hull: Vec<Option<(usize, usize)>> = vec![None; count];
+    let mut occurrences: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (i, &p) in positions.iter().enumerate() {
+        let present = liveness.live_in[i].iter().chain(&liveness.defs[i]);
+        for &v in present {
+            hull[v] = match hull[v] {
+                Some((start, end)) => Some((start.min(p), end.max(p))),
+                None => Some((p, p)),
+            };
+        }
+        for &v in liveness.uses[i].iter().chain(&liveness.defs[i]) {
+            if occurrences[v].last() != Some(&p) {
+                occurrences[v].push(p);
+            }
+        }
+    }
+
+    let segments = scan(&hull, &occurrences, registers);
+    let location = |v: usize, i: usize| -> Option<Reg> {
+        let pieces = &segments[v];
+        let index = pieces.partition_point(|s| s.end < positions[i]);
+        return pieces[index].reg;
+    };
+
+    for (i, inst) in instructions.iter_mut().enumerate() {
+        for operand in super::operands_mut(inst) {
+            if let Operand::Pseudo(name) = operand
+                && let Some(reg) = location(liveness.ids[name], i)
+            {
+                *operand = Operand::Register(reg);
+            }
+        }
+    }
+
+    resolve(instructions, &liveness, &location);
+
+    return liveness
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(v, _)| segments[*v].iter().any(|s| s.reg.is_some()))
+        .map(|(_, name)| name.clone())
+        .collect();
+}
+
+fn scan(
+    hull: &[Option<(usize, usize)>],
+    occurrences: &[Vec<usize>],
+    registers: &[Reg],
+) -> Vec<Vec<Segment>> {
+    let mut segments: Vec<Vec<Segment>> = vec![Vec::new(); hull.len()];
+    let mut unhandled: BinaryHeap<Reverse<(usize, usize, usize)>> = hull
+        .iter()
+        .enumerate()
+        .filter_map(|(v, h)| h.map(|(start, end)| Reverse((start, end, v))))
+        .collect();
+    let mut active: Vec<Active> = Vec::new();
+
+    let next_occurrence = |v: usize, from: usize| -> Option<usize> {
+        let occ = &occurrences[v];
+        return occ.get(occ.partition_point(|i| *i < from)).copied();
+    };
+
+    // The rest of a split interval waits in memory until it is next needed
+    let mut split = |v: usize, from: usize, end: usize, unhandled: &mut BinaryHeap<_>| {
+        let needed = next_occurrence(v, from + 1).filter(|n| *n <= end);
+        let memory_end = needed.map_or(end, |n| n - 1);
+        segments[v].push(Segment {
+            start: from,
+            end: memory_end,
+            reg: None,
+        });
+        if let Some(n) = needed {
+            unhandled.push(Reverse((n, end, v)));
+        }
+    };
+
+    let mut done: Vec<(usize, Segment)> = Vec::new();
+
+    while let Some(Reverse((start, end, var))) = unhandled.pop() {
+        active.retain(|a| {
+            if a.end < start {
+                done.push((
+                    a.var,
+                    Segment {
+                        start: a.start,
+                        end: a.end,
+                        reg: Some(a.reg),
+                    },
+                ));
+            }
+            a.end >= start
+        });
+
+        if let Some(reg) = registers
+            .iter()
+            .copied()
+            .find(|r| !active.iter().any(|a| a.reg == *r))
+        {
+            active.push(Active {
+                var,
+                start,
+                end,
+                reg,
+            });
+            continue;
+        }
+
+        // Out of registers: whichever interval is needed furthest in the
+        // future gives up its register here
+        let distance = |v: usize| next_occurrence(v, start).unwrap_or(usize::MAX);
+        let victim = (0..active.len()).max_by_key(|a| distance(active[*a].var));
+
+        match victim {
+            Some(a) if distance(active[a].var) > distance(var) => {
+                let evicted = active.swap_remove(a);
+                if evicted.start < start {
+                    done.push((
+                        evicted.var,
+                        Segment {
+                            start: evicted.start,
+                            end: start - 1,
+                            reg: Some(evicted.reg),
+                        },
+                    ));
+                }
+                split(evicted.var, start, evicted.end, &mut unhandled);
+
+                active.push(Active {
+                    var,
+                    start,
+                    end,
+                    reg: evicted.reg,
+                });
+            }
+            _ => split(var, start, end, &mut unhandled),
+        }
+    }
+
+    for a in active {
+        done.push((
+            a.var,
+            Segment {
+                start: a.start,
+                end: a.end,
+                reg: Some(a.reg),
+            },
+        ));
+    }
+
+    for (v, segment) in done {
+        segments[v].push(segment);
+    }
+    for pieces in &mut segments {
+        pieces.sort_by_key(|s| s.start);
+    }
+
+    return segments;
+}
+
+// Where a pseudo lives in different places at the two ends of a control-flow
+// edge, the edge gets a store or a reload. Jumps carry their moves before
+// them, fallthroughs after the instruction, and a conditional jump that needs
+// moves goes through a stub at the end of the function.
+fn resolve(
+    instructions: &mut Vec<Instruction>,
+    liveness: &Liveness,
+    location: &impl Fn(usize, usize) -> Option<Reg>,
+) {
+    let succs = liveness::successors(instructions);
+    let labels: HashMap<String, usize> = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, inst)| match inst {
+            Instruction::Label(label) => Some((label.clone(), i)),
+            _ => None,
+        })
+        .collect();
+
+    let moves = |from: usize, to: usize| -> Vec<Instruction> {
+        let mut stores = Vec::new();
+        let mut reloads = Vec::new();
+
+        let mut live: Vec<usize> = liveness.live_in[to].iter().copied().collect();
+        live.sort();
+
+        for v in live {
+            let pseudo = Operand::Pseudo(liveness.names[v].clone());
+            match (location(v, from), location(v, to)) {
+                (Some(a), Some(b)) if a == b => {}
+                (None, None) => {}
+                (Some(a), b) => {
+                    stores.push(Instruction::Move {
+                        dst: pseudo.clone(),
+                        src: Operand::Register(a),
+                    });
+                    if let Some(b) = b {
+                        reloads.push(Instruction::Move {
+                            dst: Operand::Register(b),
+                            src: pseudo,
+                        });
+                    }
+                }
+                (None, Some(b)) => reloads.push(Instruction::Move {
+                    dst: Operand::Register(b),
+                    src: pseudo,
+                }),
+            }
+        }
+
+        // Stores read the registers that reloads may overwrite
+        stores.extend(reloads);
+        return stores;
+    };
+
+    let mut out = Vec::with_capacity(instructions.len());
+    let mut stubs = Vec::new();
+    let mut split_count = 0;
+
+    for (i, inst) in std::mem::take(instructions).into_iter().enumerate() {
+        match inst {
+            Instruction::Jump(ref label) => {
+                out.extend(moves(i, labels[label]));
+                out.push(inst);
+            }
+
+            Instruction::JumpCondition(condition, label) => {
+                let taken = moves(i, labels[&label]);
+                if taken.is_empty() {
+                    out.push(Instruction::JumpCondition(condition, label));
+                } else {
+                    let stub = format!("split.{}", split_count);
+                    split_count += 1;
+                    stubs.push(Instruction::Label(stub.clone()));
+                    stubs.extend(taken);
+                    stubs.push(Instruction::Jump(label));
+                    out.push(Instruction::JumpCondition(condition, stub));
+                }
+                if succs[i].contains(&(i + 1)) {
+                    out.extend(moves(i, i + 1));
+                }
+            }
+
+            _ => {
+                out.push(inst);
+                if succs[i].contains(&(i + 1)) {
+                    out.extend(moves(i, i + 1));
+                }
+            }
+        }
+    }
+
+    out.extend(stubs);
+    *instructions = out;
+}
@@ -0,0 +1,153 @@
+// Liveness of pseudo-registers over the instruction stream
+
+use crate::backend::x86_64::asm::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub struct Liveness {
+    pub names: Vec<String>,
+    pub ids: HashMap<String, usize>,
+    pub uses: Vec<Vec<usize>>,
+    pub defs: Vec<Vec<usize>>,
+    pub live_in: Vec<HashSet<usize>>,
+    pub live_out: Vec<HashSet<usize>>,
+}
+
+impl Liveness {
+    pub fn analyze(instructions: &[Instruction]) -> Self {
+        let mut names: Vec<String> = Vec::new();
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut id = |operand: &Operand| -> Option<usize> {
+            let Operand::Pseudo(name) = operand else {
+                return None;
+            };
+            let next = names.len();
+            let id = *ids.entry(name.clone()).or_insert(next);
+            if id == next {
+                names.push(name.clone());
+            }
+            return Some(id);
+        };
+
+        let mut uses: Vec<Vec<usize>> = Vec::new();
+        let mut defs: Vec<Vec<usize>> = Vec::new();
+        for inst in instructions {
+            let (used, defined) = operands(inst);
+            uses.push(used.into_iter().filter_map(&mut id).collect());
+            defs.push(defined.into_iter().filter_map(&mut id).collect());
+        }
+
+        let succs = successors(instructions);
+        let mut live_in: Vec<HashSet<usize>> = vec![HashSet::new(); instructions.len()];
+        let mut live_out: Vec<HashSet<usize>> = vec![HashSet::new(); instructions.len()];
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for i in (0..instructions.len()).rev() {
+                let out: HashSet<usize> = succs[i]
+                    .iter()
+                    .flat_map(|s| live_in[*s].iter().copied())
+                    .collect();
+
+                let mut inside: HashSet<usize> = out
+                    .iter()
+                    .copied()
+                    .filter(|v| !defs[i].contains(v))
+                    .collect();
+                inside.extend(uses[i].iter().copied());
+
+                if inside != live_in[i] || out != live_out[i] {
+                    live_in[i] = inside;
+                    live_out[i] = out;
+                    changed = true;
+                }
+            }
+        }
+
+        return Liveness {
+            names,
+            ids,
+            uses,
+            defs,
+            live_in,
+            live_out,
+        };
+    }
+}
+
+// Operands read and written by an instruction. Instructions that update
+// their destination in place read it as well.
+pub fn operands(inst: &Instruction) -> (Vec<&Operand>, Vec<&Operand>) {
+    match inst {
+        Instruction::Move { dst, src } => (vec![src], vec![dst]),
+        Instruction::Unary(_, op) => (vec![op], vec![op]),
+        Instruction::Binary(_, dst, src) => (vec![dst, src], vec![dst]),
+        Instruction::Compare(a, b) => (vec![a, b], vec![]),
+        // `setcc` only writes the low byte
+        Instruction::SetCondition(_, dst) => (vec![dst], vec![dst]),
+        Instruction::Division(op) | Instruction::Multiply(op) => (vec![op], vec![]),
+        Instruction::Lea {
+            dst, base, index, ..
+        } => (vec![base, index], vec![dst]),
+        _ => (vec![], vec![]),
+    }
+}
+
+pub fn successors(instructions: &[Instruction]) -> Vec<Vec<usize>> {
+    let labels: HashMap<&String, usize> = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, inst)| match inst {
+            Instruction::Label(label) => Some((label, i)),
+            _ => None,
+        })
+        .collect();
+
+    let next = |i: usize| -> Vec<usize> {
+        if i + 1 < instructions.len() {
+            vec![i + 1]
+        } else {
+            vec![]
+        }
+    };
+
+    return instructions
+        .iter()
+        .enumerate()
+        .map(|(i, inst)| match inst {
+            Instruction::Jump(label) => vec![labels[label]],
+            Instruction::JumpCondition(_, label) => {
+                let mut succs = next(i);
+                succs.push(labels[label]);
+                succs
+            }
+            Instruction::Return => vec![],
+            _ => next(i),
+        })
+        .collect();
+}
+
+// A backwards jump closes a loop over the instructions it jumps across.
+// Jumps back to the same label (`continue`) belong to the same loop.
+pub fn loop_depth(instructions: &[Instruction]) -> Vec<u32> {
+    let mut loops: HashMap<usize, usize> = HashMap::new();
+    for (i, succs) in successors(instructions).iter().enumerate() {
+        for &s in succs {
+            if s <= i {
+                let end = loops.entry(s).or_insert(i);
+                *end = (*end).max(i);
+            }
+        }
+    }
+
+    let mut depth = vec![0; instructions.len()];
+    for (start, end) in loops {
+        for d in &mut depth[start..=end] {
+            *d += 1;
+        }
+    }
+
+    return depth;
+}
@@ -0,0 +1,277 @@
+// Strength reduction of multiplication, division and modulo by constants
+
+use super::asm::*;
+use crate::frontend::ir;
+
+pub fn reduce(
+    op: &ir::BinaryOperator,
+    src1: Operand,
+    src2: Operand,
+    dst: Operand,
+) -> Option<Vec<Instruction>> {
+    match (op, &src1, &src2) {
+        (ir::BinaryOperator::Multiply, _, Operand::Immediate(c)) => multiply(dst, src1, *c),
+        (ir::BinaryOperator::Multiply, Operand::Immediate(c), _) => multiply(dst, src2, *c),
+        (ir::BinaryOperator::Divide, _, Operand::Immediate(d)) => divide(dst, src1, *d),
+        (ir::BinaryOperator::Modulo, _, Operand::Immediate(d)) => modulo(dst, src1, *d),
+        _ => None,
+    }
+}
+
+// x * 2^k is a shift, and x * {3, 5, 9} * 2^k is an `lea` plus a shift
+fn multiply(dst: Operand, src: Operand, c: i32) -> Option<Vec<Instruction>> {
+    let magnitude = c.unsigned_abs();
+    let shift = magnitude.trailing_zeros();
+    let factor = magnitude.checked_shr(shift).unwrap_or(0);
+
+    let mut out = Vec::new();
+
+    match factor {
+        0 => {
+            out.push(Instruction::Move {
+                dst,
+                src: Operand::Immediate(0),
+            });
+            return Some(out);
+        }
+
+        1 => out.push(Instruction::Move {
+            dst: dst.clone(),
+            src,
+        }),
+
+        3 | 5 | 9 => out.push(Instruction::Lea {
+            dst: dst.clone(),
+            base: src.clone(),
+            index: src,
+            scale: factor as i32 - 1,
+        }),
+
+        _ => return None,
+    }
+
+    if shift > 0 {
+        out.push(Instruction::Binary(
+            BinaryOperator::Sal,
+            dst.clone(),
+            Operand::Immediate(shift as i32),
+        ));
+    }
+
+    if c < 0 {
+        out.push(Instruction::Unary(UnaryOperator::Neg, dst));
+    }
+
+    return Some(out);
+}
+
+// Division by -1 and by INT_MIN is left to `idiv`, which traps on overflow
+fn divide(dst: Operand, n: Operand, d: i32) -> Option<Vec<Instruction>> {
+    if d == 0 || d == -1 || d == i32::MIN {
+        return None;
+    }
+
+    let magnitude = d.unsigned_abs();
+
+    let (mut out, quotient) = if magnitude == 1 {
+        (
+            vec![Instruction::Move {
+                dst: Operand::Register(Reg::R10),
+                src: n,
+            }],
+            Reg::R10,
+        )
+    } else if magnitude.is_power_of_two() {
+        (divide_power_of_two(n, magnitude), Reg::R10)
+    } else {
+        (divide_magic(n, magnitude), Reg::DX)
+    };
+
+    if d < 0 {
+        out.push(Instruction::Unary(
+            UnaryOperator::Neg,
+            Operand::Register(quotient),
+        ));
+    }
+
+    out.push(Instruction::Move {
+        dst,
+        src: Operand::Register(quotient),
+    });
+
+    return Some(out);
+}
+
+// The remainder takes the sign of the dividend, so n % d == n % |d|
+fn modulo(dst: Operand, n: Operand, d: i32) -> Option<Vec<Instruction>> {
+    if d == 0 || d == -1 || d == i32::MIN {
+        return None;
+    }
+
+    let magnitude = d.unsigned_abs();
+
+    if magnitude == 1 {
+        return Some(vec![Instruction::Move {
+            dst,
+            src: Operand::Immediate(0),
+        }]);
+    }
+
+    let r10 = Operand::Register(Reg::R10);
+    let r11 = Operand::Register(Reg::R11);
+
+    let mut out = Vec::new();
+
+    if magnitude.is_power_of_two() {
+        // r = ((n + bias) & (2^k - 1)) - bias
+        out.extend(bias(n.clone(), magnitude));
+        out.push(Instruction::Move {
+            dst: r11.clone(),
+            src: n,
+        });
+        out.push(Instruction::Binary(
+            BinaryOperator::Add,
+            r11.clone(),
+            r10.clone(),
+        ));
+        out.push(Instruction::Binary(
+            BinaryOperator::And,
+            r11.clone(),
+            Operand::Immediate(magnitude as i32 - 1),
+        ));
+        out.push(Instruction::Binary(BinaryOperator::Sub, r11.clone(), r10));
+    } else {
+        // r = n - (n / |d|) * |d|
+        out.extend(divide_magic(n.clone(), magnitude));
+        out.push(Instruction::Binary(
+            BinaryOperator::Mul,
+            Operand::Register(Reg::DX),
+            Operand::Immediate(magnitude as i32),
+        ));
+        out.push(Instruction::Move {
+            dst: r11.clone(),
+            src: n,
+        });
+        out.push(Instruction::Binary(
+            BinaryOperator::Sub,
+            r11.clone(),
+            Operand::Register(Reg::DX),
+        ));
+    }
+
+    out.push(Instruction::Move { dst, src: r11 });
+
+    return Some(out);
+}
+
+// Leaves 2^k - 1 in r10d for a negative dividend and 0 otherwise. Adding it
+// before an arithmetic shift makes the shift round towards zero like `idiv`.
+fn bias(n: Operand, magnitude: u32) -> Vec<Instruction> {
+    let r10 = Operand::Register(Reg::R10);
+    let shift = magnitude.trailing_zeros() as i32;
+
+    return vec![
+        Instruction::Move {
+            dst: r10.clone(),
+            src: n,
+        },
+        Instruction::Binary(BinaryOperator::Sar, r10.clone(), Operand::Immediate(31)),
+        Instruction::Binary(BinaryOperator::Shr, r10, Operand::Immediate(32 - shift)),
+    ];
+}
+
+// Quotient in r10d
+fn divide_power_of_two(n: Operand, magnitude: u32) -> Vec<Instruction> {
+    let r10 = Operand::Register(Reg::R10);
+    let shift = magnitude.trailing_zeros() as i32;
+
+    let mut out = bias(n.clone(), magnitude);
+    out.push(Instruction::Binary(BinaryOperator::Add, r10.clone(), n));
+    out.push(Instruction::Binary(
+        BinaryOperator::Sar,
+        r10,
+        Operand::Immediate(shift),
+    ));
+    return out;
+}
+
+// Quotient in edx. The high half of n * M, shifted right, is n / d rounded
+// towards negative infinity; adding the sign bit rounds it towards zero.
+fn divide_magic(n: Operand, magnitude: u32) -> Vec<Instruction> {
+    let (multiplier, shift) = magic(magnitude);
+    let ax = Operand::Register(Reg::AX);
+    let dx = Operand::Register(Reg::DX);
+
+    let mut out = vec![
+        Instruction::Move {
+            dst: ax.clone(),
+            src: Operand::Immediate(multiplier),
+        },
+        Instruction::Multiply(n.clone()),
+    ];
+
+    // A multiplier above INT_MAX wrapped around to negative, which subtracted
+    // n * 2^32 from the product
+    if multiplier < 0 {
+        out.push(Instruction::Binary(BinaryOperator::Add, dx.clone(), n));
+    }
+
+    if shift > 0 {
+        out.push(Instruction::Binary(
+            BinaryOperator::Sar,
+            dx.clone(),
+            Operand::Immediate(shift),
+        ));
+    }
+
+    out.push(Instruction::Move {
+        dst: ax.clone(),
+        src: dx.clone(),
+    });
+    out.push(Instruction::Binary(
+        BinaryOperator::Shr,
+        ax.clone(),
+        Operand::Immediate(31),
+    ));
+    out.push(Instruction::Binary(BinaryOperator::Add, dx, ax));
+
+    return out;
+}
+
+// Magic multiplier and shift for signed division by d >= 2 (Granlund &
+// Montgomery, as given in Hacker's Delight 10-1)
+fn magic(d: u32) -> (i32, i32) {
+    let two31: u32 = 1 << 31;
+    let anc = two31 - 1 - two31 % d;
+
+    let mut p = 31;
+    let mut q1 = two31 / anc;
+    let mut r1 = two31 - q1 * anc;
+    let mut q2 = two31 / d;
+    let mut r2 = two31 - q2 * d;
+
+    loop {
+        p += 1;
+
+        q1 = q1.wrapping_mul(2);
+        r1 = r1.wrapping_mul(2);
+        if r1 >= anc {
+            q1 = q1.wrapping_add(1);
+            r1 = r1.wrapping_sub(anc);
+        }
+
+        q2 = q2.wrapping_mul(2);
+        r2 = r2.wrapping_mul(2);
+        if r2 >= d {
+            q2 = q2.wrapping_add(1);
+            r2 = r2.wrapping_sub(d);
+        }
+
+        let delta = d - r2;
+        if !(q1 < delta || (q1 == delta && r1 == 0)) {
+            break;
+        }
+    }
+
+    return (q2.wrapping_add(1) as i32, p - 32);
+}
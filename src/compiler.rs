@@ -0,0 +1,346 @@
+// The library's entry point. A `Compiler` holds the options of a build, and
+// compiles source text with them into the artifact of the stage it stops at.
+
+use crate::backend::{AArch64, ObjectFormat, RiscV64, Target, X86_64};
+use crate::dump::Dump;
+use crate::frontend::token::{Span, Token};
+use crate::frontend::{self, ast, ir};
+use crate::{Linker, OptLevel, RegAllocator, Severity, Stage, Syntax, Warning};
+use std::fmt;
+
+// What a stage produces. The SSA form and the selected instructions are
+// dumped as they are produced, since their types belong to the optimizer
+// and to each target
+#[derive(Debug)]
+pub enum Artifact {
+    Tokens(Vec<(Token, Span)>),
+    // Parsed, or validated with variables renamed and loops labeled
+    Ast(ast::Program),
+    Ir(ir::Program),
+    Ssa(Dump),
+    Instructions(Dump),
+    Assembly(String),
+    Object(Vec<u8>),
+    Executable(Vec<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    // The stage that reported it
+    pub stage: Stage,
+    // A warning is an error under `-Werror`, and still names its check
+    pub severity: Severity,
+    pub warning: Option<Warning>,
+    pub message: String,
+    // Where in the source, for errors from the lexer, parser and semantic
+    // analysis
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    pub(crate) fn error(stage: Stage, message: String, span: Option<Span>) -> Diagnostic {
+        return Diagnostic {
+            stage,
+            severity: Severity::Error,
+            warning: None,
+            message,
+            span,
+        };
+    }
+}
+
+// A warning ends with the flag that controls it, as GCC's do
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match (self.warning, self.severity) {
+            (None, _) => write!(f, "{}", self.message),
+            (Some(warning), Severity::Warning) => {
+                write!(f, "{} [-W{}]", self.message, warning.name())
+            }
+            (Some(warning), Severity::Error) => {
+                write!(f, "{} [-Werror={}]", self.message, warning.name())
+            }
+        };
+    }
+}
+
+// The outcome of compiling one source. `output` is missing when an error
+// stopped compilation, and the diagnostics say why. They also hold the
+// warnings that are turned on, in source order before any error
+#[derive(Debug)]
+pub struct Compilation<T> {
+    pub output: Option<T>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Compiler {
+    target: String,
+    stage: Stage,
+    opt_level: OptLevel,
+    allocator: Option<RegAllocator>,
+    peephole: Option<bool>,
+    linker: Linker,
+    syntax: Syntax,
+    debug: Option<String>,
+    frame_pointer: bool,
+    warnings: Vec<Warning>,
+    warnings_as_errors: bool,
+}
+
+// x86-64 in the host's object format, so that what the system assembler
+// and linker are given is what they build
+pub const DEFAULT_TARGET: &str = if cfg!(target_os = "macos") {
+    "x86_64-apple-darwin"
+} else {
+    "x86_64-linux-gnu"
+};
+
+// Intel syntax x86-64 assembly at -O0, like `crucible -S`
+impl Default for Compiler {
+    fn default() -> Compiler {
+        return Compiler {
+            target: DEFAULT_TARGET.to_string(),
+            stage: Stage::Assembly,
+            opt_level: OptLevel::O0,
+            allocator: None,
+            peephole: None,
+            linker: Linker::System,
+            syntax: Syntax::Intel,
+            debug: None,
+            frame_pointer: true,
+            warnings: Warning::DEFAULT.to_vec(),
+            warnings_as_errors: false,
+        };
+    }
+}
+
+impl Compiler {
+    pub fn new() -> Compiler {
+        return Compiler::default();
+    }
+
+    // `x86_64-linux-gnu`, `x86_64-apple-darwin`, `aarch64-linux` or
+    // `riscv64-linux`. Plain `x86_64` is the host's format
+    pub fn target(mut self, target: &str) -> Compiler {
+        self.target = target.to_string();
+        return self;
+    }
+
+    pub fn stop_at(mut self, stage: Stage) -> Compiler {
+        self.stage = stage;
+        return self;
+    }
+
+    pub fn opt_level(mut self, opt_level: OptLevel) -> Compiler {
+        self.opt_level = opt_level;
+        return self;
+    }
+
+    // The options below only apply to x86-64, and other targets ignore them
+
+    // Defaults to graph coloring at -O2, to none for debug builds at -O0, and
+    // to linear scan otherwise
+    pub fn allocator(mut self, allocator: RegAllocator) -> Compiler {
+        self.allocator = Some(allocator);
+        return self;
+    }
+
+    // Defaults to on at -O1 and above
+    pub fn peephole(mut self, peephole: bool) -> Compiler {
+        self.peephole = Some(peephole);
+        return self;
+    }
+
+    // With the built-in linker, `Stage::Full` makes an executable. The system
+    // linker is outside the library, so it gets assembly to link instead
+    pub fn linker(mut self, linker: Linker) -> Compiler {
+        self.linker = linker;
+        return self;
+    }
+
+    pub fn syntax(mut self, syntax: Syntax) -> Compiler {
+        self.syntax = syntax;
+        return self;
+    }
+
+    // Adds debug info describing `source`, the name of the file compiled.
+    // Objects with it come from the system assembler; the built-in linker
+    // and `run` work without it
+    pub fn debug(mut self, source: &str) -> Compiler {
+        self.debug = Some(source.to_string());
+        return self;
+    }
+
+    pub fn frame_pointer(mut self, frame_pointer: bool) -> Compiler {
+        self.frame_pointer = frame_pointer;
+        return self;
+    }
+
+    // Turns one warning on or off. Only `Warning::DEFAULT` are on to begin
+    // with
+    pub fn warning(mut self, warning: Warning, enabled: bool) -> Compiler {
+        self.warnings.retain(|other| *other != warning);
+        if enabled {
+            self.warnings.push(warning);
+        }
+        return self;
+    }
+
+    // Fails the compilation of a source with any warning that is on
+    pub fn warnings_as_errors(mut self, warnings_as_errors: bool) -> Compiler {
+        self.warnings_as_errors = warnings_as_errors;
+        return self;
+    }
+
+    // What an external assembler and linker are told to build for, or an
+    // error for an unknown target
+    pub fn triple(&self) -> Result<&'static str, String> {
+        return Ok(self.backend()?.triple());
+    }
+
+    pub fn compile(&self, source: &str) -> Compilation<Artifact> {
+        let mut warnings = Vec::new();
+        let artifact = self.build(source, &mut warnings);
+        return self.finish(artifact, warnings);
+    }
+
+    // Compiles into memory and runs `main`, returning its exit status. Only
+    // x86-64 can run in-process, on an x86-64 host
+    pub fn run(&self, source: &str) -> Compilation<i32> {
+        let mut warnings = Vec::new();
+        let lowered = self.lower(source, &mut warnings);
+        // Warnings turned into errors stop the program before it runs
+        let lowered = match self.finish(lowered, warnings) {
+            Compilation {
+                output: Some(ir),
+                diagnostics,
+            } => (ir, diagnostics),
+            Compilation { diagnostics, .. } => {
+                return Compilation {
+                    output: None,
+                    diagnostics,
+                };
+            }
+        };
+        let (ir, mut diagnostics) = lowered;
+
+        let backend = self.backend();
+        let status = backend.and_then(|target| target.run(ir));
+        return match status {
+            Ok(status) => Compilation {
+                output: Some(status),
+                diagnostics,
+            },
+            Err(message) => {
+                diagnostics.push(Diagnostic::error(Stage::Full, message, None));
+                Compilation {
+                    output: None,
+                    diagnostics,
+                }
+            }
+        };
+    }
+
+    fn build(&self, source: &str, warnings: &mut Vec<Diagnostic>) -> Result<Artifact, Diagnostic> {
+        let debug = self.debug.is_some();
+        let artifact = frontend::compile(source, self.stage, self.opt_level, debug, warnings)?;
+        let Artifact::Ir(ir) = artifact else {
+            return Ok(artifact);
+        };
+        if self.stage == Stage::Ir {
+            return Ok(Artifact::Ir(ir));
+        }
+
+        let backend = self.backend();
+        return backend
+            .and_then(|target| target.compile(ir, self.stage))
+            .map_err(|message| Diagnostic::error(self.stage, message, None));
+    }
+
+    fn lower(
+        &self,
+        source: &str,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<ir::Program, Diagnostic> {
+        let debug = self.debug.is_some();
+        return match frontend::compile(source, Stage::Full, self.opt_level, debug, warnings)? {
+            Artifact::Ir(ir) => Ok(ir),
+            _ => unreachable!("the frontend hands on the IR past its stages"),
+        };
+    }
+
+    fn backend(&self) -> Result<Box<dyn Target>, String> {
+        // Optimized builds can afford the slower, better allocator, and debug
+        // builds at -O0 keep every variable in memory where a debugger sees it
+        let allocator = self.allocator.unwrap_or(match self.opt_level {
+            OptLevel::O2 => RegAllocator::Graph,
+            OptLevel::O0 if self.debug.is_some() => RegAllocator::None,
+            _ => RegAllocator::Linear,
+        });
+
+        let triple = match self.target.as_str() {
+            "x86_64" => DEFAULT_TARGET,
+            target => target,
+        };
+        let format = match triple {
+            "x86_64-apple-darwin" => ObjectFormat::MachO,
+            _ => ObjectFormat::Elf,
+        };
+        return match self.target.as_str() {
+            "x86_64" | "x86_64-apple-darwin" | "x86_64-linux" | "x86_64-linux-gnu" => {
+                Ok(Box::new(X86_64 {
+                    format,
+                    allocator,
+                    peephole: self.peephole.unwrap_or(self.opt_level != OptLevel::O0),
+                    linker: self.linker,
+                    syntax: self.syntax,
+                    debug: self.debug.clone(),
+                    frame_pointer: self.frame_pointer,
+                }))
+            }
+            "aarch64" | "aarch64-linux" | "aarch64-linux-gnu" => Ok(Box::new(AArch64)),
+            "riscv64" | "riscv64-linux" | "riscv64-linux-gnu" => Ok(Box::new(RiscV64)),
+            other => Err(format!("Unknown target: {}", other)),
+        };
+    }
+
+    // Keeps the warnings that are on, and fails on them with `-Werror`
+    fn finish<T>(
+        &self,
+        result: Result<T, Diagnostic>,
+        warnings: Vec<Diagnostic>,
+    ) -> Compilation<T> {
+        let mut diagnostics: Vec<Diagnostic> = warnings
+            .into_iter()
+            .filter(|diagnostic| {
+                let warning = diagnostic.warning.unwrap();
+                return self.warnings.contains(&warning);
+            })
+            .collect();
+        if self.warnings_as_errors {
+            for diagnostic in &mut diagnostics {
+                diagnostic.severity = Severity::Error;
+            }
+        }
+
+        let failed = self.warnings_as_errors && !diagnostics.is_empty();
+        return match result {
+            Ok(output) if !failed => Compilation {
+                output: Some(output),
+                diagnostics,
+            },
+            Ok(_) => Compilation {
+                output: None,
+                diagnostics,
+            },
+            Err(diagnostic) => {
+                diagnostics.push(diagnostic);
+                Compilation {
+                    output: None,
+                    diagnostics,
+                }
+            }
+        };
+    }
+}
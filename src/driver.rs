@@ -0,0 +1,763 @@
+// The command line driver. It parses the arguments like `cc` does, runs each
+// input through the library's `Compiler`, and hands the results to the
+// external toolchain to assemble and link.
+
+mod format;
+mod lsp;
+mod toolchain;
+
+use crucible::dump::Format;
+use crucible::{
+    Artifact, Compilation, Compiler, DEFAULT_TARGET, Linker, OptLevel, RegAllocator, Severity,
+    Stage, Syntax, Warning,
+};
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::{self, Stdio};
+use std::thread;
+use toolchain::{Tool, Toolchain};
+
+const USAGE: &str = "\
+Usage: crucible [run] [flags] <inputs...>
+       crucible fmt [--check] <sources...>
+       crucible lsp
+Inputs: .c sources (- reads standard input), .s assembly, objects and archives
+Flags: [-o <output>]
+Flags: [-E OR -S OR -c]
+Flags: [--lex OR --parse OR --validate OR --ir OR --ssa OR --codegen OR --emit]
+Flags: [--dump-format=text OR --dump-format=json]
+Flags: [-D<macro> OR -U<macro> OR -I<directory>]
+Flags: [-l<library> OR -L<directory> OR -Wl,<flags>]
+Flags: [-O0 OR -O1 OR -O2 OR -O3 OR -Os]
+Flags: [-fregalloc=linear OR -fregalloc=graph OR -fregalloc=none]
+Flags: [-fpeephole OR -fno-peephole]
+Flags: [-fuse-ld=system OR -fuse-ld=builtin]
+Flags: [-masm=intel OR -masm=att]
+Flags: [-g]
+Flags: [-fomit-frame-pointer OR -fno-omit-frame-pointer]
+Flags: [-W<warning> OR -Wno-<warning>] [-Wall] [-Wextra] [-w] [-Werror]
+Flags: [--target x86_64-linux-gnu OR x86_64-apple-darwin OR aarch64-linux OR riscv64-linux]
+Flags: [--cc <command>] [--as <command>] [--ld <command>]
+Flags: [--save-temps]
+";
+
+// Decides the exit status: 2 for a bad command line, and 1 for a program
+// that does not compile, assemble or link
+pub enum Error {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Error {
+        return Error::Failed(message);
+    }
+}
+
+enum Input {
+    // A C source file, or `-` for standard input
+    Source(String),
+    Assembly(String),
+    // Objects, archives and `-l`, `-L` and `-Wl,` flags, passed on to the
+    // linker in order
+    Linker(String),
+}
+
+// What the command line asks for, the last flag winning
+enum Action {
+    Preprocess,
+    // Prints what a stage produces, from --lex to --emit
+    Dump(Stage),
+    // -S and -c: a file for each input
+    Stop(Stage),
+    Link,
+}
+
+struct Options {
+    action: Action,
+    run: bool,
+    dump_format: Format,
+    // The options shared by every input
+    compiler: Compiler,
+    // Each input's debug info names that input
+    debug: bool,
+    linker: Linker,
+    output: Option<String>,
+    inputs: Vec<Input>,
+    // `-D`, `-U` and `-I`, for the preprocessor
+    preprocessor: Vec<String>,
+    toolchain: Toolchain,
+    // Keep the preprocessed sources, assembly and objects in the current
+    // directory
+    save_temps: bool,
+}
+
+// Returns the exit status: 0, or the program's own with `crucible run`
+pub fn drive(args: &[String]) -> Result<i32, Error> {
+    if args.is_empty() {
+        eprint!("{}", USAGE);
+        return Err(Error::Usage("no arguments provided".to_string()));
+    }
+
+    // `crucible fmt` and `crucible lsp` take flags of their own
+    if args[0] == "fmt" {
+        return format::format(&args[1..]);
+    }
+    if args[0] == "lsp" {
+        return lsp::lsp(&args[1..]);
+    }
+
+    let Some(options) = parse(args)? else {
+        return Ok(0); // Printed help or version
+    };
+
+    if options.run {
+        return run(&options);
+    }
+
+    match options.action {
+        Action::Preprocess => preprocess(&options)?,
+        Action::Dump(stage) => {
+            for input in &options.inputs {
+                if let Input::Source(path) = input {
+                    dump(&compile(&options, path, stage)?, options.dump_format);
+                }
+            }
+        }
+        Action::Stop(stage) => compile_only(&options, stage)?,
+        Action::Link => link(&options)?,
+    }
+
+    return Ok(0);
+}
+
+fn parse(args: &[String]) -> Result<Option<Options>, Error> {
+    let mut action = Action::Link;
+    let mut dump_format = Format::Text;
+    let mut opt_level = OptLevel::O0;
+    let mut allocator = None;
+    let mut peephole = None;
+    let mut linker = Linker::System;
+    let mut syntax = None;
+    let mut debug = false;
+    let mut frame_pointer = None;
+    let mut target_name = DEFAULT_TARGET.to_string();
+    let mut output = None;
+    let mut inputs = Vec::new();
+    let mut preprocessor = Vec::new();
+    let mut compiler = None;
+    let mut assembler = None;
+    let mut link_command = None;
+    let mut save_temps = false;
+    // Each -W flag in order, later ones winning
+    let mut warnings = Vec::new();
+    let mut no_warnings = false;
+    let mut warnings_as_errors = false;
+
+    // `crucible run` compiles into memory and runs the program right away
+    let run = args[0] == "run";
+    let mut flags = if run { args[1..].iter() } else { args.iter() };
+
+    while let Some(arg) = flags.next() {
+        match arg.as_str() {
+            "--lex" => action = Action::Dump(Stage::Lex),
+            "--parse" => action = Action::Dump(Stage::Parse),
+            "--validate" => action = Action::Dump(Stage::Validate),
+            "--codegen" => action = Action::Dump(Stage::Codegen),
+
+            "--tacky" | "--ir" => action = Action::Dump(Stage::Ir),
+            "--ssa" => action = Action::Dump(Stage::Ssa),
+            "--emit" => action = Action::Dump(Stage::Assembly),
+            "--dump-format=text" => dump_format = Format::Text,
+            "--dump-format=json" => dump_format = Format::Json,
+
+            "-E" => action = Action::Preprocess,
+            "-S" => action = Action::Stop(Stage::Assembly),
+            "-c" => action = Action::Stop(Stage::Object),
+
+            "-O0" => opt_level = OptLevel::O0,
+            "-O" | "-O1" => opt_level = OptLevel::O1,
+            // Nothing goes further than -O2, or optimizes for size
+            "-O2" | "-O3" | "-Os" => opt_level = OptLevel::O2,
+
+            "-fregalloc=none" => allocator = Some(RegAllocator::None),
+            "-fregalloc=linear" => allocator = Some(RegAllocator::Linear),
+            "-fregalloc=graph" => allocator = Some(RegAllocator::Graph),
+
+            "-fpeephole" => peephole = Some(true),
+            "-fno-peephole" => peephole = Some(false),
+
+            "-fuse-ld=system" => linker = Linker::System,
+            "-fuse-ld=builtin" => linker = Linker::Builtin,
+
+            "-masm=intel" => syntax = Some(Syntax::Intel),
+            "-masm=att" => syntax = Some(Syntax::Att),
+
+            "-g" => debug = true,
+
+            "-fomit-frame-pointer" => frame_pointer = Some(false),
+            "-fno-omit-frame-pointer" => frame_pointer = Some(true),
+
+            "-save-temps" | "--save-temps" => save_temps = true,
+
+            "-Wall" => warnings.extend(Warning::ALL.map(|warning| (warning, true))),
+            "-Wextra" => warnings.extend(Warning::EXTRA.map(|warning| (warning, true))),
+            "-w" => no_warnings = true,
+            "-Werror" => warnings_as_errors = true,
+            "-Wno-error" => warnings_as_errors = false,
+            // Linker flags go through as written, like GCC passes them on
+            flag if flag.starts_with("-Wl,") => inputs.push(Input::Linker(flag.to_string())),
+            flag if flag.starts_with("-W") => {
+                let (name, enabled) = match flag.strip_prefix("-Wno-") {
+                    Some(name) => (name, false),
+                    None => (&flag[2..], true),
+                };
+                let Some(warning) = Warning::from_name(name) else {
+                    return Err(Error::Usage(format!("Unknown warning: {}", flag)));
+                };
+                warnings.push((warning, enabled));
+            }
+
+            "--version" | "-v" => {
+                println!("crucible version 0.1.0");
+                println!("target: {}", DEFAULT_TARGET);
+                return Ok(None);
+            }
+
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                return Ok(None);
+            }
+
+            "-" => inputs.push(Input::Source(arg.clone())),
+
+            flag if long(flag, "--target") => {
+                target_name = value(flag, "--target", &mut flags)?;
+            }
+            flag if long(flag, "--cc") => compiler = Some(value(flag, "--cc", &mut flags)?),
+            flag if long(flag, "--as") => assembler = Some(value(flag, "--as", &mut flags)?),
+            flag if long(flag, "--ld") => {
+                link_command = Some(value(flag, "--ld", &mut flags)?);
+            }
+            flag if flag.starts_with("-o") => output = Some(value(flag, "-o", &mut flags)?),
+            flag if flag.starts_with("-l") || flag.starts_with("-L") => {
+                let value = value(flag, &flag[..2], &mut flags)?;
+                inputs.push(Input::Linker(format!("{}{}", &flag[..2], value)));
+            }
+            flag if ["-D", "-U", "-I"].iter().any(|f| flag.starts_with(f)) => {
+                let value = value(flag, &flag[..2], &mut flags)?;
+                preprocessor.push(format!("{}{}", &flag[..2], value));
+            }
+
+            flag if flag.starts_with('-') => {
+                return Err(Error::Usage(format!("Unknown flag: {}", flag)));
+            }
+
+            file if file.ends_with(".c") => inputs.push(Input::Source(file.to_string())),
+            file if file.ends_with(".s") || file.ends_with(".S") => {
+                inputs.push(Input::Assembly(file.to_string()))
+            }
+            file => inputs.push(Input::Linker(file.to_string())),
+        }
+    }
+
+    if !inputs
+        .iter()
+        .any(|input| !matches!(input, Input::Linker(flag) if flag.starts_with('-')))
+    {
+        return Err(Error::Usage("no input files".to_string()));
+    }
+
+    // Each input of -S or -c makes its own file, so one name cannot do
+    let outputs = inputs
+        .iter()
+        .filter(|input| match input {
+            Input::Source(_) => true,
+            Input::Assembly(_) => matches!(action, Action::Stop(Stage::Object)),
+            Input::Linker(_) => false,
+        })
+        .count();
+    if output.is_some() && matches!(action, Action::Stop(_)) && outputs > 1 {
+        return Err(Error::Usage(
+            "-o cannot be used with -S or -c and several inputs".to_string(),
+        ));
+    }
+
+    // Only x86-64 has an encoder for the built-in linker to work with
+    let x86_64 = target_name.starts_with("x86_64");
+    if linker == Linker::Builtin && !x86_64 {
+        return Err(Error::Usage(
+            "The built-in linker only supports x86-64".to_string(),
+        ));
+    }
+    if syntax.is_some() && !x86_64 {
+        return Err(Error::Usage("-masm only applies to x86-64".to_string()));
+    }
+    if debug && !x86_64 {
+        return Err(Error::Usage("-g only applies to x86-64".to_string()));
+    }
+    if frame_pointer.is_some() && !x86_64 {
+        return Err(Error::Usage(
+            "-fomit-frame-pointer only applies to x86-64".to_string(),
+        ));
+    }
+
+    // The options left unset keep the library's defaults
+    let mut settings = Compiler::new()
+        .target(&target_name)
+        .opt_level(opt_level)
+        .linker(linker);
+    if let Some(allocator) = allocator {
+        settings = settings.allocator(allocator);
+    }
+    if let Some(peephole) = peephole {
+        settings = settings.peephole(peephole);
+    }
+    if let Some(syntax) = syntax {
+        settings = settings.syntax(syntax);
+    }
+    if let Some(frame_pointer) = frame_pointer {
+        settings = settings.frame_pointer(frame_pointer);
+    }
+    // -w silences everything, wherever it comes
+    if no_warnings {
+        warnings = Warning::ALL
+            .into_iter()
+            .chain(Warning::EXTRA)
+            .map(|warning| (warning, false))
+            .collect();
+    }
+    for (warning, enabled) in warnings {
+        settings = settings.warning(warning, enabled);
+    }
+    settings = settings.warnings_as_errors(warnings_as_errors);
+    settings.triple().map_err(Error::Usage)?;
+
+    return Ok(Some(Options {
+        action,
+        run,
+        dump_format,
+        compiler: settings,
+        debug,
+        linker,
+        output,
+        inputs,
+        preprocessor,
+        toolchain: Toolchain::new(compiler, assembler, link_command),
+        save_temps,
+    }));
+}
+
+// Long flags take their value after `=` or as the next argument, so
+// `--ccache` is not `--cc ache`
+fn long(flag: &str, name: &str) -> bool {
+    return flag
+        .strip_prefix(name)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('='));
+}
+
+// Flags that take a value accept it attached, as in `-lm`, or as the next
+// argument
+fn value(flag: &str, name: &str, rest: &mut std::slice::Iter<String>) -> Result<String, Error> {
+    let attached = &flag[name.len()..];
+    let attached = attached.strip_prefix('=').unwrap_or(attached);
+    if !attached.is_empty() {
+        return Ok(attached.to_string());
+    }
+
+    return match rest.next() {
+        Some(value) => Ok(value.clone()),
+        None => Err(Error::Usage(format!("{} needs a value", name))),
+    };
+}
+
+fn run(options: &Options) -> Result<i32, Error> {
+    let [Input::Source(path)] = options.inputs.as_slice() else {
+        return Err(Error::Usage(
+            "crucible run takes a single C source".to_string(),
+        ));
+    };
+
+    if let Action::Dump(stage) = options.action {
+        dump(&compile(options, path, stage)?, options.dump_format);
+        return Ok(0);
+    }
+
+    if options.debug {
+        eprintln!("crucible: warning: -g is ignored when running in memory");
+    }
+    let source = read_source(options, path)?;
+    return finish(path, compiler(options, path).run(&source));
+}
+
+fn dump(artifact: &Artifact, format: Format) {
+    print!("{}", artifact.dump(format));
+}
+
+fn preprocess(options: &Options) -> Result<(), Error> {
+    let mut text = String::new();
+    for input in &options.inputs {
+        match input {
+            Input::Source(path) => text += &read_source(options, path)?,
+            Input::Assembly(path) | Input::Linker(path) => unused(path, "preprocessing"),
+        }
+    }
+
+    let output = options.output.as_deref().unwrap_or("-");
+    return write(output, text.as_bytes());
+}
+
+// -S and -c: one assembly or object file for each input
+fn compile_only(options: &Options, stage: Stage) -> Result<(), Error> {
+    let (extension, step) = match stage {
+        Stage::Assembly => ("s", "compiling"),
+        _ => ("o", "assembling"),
+    };
+
+    for (index, input) in options.inputs.iter().enumerate() {
+        match input {
+            Input::Source(path) => {
+                let output = output_path(options, path, extension)?;
+                match compile(options, path, stage)? {
+                    Artifact::Assembly(code) if stage == Stage::Assembly => {
+                        write(&output, code.as_bytes())?
+                    }
+                    // Targets without an encoder go through the assembler
+                    Artifact::Assembly(code) => {
+                        let assembly = intermediate(options, index, path, "s");
+                        write(&assembly, code.as_bytes())?;
+                        let assembled = external(
+                            options,
+                            Tool::Assembler,
+                            &["-c", &assembly, "-o", &output],
+                            "assemble",
+                        );
+                        discard(options, &assembly);
+                        assembled?;
+                    }
+                    Artifact::Object(object) => write(&output, &object)?,
+                    _ => unreachable!("the backend stops at -S and -c"),
+                }
+            }
+
+            Input::Assembly(path) if stage == Stage::Object => {
+                let output = output_path(options, path, extension)?;
+                external(
+                    options,
+                    Tool::Assembler,
+                    &["-c", path, "-o", &output],
+                    "assemble",
+                )?;
+            }
+
+            Input::Assembly(path) | Input::Linker(path) => unused(path, step),
+        }
+    }
+
+    return Ok(());
+}
+
+fn link(options: &Options) -> Result<(), Error> {
+    let output = match (&options.output, options.inputs.as_slice()) {
+        (Some(output), _) => output.clone(),
+        (None, [Input::Source(path) | Input::Assembly(path) | Input::Linker(path)])
+            if !path.starts_with('-') =>
+        {
+            Path::new(path).with_extension("").display().to_string()
+        }
+        (None, _) => "a.out".to_string(),
+    };
+
+    // The built-in linker works on the encoder's objects in memory, so it
+    // takes the one C source it can compile itself
+    if options.linker == Linker::Builtin {
+        let [Input::Source(path)] = options.inputs.as_slice() else {
+            return Err(Error::Usage(
+                "The built-in linker takes a single C source".to_string(),
+            ));
+        };
+        if options.debug {
+            eprintln!("crucible: warning: -g is ignored by the built-in linker");
+        }
+        let Artifact::Executable(executable) = compile(options, path, Stage::Full)? else {
+            unreachable!("the built-in linker makes an executable");
+        };
+
+        write(&output, &executable)?;
+        if output != "-" {
+            fs::set_permissions(&output, fs::Permissions::from_mode(0o755))
+                .map_err(|e| format!("{}: {}", output, e))?;
+        }
+        return Ok(());
+    }
+
+    let mut temporaries = Vec::new();
+    let linked = link_externally(options, &output, &mut temporaries);
+    for path in temporaries {
+        discard(options, &path);
+    }
+    return linked;
+}
+
+// Every input becomes an object first, so the assembler and linker can be
+// different programs
+fn link_externally(
+    options: &Options,
+    output: &str,
+    temporaries: &mut Vec<String>,
+) -> Result<(), Error> {
+    let mut arguments = Vec::new();
+    for (index, input) in options.inputs.iter().enumerate() {
+        match input {
+            Input::Source(path) => match compile(options, path, Stage::Full)? {
+                Artifact::Assembly(code) => {
+                    let assembly = intermediate(options, index, path, "s");
+                    write(&assembly, code.as_bytes())?;
+                    temporaries.push(assembly.clone());
+                    arguments.push(assemble(options, index, &assembly, path, temporaries)?);
+                }
+                Artifact::Object(bytes) => {
+                    let object = intermediate(options, index, path, "o");
+                    write(&object, &bytes)?;
+                    temporaries.push(object.clone());
+                    arguments.push(object);
+                }
+                _ => unreachable!("the system linker links assembly or objects"),
+            },
+
+            Input::Assembly(path) => {
+                arguments.push(assemble(options, index, path, path, temporaries)?);
+            }
+            Input::Linker(path) => arguments.push(path.clone()),
+        }
+    }
+
+    arguments.push("-o".to_string());
+    arguments.push(output.to_string());
+
+    let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+    return external(options, Tool::Linker, &arguments, "link");
+}
+
+// `input` names the object, which may be assembled from an intermediate
+fn assemble(
+    options: &Options,
+    index: usize,
+    assembly: &str,
+    input: &str,
+    temporaries: &mut Vec<String>,
+) -> Result<String, Error> {
+    let object = intermediate(options, index, input, "o");
+    temporaries.push(object.clone());
+    external(
+        options,
+        Tool::Assembler,
+        &["-c", assembly, "-o", &object],
+        "assemble",
+    )?;
+    return Ok(object);
+}
+
+fn compile(options: &Options, path: &str, stage: Stage) -> Result<Artifact, Error> {
+    let source = read_source(options, path)?;
+    return finish(
+        path,
+        compiler(options, path).stop_at(stage).compile(&source),
+    );
+}
+
+fn compiler(options: &Options, path: &str) -> Compiler {
+    let compiler = options.compiler.clone();
+    if options.debug {
+        return compiler.debug(name(path));
+    }
+    return compiler;
+}
+
+// Diagnostics are prefixed with the input they are about, and the line and
+// column when they have them. Warnings are printed as they come, and errors
+// fail the input
+fn finish<T>(path: &str, compilation: Compilation<T>) -> Result<T, Error> {
+    let mut errors = Vec::new();
+    for diagnostic in &compilation.diagnostics {
+        let message = match diagnostic.span {
+            Some(span) => format!(
+                "{}:{}:{}: {}",
+                name(path),
+                span.line,
+                span.column,
+                diagnostic
+            ),
+            None => format!("{}: {}", name(path), diagnostic),
+        };
+        match diagnostic.severity {
+            Severity::Warning => eprintln!("crucible: warning: {}", message),
+            Severity::Error => errors.push(message),
+        }
+    }
+
+    let Some(output) = compilation.output else {
+        return Err(Error::Failed(errors.join("\n")));
+    };
+    return Ok(output);
+}
+
+// Every source goes through the preprocessor. Without one on the PATH, a
+// source with no directives, and no -D or -I for it, is compiled as written:
+// the lexer skips comments and joins spliced lines itself, so the built-in
+// linker needs no tools at all
+fn read_source(options: &Options, path: &str) -> Result<String, Error> {
+    let source = if path == "-" {
+        let mut source = String::new();
+        io::stdin()
+            .read_to_string(&mut source)
+            .map_err(|e| format!("{}: {}", name(path), e))?;
+        source
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?
+    };
+
+    let directives = source
+        .lines()
+        .any(|line| line.trim_start().starts_with('#'));
+    let mut command = match options.toolchain.command(Tool::Preprocessor, "") {
+        Ok(command) => command,
+        Err(_)
+            if !directives
+                && options.preprocessor.is_empty()
+                && !matches!(options.action, Action::Preprocess) =>
+        {
+            if options.save_temps {
+                write(&saved(path, "i"), source.as_bytes())?;
+            }
+            return Ok(source);
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let program = command.get_program().to_string_lossy().into_owned();
+    command.arg("-E").arg("-P").args(&options.preprocessor);
+    command.stdout(Stdio::piped()).stderr(Stdio::inherit());
+
+    // Standard input has been read already, so it is piped back in
+    let preprocessed = if path == "-" {
+        command.arg("-x").arg("c").arg("-").stdin(Stdio::piped());
+        let mut child = command
+            .spawn()
+            .map_err(|e| format!("failed to run {}: {}", program, e))?;
+        let mut stdin = child.stdin.take().unwrap();
+        let writer = thread::spawn(move || stdin.write_all(source.as_bytes()));
+        let preprocessed = child.wait_with_output();
+        let _ = writer.join();
+        preprocessed
+    } else {
+        command.arg(path).output()
+    };
+
+    let preprocessed = preprocessed.map_err(|e| format!("failed to run {}: {}", program, e))?;
+    if !preprocessed.status.success() {
+        return Err(Error::Failed(format!("{} failed to preprocess", program)));
+    }
+    if options.save_temps {
+        write(&saved(path, "i"), &preprocessed.stdout)?;
+    }
+    return Ok(String::from_utf8(preprocessed.stdout).map_err(|e| e.to_string())?);
+}
+
+fn external(options: &Options, tool: Tool, arguments: &[&str], step: &str) -> Result<(), Error> {
+    let mut command = options
+        .toolchain
+        .command(tool, options.compiler.triple()?)?;
+    let program = command.get_program().to_string_lossy().into_owned();
+    let status = command
+        .args(arguments)
+        .status()
+        .map_err(|e| format!("failed to run {}: {}", program, e))?;
+    if !status.success() {
+        return Err(Error::Failed(format!("{} failed to {}", program, step)));
+    }
+    return Ok(());
+}
+
+// Where -S or -c puts the file for an input without -o: next to the input
+fn output_path(options: &Options, path: &str, extension: &str) -> Result<String, Error> {
+    if let Some(output) = &options.output {
+        return Ok(output.clone());
+    }
+
+    return match (path, extension) {
+        ("-", "s") => Ok("-".to_string()),
+        ("-", _) => Err(Error::Usage(
+            "-c needs -o to compile standard input".to_string(),
+        )),
+        _ => Ok(Path::new(path)
+            .with_extension(extension)
+            .display()
+            .to_string()),
+    };
+}
+
+// Inputs can share a stem, so the position on the command line tells them
+// apart in the temporary directory
+fn intermediate(options: &Options, index: usize, path: &str, extension: &str) -> String {
+    if options.save_temps {
+        return saved(path, extension);
+    }
+
+    let name = format!(
+        "crucible-{}-{}-{}.{}",
+        process::id(),
+        index,
+        stem(path),
+        extension
+    );
+    return env::temp_dir().join(name).display().to_string();
+}
+
+// With --save-temps, intermediates go to the current directory, named after
+// their input the way gcc names them
+fn saved(path: &str, extension: &str) -> String {
+    return format!("{}.{}", stem(path), extension);
+}
+
+fn discard(options: &Options, path: &str) {
+    if !options.save_temps {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn stem(path: &str) -> String {
+    if path == "-" {
+        return "stdin".to_string();
+    }
+    return Path::new(path)
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+}
+
+// `-` writes to standard output
+fn write(path: &str, bytes: &[u8]) -> Result<(), Error> {
+    let written = if path == "-" {
+        io::stdout().write_all(bytes)
+    } else {
+        fs::write(path, bytes)
+    };
+    return Ok(written.map_err(|e| format!("{}: {}", name(path), e))?);
+}
+
+fn unused(path: &str, step: &str) {
+    if !path.starts_with('-') {
+        eprintln!(
+            "crucible: warning: {}: input unused when only {}",
+            path, step
+        );
+    }
+}
+
+fn name(path: &str) -> &str {
+    return if path == "-" { "<stdin>" } else { path };
+}
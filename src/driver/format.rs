@@ -0,0 +1,65 @@
+// `crucible fmt`: rewrites C sources in the formatter's layout, or with
+// `--check` lists the ones it would change and fails, for CI
+
+use super::{Error, name};
+use std::fs;
+use std::io::{self, Read};
+
+const USAGE: &str = "\
+Usage: crucible fmt [--check] <sources...>
+Sources are rewritten in place, and standard input (-) is printed
+";
+
+pub fn format(args: &[String]) -> Result<i32, Error> {
+    let mut check = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                return Ok(0);
+            }
+            "-" => paths.push(arg.clone()),
+            flag if flag.starts_with('-') => {
+                return Err(Error::Usage(format!("Unknown flag: {}", flag)));
+            }
+            path => paths.push(path.to_string()),
+        }
+    }
+    if paths.is_empty() {
+        return Err(Error::Usage("no input files".to_string()));
+    }
+
+    let mut status = 0;
+    for path in &paths {
+        let source = if path == "-" {
+            let mut source = String::new();
+            io::stdin()
+                .read_to_string(&mut source)
+                .map_err(|e| format!("{}: {}", name(path), e))?;
+            source
+        } else {
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?
+        };
+
+        let formatted = match crucible::format(&source) {
+            Ok(formatted) => formatted,
+            Err(diagnostic) => {
+                return Err(Error::Failed(format!("{}: {}", name(path), diagnostic)));
+            }
+        };
+
+        if check {
+            if formatted != source {
+                println!("{}", name(path));
+                status = 1;
+            }
+        } else if path == "-" {
+            print!("{}", formatted);
+        } else if formatted != source {
+            fs::write(path, formatted).map_err(|e| format!("{}: {}", path, e))?;
+        }
+    }
+    return Ok(status);
+}
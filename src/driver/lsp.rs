@@ -0,0 +1,457 @@
+// `crucible lsp`: a language server speaking JSON-RPC over standard input
+// and output. Each open document is compiled through validation whenever it
+// changes, and its errors and warnings, every warning turned on, are
+// published as diagnostics. Definitions, references, hover and the outline
+// come from the names semantic analysis resolved, in `lsp/index.rs`.
+// Sources are taken as written, without the preprocessor.
+
+mod index;
+
+use super::Error;
+use crucible::json::Json;
+use crucible::token::Span;
+use crucible::{Compiler, Severity, Stage, Warning};
+use index::{Index, Kind, Occurrence};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+const USAGE: &str = "\
+Usage: crucible lsp [--stdio]
+Serves the Language Server Protocol on standard input and output
+";
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+struct Document {
+    text: String,
+    // Missing while the source does not parse
+    index: Option<Index>,
+}
+
+struct Server {
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+// Returns 0 after a `shutdown` request and an `exit`, and 1 when the client
+// exits or goes away without asking to shut down first
+pub fn lsp(args: &[String]) -> Result<i32, Error> {
+    for arg in args {
+        match arg.as_str() {
+            // What editors pass to say how to talk, which is the only way
+            "--stdio" => {}
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                return Ok(0);
+            }
+            other => return Err(Error::Usage(format!("Unknown argument: {}", other))),
+        }
+    }
+
+    let mut server = Server {
+        documents: HashMap::new(),
+        shutdown: false,
+    };
+    let mut input = io::stdin().lock();
+    let mut output = io::stdout().lock();
+    let io_error = |e: io::Error| Error::Failed(format!("lsp: {}", e));
+
+    while let Some(body) = receive(&mut input).map_err(io_error)? {
+        let message = match Json::parse(&body) {
+            Ok(message) => message,
+            Err(e) => {
+                let reply = failure(Json::Null, PARSE_ERROR, &format!("Invalid JSON: {}", e));
+                send(&mut output, &reply).map_err(io_error)?;
+                continue;
+            }
+        };
+        if message.get("method").and_then(Json::as_str) == Some("exit") {
+            return Ok(if server.shutdown { 0 } else { 1 });
+        }
+        for reply in server.handle(&message) {
+            send(&mut output, &reply).map_err(io_error)?;
+        }
+    }
+    return Ok(1);
+}
+
+// One message's content, or nothing at the end of the input
+fn receive(input: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let Some(length) = length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "message without a Content-Length",
+        ));
+    };
+    let mut body = vec![0; length];
+    input.read_exact(&mut body)?;
+    return Ok(Some(String::from_utf8_lossy(&body).into_owned()));
+}
+
+fn send(output: &mut impl Write, message: &Json) -> io::Result<()> {
+    let body = message.compact();
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    return output.flush();
+}
+
+fn success(id: Json, result: Json) -> Json {
+    return Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("id", id),
+        ("result", result),
+    ]);
+}
+
+fn failure(id: Json, code: i64, message: &str) -> Json {
+    let error = Json::object(vec![
+        ("code", Json::Number(code)),
+        ("message", Json::string(message)),
+    ]);
+    return Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("id", id),
+        ("error", error),
+    ]);
+}
+
+fn notification(method: &str, params: Json) -> Json {
+    return Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("method", Json::string(method)),
+        ("params", params),
+    ]);
+}
+
+impl Server {
+    // The replies to a message: the response to a request, and the
+    // diagnostics of a document that changed
+    fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let id = message.get("id").cloned();
+        let Some(method) = message.get("method").and_then(Json::as_str) else {
+            // Only responses have an id and no method, and the server sends
+            // no requests to be answered
+            if id.is_some() {
+                return Vec::new();
+            }
+            return vec![failure(Json::Null, INVALID_REQUEST, "Missing method")];
+        };
+        let params = message.get("params").unwrap_or(&Json::Null);
+        let uri = params
+            .get("textDocument")
+            .and_then(|document| document.get("uri"))
+            .and_then(Json::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        // Notifications get no response
+        match method {
+            "textDocument/didOpen" => {
+                let text = params
+                    .get("textDocument")
+                    .and_then(|document| document.get("text"));
+                return self.update(&uri, text);
+            }
+            // The server asks for whole documents, so the last change holds
+            // the text
+            "textDocument/didChange" => {
+                let text = match params.get("contentChanges") {
+                    Some(Json::Array(changes)) => changes.last().and_then(|c| c.get("text")),
+                    _ => None,
+                };
+                return self.update(&uri, text);
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                return vec![diagnostics(&uri, Vec::new())];
+            }
+            _ if id.is_none() => return Vec::new(),
+            _ => {}
+        }
+
+        let id = id.unwrap();
+        let result = match method {
+            "initialize" => capabilities(),
+            "shutdown" => {
+                self.shutdown = true;
+                Json::Null
+            }
+            "textDocument/definition" => self.definition(&uri, params),
+            "textDocument/references" => self.references(&uri, params),
+            "textDocument/hover" => self.hover(&uri, params),
+            "textDocument/documentSymbol" => self.symbols(&uri),
+            _ => {
+                let message = format!("Unsupported method: {}", method);
+                return vec![failure(id, METHOD_NOT_FOUND, &message)];
+            }
+        };
+        return vec![success(id, result)];
+    }
+
+    fn update(&mut self, uri: &str, text: Option<&Json>) -> Vec<Json> {
+        let Some(text) = text.and_then(Json::as_str) else {
+            return Vec::new();
+        };
+        let mut compiler = Compiler::new().stop_at(Stage::Validate);
+        for warning in Warning::ALL.into_iter().chain(Warning::EXTRA) {
+            compiler = compiler.warning(warning, true);
+        }
+        let compilation = compiler.compile(text);
+        let found = compilation
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let range = match diagnostic.span {
+                    Some(span) => word(text, span),
+                    None => range(text, Span { line: 1, column: 1 }, 0),
+                };
+                let severity = match diagnostic.severity {
+                    Severity::Error => 1,
+                    Severity::Warning => 2,
+                };
+                let mut members = vec![
+                    ("range", range),
+                    ("severity", Json::Number(severity)),
+                    ("source", Json::string("crucible")),
+                    ("message", Json::string(&diagnostic.message)),
+                ];
+                // The warning's name, as in its flag
+                if let Some(warning) = diagnostic.warning {
+                    members.push(("code", Json::string(warning.name())));
+                }
+                return Json::object(members);
+            })
+            .collect();
+
+        let document = Document {
+            text: text.to_string(),
+            index: index::index(text),
+        };
+        self.documents.insert(uri.to_string(), document);
+        return vec![diagnostics(uri, found)];
+    }
+
+    // The document and the resolved name under the position in `params`
+    fn lookup(&self, uri: &str, params: &Json) -> Option<(&Document, &Index, &Occurrence)> {
+        let document = self.documents.get(uri)?;
+        let index = document.index.as_ref()?;
+        let position = params.get("position")?;
+        let line = position.get("line")?.as_i64()?;
+        let character = position.get("character")?.as_i64()?;
+        let span = span(&document.text, line, character)?;
+        let occurrence = index.at(span.line, span.column)?;
+        return Some((document, index, occurrence));
+    }
+
+    fn definition(&self, uri: &str, params: &Json) -> Json {
+        let Some((document, index, occurrence)) = self.lookup(uri, params) else {
+            return Json::Null;
+        };
+        if !index.resolved {
+            return Json::Null;
+        }
+        return match index.definition(&occurrence.symbol) {
+            Some(definition) => location(uri, &document.text, definition),
+            None => Json::Null,
+        };
+    }
+
+    fn references(&self, uri: &str, params: &Json) -> Json {
+        let Some((document, index, occurrence)) = self.lookup(uri, params) else {
+            return Json::Null;
+        };
+        if !index.resolved {
+            return Json::Null;
+        }
+        let declaration = params
+            .get("context")
+            .and_then(|context| context.get("includeDeclaration"))
+            != Some(&Json::Bool(false));
+        let references = index
+            .references(&occurrence.symbol)
+            .filter(|reference| declaration || !reference.definition)
+            .map(|reference| location(uri, &document.text, reference));
+        return Json::Array(references.collect());
+    }
+
+    // Every value is an `int`, so the type is all there is to say
+    fn hover(&self, uri: &str, params: &Json) -> Json {
+        let Some((document, _, occurrence)) = self.lookup(uri, params) else {
+            return Json::Null;
+        };
+        let code = match occurrence.kind {
+            Kind::Function => format!("int {}(void)", occurrence.name),
+            Kind::Variable => format!("int {}", occurrence.name),
+            Kind::Label => format!("{}:", occurrence.name),
+        };
+        let contents = Json::object(vec![
+            ("kind", Json::string("markdown")),
+            ("value", Json::String(format!("```c\n{}\n```", code))),
+        ]);
+        return Json::object(vec![
+            ("contents", contents),
+            ("range", name_range(&document.text, occurrence)),
+        ]);
+    }
+
+    // The function, with its variables and labels inside it
+    fn symbols(&self, uri: &str) -> Json {
+        let Some(document) = self.documents.get(uri) else {
+            return Json::Null;
+        };
+        let Some(index) = &document.index else {
+            return Json::Array(Vec::new());
+        };
+        let text = &document.text;
+        let mut definitions = index.occurrences.iter().filter(|o| o.definition);
+        let Some(function) = definitions.next() else {
+            return Json::Array(Vec::new());
+        };
+
+        let children = definitions.map(|definition| {
+            let (kind, detail) = match definition.kind {
+                Kind::Label => (20, "label"),
+                _ => (13, "int"),
+            };
+            return symbol(text, definition, kind, detail, name_range(text, definition));
+        });
+        // From the name to the closing brace
+        let end = Span {
+            line: index.end.line,
+            column: index.end.column + 1,
+        };
+        let mut function = symbol(
+            text,
+            function,
+            12,
+            "int (void)",
+            range_between(text, function.span, end),
+        );
+        if let Json::Object(members) = &mut function {
+            members.push(("children".to_string(), Json::Array(children.collect())));
+        }
+        return Json::Array(vec![function]);
+    }
+}
+
+fn capabilities() -> Json {
+    let capabilities = Json::object(vec![
+        ("textDocumentSync", Json::Number(1)),
+        ("definitionProvider", Json::Bool(true)),
+        ("referencesProvider", Json::Bool(true)),
+        ("hoverProvider", Json::Bool(true)),
+        ("documentSymbolProvider", Json::Bool(true)),
+    ]);
+    let info = Json::object(vec![
+        ("name", Json::string("crucible")),
+        ("version", Json::string(env!("CARGO_PKG_VERSION"))),
+    ]);
+    return Json::object(vec![("capabilities", capabilities), ("serverInfo", info)]);
+}
+
+fn diagnostics(uri: &str, found: Vec<Json>) -> Json {
+    let params = Json::object(vec![
+        ("uri", Json::string(uri)),
+        ("diagnostics", Json::Array(found)),
+    ]);
+    return notification("textDocument/publishDiagnostics", params);
+}
+
+fn symbol(text: &str, definition: &Occurrence, kind: i64, detail: &str, range: Json) -> Json {
+    return Json::object(vec![
+        ("name", Json::string(&definition.name)),
+        ("detail", Json::string(detail)),
+        ("kind", Json::Number(kind)),
+        ("range", range),
+        ("selectionRange", name_range(text, definition)),
+    ]);
+}
+
+fn location(uri: &str, text: &str, occurrence: &Occurrence) -> Json {
+    return Json::object(vec![
+        ("uri", Json::string(uri)),
+        ("range", name_range(text, occurrence)),
+    ]);
+}
+
+// Spans count lines from 1 and columns in bytes from 1, where the protocol
+// counts both from 0 and columns in UTF-16 code units
+
+fn position(text: &str, span: Span) -> Json {
+    let line = text.lines().nth(span.line as usize - 1).unwrap_or_default();
+    let bytes = (span.column as usize - 1).min(line.len());
+    let character = line
+        .get(..bytes)
+        .map_or(bytes, |prefix| prefix.encode_utf16().count());
+    return Json::object(vec![
+        ("line", Json::Number(i64::from(span.line) - 1)),
+        ("character", Json::Number(character as i64)),
+    ]);
+}
+
+fn span(text: &str, line: i64, character: i64) -> Option<Span> {
+    let source_line = text.lines().nth(usize::try_from(line).ok()?)?;
+    let mut units = 0;
+    let mut column = source_line.len();
+    for (offset, ch) in source_line.char_indices() {
+        if units >= character {
+            column = offset;
+            break;
+        }
+        units += ch.len_utf16() as i64;
+    }
+    return Some(Span {
+        line: u32::try_from(line + 1).ok()?,
+        column: column as u32 + 1,
+    });
+}
+
+fn range(text: &str, start: Span, length: u32) -> Json {
+    let end = Span {
+        line: start.line,
+        column: start.column + length,
+    };
+    return range_between(text, start, end);
+}
+
+fn range_between(text: &str, start: Span, end: Span) -> Json {
+    return Json::object(vec![
+        ("start", position(text, start)),
+        ("end", position(text, end)),
+    ]);
+}
+
+fn name_range(text: &str, occurrence: &Occurrence) -> Json {
+    return range(text, occurrence.span, occurrence.name.len() as u32);
+}
+
+// A diagnostic covers the word or the character where it was found
+fn word(text: &str, span: Span) -> Json {
+    let line = text.lines().nth(span.line as usize - 1).unwrap_or_default();
+    let rest = line.get(span.column as usize - 1..).unwrap_or_default();
+    let length = match rest.find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_') {
+        Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+        Some(length) => length,
+        None => rest.len(),
+    };
+    return range(text, span, length as u32);
+}
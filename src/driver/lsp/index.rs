@@ -0,0 +1,217 @@
+// Where each name in a source is written and what it refers to. Semantic
+// analysis renames every variable and label to a unique name (`a.0`,
+// `label.end.1`), so names that resolve to the same unique name are the same
+// symbol. Each name in the tree carries the span of its identifier, and the
+// resolvers record how each unique name was written.
+
+use crucible::ast::*;
+use crucible::token::{Span, Token};
+use crucible::{Artifact, Compiler, Stage};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Function,
+    Variable,
+    Label,
+}
+
+pub struct Occurrence {
+    // As written in the source
+    pub name: String,
+    // The unique name it resolved to, or the name itself for the function
+    // and for a source that did not validate
+    pub symbol: String,
+    pub kind: Kind,
+    pub definition: bool,
+    pub span: Span,
+}
+
+pub struct Index {
+    // In source order, the function's name first
+    pub occurrences: Vec<Occurrence>,
+    // Where the function's body ends
+    pub end: Span,
+    // Whether the names were resolved. Without that, names cannot be told
+    // apart by scope, and only the definitions mean anything
+    pub resolved: bool,
+}
+
+// A source that does not validate is indexed from its parse, which still
+// gives its outline. One that does not parse has no index
+pub fn index(source: &str) -> Option<Index> {
+    let compile = |stage| Compiler::new().stop_at(stage).compile(source).output;
+    let Some(Artifact::Tokens(tokens)) = compile(Stage::Lex) else {
+        return None;
+    };
+    let (program, resolved) = match compile(Stage::Validate) {
+        Some(Artifact::Ast(program)) => (program, true),
+        _ => match compile(Stage::Parse) {
+            Some(Artifact::Ast(program)) => (program, false),
+            _ => return None,
+        },
+    };
+
+    let function = &program.function;
+    let mut names = vec![(function.name.clone(), Kind::Function, true, function.span)];
+    block(&function.body, &mut names);
+
+    let occurrences = names
+        .into_iter()
+        .map(|(symbol, kind, definition, span)| {
+            let name = match program.names.get(&symbol) {
+                Some(written) => written.clone(),
+                None => symbol.clone(),
+            };
+            return Occurrence {
+                name,
+                symbol,
+                kind,
+                definition,
+                span,
+            };
+        })
+        .collect();
+
+    let end = tokens
+        .iter()
+        .rev()
+        .find(|(token, _)| *token == Token::CloseBrace)
+        .map_or(tokens[tokens.len() - 1].1, |(_, span)| *span);
+    return Some(Index {
+        occurrences,
+        end,
+        resolved,
+    });
+}
+
+impl Index {
+    // The name at `line` and `column`, counting a cursor just past its end
+    pub fn at(&self, line: u32, column: u32) -> Option<&Occurrence> {
+        return self.occurrences.iter().find(|occurrence| {
+            let span = occurrence.span;
+            let end = span.column + occurrence.name.len() as u32;
+            return span.line == line && span.column <= column && column <= end;
+        });
+    }
+
+    pub fn definition(&self, symbol: &str) -> Option<&Occurrence> {
+        return self
+            .occurrences
+            .iter()
+            .find(|occurrence| occurrence.symbol == symbol && occurrence.definition);
+    }
+
+    pub fn references<'a>(&'a self, symbol: &'a str) -> impl Iterator<Item = &'a Occurrence> {
+        return self
+            .occurrences
+            .iter()
+            .filter(move |occurrence| occurrence.symbol == symbol);
+    }
+}
+
+type Names = Vec<(String, Kind, bool, Span)>;
+
+fn block(block: &Block, names: &mut Names) {
+    for item in &block.items {
+        match item {
+            BlockItem::Declaration(declaration) => self::declaration(declaration, names),
+            BlockItem::Statement(statement) => self::statement(statement, names),
+        }
+    }
+}
+
+fn declaration(declaration: &Declaration, names: &mut Names) {
+    names.push((
+        declaration.name.clone(),
+        Kind::Variable,
+        true,
+        declaration.name_span,
+    ));
+    if let Some(init) = &declaration.init {
+        expression(init, names);
+    }
+}
+
+// Loop labels are made up by validation and never written, so `break` and
+// `continue` name nothing
+fn statement(statement: &Statement, names: &mut Names) {
+    match &statement.kind {
+        StatementKind::Return(value) | StatementKind::Expression(value) => {
+            expression(value, names);
+        }
+        StatementKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            expression(condition, names);
+            self::statement(then_branch, names);
+            if let Some(else_branch) = else_branch {
+                self::statement(else_branch, names);
+            }
+        }
+        StatementKind::Compound(body) => block(body, names),
+        StatementKind::While {
+            condition, body, ..
+        } => {
+            expression(condition, names);
+            self::statement(body, names);
+        }
+        StatementKind::DoWhile {
+            body, condition, ..
+        } => {
+            self::statement(body, names);
+            expression(condition, names);
+        }
+        StatementKind::For {
+            init,
+            condition,
+            post,
+            body,
+            ..
+        } => {
+            match init {
+                ForInit::InitDecl(init) => declaration(init, names),
+                ForInit::InitExpr(Some(init)) => expression(init, names),
+                ForInit::InitExpr(None) => {}
+            }
+            for clause in [condition, post].into_iter().flatten() {
+                expression(clause, names);
+            }
+            self::statement(body, names);
+        }
+        StatementKind::Goto(label, span) => names.push((label.clone(), Kind::Label, false, *span)),
+        StatementKind::Labeled(label, span, body) => {
+            names.push((label.clone(), Kind::Label, true, *span));
+            self::statement(body, names);
+        }
+        StatementKind::Break(_) | StatementKind::Continue(_) | StatementKind::Null => {}
+    }
+}
+
+fn expression(expr: &Expr, names: &mut Names) {
+    match expr {
+        Expr::Constant(_) => {}
+        Expr::Variable(name, span) => names.push((name.clone(), Kind::Variable, false, *span)),
+        Expr::Unary(_, operand)
+        | Expr::PostfixIncrement(operand)
+        | Expr::PostfixDecrement(operand) => expression(operand, names),
+        Expr::Binary { left, right, .. } => {
+            expression(left, names);
+            expression(right, names);
+        }
+        Expr::Assignment { target, value } | Expr::CompoundAssignment { target, value, .. } => {
+            expression(target, names);
+            expression(value, names);
+        }
+        Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            expression(condition, names);
+            expression(then_branch, names);
+            expression(else_branch, names);
+        }
+    }
+}
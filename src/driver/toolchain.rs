@@ -0,0 +1,107 @@
+// The external programs the driver runs to preprocess, assemble and link.
+// Each one is called the way `cc` is, so a compiler driver works for all
+// three, and `as` works as the assembler.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// Tried in order when a tool is not configured. Clang comes first since it
+// is the only one that can assemble and link for another target
+const COMPILERS: [&str; 3] = ["clang", "gcc", "cc"];
+
+#[derive(Clone, Copy)]
+pub enum Tool {
+    Preprocessor,
+    Assembler,
+    Linker,
+}
+
+pub struct Toolchain {
+    compiler: Option<String>,
+    assembler: Option<String>,
+    linker: Option<String>,
+}
+
+impl Toolchain {
+    // Flags win over `CRUCIBLE_CC`, `CRUCIBLE_AS` and `CRUCIBLE_LD`, and the
+    // assembler and linker default to the compiler
+    pub fn new(
+        compiler: Option<String>,
+        assembler: Option<String>,
+        linker: Option<String>,
+    ) -> Toolchain {
+        return Toolchain {
+            compiler: compiler.or_else(|| variable("CRUCIBLE_CC")),
+            assembler: assembler.or_else(|| variable("CRUCIBLE_AS")),
+            linker: linker.or_else(|| variable("CRUCIBLE_LD")),
+        };
+    }
+
+    // A command may carry its own arguments, as in `--cc "zig cc"`. Only
+    // clang is told the target; other compilers build for their own
+    pub fn command(&self, tool: Tool, triple: &str) -> Result<Command, String> {
+        let configured = match tool {
+            Tool::Preprocessor => self.compiler.as_ref(),
+            Tool::Assembler => self.assembler.as_ref().or(self.compiler.as_ref()),
+            Tool::Linker => self.linker.as_ref().or(self.compiler.as_ref()),
+        };
+        let line = match configured {
+            Some(line) => line.clone(),
+            None => detect(triple)?,
+        };
+
+        let mut words = line.split_whitespace();
+        let Some(program) = words.next() else {
+            return Err("an empty command was given for a tool".to_string());
+        };
+
+        let mut command = Command::new(program);
+        command.args(words);
+
+        let name = Path::new(program).file_name().unwrap_or_default();
+        let clang = name.to_string_lossy().contains("clang");
+        if clang && !matches!(tool, Tool::Preprocessor) {
+            command.arg("-target").arg(triple);
+        }
+        return Ok(command);
+    }
+}
+
+fn variable(name: &str) -> Option<String> {
+    return env::var(name).ok().filter(|value| !value.trim().is_empty());
+}
+
+// The host's compilers only build for its own architecture, so another one
+// needs clang or a cross compiler named after the triple, like
+// `riscv64-linux-gnu-gcc`. The preprocessor passes no triple.
+fn detect(triple: &str) -> Result<String, String> {
+    let architecture = triple.split('-').next().unwrap_or_default();
+    let cross = !triple.is_empty() && architecture != env::consts::ARCH;
+    let compilers: Vec<String> = if cross {
+        vec!["clang".to_string(), format!("{}-gcc", triple)]
+    } else {
+        COMPILERS
+            .iter()
+            .map(|compiler| compiler.to_string())
+            .collect()
+    };
+
+    let path = env::var_os("PATH").unwrap_or_default();
+    for compiler in &compilers {
+        if env::split_paths(&path).any(|directory| directory.join(compiler).is_file()) {
+            return Ok(compiler.clone());
+        }
+    }
+
+    if cross {
+        return Err(format!(
+            "no C compiler for {} found: install clang or {}, or name one with --cc or CRUCIBLE_CC",
+            triple, compilers[1]
+        ));
+    }
+    return Err(
+        "no C compiler found: install clang, gcc or cc, or name one with --cc or CRUCIBLE_CC"
+            .to_string(),
+    );
+}
@@ -0,0 +1,67 @@
+// Printers for what each stage produces: text for people and snapshot tests,
+// and JSON for tools. Unlike `{:?}`, neither changes when a Rust type does.
+
+mod ast;
+mod ir;
+pub(crate) mod listing;
+mod tokens;
+
+pub(crate) use ir::ssa;
+
+use crate::Artifact;
+use crate::json::Json;
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+// A stage whose types are private to the compiler, the SSA form and each
+// target's instructions, printed both ways when it is produced
+#[derive(Debug)]
+pub struct Dump {
+    pub text: String,
+    pub json: Json,
+}
+
+impl Artifact {
+    pub fn dump(&self, format: Format) -> String {
+        let json = match (self, format) {
+            (Artifact::Tokens(tokens), Format::Text) => return tokens::text(tokens),
+            (Artifact::Tokens(tokens), Format::Json) => tokens::json(tokens),
+            (Artifact::Ast(program), Format::Text) => return ast::text(program),
+            (Artifact::Ast(program), Format::Json) => ast::json(program),
+            (Artifact::Ir(program), Format::Text) => return ir::text(program),
+            (Artifact::Ir(program), Format::Json) => ir::json(program),
+
+            (Artifact::Ssa(dump) | Artifact::Instructions(dump), Format::Text) => {
+                return dump.text.clone();
+            }
+            (Artifact::Ssa(dump) | Artifact::Instructions(dump), Format::Json) => dump.json.clone(),
+
+            (Artifact::Assembly(code), Format::Text) => return code.clone(),
+            (Artifact::Assembly(code), Format::Json) => {
+                Json::object(vec![("assembly", Json::string(code))])
+            }
+
+            // Sixteen bytes of hex to a line, like `xxd -p`
+            (Artifact::Object(bytes) | Artifact::Executable(bytes), Format::Text) => {
+                let mut output = String::new();
+                for line in bytes.chunks(16) {
+                    writeln!(output, "{}", hex(line)).unwrap();
+                }
+                return output;
+            }
+            (Artifact::Object(bytes) | Artifact::Executable(bytes), Format::Json) => {
+                Json::object(vec![("bytes", Json::String(hex(bytes)))])
+            }
+        };
+        return json.pretty();
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    return bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+}
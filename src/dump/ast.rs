@@ -0,0 +1,385 @@
+// The AST as S-expressions, a statement to a line and each expression on the
+// line of its statement, and as JSON nodes tagged with their kind.
+
+use crate::ast::*;
+use crate::json::Json;
+use crate::token::Span;
+
+pub fn text(program: &Program) -> String {
+    let function = &program.function;
+    let items = function.body.items.iter().map(item).collect();
+    let mut output = String::new();
+    for line in nested(format!("function {}", function.name), items) {
+        output.push_str(&line);
+        output.push('\n');
+    }
+    return output;
+}
+
+// `(head`, then the children indented below it, and the closing parenthesis
+// on the last line
+fn nested(head: String, children: Vec<Vec<String>>) -> Vec<String> {
+    if children.is_empty() {
+        return vec![format!("({})", head)];
+    }
+    let mut lines = vec![format!("({}", head)];
+    for child in children {
+        lines.extend(child.into_iter().map(|line| format!("  {}", line)));
+    }
+    lines.last_mut().unwrap().push(')');
+    return lines;
+}
+
+fn item(item: &BlockItem) -> Vec<String> {
+    return match item {
+        BlockItem::Declaration(declaration) => vec![declare(declaration)],
+        BlockItem::Statement(statement) => self::statement(statement),
+    };
+}
+
+fn declare(declaration: &Declaration) -> String {
+    return match &declaration.init {
+        Some(init) => format!("(declare {} {})", declaration.name, expression(init)),
+        None => format!("(declare {})", declaration.name),
+    };
+}
+
+// Loops are labeled by validation, so a parsed one prints without a label
+fn labeled(keyword: &str, label: &str) -> String {
+    if label.is_empty() {
+        return keyword.to_string();
+    }
+    return format!("{} {}", keyword, label);
+}
+
+fn statement(statement: &Statement) -> Vec<String> {
+    let line = match &statement.kind {
+        StatementKind::Return(value) => format!("(return {})", expression(value)),
+        StatementKind::Expression(value) => format!("(expression {})", expression(value)),
+        StatementKind::Break(label) => format!("({})", labeled("break", label)),
+        StatementKind::Continue(label) => format!("({})", labeled("continue", label)),
+        StatementKind::Goto(label, _) => format!("(goto {})", label),
+        StatementKind::Null => "(null)".to_string(),
+
+        StatementKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            let mut branches = vec![self::statement(then_branch)];
+            if let Some(else_branch) = else_branch {
+                branches.push(self::statement(else_branch));
+            }
+            return nested(format!("if {}", expression(condition)), branches);
+        }
+
+        StatementKind::Compound(block) => {
+            return nested("block".to_string(), block.items.iter().map(item).collect());
+        }
+
+        StatementKind::While {
+            condition,
+            body,
+            label,
+        } => {
+            let head = format!("{} {}", labeled("while", label), expression(condition));
+            return nested(head, vec![self::statement(body)]);
+        }
+
+        StatementKind::DoWhile {
+            body,
+            condition,
+            label,
+        } => {
+            let head = format!("{} {}", labeled("do-while", label), expression(condition));
+            return nested(head, vec![self::statement(body)]);
+        }
+
+        StatementKind::For {
+            init,
+            condition,
+            post,
+            body,
+            label,
+        } => {
+            let init = match init {
+                ForInit::InitDecl(declaration) => declare(declaration),
+                ForInit::InitExpr(value) => optional(value),
+            };
+            let head = format!(
+                "{} {} {} {}",
+                labeled("for", label),
+                init,
+                optional(condition),
+                optional(post)
+            );
+            return nested(head, vec![self::statement(body)]);
+        }
+
+        StatementKind::Labeled(label, _, body) => {
+            return nested(format!("label {}", label), vec![self::statement(body)]);
+        }
+    };
+    return vec![line];
+}
+
+// A missing clause of a `for`
+fn optional(value: &Option<Expr>) -> String {
+    return match value {
+        Some(value) => expression(value),
+        None => "()".to_string(),
+    };
+}
+
+fn expression(expr: &Expr) -> String {
+    return match expr {
+        Expr::Constant(value) => value.to_string(),
+        Expr::Variable(name, _) => name.clone(),
+        Expr::Unary(op, operand) => format!("({} {})", unary(op), expression(operand)),
+        Expr::Binary { op, left, right } => {
+            format!(
+                "({} {} {})",
+                binary(op),
+                expression(left),
+                expression(right)
+            )
+        }
+        Expr::PostfixIncrement(operand) => format!("(post++ {})", expression(operand)),
+        Expr::PostfixDecrement(operand) => format!("(post-- {})", expression(operand)),
+        Expr::Assignment { target, value } => {
+            format!("(= {} {})", expression(target), expression(value))
+        }
+        Expr::CompoundAssignment { target, op, value } => format!(
+            "({}= {} {})",
+            binary(op),
+            expression(target),
+            expression(value)
+        ),
+        Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        } => format!(
+            "(? {} {} {})",
+            expression(condition),
+            expression(then_branch),
+            expression(else_branch)
+        ),
+    };
+}
+
+fn unary(op: &UnaryOperator) -> &'static str {
+    return match op {
+        UnaryOperator::Negate => "-",
+        UnaryOperator::LogicalNot => "!",
+        UnaryOperator::Complement => "~",
+        UnaryOperator::PrefixIncrement => "++",
+        UnaryOperator::PrefixDecrement => "--",
+    };
+}
+
+fn binary(op: &BinaryOperator) -> &'static str {
+    return match op {
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::BitwiseAnd => "&",
+        BinaryOperator::BitwiseOr => "|",
+        BinaryOperator::BitwiseXor => "^",
+        BinaryOperator::LeftShift => "<<",
+        BinaryOperator::RightShift => ">>",
+        BinaryOperator::LogicalAnd => "&&",
+        BinaryOperator::LogicalOr => "||",
+        BinaryOperator::Equal => "==",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::LessThan => "<",
+        BinaryOperator::LessOrEqual => "<=",
+        BinaryOperator::GreaterThan => ">",
+        BinaryOperator::GreaterOrEqual => ">=",
+    };
+}
+
+pub fn json(program: &Program) -> Json {
+    let function = &program.function;
+    let mut members = vec![
+        ("kind", Json::string("function")),
+        ("name", Json::string(&function.name)),
+    ];
+    members.extend(location(function.span));
+    members.push(("body", block_json(&function.body)));
+    return Json::object(vec![("function", Json::object(members))]);
+}
+
+fn location(span: Span) -> [(&'static str, Json); 2] {
+    return [
+        ("line", Json::Number(span.line.into())),
+        ("column", Json::Number(span.column.into())),
+    ];
+}
+
+fn block_json(block: &Block) -> Json {
+    let items = block.items.iter().map(|item| match item {
+        BlockItem::Declaration(declaration) => declaration_json(declaration),
+        BlockItem::Statement(statement) => statement_json(statement),
+    });
+    return Json::Array(items.collect());
+}
+
+fn declaration_json(declaration: &Declaration) -> Json {
+    let mut members = vec![
+        ("kind", Json::string("declaration")),
+        ("name", Json::string(&declaration.name)),
+    ];
+    members.extend(location(declaration.span));
+    members.push(("init", optional_json(&declaration.init)));
+    return Json::object(members);
+}
+
+fn optional_json(value: &Option<Expr>) -> Json {
+    return match value {
+        Some(value) => expression_json(value),
+        None => Json::Null,
+    };
+}
+
+fn statement_json(statement: &Statement) -> Json {
+    let label = |label: &String| ("label", Json::string(label));
+    let body = |body: &Statement| ("body", statement_json(body));
+
+    let (kind, fields) = match &statement.kind {
+        StatementKind::Return(value) => ("return", vec![("value", expression_json(value))]),
+        StatementKind::Expression(value) => ("expression", vec![("value", expression_json(value))]),
+        StatementKind::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            let else_branch = match else_branch {
+                Some(else_branch) => statement_json(else_branch),
+                None => Json::Null,
+            };
+            let fields = vec![
+                ("condition", expression_json(condition)),
+                ("then", statement_json(then_branch)),
+                ("else", else_branch),
+            ];
+            ("if", fields)
+        }
+        StatementKind::Compound(block) => ("block", vec![("items", block_json(block))]),
+        StatementKind::Break(target) => ("break", vec![label(target)]),
+        StatementKind::Continue(target) => ("continue", vec![label(target)]),
+        StatementKind::While {
+            condition,
+            body: loop_body,
+            label: target,
+        } => {
+            let fields = vec![
+                label(target),
+                ("condition", expression_json(condition)),
+                body(loop_body),
+            ];
+            ("while", fields)
+        }
+        StatementKind::DoWhile {
+            body: loop_body,
+            condition,
+            label: target,
+        } => {
+            let fields = vec![
+                label(target),
+                body(loop_body),
+                ("condition", expression_json(condition)),
+            ];
+            ("do-while", fields)
+        }
+        StatementKind::For {
+            init,
+            condition,
+            post,
+            body: loop_body,
+            label: target,
+        } => {
+            let init = match init {
+                ForInit::InitDecl(declaration) => declaration_json(declaration),
+                ForInit::InitExpr(value) => optional_json(value),
+            };
+            let fields = vec![
+                label(target),
+                ("init", init),
+                ("condition", optional_json(condition)),
+                ("post", optional_json(post)),
+                body(loop_body),
+            ];
+            ("for", fields)
+        }
+        StatementKind::Goto(target, _) => ("goto", vec![label(target)]),
+        StatementKind::Labeled(target, _, statement) => {
+            ("labeled", vec![label(target), body(statement)])
+        }
+        StatementKind::Null => ("null", Vec::new()),
+    };
+
+    let mut members = vec![("kind", Json::string(kind))];
+    members.extend(location(statement.span));
+    members.extend(fields);
+    return Json::object(members);
+}
+
+fn expression_json(expr: &Expr) -> Json {
+    let members = match expr {
+        Expr::Constant(value) => vec![
+            ("kind", Json::string("constant")),
+            ("value", Json::Number((*value).into())),
+        ],
+        Expr::Variable(name, _) => vec![
+            ("kind", Json::string("variable")),
+            ("name", Json::string(name)),
+        ],
+        Expr::Unary(op, operand) => vec![
+            ("kind", Json::string("unary")),
+            ("op", Json::string(unary(op))),
+            ("operand", expression_json(operand)),
+        ],
+        Expr::Binary { op, left, right } => vec![
+            ("kind", Json::string("binary")),
+            ("op", Json::string(binary(op))),
+            ("left", expression_json(left)),
+            ("right", expression_json(right)),
+        ],
+        Expr::PostfixIncrement(operand) => vec![
+            ("kind", Json::string("postfix")),
+            ("op", Json::string("++")),
+            ("operand", expression_json(operand)),
+        ],
+        Expr::PostfixDecrement(operand) => vec![
+            ("kind", Json::string("postfix")),
+            ("op", Json::string("--")),
+            ("operand", expression_json(operand)),
+        ],
+        Expr::Assignment { target, value } => vec![
+            ("kind", Json::string("assignment")),
+            ("op", Json::string("=")),
+            ("target", expression_json(target)),
+            ("value", expression_json(value)),
+        ],
+        Expr::CompoundAssignment { target, op, value } => vec![
+            ("kind", Json::string("assignment")),
+            ("op", Json::String(format!("{}=", binary(op)))),
+            ("target", expression_json(target)),
+            ("value", expression_json(value)),
+        ],
+        Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        } => vec![
+            ("kind", Json::string("conditional")),
+            ("condition", expression_json(condition)),
+            ("then", expression_json(then_branch)),
+            ("else", expression_json(else_branch)),
+        ],
+    };
+    return Json::object(members);
+}
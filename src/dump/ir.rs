@@ -0,0 +1,267 @@
+// Three-address code in a syntax of its own: `dst = a + b`, labels flush
+// left, and the control-flow graph of the SSA form as labeled blocks.
+
+use super::Dump;
+use crate::frontend::optimize::cfg::{Cfg, Phi, Terminator};
+use crate::ir::*;
+use crate::json::Json;
+use std::fmt::Write;
+
+pub fn text(program: &Program) -> String {
+    let function = &program.function;
+    let mut output = format!("function {} {{\n", function.name);
+    for instruction in &function.body {
+        match instruction {
+            Instruction::Label(label) => writeln!(output, "{}:", label).unwrap(),
+            _ => writeln!(output, "    {}", self::instruction(instruction)).unwrap(),
+        }
+    }
+    output.push_str("}\n");
+    return output;
+}
+
+fn instruction(instruction: &Instruction) -> String {
+    return match instruction {
+        Instruction::Return(value) => format!("return {}", self::value(value)),
+        Instruction::Unary { op, dst, src } => format!("{} = {}{}", dst, unary(op), value(src)),
+        Instruction::Binary {
+            op,
+            dst,
+            src1,
+            src2,
+        } => format!("{} = {} {} {}", dst, value(src1), binary(op), value(src2)),
+        Instruction::Copy { src, dst } => format!("{} = {}", dst, value(src)),
+        Instruction::Jump { target } => format!("jump {}", target),
+        Instruction::JumpIfZero { condition, target } => {
+            format!("jump_if_zero {}, {}", value(condition), target)
+        }
+        Instruction::JumpIfNotZero { condition, target } => {
+            format!("jump_if_not_zero {}, {}", value(condition), target)
+        }
+        Instruction::Label(label) => format!("{}:", label),
+        Instruction::Location(span) => format!(".loc {}:{}", span.line, span.column),
+    };
+}
+
+fn value(value: &Value) -> String {
+    return match value {
+        Value::Constant(constant) => constant.to_string(),
+        Value::Variable(name) => name.clone(),
+    };
+}
+
+fn unary(op: &UnaryOperator) -> &'static str {
+    return match op {
+        UnaryOperator::Complement => "~",
+        UnaryOperator::Negate => "-",
+        UnaryOperator::Not => "!",
+    };
+}
+
+fn binary(op: &BinaryOperator) -> &'static str {
+    return match op {
+        BinaryOperator::Add => "+",
+        BinaryOperator::Subtract => "-",
+        BinaryOperator::Multiply => "*",
+        BinaryOperator::Divide => "/",
+        BinaryOperator::Modulo => "%",
+        BinaryOperator::BitwiseAnd => "&",
+        BinaryOperator::BitwiseOr => "|",
+        BinaryOperator::BitwiseXor => "^",
+        BinaryOperator::LeftShift => "<<",
+        BinaryOperator::RightShift => ">>",
+        BinaryOperator::Equal => "==",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::LessThan => "<",
+        BinaryOperator::LessOrEqual => "<=",
+        BinaryOperator::GreaterThan => ">",
+        BinaryOperator::GreaterOrEqual => ">=",
+    };
+}
+
+pub fn json(program: &Program) -> Json {
+    let function = &program.function;
+    let locals = function.locals.iter().map(|local| {
+        return Json::object(vec![
+            ("name", Json::string(&local.name)),
+            ("line", Json::Number(local.span.line.into())),
+            ("column", Json::Number(local.span.column.into())),
+        ]);
+    });
+    let function = Json::object(vec![
+        ("name", Json::string(&function.name)),
+        ("line", Json::Number(function.span.line.into())),
+        ("column", Json::Number(function.span.column.into())),
+        ("locals", Json::Array(locals.collect())),
+        (
+            "body",
+            Json::Array(function.body.iter().map(instruction_json).collect()),
+        ),
+    ]);
+    return Json::object(vec![("function", function)]);
+}
+
+fn instruction_json(instruction: &Instruction) -> Json {
+    let members = match instruction {
+        Instruction::Return(value) => vec![
+            ("kind", Json::string("return")),
+            ("value", value_json(value)),
+        ],
+        Instruction::Unary { op, dst, src } => vec![
+            ("kind", Json::string("unary")),
+            ("op", Json::string(unary(op))),
+            ("dst", Json::string(dst)),
+            ("src", value_json(src)),
+        ],
+        Instruction::Binary {
+            op,
+            dst,
+            src1,
+            src2,
+        } => vec![
+            ("kind", Json::string("binary")),
+            ("op", Json::string(binary(op))),
+            ("dst", Json::string(dst)),
+            ("src1", value_json(src1)),
+            ("src2", value_json(src2)),
+        ],
+        Instruction::Copy { src, dst } => vec![
+            ("kind", Json::string("copy")),
+            ("dst", Json::string(dst)),
+            ("src", value_json(src)),
+        ],
+        Instruction::Jump { target } => vec![
+            ("kind", Json::string("jump")),
+            ("target", Json::string(target)),
+        ],
+        Instruction::JumpIfZero { condition, target } => vec![
+            ("kind", Json::string("jump_if_zero")),
+            ("condition", value_json(condition)),
+            ("target", Json::string(target)),
+        ],
+        Instruction::JumpIfNotZero { condition, target } => vec![
+            ("kind", Json::string("jump_if_not_zero")),
+            ("condition", value_json(condition)),
+            ("target", Json::string(target)),
+        ],
+        Instruction::Label(label) => vec![
+            ("kind", Json::string("label")),
+            ("name", Json::string(label)),
+        ],
+        Instruction::Location(span) => vec![
+            ("kind", Json::string("location")),
+            ("line", Json::Number(span.line.into())),
+            ("column", Json::Number(span.column.into())),
+        ],
+    };
+    return Json::object(members);
+}
+
+// Constants are numbers and variables are their names
+fn value_json(value: &Value) -> Json {
+    return match value {
+        Value::Constant(constant) => Json::Number((*constant).into()),
+        Value::Variable(name) => Json::string(name),
+    };
+}
+
+// Blocks are printed in order, each ending in its terminator. Edges name
+// the block they lead to, and phi arguments the predecessor they come from
+pub fn ssa(name: &str, cfg: &Cfg) -> Dump {
+    let label = |block: usize| cfg.blocks[block].label.as_str();
+
+    let mut text = format!("function {} {{\n", name);
+    let mut blocks = Vec::new();
+    for block in &cfg.blocks {
+        writeln!(text, "{}:", block.label).unwrap();
+        for phi in &block.phis {
+            writeln!(text, "    {}", self::phi(phi, label)).unwrap();
+        }
+        for instruction in &block.instructions {
+            writeln!(text, "    {}", self::instruction(instruction)).unwrap();
+        }
+        writeln!(text, "    {}", terminator(&block.terminator, label)).unwrap();
+
+        let phis = block.phis.iter().map(|phi| {
+            let args = phi.args.iter().map(|(predecessor, value)| {
+                return Json::object(vec![
+                    ("block", Json::string(label(*predecessor))),
+                    ("value", value_json(value)),
+                ]);
+            });
+            return Json::object(vec![
+                ("dst", Json::string(&phi.dst)),
+                ("args", Json::Array(args.collect())),
+            ]);
+        });
+        blocks.push(Json::object(vec![
+            ("label", Json::string(&block.label)),
+            ("phis", Json::Array(phis.collect())),
+            (
+                "instructions",
+                Json::Array(block.instructions.iter().map(instruction_json).collect()),
+            ),
+            ("terminator", terminator_json(&block.terminator, label)),
+        ]));
+    }
+    text.push_str("}\n");
+
+    let function = Json::object(vec![
+        ("name", Json::string(name)),
+        ("blocks", Json::Array(blocks)),
+    ]);
+    return Dump {
+        text,
+        json: Json::object(vec![("function", function)]),
+    };
+}
+
+fn phi<'a>(phi: &Phi, label: impl Fn(usize) -> &'a str) -> String {
+    let args: Vec<String> = phi
+        .args
+        .iter()
+        .map(|(predecessor, value)| format!("[{}: {}]", label(*predecessor), self::value(value)))
+        .collect();
+    return format!("{} = phi {}", phi.dst, args.join(", "));
+}
+
+fn terminator<'a>(terminator: &Terminator, label: impl Fn(usize) -> &'a str) -> String {
+    return match terminator {
+        Terminator::Return(value) => format!("return {}", self::value(value)),
+        Terminator::Jump(target) => format!("jump {}", label(*target)),
+        Terminator::Branch {
+            condition,
+            zero,
+            nonzero,
+        } => format!(
+            "branch {} ? {} : {}",
+            value(condition),
+            label(*nonzero),
+            label(*zero)
+        ),
+    };
+}
+
+fn terminator_json<'a>(terminator: &Terminator, label: impl Fn(usize) -> &'a str) -> Json {
+    let members = match terminator {
+        Terminator::Return(value) => vec![
+            ("kind", Json::string("return")),
+            ("value", value_json(value)),
+        ],
+        Terminator::Jump(target) => vec![
+            ("kind", Json::string("jump")),
+            ("target", Json::string(label(*target))),
+        ],
+        Terminator::Branch {
+            condition,
+            zero,
+            nonzero,
+        } => vec![
+            ("kind", Json::string("branch")),
+            ("condition", value_json(condition)),
+            ("nonzero", Json::string(label(*nonzero))),
+            ("zero", Json::string(label(*zero))),
+        ],
+    };
+    return Json::object(members);
+}
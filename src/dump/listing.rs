@@ -0,0 +1,102 @@
+// Selected instructions, before register allocation and fixup make them
+// legal. Each target spells its instructions as lines of this listing, so
+// pseudos and out-of-range immediates print the same way on all of them.
+
+use super::Dump;
+use crate::json::Json;
+use std::fmt::Write;
+
+pub enum Operand {
+    Immediate(i64),
+    Register(String),
+    // A variable not yet given a register or a stack slot, printed as `%name`
+    Pseudo(String),
+    Memory {
+        base: &'static str,
+        offset: i64,
+    },
+    Address {
+        base: String,
+        index: String,
+        scale: i64,
+    },
+    // A label, or a condition code
+    Symbol(String),
+}
+
+pub enum Line {
+    Label(String),
+    Instruction(String, Vec<Operand>),
+}
+
+pub fn listing(function: &str, lines: &[Line]) -> Dump {
+    let mut text = format!("{}:\n", function);
+    let mut instructions = Vec::new();
+    for line in lines {
+        match line {
+            Line::Label(label) => {
+                writeln!(text, "{}:", label).unwrap();
+                instructions.push(Json::object(vec![("label", Json::string(label))]));
+            }
+            Line::Instruction(opcode, operands) => {
+                let spelled: Vec<String> = operands.iter().map(spell).collect();
+                let line = format!("    {} {}", opcode, spelled.join(", "));
+                writeln!(text, "{}", line.trim_end()).unwrap();
+                instructions.push(Json::object(vec![
+                    ("opcode", Json::string(opcode)),
+                    (
+                        "operands",
+                        Json::Array(operands.iter().map(operand_json).collect()),
+                    ),
+                ]));
+            }
+        }
+    }
+
+    let function = Json::object(vec![
+        ("name", Json::string(function)),
+        ("instructions", Json::Array(instructions)),
+    ]);
+    return Dump {
+        text,
+        json: Json::object(vec![("function", function)]),
+    };
+}
+
+// How an operand reads in the text, for the base and index of an address
+pub fn spell(operand: &Operand) -> String {
+    return match operand {
+        Operand::Immediate(value) => value.to_string(),
+        Operand::Register(name) => name.clone(),
+        Operand::Pseudo(name) => format!("%{}", name),
+        Operand::Memory { base, offset } if *offset < 0 => format!("[{} - {}]", base, -offset),
+        Operand::Memory { base, offset } => format!("[{} + {}]", base, offset),
+        Operand::Address { base, index, scale } => format!("[{} + {}*{}]", base, index, scale),
+        Operand::Symbol(name) => name.clone(),
+    };
+}
+
+fn operand_json(operand: &Operand) -> Json {
+    let (kind, value) = match operand {
+        Operand::Immediate(value) => ("immediate", Json::Number(*value)),
+        Operand::Register(name) => ("register", Json::string(name)),
+        Operand::Pseudo(name) => ("pseudo", Json::string(name)),
+        Operand::Memory { base, offset } => (
+            "memory",
+            Json::object(vec![
+                ("base", Json::string(base)),
+                ("offset", Json::Number(*offset)),
+            ]),
+        ),
+        Operand::Address { base, index, scale } => (
+            "address",
+            Json::object(vec![
+                ("base", Json::string(base)),
+                ("index", Json::string(index)),
+                ("scale", Json::Number(*scale)),
+            ]),
+        ),
+        Operand::Symbol(name) => ("symbol", Json::string(name)),
+    };
+    return Json::object(vec![(kind, value)]);
+}
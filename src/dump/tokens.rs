@@ -0,0 +1,99 @@
+// One token to a line: where it starts, what kind it is, and how it is
+// spelled in the source.
+
+use crate::json::Json;
+use crate::token::{Span, Token};
+use std::fmt::Write;
+
+pub fn text(tokens: &[(Token, Span)]) -> String {
+    let mut output = String::new();
+    for (token, span) in tokens {
+        let (kind, spelling) = describe(token);
+        let location = format!("{}:{}", span.line, span.column);
+        let line = format!("{:<8}{:<12}{}", location, kind, spelling);
+        writeln!(output, "{}", line.trim_end()).unwrap();
+    }
+    return output;
+}
+
+pub fn json(tokens: &[(Token, Span)]) -> Json {
+    let tokens = tokens
+        .iter()
+        .map(|(token, span)| {
+            let (kind, spelling) = describe(token);
+            return Json::object(vec![
+                ("kind", Json::string(kind)),
+                ("text", Json::String(spelling)),
+                ("line", Json::Number(span.line.into())),
+                ("column", Json::Number(span.column.into())),
+            ]);
+        })
+        .collect();
+    return Json::object(vec![("tokens", Json::Array(tokens))]);
+}
+
+fn describe(token: &Token) -> (&'static str, String) {
+    let (kind, spelling) = match token {
+        Token::Identifier(name) => return ("identifier", name.clone()),
+        Token::Constant(value) => return ("constant", value.clone()),
+        Token::Eof => ("eof", ""),
+
+        Token::Int => ("keyword", "int"),
+        Token::Void => ("keyword", "void"),
+        Token::Return => ("keyword", "return"),
+        Token::If => ("keyword", "if"),
+        Token::Else => ("keyword", "else"),
+        Token::Goto => ("keyword", "goto"),
+        Token::Switch => ("keyword", "switch"),
+        Token::Case => ("keyword", "case"),
+        Token::Default => ("keyword", "default"),
+        Token::Do => ("keyword", "do"),
+        Token::While => ("keyword", "while"),
+        Token::For => ("keyword", "for"),
+        Token::Break => ("keyword", "break"),
+        Token::Continue => ("keyword", "continue"),
+
+        Token::OpenParen => ("punctuator", "("),
+        Token::CloseParen => ("punctuator", ")"),
+        Token::OpenBrace => ("punctuator", "{"),
+        Token::CloseBrace => ("punctuator", "}"),
+        Token::Semicolon => ("punctuator", ";"),
+        Token::Comma => ("punctuator", ","),
+        Token::Tilde => ("punctuator", "~"),
+        Token::Exclaim => ("punctuator", "!"),
+        Token::PlusPlus => ("punctuator", "++"),
+        Token::MinusMinus => ("punctuator", "--"),
+        Token::Colon => ("punctuator", ":"),
+        Token::Question => ("punctuator", "?"),
+        Token::Plus => ("punctuator", "+"),
+        Token::Minus => ("punctuator", "-"),
+        Token::Star => ("punctuator", "*"),
+        Token::Slash => ("punctuator", "/"),
+        Token::Percent => ("punctuator", "%"),
+        Token::Equal => ("punctuator", "="),
+        Token::Pipe => ("punctuator", "|"),
+        Token::Caret => ("punctuator", "^"),
+        Token::Ampersand => ("punctuator", "&"),
+        Token::LessLess => ("punctuator", "<<"),
+        Token::GreaterGreater => ("punctuator", ">>"),
+        Token::AmpAmp => ("punctuator", "&&"),
+        Token::PipePipe => ("punctuator", "||"),
+        Token::Less => ("punctuator", "<"),
+        Token::LessEqual => ("punctuator", "<="),
+        Token::Greater => ("punctuator", ">"),
+        Token::GreaterEqual => ("punctuator", ">="),
+        Token::EqualEqual => ("punctuator", "=="),
+        Token::ExclaimEqual => ("punctuator", "!="),
+        Token::PlusEqual => ("punctuator", "+="),
+        Token::MinusEqual => ("punctuator", "-="),
+        Token::StarEqual => ("punctuator", "*="),
+        Token::SlashEqual => ("punctuator", "/="),
+        Token::PercentEqual => ("punctuator", "%="),
+        Token::AmpEqual => ("punctuator", "&="),
+        Token::PipeEqual => ("punctuator", "|="),
+        Token::CaretEqual => ("punctuator", "^="),
+        Token::LessLessEqual => ("punctuator", "<<="),
+        Token::GreaterGreaterEqual => ("punctuator", ">>="),
+    };
+    return (kind, spelling.to_string());
+}
@@ -1,57 +1,128 @@
-mod ast;
+pub mod ast;
 pub mod ir;
 mod irgen;
 mod lexer;
+pub(crate) mod optimize;
 mod parser;
+mod printer;
 mod semantic;
-mod token;
+pub mod token;
 
-use crate::Stage;
+use crate::dump::Format;
+use crate::{Artifact, Diagnostic, OptLevel, Severity, Stage};
 use irgen::flatten;
-use lexer::lex;
-use parser::parse;
+use lexer::{lex, lex_with_comments};
+use parser::{parse, parse_with_comments, parse_with_warnings};
 use semantic::analyze;
+use token::Warned;
+
+// Returns the artifact of `stage`, or the optimized IR for the backend when
+// `stage` is past the frontend. Every warning found on the way is added to
+// `warnings` in source order, even when an error follows
+pub fn compile(
+    source: &str,
+    stage: Stage,
+    opt_level: OptLevel,
+    debug: bool,
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<Artifact, Diagnostic> {
+    let error = |stage, message, span| Diagnostic::error(stage, message, Some(span));
+    let mut warn = |stage, found: Vec<Warned>| {
+        warnings.extend(
+            found
+                .into_iter()
+                .map(|(warning, (message, span))| Diagnostic {
+                    stage,
+                    severity: Severity::Warning,
+                    warning: Some(warning),
+                    message,
+                    span: Some(span),
+                }),
+        );
+        warnings.sort_by_key(|diagnostic| diagnostic.span);
+    };
 
-pub fn compile(source: String, stage: Stage) -> Result<Option<ir::Program>, String> {
     // Invoke Lexer
-    let tokens = match lex(&source) {
+    let tokens = match lex(source) {
         Ok(tokens) => tokens,
-        Err(e) => return Err(format!("Lexical error: {}", e).into()),
+        Err((e, span)) => return Err(error(Stage::Lex, format!("Lexical error: {}", e), span)),
     };
     if stage == Stage::Lex {
-        dbg!(tokens);
-        println!("Lexer OK!");
-        return Ok(None);
+        return Ok(Artifact::Tokens(tokens));
     }
 
     // Invoke Parser
-    let mut ast = match parse(tokens) {
-        Ok(ast) => ast,
-        Err(e) => return Err(format!("Syntax error: {}", e).into()),
+    let mut ast = match parse_with_warnings(tokens) {
+        Ok((ast, found)) => {
+            warn(Stage::Parse, found);
+            ast
+        }
+        Err((e, span)) => return Err(error(Stage::Parse, format!("Syntax error: {}", e), span)),
     };
     if stage == Stage::Parse {
-        dbg!(ast);
-        println!("Parser OK!");
-        return Ok(None);
+        return Ok(Artifact::Ast(ast));
     }
 
     // Semantic Analysis
-    if let Err(e) = analyze(&mut ast) {
-        return Err(format!("Semantic error: {}", e).into());
+    match analyze(&mut ast) {
+        Ok(found) => warn(Stage::Validate, found),
+        Err((e, span)) => {
+            return Err(error(
+                Stage::Validate,
+                format!("Semantic error: {}", e),
+                span,
+            ));
+        }
     }
     if stage == Stage::Validate {
-        dbg!(ast);
-        println!("Validation OK!");
-        return Ok(None);
+        return Ok(Artifact::Ast(ast));
     }
 
     // IR Generation
-    let ir = flatten(ast);
-    if stage == Stage::Ir {
-        dbg!(ir);
-        println!("IR OK!");
-        return Ok(None);
+    let mut ir = flatten(ast, debug);
+    if stage == Stage::Ssa {
+        let name = ir.function.name.clone();
+        let cfg = optimize::build_ssa(ir);
+        return Ok(Artifact::Ssa(crate::dump::ssa(&name, &cfg)));
     }
 
-    return Ok(Some(ir));
+    optimize::optimize(&mut ir, opt_level);
+    return Ok(Artifact::Ir(ir));
+}
+
+// Reprints the source in the formatter's layout. The result must parse back
+// into the same tree with the same comments, or it is not returned
+pub fn format(source: &str) -> Result<String, Diagnostic> {
+    let error = |stage, message, span| Diagnostic::error(stage, message, Some(span));
+
+    let (tokens, comments) = match lex_with_comments(source) {
+        Ok(lexed) => lexed,
+        Err((e, span)) => return Err(error(Stage::Lex, format!("Lexical error: {}", e), span)),
+    };
+    let ast = match parse_with_comments(tokens, comments.clone()) {
+        Ok(ast) => ast,
+        Err((e, span)) => return Err(error(Stage::Parse, format!("Syntax error: {}", e), span)),
+    };
+    let formatted = printer::print(&ast);
+
+    let unchanged = match lex_with_comments(&formatted) {
+        Ok((tokens, kept)) => parse(tokens).is_ok_and(|reparsed| {
+            let texts = |comments: &[token::Comment]| -> Vec<String> {
+                comments
+                    .iter()
+                    .map(|comment| comment.text.clone())
+                    .collect()
+            };
+            return texts(&kept) == texts(&comments)
+                && Artifact::Ast(reparsed).dump(Format::Text)
+                    == Artifact::Ast(ast).dump(Format::Text);
+        }),
+        Err(_) => false,
+    };
+    if !unchanged {
+        let message =
+            "Formatting error: the formatted source does not parse back into the same program";
+        return Err(Diagnostic::error(Stage::Parse, message.to_string(), None));
+    }
+    return Ok(formatted);
 }
@@ -1,17 +1,30 @@
+use super::token::{Comment, Span};
+use std::collections::HashMap;
+
 #[derive(Debug)]
 pub struct Program {
     pub function: Function,
+    // Comments after the function
+    pub comments: Vec<Comment>,
+    // Filled in by validation: how each variable and label it renamed was
+    // written, by unique name
+    pub names: HashMap<String, String>,
 }
 
 #[derive(Debug)]
 pub struct Function {
     pub name: String,
     pub body: Block,
+    // Where the name is
+    pub span: Span,
+    pub trivia: Trivia,
 }
 
 #[derive(Debug)]
 pub struct Block {
     pub items: Vec<BlockItem>,
+    // Comments after the last item, before the closing brace
+    pub comments: Vec<Comment>,
 }
 
 #[derive(Debug)]
@@ -23,11 +36,33 @@ pub enum BlockItem {
 #[derive(Debug)]
 pub struct Declaration {
     pub name: String,
+    // Where the name is, after `int` at `span`
+    pub name_span: Span,
     pub init: Option<Expr>,
+    pub span: Span,
+    pub trivia: Trivia,
+}
+
+#[derive(Debug)]
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: Span,
+    pub trivia: Trivia,
+}
+
+// The comments around a node, which only the formatter looks at
+#[derive(Debug, Default)]
+pub struct Trivia {
+    // On the lines before the node
+    pub leading: Vec<Comment>,
+    // After the node, on its last line
+    pub trailing: Option<Comment>,
+    // Whether a blank line comes before the node and its comments
+    pub blank_line: bool,
 }
 
 #[derive(Debug)]
-pub enum Statement {
+pub enum StatementKind {
     Return(Expr),
     Expression(Expr),
 
@@ -62,8 +97,9 @@ pub enum Statement {
         label: String,
     },
 
-    Goto(String),
-    Labeled(String, Box<Statement>),
+    // Each label with where it is written
+    Goto(String, Span),
+    Labeled(String, Span, Box<Statement>),
 
     Null,
 }
@@ -77,7 +113,7 @@ pub enum ForInit {
 #[derive(Debug, Clone)]
 pub enum Expr {
     Constant(i32),
-    Variable(String),
+    Variable(String, Span),
 
     Unary(UnaryOperator, Box<Expr>),
 
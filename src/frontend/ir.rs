@@ -1,5 +1,7 @@
 // Three-Address Code Intermediate Representation
 
+pub use super::token::Span;
+
 #[derive(Debug)]
 pub struct Program {
     pub function: Function,
@@ -9,6 +11,15 @@ pub struct Program {
 pub struct Function {
     pub name: String,
     pub body: Vec<Instruction>,
+    pub span: Span,
+    pub locals: Vec<Local>,
+}
+
+// A variable declared in the source, under its unique name
+#[derive(Debug)]
+pub struct Local {
+    pub name: String,
+    pub span: Span,
 }
 
 #[derive(Debug)]
@@ -48,22 +59,25 @@ pub enum Instruction {
     },
 
     Label(String),
+
+    // Marks where the code of a statement starts, with `-g`
+    Location(Span),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Value {
     Constant(i32),
     Variable(String),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnaryOperator {
     Complement,
     Negate,
     Not,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BinaryOperator {
     Add,
     Subtract,
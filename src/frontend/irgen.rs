@@ -3,16 +3,20 @@ use super::ir::*;
 
 struct Context {
     instructions: Vec<Instruction>,
+    locals: Vec<Local>,
     var_count: u32,
     label_count: u32,
+    debug: bool,
 }
 
 impl Context {
-    fn new() -> Self {
+    fn new(debug: bool) -> Self {
         Self {
             instructions: Vec::new(),
+            locals: Vec::new(),
             var_count: 0,
             label_count: 0,
+            debug,
         }
     }
 
@@ -32,6 +36,12 @@ impl Context {
         self.instructions.push(instr);
     }
 
+    fn locate(&mut self, span: Span) {
+        if self.debug {
+            self.append(Instruction::Location(span));
+        }
+    }
+
     fn break_label(loop_label: &str) -> String {
         format!("break_{}", loop_label)
     }
@@ -82,13 +92,13 @@ impl Context {
 }
 
 // Main IR function
-pub fn flatten(ast_program: ast::Program) -> Program {
-    let function = flatten_function(ast_program.function);
+pub fn flatten(ast_program: ast::Program, debug: bool) -> Program {
+    let function = flatten_function(ast_program.function, debug);
     return Program { function };
 }
 
-fn flatten_function(ast_func: ast::Function) -> Function {
-    let mut ctx = Context::new();
+fn flatten_function(ast_func: ast::Function, debug: bool) -> Function {
+    let mut ctx = Context::new(debug);
 
     flatten_block(ast_func.body, &mut ctx);
 
@@ -99,6 +109,8 @@ fn flatten_function(ast_func: ast::Function) -> Function {
     return Function {
         name: ast_func.name,
         body: ctx.instructions,
+        span: ast_func.span,
+        locals: ctx.locals,
     };
 }
 
@@ -116,7 +128,13 @@ fn flatten_block_item(item: ast::BlockItem, ctx: &mut Context) {
 }
 
 fn flatten_declaration(decl: ast::Declaration, ctx: &mut Context) {
+    ctx.locals.push(Local {
+        name: decl.name.clone(),
+        span: decl.span,
+    });
+
     if let Some(init) = decl.init {
+        ctx.locate(decl.span);
         let val = flatten_expr(init, ctx);
         ctx.append(Instruction::Copy {
             src: val,
@@ -136,17 +154,21 @@ fn flatten_for_init(init: ast::ForInit, ctx: &mut Context) {
 }
 
 fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
-    match statement {
-        ast::Statement::Return(expr) => {
+    let span = statement.span;
+
+    match statement.kind {
+        ast::StatementKind::Return(expr) => {
+            ctx.locate(span);
             let result_val = flatten_expr(expr, ctx);
             ctx.append(Instruction::Return(result_val));
         }
 
-        ast::Statement::If {
+        ast::StatementKind::If {
             condition,
             then_branch,
             else_branch,
         } => {
+            ctx.locate(span);
             let cond_val = flatten_expr(condition, ctx);
 
             match else_branch {
@@ -187,30 +209,33 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
             }
         }
 
-        ast::Statement::Expression(expr) => {
+        ast::StatementKind::Expression(expr) => {
+            ctx.locate(span);
             flatten_expr(expr, ctx);
         }
 
-        ast::Statement::Compound(block) => {
+        ast::StatementKind::Compound(block) => {
             flatten_block(block, ctx);
         }
 
         // break label; -> Jump(break_<label>)
-        ast::Statement::Break(label) => {
+        ast::StatementKind::Break(label) => {
+            ctx.locate(span);
             ctx.append(Instruction::Jump {
                 target: Context::break_label(&label),
             });
         }
 
         // continue label; -> Jump(continue_<label>)
-        ast::Statement::Continue(label) => {
+        ast::StatementKind::Continue(label) => {
+            ctx.locate(span);
             ctx.append(Instruction::Jump {
                 target: Context::continue_label(&label),
             });
         }
 
         // While loop:
-        ast::Statement::While {
+        ast::StatementKind::While {
             condition,
             body,
             label,
@@ -220,6 +245,7 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
 
             ctx.append(Instruction::Label(cont_label.clone()));
 
+            ctx.locate(span);
             let cond_val = flatten_expr(condition, ctx);
             ctx.append(Instruction::JumpIfZero {
                 condition: cond_val,
@@ -234,7 +260,7 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
         }
 
         // Do-While loop:
-        ast::Statement::DoWhile {
+        ast::StatementKind::DoWhile {
             body,
             condition,
             label,
@@ -249,6 +275,7 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
 
             ctx.append(Instruction::Label(cont_label));
 
+            ctx.locate(span);
             let cond_val = flatten_expr(condition, ctx);
             ctx.append(Instruction::JumpIfNotZero {
                 condition: cond_val,
@@ -259,7 +286,7 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
         }
 
         // For loop:
-        ast::Statement::For {
+        ast::StatementKind::For {
             init,
             condition,
             post,
@@ -271,12 +298,14 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
             let brk_label = Context::break_label(&label);
 
             // Init clause
+            ctx.locate(span);
             flatten_for_init(init, ctx);
 
             ctx.append(Instruction::Label(start_label.clone()));
 
             // Condition: if present, emit JumpIfZero; if absent, omit entirely
             if let Some(cond) = condition {
+                ctx.locate(span);
                 let cond_val = flatten_expr(cond, ctx);
                 ctx.append(Instruction::JumpIfZero {
                     condition: cond_val,
@@ -290,6 +319,7 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
 
             // Post expression
             if let Some(post_expr) = post {
+                ctx.locate(span);
                 flatten_expr(post_expr, ctx);
             }
 
@@ -301,17 +331,18 @@ fn flatten_statement(statement: ast::Statement, ctx: &mut Context) {
         }
 
         // Goto: emit a jump to the (already-resolved) label
-        ast::Statement::Goto(label) => {
+        ast::StatementKind::Goto(label, _) => {
+            ctx.locate(span);
             ctx.append(Instruction::Jump { target: label });
         }
 
         // Labeled statement: emit the label, then flatten the inner statement
-        ast::Statement::Labeled(name, inner) => {
+        ast::StatementKind::Labeled(name, _, inner) => {
             ctx.append(Instruction::Label(name));
             flatten_statement(*inner, ctx);
         }
 
-        ast::Statement::Null => {}
+        ast::StatementKind::Null => {}
     }
 }
 
@@ -322,7 +353,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
         // Prefix ++x: increment, return new value
         ast::Expr::Unary(ast::UnaryOperator::PrefixIncrement, inner) => {
             let var = match *inner {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -344,7 +375,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
         // Prefix --x: decrement, return new value
         ast::Expr::Unary(ast::UnaryOperator::PrefixDecrement, inner) => {
             let var = match *inner {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -366,7 +397,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
         // Postfix x++: increment, return old value
         ast::Expr::PostfixIncrement(inner) => {
             let var = match *inner {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -388,7 +419,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
         // Postfix x--: decrement, return old value
         ast::Expr::PostfixDecrement(inner) => {
             let var = match *inner {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -414,7 +445,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
             ctx.append(Instruction::Unary {
                 op: Context::convert_unary_op(&op),
                 dst: dst.clone(),
-                src: src,
+                src,
             });
 
             return Value::Variable(dst);
@@ -543,7 +574,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
             }
         }
 
-        ast::Expr::Variable(name) => Value::Variable(name),
+        ast::Expr::Variable(name, _) => Value::Variable(name),
 
         ast::Expr::Conditional {
             condition,
@@ -585,7 +616,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
 
         ast::Expr::Assignment { target, value } => {
             let dst = match *target {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -601,7 +632,7 @@ fn flatten_expr(expr: ast::Expr, ctx: &mut Context) -> Value {
 
         ast::Expr::CompoundAssignment { target, op, value } => {
             let var = match *target {
-                ast::Expr::Variable(name) => name,
+                ast::Expr::Variable(name, _) => name,
                 _ => unreachable!(),
             };
 
@@ -1,12 +1,24 @@
-use super::token::Token;
+use super::token::{Comment, Located, Span, Token};
 use regex::Regex;
 
-pub fn lex(source: &str) -> Result<Vec<Token>, String> {
+pub fn lex(source: &str) -> Result<Vec<(Token, Span)>, Located> {
+    return lex_with_comments(source).map(|(tokens, _)| tokens);
+}
+
+// The tokens, and the comments that only the formatter keeps
+type Lexed = (Vec<(Token, Span)>, Vec<Comment>);
+
+pub fn lex_with_comments(source: &str) -> Result<Lexed, Located> {
     let mut input = source;
     let mut tokens = Vec::new();
+    let mut comments = Vec::new();
 
     // Define regexes
-    let whitespace = Regex::new(r"^\s+").unwrap();
+    // A backslash at the end of a line splices it to the next, which the
+    // preprocessor does when there is one
+    let whitespace = Regex::new(r"^(?:\s|\\\r?\n)+").unwrap();
+    let line_comment = Regex::new(r"^//(?:\\\r?\n|[^\n])*").unwrap();
+    let block_comment = Regex::new(r"^/\*(?s:.*?)\*/").unwrap();
     let int_kw = Regex::new(r"^int\b").unwrap();
     let void_kw = Regex::new(r"^void\b").unwrap();
     let return_kw = Regex::new(r"^return\b").unwrap();
@@ -56,195 +68,220 @@ pub fn lex(source: &str) -> Result<Vec<Token>, String> {
     let pipe_equal = Regex::new(r"^\|=").unwrap();
     let caret_equal = Regex::new(r"^\^=").unwrap();
 
+    // Only whitespace and comments span lines, so the line is counted as
+    // they are skipped
+    let mut line = 1;
+    let mut line_start = 0;
+
     while !input.is_empty() {
-        // Skip whitespace
-        if let Some(m) = whitespace.find(input) {
+        let offset = source.len() - input.len();
+        let span = Span {
+            line,
+            column: (offset - line_start + 1) as u32,
+        };
+
+        // Skip whitespace and comments
+        if let Some(m) = whitespace
+            .find(input)
+            .or_else(|| line_comment.find(input))
+            .or_else(|| block_comment.find(input))
+        {
+            if m.as_str().starts_with('/') {
+                comments.push(Comment {
+                    text: m.as_str().trim_end().to_string(),
+                    span,
+                });
+            }
+            for (i, _) in m.as_str().match_indices('\n') {
+                line += 1;
+                line_start = offset + i + 1;
+            }
             input = &input[m.end()..];
             continue;
         }
 
         // Keywords
         if let Some(m) = int_kw.find(input) {
-            tokens.push(Token::Int);
+            tokens.push((Token::Int, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = void_kw.find(input) {
-            tokens.push(Token::Void);
+            tokens.push((Token::Void, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = return_kw.find(input) {
-            tokens.push(Token::Return);
+            tokens.push((Token::Return, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = if_kw.find(input) {
-            tokens.push(Token::If);
+            tokens.push((Token::If, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = else_kw.find(input) {
-            tokens.push(Token::Else);
+            tokens.push((Token::Else, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = do_kw.find(input) {
-            tokens.push(Token::Do);
+            tokens.push((Token::Do, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = while_kw.find(input) {
-            tokens.push(Token::While);
+            tokens.push((Token::While, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = for_kw.find(input) {
-            tokens.push(Token::For);
+            tokens.push((Token::For, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = break_kw.find(input) {
-            tokens.push(Token::Break);
+            tokens.push((Token::Break, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = continue_kw.find(input) {
-            tokens.push(Token::Continue);
+            tokens.push((Token::Continue, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = goto_kw.find(input) {
-            tokens.push(Token::Goto);
+            tokens.push((Token::Goto, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = switch_kw.find(input) {
-            tokens.push(Token::Switch);
+            tokens.push((Token::Switch, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = case_kw.find(input) {
-            tokens.push(Token::Case);
+            tokens.push((Token::Case, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = default_kw.find(input) {
-            tokens.push(Token::Default);
+            tokens.push((Token::Default, span));
             input = &input[m.end()..];
             continue;
         }
 
         // Identifiers and constants
         if let Some(m) = ident.find(input) {
-            tokens.push(Token::Identifier(m.as_str().to_string()));
+            tokens.push((Token::Identifier(m.as_str().to_string()), span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = number.find(input) {
-            tokens.push(Token::Constant(m.as_str().to_string()));
+            tokens.push((Token::Constant(m.as_str().to_string()), span));
             input = &input[m.end()..];
             continue;
         }
 
         // Operators
         if let Some(m) = left_shift_equal.find(input) {
-            tokens.push(Token::LessLessEqual);
+            tokens.push((Token::LessLessEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = right_shift_equal.find(input) {
-            tokens.push(Token::GreaterGreaterEqual);
+            tokens.push((Token::GreaterGreaterEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = left_shift.find(input) {
-            tokens.push(Token::LessLess);
+            tokens.push((Token::LessLess, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = right_shift.find(input) {
-            tokens.push(Token::GreaterGreater);
+            tokens.push((Token::GreaterGreater, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = logical_and.find(input) {
-            tokens.push(Token::AmpAmp);
+            tokens.push((Token::AmpAmp, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = logical_or.find(input) {
-            tokens.push(Token::PipePipe);
+            tokens.push((Token::PipePipe, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = equal.find(input) {
-            tokens.push(Token::EqualEqual);
+            tokens.push((Token::EqualEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = not_equal.find(input) {
-            tokens.push(Token::ExclaimEqual);
+            tokens.push((Token::ExclaimEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = less_equal.find(input) {
-            tokens.push(Token::LessEqual);
+            tokens.push((Token::LessEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = greater_equal.find(input) {
-            tokens.push(Token::GreaterEqual);
+            tokens.push((Token::GreaterEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = plus_equal.find(input) {
-            tokens.push(Token::PlusEqual);
+            tokens.push((Token::PlusEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = minus_equal.find(input) {
-            tokens.push(Token::MinusEqual);
+            tokens.push((Token::MinusEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = star_equal.find(input) {
-            tokens.push(Token::StarEqual);
+            tokens.push((Token::StarEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = slash_equal.find(input) {
-            tokens.push(Token::SlashEqual);
+            tokens.push((Token::SlashEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = percent_equal.find(input) {
-            tokens.push(Token::PercentEqual);
+            tokens.push((Token::PercentEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = amp_equal.find(input) {
-            tokens.push(Token::AmpEqual);
+            tokens.push((Token::AmpEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = pipe_equal.find(input) {
-            tokens.push(Token::PipeEqual);
+            tokens.push((Token::PipeEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = caret_equal.find(input) {
-            tokens.push(Token::CaretEqual);
+            tokens.push((Token::CaretEqual, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = increment.find(input) {
-            tokens.push(Token::PlusPlus);
+            tokens.push((Token::PlusPlus, span));
             input = &input[m.end()..];
             continue;
         }
         if let Some(m) = decrement.find(input) {
-            tokens.push(Token::MinusMinus);
+            tokens.push((Token::MinusMinus, span));
             input = &input[m.end()..];
             continue;
         }
@@ -252,33 +289,37 @@ pub fn lex(source: &str) -> Result<Vec<Token>, String> {
         // Single-character tokens
         let ch = input.chars().next().unwrap();
         match ch {
-            '(' => tokens.push(Token::OpenParen),
-            ')' => tokens.push(Token::CloseParen),
-            '{' => tokens.push(Token::OpenBrace),
-            '}' => tokens.push(Token::CloseBrace),
-            ';' => tokens.push(Token::Semicolon),
-            ',' => tokens.push(Token::Comma),
-            '~' => tokens.push(Token::Tilde),
-            '+' => tokens.push(Token::Plus),
-            '-' => tokens.push(Token::Minus),
-            '*' => tokens.push(Token::Star),
-            '/' => tokens.push(Token::Slash),
-            '%' => tokens.push(Token::Percent),
-            '&' => tokens.push(Token::Ampersand),
-            '|' => tokens.push(Token::Pipe),
-            '^' => tokens.push(Token::Caret),
-            '!' => tokens.push(Token::Exclaim),
-            '<' => tokens.push(Token::Less),
-            '>' => tokens.push(Token::Greater),
-            '=' => tokens.push(Token::Equal),
-            ':' => tokens.push(Token::Colon),
-            '?' => tokens.push(Token::Question),
-            _ => return Err(format!("Unexpected character: '{}'", ch)),
+            '(' => tokens.push((Token::OpenParen, span)),
+            ')' => tokens.push((Token::CloseParen, span)),
+            '{' => tokens.push((Token::OpenBrace, span)),
+            '}' => tokens.push((Token::CloseBrace, span)),
+            ';' => tokens.push((Token::Semicolon, span)),
+            ',' => tokens.push((Token::Comma, span)),
+            '~' => tokens.push((Token::Tilde, span)),
+            '+' => tokens.push((Token::Plus, span)),
+            '-' => tokens.push((Token::Minus, span)),
+            '*' => tokens.push((Token::Star, span)),
+            '/' => tokens.push((Token::Slash, span)),
+            '%' => tokens.push((Token::Percent, span)),
+            '&' => tokens.push((Token::Ampersand, span)),
+            '|' => tokens.push((Token::Pipe, span)),
+            '^' => tokens.push((Token::Caret, span)),
+            '!' => tokens.push((Token::Exclaim, span)),
+            '<' => tokens.push((Token::Less, span)),
+            '>' => tokens.push((Token::Greater, span)),
+            '=' => tokens.push((Token::Equal, span)),
+            ':' => tokens.push((Token::Colon, span)),
+            '?' => tokens.push((Token::Question, span)),
+            _ => return Err((format!("Unexpected character: '{}'", ch), span)),
         }
         input = &input[1..];
     }
 
-    tokens.push(Token::EOF);
+    let span = Span {
+        line,
+        column: (source.len() - line_start + 1) as u32,
+    };
+    tokens.push((Token::Eof, span));
 
-    return Ok(tokens);
+    return Ok((tokens, comments));
 }
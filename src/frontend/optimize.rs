@@ -0,0 +1,45 @@
+pub mod cfg;
+mod dominance;
+mod gvn;
+mod licm;
+mod loops;
+mod sccp;
+mod ssa;
+mod unreachable;
+
+use crate::OptLevel;
+use crate::frontend::ir::Program;
+use cfg::Cfg;
+
+pub fn optimize(program: &mut Program, level: OptLevel) {
+    if level == OptLevel::O0 {
+        return;
+    }
+
+    let body = std::mem::take(&mut program.function.body);
+    let mut cfg = Cfg::build(body);
+
+    unreachable::run(&mut cfg);
+
+    // The sparse passes work on SSA form. Constants are cheap to propagate
+    // and leave less for everything after, so only the passes that move
+    // code wait for -O2
+    ssa::construct(&mut cfg);
+    sccp::run(&mut cfg);
+    if level == OptLevel::O2 {
+        gvn::run(&mut cfg);
+        licm::run(&mut cfg);
+    }
+    ssa::destruct(&mut cfg);
+    unreachable::run(&mut cfg);
+
+    program.function.body = cfg.linearize();
+}
+
+// SSA form of the function, for `--ssa`
+pub fn build_ssa(program: Program) -> Cfg {
+    let mut cfg = Cfg::build(program.function.body);
+    unreachable::run(&mut cfg);
+    ssa::construct(&mut cfg);
+    return cfg;
+}
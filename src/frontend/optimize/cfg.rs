@@ -0,0 +1,441 @@
+// Control-Flow Graph over the three-address code
+
+use crate::frontend::ir::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum Terminator {
+    Return(Value),
+    Jump(usize),
+    Branch {
+        condition: Value,
+        zero: usize,
+        nonzero: usize,
+    },
+}
+
+// A phi node selects the incoming value by the predecessor control came from
+#[derive(Debug, Clone)]
+pub struct Phi {
+    pub dst: String,
+    pub args: Vec<(usize, Value)>,
+}
+
+#[derive(Debug)]
+pub struct BasicBlock {
+    pub label: String,
+    pub phis: Vec<Phi>,
+    pub instructions: Vec<Instruction>,
+    pub terminator: Terminator,
+}
+
+#[derive(Debug)]
+pub struct Cfg {
+    pub blocks: Vec<BasicBlock>,
+    label_count: u32,
+    var_count: u32,
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<usize> {
+        match self {
+            Terminator::Return(_) => Vec::new(),
+            Terminator::Jump(target) => vec![*target],
+            Terminator::Branch { zero, nonzero, .. } => vec![*nonzero, *zero],
+        }
+    }
+
+    pub fn targets_mut(&mut self) -> Vec<&mut usize> {
+        match self {
+            Terminator::Return(_) => Vec::new(),
+            Terminator::Jump(target) => vec![target],
+            Terminator::Branch { zero, nonzero, .. } => vec![nonzero, zero],
+        }
+    }
+
+    pub fn uses_mut(&mut self) -> Option<&mut Value> {
+        match self {
+            Terminator::Return(val) => Some(val),
+            Terminator::Branch { condition, .. } => Some(condition),
+            Terminator::Jump(_) => None,
+        }
+    }
+
+    pub fn uses(&self) -> Option<&Value> {
+        match self {
+            Terminator::Return(val) => Some(val),
+            Terminator::Branch { condition, .. } => Some(condition),
+            Terminator::Jump(_) => None,
+        }
+    }
+}
+
+impl Cfg {
+    // Splits the linear instruction stream into basic blocks. A block starts at
+    // every label and after every jump or return; fallthrough becomes an
+    // explicit edge so that blocks can be freely reordered afterwards.
+    pub fn build(body: Vec<Instruction>) -> Self {
+        let mut raw: Vec<(Option<String>, Vec<Instruction>, Option<Instruction>)> = Vec::new();
+
+        // Keep the entry block free of predecessors, even when the function
+        // starts with a loop
+        if matches!(body.first(), Some(Instruction::Label(_))) {
+            raw.push((None, Vec::new(), None));
+        }
+
+        let mut label: Option<String> = None;
+        let mut current: Vec<Instruction> = Vec::new();
+        let mut open = false;
+
+        for inst in body {
+            match inst {
+                Instruction::Label(name) => {
+                    if open || label.is_some() {
+                        raw.push((label.take(), std::mem::take(&mut current), None));
+                    }
+                    label = Some(name);
+                    open = true;
+                }
+
+                Instruction::Return(_)
+                | Instruction::Jump { .. }
+                | Instruction::JumpIfZero { .. }
+                | Instruction::JumpIfNotZero { .. } => {
+                    raw.push((label.take(), std::mem::take(&mut current), Some(inst)));
+                    open = false;
+                }
+
+                _ => {
+                    current.push(inst);
+                    open = true;
+                }
+            }
+        }
+
+        if open || label.is_some() {
+            raw.push((label.take(), current, None));
+        }
+
+        // A conditional jump at the very end falls off the function when it
+        // is not taken, so it gets an empty block to fall into
+        if let Some((
+            _,
+            _,
+            Some(Instruction::JumpIfZero { .. } | Instruction::JumpIfNotZero { .. }),
+        )) = raw.last()
+        {
+            raw.push((None, Vec::new(), None));
+        }
+
+        let mut cfg = Cfg {
+            blocks: Vec::new(),
+            label_count: 0,
+            var_count: 0,
+        };
+
+        let names: Vec<String> = raw
+            .iter()
+            .map(|(label, _, _)| match label {
+                Some(name) => name.clone(),
+                None => cfg.alloc_label(),
+            })
+            .collect();
+
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        let count = raw.len();
+        let fallthrough =
+            |i: usize| -> Option<usize> { if i + 1 < count { Some(i + 1) } else { None } };
+
+        // Every conditional jump has a block after it, from above
+
+        for (i, (_, instructions, end)) in raw.into_iter().enumerate() {
+            let terminator = match end {
+                Some(Instruction::Return(val)) => Terminator::Return(val),
+                Some(Instruction::Jump { target }) => Terminator::Jump(index[target.as_str()]),
+                Some(Instruction::JumpIfZero { condition, target }) => Terminator::Branch {
+                    condition,
+                    zero: index[target.as_str()],
+                    nonzero: i + 1,
+                },
+                Some(Instruction::JumpIfNotZero { condition, target }) => Terminator::Branch {
+                    condition,
+                    zero: i + 1,
+                    nonzero: index[target.as_str()],
+                },
+                _ => match fallthrough(i) {
+                    Some(next) => Terminator::Jump(next),
+                    // Falling off the end of main returns 0
+                    None => Terminator::Return(Value::Constant(0)),
+                },
+            };
+
+            cfg.blocks.push(BasicBlock {
+                label: names[i].clone(),
+                phis: Vec::new(),
+                instructions,
+                terminator,
+            });
+        }
+
+        return cfg;
+    }
+
+    pub fn alloc_label(&mut self) -> String {
+        let name = format!("block.{}", self.label_count);
+        self.label_count += 1;
+        return name;
+    }
+
+    // Two dots keep these apart from both resolver names (`x.0`) and IR
+    // temporaries (`tmp.0`)
+    pub fn alloc_var(&mut self, prefix: &str) -> String {
+        let name = format!("{}.opt.{}", prefix, self.var_count);
+        self.var_count += 1;
+        return name;
+    }
+
+    pub fn add_block(&mut self, terminator: Terminator) -> usize {
+        let label = self.alloc_label();
+        self.blocks.push(BasicBlock {
+            label,
+            phis: Vec::new(),
+            instructions: Vec::new(),
+            terminator,
+        });
+        return self.blocks.len() - 1;
+    }
+
+    pub fn predecessors(&self) -> Vec<Vec<usize>> {
+        let mut preds = vec![Vec::new(); self.blocks.len()];
+        for (i, block) in self.blocks.iter().enumerate() {
+            for succ in block.terminator.successors() {
+                preds[succ].push(i);
+            }
+        }
+        return preds;
+    }
+
+    pub fn reachable(&self) -> Vec<bool> {
+        let mut seen = vec![false; self.blocks.len()];
+        let mut stack = vec![0];
+
+        while let Some(b) = stack.pop() {
+            if seen[b] {
+                continue;
+            }
+            seen[b] = true;
+            stack.extend(self.blocks[b].terminator.successors());
+        }
+
+        return seen;
+    }
+
+    // Drops every block whose flag is false. The entry block is always kept
+    // and no surviving terminator may refer to a dropped block.
+    pub fn retain(&mut self, keep: &[bool]) {
+        let mut remap = vec![usize::MAX; self.blocks.len()];
+        let mut next = 0;
+        for (i, k) in keep.iter().enumerate() {
+            if *k || i == 0 {
+                remap[i] = next;
+                next += 1;
+            }
+        }
+
+        let blocks = std::mem::take(&mut self.blocks);
+        for (i, mut block) in blocks.into_iter().enumerate() {
+            if remap[i] == usize::MAX {
+                continue;
+            }
+
+            for target in block.terminator.targets_mut() {
+                *target = remap[*target];
+            }
+
+            for phi in &mut block.phis {
+                phi.args.retain(|(pred, _)| remap[*pred] != usize::MAX);
+                for (pred, _) in &mut phi.args {
+                    *pred = remap[*pred];
+                }
+            }
+
+            self.blocks.push(block);
+        }
+    }
+
+    // Forgets phi arguments for edges that no longer exist
+    pub fn prune_phis(&mut self) {
+        let preds = self.predecessors();
+        for (b, block) in self.blocks.iter_mut().enumerate() {
+            for phi in &mut block.phis {
+                phi.args.retain(|(pred, _)| preds[b].contains(pred));
+            }
+        }
+    }
+
+    // Flattens the graph back into TAC in block order. Jumps to the next block
+    // are implicit, and only labels that some jump still targets are emitted.
+    pub fn linearize(self) -> Vec<Instruction> {
+        debug_assert!(self.blocks.iter().all(|b| b.phis.is_empty()));
+
+        let mut targeted = vec![false; self.blocks.len()];
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            let next = i + 1;
+            match &block.terminator {
+                Terminator::Return(_) => {}
+                Terminator::Jump(target) => targeted[*target] |= *target != next,
+                Terminator::Branch { zero, nonzero, .. } => {
+                    if zero == nonzero {
+                        targeted[*zero] |= *zero != next;
+                    } else if *nonzero == next {
+                        targeted[*zero] = true;
+                    } else if *zero == next {
+                        targeted[*nonzero] = true;
+                    } else {
+                        targeted[*zero] = true;
+                        targeted[*nonzero] = true;
+                    }
+                }
+            }
+        }
+
+        let labels: Vec<String> = self.blocks.iter().map(|b| b.label.clone()).collect();
+        let mut out = Vec::new();
+
+        for (i, block) in self.blocks.into_iter().enumerate() {
+            let next = i + 1;
+
+            if targeted[i] {
+                out.push(Instruction::Label(block.label));
+            }
+            out.extend(block.instructions);
+
+            match block.terminator {
+                Terminator::Return(val) => out.push(Instruction::Return(val)),
+
+                Terminator::Jump(target) => {
+                    if target != next {
+                        out.push(Instruction::Jump {
+                            target: labels[target].clone(),
+                        });
+                    }
+                }
+
+                Terminator::Branch {
+                    condition,
+                    zero,
+                    nonzero,
+                } => {
+                    if zero == nonzero {
+                        if zero != next {
+                            out.push(Instruction::Jump {
+                                target: labels[zero].clone(),
+                            });
+                        }
+                    } else if nonzero == next {
+                        out.push(Instruction::JumpIfZero {
+                            condition,
+                            target: labels[zero].clone(),
+                        });
+                    } else if zero == next {
+                        out.push(Instruction::JumpIfNotZero {
+                            condition,
+                            target: labels[nonzero].clone(),
+                        });
+                    } else {
+                        out.push(Instruction::JumpIfZero {
+                            condition,
+                            target: labels[zero].clone(),
+                        });
+                        out.push(Instruction::Jump {
+                            target: labels[nonzero].clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        return out;
+    }
+}
+
+pub fn defined(inst: &Instruction) -> Option<&String> {
+    match inst {
+        Instruction::Unary { dst, .. }
+        | Instruction::Binary { dst, .. }
+        | Instruction::Copy { dst, .. } => Some(dst),
+        _ => None,
+    }
+}
+
+pub fn defined_mut(inst: &mut Instruction) -> Option<&mut String> {
+    match inst {
+        Instruction::Unary { dst, .. }
+        | Instruction::Binary { dst, .. }
+        | Instruction::Copy { dst, .. } => Some(dst),
+        _ => None,
+    }
+}
+
+pub fn uses(inst: &Instruction) -> Vec<&Value> {
+    match inst {
+        Instruction::Unary { src, .. } | Instruction::Copy { src, .. } => vec![src],
+        Instruction::Binary { src1, src2, .. } => vec![src1, src2],
+        Instruction::Return(val) => vec![val],
+        Instruction::JumpIfZero { condition, .. }
+        | Instruction::JumpIfNotZero { condition, .. } => {
+            vec![condition]
+        }
+        Instruction::Jump { .. } | Instruction::Label(_) | Instruction::Location(_) => Vec::new(),
+    }
+}
+
+pub fn uses_mut(inst: &mut Instruction) -> Vec<&mut Value> {
+    match inst {
+        Instruction::Unary { src, .. } | Instruction::Copy { src, .. } => vec![src],
+        Instruction::Binary { src1, src2, .. } => vec![src1, src2],
+        Instruction::Return(val) => vec![val],
+        Instruction::JumpIfZero { condition, .. }
+        | Instruction::JumpIfNotZero { condition, .. } => {
+            vec![condition]
+        }
+        Instruction::Jump { .. } | Instruction::Label(_) | Instruction::Location(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conditional_jump_at_the_end() {
+        let cfg = Cfg::build(vec![
+            Instruction::Label("top".to_string()),
+            Instruction::JumpIfNotZero {
+                condition: Value::Variable("x".to_string()),
+                target: "top".to_string(),
+            },
+        ]);
+
+        // Not taken, it falls off the end, which returns 0
+        assert_eq!(cfg.blocks.len(), 3);
+        assert!(matches!(
+            cfg.blocks[1].terminator,
+            Terminator::Branch {
+                zero: 2,
+                nonzero: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            cfg.blocks[2].terminator,
+            Terminator::Return(Value::Constant(0))
+        ));
+    }
+}
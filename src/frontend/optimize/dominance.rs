@@ -0,0 +1,158 @@
+// Dominator tree and dominance frontiers (Cooper, Harvey & Kennedy)
+
+use super::cfg::Cfg;
+
+const UNDEFINED: usize = usize::MAX;
+
+#[derive(Debug)]
+pub struct Dominance {
+    pub idom: Vec<usize>,
+    pub children: Vec<Vec<usize>>,
+    pub frontier: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Walk {
+    Enter(usize),
+    Leave(usize),
+}
+
+impl Dominance {
+    pub fn compute(cfg: &Cfg) -> Self {
+        let count = cfg.blocks.len();
+        let preds = cfg.predecessors();
+        let rpo = reverse_postorder(cfg);
+
+        let mut order = vec![UNDEFINED; count];
+        for (i, b) in rpo.iter().enumerate() {
+            order[*b] = i;
+        }
+
+        let mut idom = vec![UNDEFINED; count];
+        idom[0] = 0;
+
+        let intersect = |idom: &[usize], mut a: usize, mut b: usize| -> usize {
+            while a != b {
+                while order[a] > order[b] {
+                    a = idom[a];
+                }
+                while order[b] > order[a] {
+                    b = idom[b];
+                }
+            }
+            return a;
+        };
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for &b in rpo.iter().skip(1) {
+                let mut new_idom = UNDEFINED;
+                for &p in &preds[b] {
+                    if idom[p] == UNDEFINED {
+                        continue;
+                    }
+                    new_idom = if new_idom == UNDEFINED {
+                        p
+                    } else {
+                        intersect(&idom, p, new_idom)
+                    };
+                }
+
+                if idom[b] != new_idom {
+                    idom[b] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut children = vec![Vec::new(); count];
+        for &b in rpo.iter().skip(1) {
+            children[idom[b]].push(b);
+        }
+
+        // A join point is in the frontier of every block on the way up from
+        // each of its predecessors to its immediate dominator
+        let mut frontier: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for &b in &rpo {
+            if preds[b].len() < 2 {
+                continue;
+            }
+            for &p in &preds[b] {
+                if idom[p] == UNDEFINED {
+                    continue;
+                }
+                let mut runner = p;
+                while runner != idom[b] {
+                    if !frontier[runner].contains(&b) {
+                        frontier[runner].push(b);
+                    }
+                    runner = idom[runner];
+                }
+            }
+        }
+
+        return Dominance {
+            idom,
+            children,
+            frontier,
+        };
+    }
+
+    // Every block of the dominator tree in preorder, each entered before its
+    // children and left after them. Passes that keep scoped state walk this
+    // instead of recursing, since a run of thousands of `if`s nests the tree
+    // as deep
+    pub fn walk(&self) -> Vec<Walk> {
+        let mut order = Vec::new();
+        let mut stack = vec![Walk::Enter(0)];
+        while let Some(step) = stack.pop() {
+            if let Walk::Enter(b) = step {
+                stack.push(Walk::Leave(b));
+                // Reversed, so that the children come out in order
+                for &child in self.children[b].iter().rev() {
+                    stack.push(Walk::Enter(child));
+                }
+            }
+            order.push(step);
+        }
+        return order;
+    }
+
+    pub fn dominates(&self, a: usize, mut b: usize) -> bool {
+        loop {
+            if a == b {
+                return true;
+            }
+            if b == 0 || self.idom[b] == UNDEFINED {
+                return false;
+            }
+            b = self.idom[b];
+        }
+    }
+}
+
+pub fn reverse_postorder(cfg: &Cfg) -> Vec<usize> {
+    let mut visited = vec![false; cfg.blocks.len()];
+    let mut postorder = Vec::new();
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0] = true;
+
+    while let Some((b, next)) = stack.pop() {
+        let succs = cfg.blocks[b].terminator.successors();
+        if next < succs.len() {
+            stack.push((b, next + 1));
+            let s = succs[next];
+            if !visited[s] {
+                visited[s] = true;
+                stack.push((s, 0));
+            }
+        } else {
+            postorder.push(b);
+        }
+    }
+
+    postorder.reverse();
+    return postorder;
+}
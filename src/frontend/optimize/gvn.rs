@@ -0,0 +1,297 @@
+// Dominator-based global value numbering (Briggs, Cooper & Simpson)
+
+use super::cfg::{self, Cfg, Terminator};
+use super::dominance::{Dominance, Walk};
+use super::sccp::{fold_binary, fold_unary};
+use crate::frontend::ir::*;
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Expression {
+    Unary(UnaryOperator, Value),
+    Binary(BinaryOperator, Value, Value),
+}
+
+struct Numbering {
+    // Every SSA name that turned out to be redundant, mapped to its leader
+    leaders: HashMap<String, Value>,
+    // Expressions available in the current dominator subtree
+    available: HashMap<Expression, Vec<String>>,
+}
+
+pub fn run(cfg: &mut Cfg) {
+    hoist(cfg);
+
+    let dom = Dominance::compute(cfg);
+    let mut numbering = Numbering {
+        leaders: HashMap::new(),
+        available: HashMap::new(),
+    };
+
+    // The expressions of a block are no longer available once the walk
+    // leaves its subtree
+    let mut scopes: Vec<Vec<Expression>> = Vec::new();
+    for step in dom.walk() {
+        match step {
+            Walk::Enter(b) => scopes.push(numbering.visit(b, cfg)),
+            Walk::Leave(_) => {
+                for expr in scopes.pop().unwrap() {
+                    if let Some(names) = numbering.available.get_mut(&expr) {
+                        names.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Numbering only finds an expression that was computed in a dominating block,
+// and the two sides of a branch do not dominate each other. So an expression
+// computed on both sides is first moved up into the branching block, when its
+// operands are already defined there: the other side's copy becomes a copy of
+// it, and a recomputation after the join is dominated by it. It ran on every
+// path before, so moving it up never computes anything new.
+fn hoist(cfg: &mut Cfg) {
+    let preds = cfg.predecessors();
+    for b in 0..cfg.blocks.len() {
+        let Terminator::Branch { zero, nonzero, .. } = cfg.blocks[b].terminator else {
+            continue;
+        };
+        if zero == nonzero || preds[zero].len() != 1 || preds[nonzero].len() != 1 {
+            continue;
+        }
+
+        let mut i = 0;
+        while i < cfg.blocks[nonzero].instructions.len() {
+            let found = hoistable(&cfg.blocks[nonzero], i).and_then(|(expr, inst)| {
+                let (j, other) = (0..cfg.blocks[zero].instructions.len()).find_map(|j| {
+                    let (other, inst) = hoistable(&cfg.blocks[zero], j)?;
+                    return (other == expr).then_some((j, inst));
+                })?;
+                return Some((inst, j, other));
+            });
+            let Some((inst, j, other)) = found else {
+                i += 1;
+                continue;
+            };
+
+            let (Some(dst), Some(copy)) = (cfg::defined(&inst), cfg::defined(&other)) else {
+                unreachable!("only computations are hoisted");
+            };
+            cfg.blocks[zero].instructions[j] = Instruction::Copy {
+                src: Value::Variable(dst.clone()),
+                dst: copy.clone(),
+            };
+            cfg.blocks[nonzero].instructions.remove(i);
+            cfg.blocks[b].instructions.push(inst);
+        }
+    }
+}
+
+// The expression the `i`th instruction of `block` computes, and the
+// instruction itself as it would read above `block`, if its operands are
+// defined there. Operands that are copies made earlier in `block` are read
+// through, so that both sides of a branch spell the same expression the same
+// way.
+fn hoistable(block: &cfg::BasicBlock, i: usize) -> Option<(Expression, Instruction)> {
+    let resolve = |val: &Value| -> Option<Value> {
+        let mut val = val.clone();
+        while let Value::Variable(name) = &val {
+            if block.phis.iter().any(|phi| phi.dst == *name) {
+                return None;
+            }
+            let definition = block.instructions[..i]
+                .iter()
+                .rev()
+                .find(|inst| cfg::defined(inst) == Some(name));
+            match definition {
+                Some(Instruction::Copy { src, .. }) => val = src.clone(),
+                Some(_) => return None,
+                None => break,
+            }
+        }
+        return Some(val);
+    };
+
+    return match &block.instructions[i] {
+        Instruction::Unary { op, src, dst } => {
+            let src = resolve(src)?;
+            let expr = Expression::Unary(*op, src.clone());
+            let dst = dst.clone();
+            Some((expr, Instruction::Unary { op: *op, src, dst }))
+        }
+        Instruction::Binary {
+            op,
+            src1,
+            src2,
+            dst,
+        } => {
+            let (src1, src2) = (resolve(src1)?, resolve(src2)?);
+            let expr = binary_expression(*op, src1.clone(), src2.clone());
+            let dst = dst.clone();
+            Some((
+                expr,
+                Instruction::Binary {
+                    op: *op,
+                    src1,
+                    src2,
+                    dst,
+                },
+            ))
+        }
+        _ => None,
+    };
+}
+
+impl Numbering {
+    fn leader(&self, val: &Value) -> Value {
+        match val {
+            Value::Variable(name) => match self.leaders.get(name) {
+                Some(leader) => leader.clone(),
+                None => val.clone(),
+            },
+            Value::Constant(_) => val.clone(),
+        }
+    }
+
+    fn canonicalize(&self, val: &mut Value) {
+        *val = self.leader(val);
+    }
+
+    // Numbers the block's instructions, and returns the expressions it made
+    // available
+    fn visit(&mut self, b: usize, cfg: &mut Cfg) -> Vec<Expression> {
+        let mut scope: Vec<Expression> = Vec::new();
+
+        // A phi whose arguments are all the same value is just that value
+        let phis = std::mem::take(&mut cfg.blocks[b].phis);
+        for mut phi in phis {
+            for (_, val) in &mut phi.args {
+                self.canonicalize(val);
+            }
+
+            let mut distinct = phi
+                .args
+                .iter()
+                .map(|(_, val)| val)
+                .filter(|val| !matches!(val, Value::Variable(name) if *name == phi.dst));
+            let first = distinct.next().cloned();
+
+            match first {
+                Some(val) if distinct.all(|other| *other == val) => {
+                    self.leaders.insert(phi.dst, val);
+                }
+                _ => cfg.blocks[b].phis.push(phi),
+            }
+        }
+
+        let instructions = std::mem::take(&mut cfg.blocks[b].instructions);
+        for mut inst in instructions {
+            for val in cfg::uses_mut(&mut inst) {
+                self.canonicalize(val);
+            }
+
+            let (dst, expr) = match &inst {
+                // Copies are propagated into their uses
+                Instruction::Copy { src, dst } => {
+                    self.leaders.insert(dst.clone(), src.clone());
+                    continue;
+                }
+
+                Instruction::Unary { op, src, dst } => {
+                    if let Value::Constant(v) = src {
+                        let folded = Value::Constant(fold_unary(op, *v));
+                        self.leaders.insert(dst.clone(), folded);
+                        continue;
+                    }
+                    (dst, Expression::Unary(*op, src.clone()))
+                }
+
+                Instruction::Binary {
+                    op,
+                    src1,
+                    src2,
+                    dst,
+                } => {
+                    if let (Value::Constant(a), Value::Constant(b)) = (src1, src2)
+                        && let Some(v) = fold_binary(op, *a, *b)
+                    {
+                        self.leaders.insert(dst.clone(), Value::Constant(v));
+                        continue;
+                    }
+                    (dst, binary_expression(*op, src1.clone(), src2.clone()))
+                }
+
+                Instruction::Location(_) => {
+                    cfg.blocks[b].instructions.push(inst);
+                    continue;
+                }
+
+                _ => unreachable!("terminators live outside the instruction list"),
+            };
+
+            // An equal expression computed in a dominating block already holds
+            // the result, so this one becomes a copy of it
+            if let Some(leader) = self.available.get(&expr).and_then(|l| l.last()) {
+                self.leaders
+                    .insert(dst.clone(), Value::Variable(leader.clone()));
+                continue;
+            }
+
+            self.available
+                .entry(expr.clone())
+                .or_default()
+                .push(dst.clone());
+            scope.push(expr);
+            cfg.blocks[b].instructions.push(inst);
+        }
+
+        if let Some(val) = cfg.blocks[b].terminator.uses_mut() {
+            let leader = self.leader(val);
+            *val = leader;
+        }
+
+        for succ in cfg.blocks[b].terminator.successors() {
+            let leaders = &self.leaders;
+            for phi in &mut cfg.blocks[succ].phis {
+                for (pred, val) in &mut phi.args {
+                    if *pred == b
+                        && let Value::Variable(name) = val
+                        && let Some(leader) = leaders.get(name)
+                    {
+                        *val = leader.clone();
+                    }
+                }
+            }
+        }
+
+        return scope;
+    }
+}
+
+// Operands of commutative operators are put in a fixed order so that `a * b`
+// and `b * a` get the same number
+fn binary_expression(op: BinaryOperator, a: Value, b: Value) -> Expression {
+    let commutative = matches!(
+        op,
+        BinaryOperator::Add
+            | BinaryOperator::Multiply
+            | BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOr
+            | BinaryOperator::BitwiseXor
+            | BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+    );
+
+    if commutative && order(&b) < order(&a) {
+        return Expression::Binary(op, b, a);
+    }
+    return Expression::Binary(op, a, b);
+}
+
+fn order(val: &Value) -> (u8, i32, &str) {
+    match val {
+        Value::Constant(v) => (0, *v, ""),
+        Value::Variable(name) => (1, 0, name.as_str()),
+    }
+}
@@ -0,0 +1,86 @@
+// Loop-invariant code motion
+
+use super::cfg::{self, Cfg};
+use super::dominance::{self, Dominance};
+use super::loops::{self, Loop};
+use crate::frontend::ir::*;
+use std::collections::HashMap;
+
+pub fn run(cfg: &mut Cfg) {
+    let dom = Dominance::compute(cfg);
+    let mut loops = loops::find(cfg, &dom);
+    if loops.is_empty() {
+        return;
+    }
+
+    loops::insert_preheaders(cfg, &mut loops);
+
+    // Inner loops first, so that their invariants can keep moving outwards
+    let mut order: Vec<usize> = (0..loops.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(loops[i].depth));
+
+    let rpo = dominance::reverse_postorder(cfg);
+
+    for i in order {
+        hoist(cfg, &loops[i], &rpo);
+    }
+}
+
+// In SSA form an instruction is invariant when none of its operands is
+// defined inside the loop. Walking the body in reverse postorder sees the
+// definition of an operand before its uses, so chains hoist in one pass.
+fn hoist(cfg: &mut Cfg, l: &Loop, rpo: &[usize]) {
+    let preheader = l.preheader.expect("loop without a preheader");
+
+    let mut defined_in: HashMap<String, usize> = HashMap::new();
+    for (b, block) in cfg.blocks.iter().enumerate() {
+        for phi in &block.phis {
+            defined_in.insert(phi.dst.clone(), b);
+        }
+        for inst in &block.instructions {
+            if let Some(dst) = cfg::defined(inst) {
+                defined_in.insert(dst.clone(), b);
+            }
+        }
+    }
+
+    for &b in rpo {
+        if !l.contains(b) {
+            continue;
+        }
+
+        let instructions = std::mem::take(&mut cfg.blocks[b].instructions);
+        for inst in instructions {
+            let invariant = cfg::uses(&inst).iter().all(|val| match val {
+                Value::Constant(_) => true,
+                Value::Variable(name) => match defined_in.get(name) {
+                    Some(&def) => !l.contains(def),
+                    None => true,
+                },
+            });
+
+            if invariant && is_safe_to_speculate(&inst) {
+                if let Some(dst) = cfg::defined(&inst) {
+                    defined_in.insert(dst.clone(), preheader);
+                }
+                cfg.blocks[preheader].instructions.push(inst);
+            } else {
+                cfg.blocks[b].instructions.push(inst);
+            }
+        }
+    }
+}
+
+// The preheader runs even when the loop body would not, so only instructions
+// that cannot trap may move there
+fn is_safe_to_speculate(inst: &Instruction) -> bool {
+    match inst {
+        Instruction::Binary {
+            op: BinaryOperator::Divide | BinaryOperator::Modulo,
+            src2,
+            ..
+        } => matches!(src2, Value::Constant(v) if *v != 0 && *v != -1),
+        Instruction::Binary { .. } | Instruction::Unary { .. } | Instruction::Copy { .. } => true,
+        _ => false,
+    }
+}
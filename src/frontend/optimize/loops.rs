@@ -0,0 +1,149 @@
+// Natural loop detection on the control-flow graph
+
+use super::cfg::{Cfg, Phi, Terminator};
+use super::dominance::Dominance;
+use crate::frontend::ir::Value;
+
+#[derive(Debug)]
+pub struct Loop {
+    pub header: usize,
+    pub preheader: Option<usize>,
+    pub body: Vec<bool>,
+    pub parent: Option<usize>,
+    pub depth: u32,
+}
+
+impl Loop {
+    pub fn contains(&self, b: usize) -> bool {
+        self.body.get(b).copied().unwrap_or(false)
+    }
+}
+
+// An edge whose target dominates its source is a back edge, and the loop it
+// closes is the header plus everything that reaches the edge without passing
+// through the header. Back edges into the same header share one loop.
+pub fn find(cfg: &Cfg, dom: &Dominance) -> Vec<Loop> {
+    let preds = cfg.predecessors();
+    let mut loops: Vec<Loop> = Vec::new();
+
+    for (b, block) in cfg.blocks.iter().enumerate() {
+        for header in block.terminator.successors() {
+            if !dom.dominates(header, b) {
+                continue;
+            }
+
+            let index = match loops.iter().position(|l| l.header == header) {
+                Some(index) => index,
+                None => {
+                    let mut body = vec![false; cfg.blocks.len()];
+                    body[header] = true;
+                    loops.push(Loop {
+                        header,
+                        preheader: None,
+                        body,
+                        parent: None,
+                        depth: 1,
+                    });
+                    loops.len() - 1
+                }
+            };
+
+            let mut stack = vec![b];
+            while let Some(n) = stack.pop() {
+                if loops[index].body[n] {
+                    continue;
+                }
+                loops[index].body[n] = true;
+                stack.extend(&preds[n]);
+            }
+        }
+    }
+
+    // The innermost enclosing loop is the smallest one containing the header
+    let sizes: Vec<usize> = loops
+        .iter()
+        .map(|l| l.body.iter().filter(|b| **b).count())
+        .collect();
+
+    for i in 0..loops.len() {
+        loops[i].parent = (0..loops.len())
+            .filter(|&j| j != i && loops[j].contains(loops[i].header) && sizes[j] > sizes[i])
+            .min_by_key(|&j| sizes[j]);
+    }
+
+    for i in 0..loops.len() {
+        let mut depth = 1;
+        let mut current = loops[i].parent;
+        while let Some(p) = current {
+            depth += 1;
+            current = loops[p].parent;
+        }
+        loops[i].depth = depth;
+    }
+
+    return loops;
+}
+
+// Gives every loop a single block that all entries into the header go
+// through. Phi arguments coming from outside the loop move to the preheader,
+// merged by a phi of their own if there is more than one such edge.
+pub fn insert_preheaders(cfg: &mut Cfg, loops: &mut [Loop]) {
+    for i in 0..loops.len() {
+        let header = loops[i].header;
+        let outside: Vec<usize> = cfg
+            .predecessors()
+            .swap_remove(header)
+            .into_iter()
+            .filter(|p| !loops[i].contains(*p))
+            .collect();
+
+        let preheader = cfg.add_block(Terminator::Jump(header));
+
+        for &pred in &outside {
+            for target in cfg.blocks[pred].terminator.targets_mut() {
+                if *target == header {
+                    *target = preheader;
+                }
+            }
+        }
+
+        let phis = std::mem::take(&mut cfg.blocks[header].phis);
+        for mut phi in phis {
+            let (entering, mut staying): (Vec<_>, Vec<_>) = phi
+                .args
+                .into_iter()
+                .partition(|(pred, _)| outside.contains(pred));
+
+            let val = match entering.len() {
+                0 => None,
+                1 => Some(entering[0].1.clone()),
+                _ => {
+                    let dst = cfg.alloc_var("preheader");
+                    cfg.blocks[preheader].phis.push(Phi {
+                        dst: dst.clone(),
+                        args: entering,
+                    });
+                    Some(Value::Variable(dst))
+                }
+            };
+
+            if let Some(val) = val {
+                staying.push((preheader, val));
+            }
+            phi.args = staying;
+            cfg.blocks[header].phis.push(phi);
+        }
+
+        // The preheader sits inside every loop that encloses this one
+        for l in loops.iter_mut() {
+            l.body.resize(cfg.blocks.len(), false);
+        }
+        let mut current = loops[i].parent;
+        while let Some(p) = current {
+            loops[p].body[preheader] = true;
+            current = loops[p].parent;
+        }
+
+        loops[i].preheader = Some(preheader);
+    }
+}
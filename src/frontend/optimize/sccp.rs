@@ -0,0 +1,326 @@
+// Sparse conditional constant propagation (Wegman & Zadeck)
+
+use super::cfg::{self, Cfg, Terminator};
+use crate::frontend::ir::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Lattice {
+    Unknown,
+    Constant(i32),
+    Overdefined,
+}
+
+impl Lattice {
+    fn meet(self, other: Lattice) -> Lattice {
+        match (self, other) {
+            (Lattice::Unknown, x) | (x, Lattice::Unknown) => x,
+            (Lattice::Constant(a), Lattice::Constant(b)) if a == b => Lattice::Constant(a),
+            _ => Lattice::Overdefined,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Site {
+    Phi(usize),
+    Instruction(usize),
+    Terminator,
+}
+
+struct Solver<'a> {
+    cfg: &'a Cfg,
+    values: HashMap<String, Lattice>,
+    defined: HashSet<String>,
+    executable: Vec<bool>,
+    edges: Vec<Vec<usize>>,
+    users: HashMap<String, Vec<(usize, Site)>>,
+    flow_worklist: Vec<(usize, usize)>,
+    value_worklist: Vec<String>,
+}
+
+pub fn run(cfg: &mut Cfg) {
+    let (values, executable) = {
+        let mut solver = Solver::new(cfg);
+        solver.solve();
+        (solver.values, solver.executable)
+    };
+
+    rewrite(cfg, &values, &executable);
+}
+
+impl<'a> Solver<'a> {
+    fn new(cfg: &'a Cfg) -> Self {
+        let mut users: HashMap<String, Vec<(usize, Site)>> = HashMap::new();
+        let mut defined: HashSet<String> = HashSet::new();
+
+        for (b, block) in cfg.blocks.iter().enumerate() {
+            for (i, phi) in block.phis.iter().enumerate() {
+                defined.insert(phi.dst.clone());
+                for (_, val) in &phi.args {
+                    if let Value::Variable(name) = val {
+                        users
+                            .entry(name.clone())
+                            .or_default()
+                            .push((b, Site::Phi(i)));
+                    }
+                }
+            }
+
+            for (i, inst) in block.instructions.iter().enumerate() {
+                if let Some(dst) = cfg::defined(inst) {
+                    defined.insert(dst.clone());
+                }
+                for val in cfg::uses(inst) {
+                    if let Value::Variable(name) = val {
+                        users
+                            .entry(name.clone())
+                            .or_default()
+                            .push((b, Site::Instruction(i)));
+                    }
+                }
+            }
+
+            if let Some(Value::Variable(name)) = block.terminator.uses() {
+                users
+                    .entry(name.clone())
+                    .or_default()
+                    .push((b, Site::Terminator));
+            }
+        }
+
+        return Solver {
+            cfg,
+            values: HashMap::new(),
+            defined,
+            executable: vec![false; cfg.blocks.len()],
+            edges: vec![Vec::new(); cfg.blocks.len()],
+            users,
+            flow_worklist: Vec::new(),
+            value_worklist: Vec::new(),
+        };
+    }
+
+    fn solve(&mut self) {
+        self.visit_block(0);
+
+        loop {
+            if let Some((from, to)) = self.flow_worklist.pop() {
+                if self.edges[to].contains(&from) {
+                    continue;
+                }
+                self.edges[to].push(from);
+
+                if self.executable[to] {
+                    // Only the phis can see a new incoming edge
+                    for i in 0..self.cfg.blocks[to].phis.len() {
+                        self.visit(to, Site::Phi(i));
+                    }
+                } else {
+                    self.visit_block(to);
+                }
+            } else if let Some(name) = self.value_worklist.pop() {
+                let users = self.users.get(&name).cloned().unwrap_or_default();
+                for (b, site) in users {
+                    if self.executable[b] {
+                        self.visit(b, site);
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn visit_block(&mut self, b: usize) {
+        self.executable[b] = true;
+
+        for i in 0..self.cfg.blocks[b].phis.len() {
+            self.visit(b, Site::Phi(i));
+        }
+        for i in 0..self.cfg.blocks[b].instructions.len() {
+            self.visit(b, Site::Instruction(i));
+        }
+        self.visit(b, Site::Terminator);
+    }
+
+    fn visit(&mut self, b: usize, site: Site) {
+        let block = &self.cfg.blocks[b];
+
+        match site {
+            Site::Phi(i) => {
+                let phi = &block.phis[i];
+                let mut result = Lattice::Unknown;
+                for (pred, val) in &phi.args {
+                    if self.edges[b].contains(pred) {
+                        result = result.meet(self.lookup(val));
+                    }
+                }
+                self.update(&phi.dst, result);
+            }
+
+            Site::Instruction(i) => {
+                let result = match &block.instructions[i] {
+                    Instruction::Copy { src, .. } => self.lookup(src),
+
+                    Instruction::Unary { op, src, .. } => match self.lookup(src) {
+                        Lattice::Constant(v) => Lattice::Constant(fold_unary(op, v)),
+                        other => other,
+                    },
+
+                    Instruction::Binary { op, src1, src2, .. } => {
+                        match (self.lookup(src1), self.lookup(src2)) {
+                            (Lattice::Constant(a), Lattice::Constant(b)) => {
+                                match fold_binary(op, a, b) {
+                                    Some(v) => Lattice::Constant(v),
+                                    None => Lattice::Overdefined,
+                                }
+                            }
+                            (Lattice::Overdefined, _) | (_, Lattice::Overdefined) => {
+                                Lattice::Overdefined
+                            }
+                            _ => Lattice::Unknown,
+                        }
+                    }
+
+                    _ => return,
+                };
+
+                if let Some(dst) = cfg::defined(&block.instructions[i]) {
+                    self.update(dst, result);
+                }
+            }
+
+            Site::Terminator => match &block.terminator {
+                Terminator::Return(_) => {}
+                Terminator::Jump(target) => self.flow_worklist.push((b, *target)),
+                Terminator::Branch {
+                    condition,
+                    zero,
+                    nonzero,
+                } => match self.lookup(condition) {
+                    Lattice::Constant(0) => self.flow_worklist.push((b, *zero)),
+                    Lattice::Constant(_) => self.flow_worklist.push((b, *nonzero)),
+                    _ => {
+                        self.flow_worklist.push((b, *zero));
+                        self.flow_worklist.push((b, *nonzero));
+                    }
+                },
+            },
+        }
+    }
+
+    // Variables without a definition are uninitialized reads and can hold
+    // anything
+    fn lookup(&self, val: &Value) -> Lattice {
+        match val {
+            Value::Constant(v) => Lattice::Constant(*v),
+            Value::Variable(name) => match self.values.get(name) {
+                Some(lattice) => *lattice,
+                None if !self.defined.contains(name) => Lattice::Overdefined,
+                None => Lattice::Unknown,
+            },
+        }
+    }
+
+    fn update(&mut self, name: &str, result: Lattice) {
+        let old = self.values.get(name).copied().unwrap_or(Lattice::Unknown);
+        let new = old.meet(result);
+
+        if new != old {
+            self.values.insert(name.to_string(), new);
+            self.value_worklist.push(name.to_string());
+        }
+    }
+}
+
+fn rewrite(cfg: &mut Cfg, values: &HashMap<String, Lattice>, executable: &[bool]) {
+    let constant = |val: &Value| -> Option<i32> {
+        match val {
+            Value::Constant(v) => Some(*v),
+            Value::Variable(name) => match values.get(name) {
+                Some(Lattice::Constant(v)) => Some(*v),
+                _ => None,
+            },
+        }
+    };
+
+    let is_constant = |name: &String| matches!(values.get(name), Some(Lattice::Constant(_)));
+
+    for block in &mut cfg.blocks {
+        block.phis.retain(|phi| !is_constant(&phi.dst));
+        for phi in &mut block.phis {
+            for (_, val) in &mut phi.args {
+                if let Some(v) = constant(val) {
+                    *val = Value::Constant(v);
+                }
+            }
+        }
+
+        block
+            .instructions
+            .retain(|inst| !cfg::defined(inst).is_some_and(is_constant));
+        for inst in &mut block.instructions {
+            for val in cfg::uses_mut(inst) {
+                if let Some(v) = constant(val) {
+                    *val = Value::Constant(v);
+                }
+            }
+        }
+
+        if let Some(val) = block.terminator.uses_mut()
+            && let Some(v) = constant(val)
+        {
+            *val = Value::Constant(v);
+        }
+
+        if let Terminator::Branch {
+            condition: Value::Constant(v),
+            zero,
+            nonzero,
+        } = block.terminator
+        {
+            block.terminator = Terminator::Jump(if v == 0 { zero } else { nonzero });
+        }
+    }
+
+    cfg.retain(executable);
+    cfg.prune_phis();
+}
+
+pub fn fold_unary(op: &UnaryOperator, v: i32) -> i32 {
+    match op {
+        UnaryOperator::Complement => !v,
+        UnaryOperator::Negate => v.wrapping_neg(),
+        UnaryOperator::Not => (v == 0) as i32,
+    }
+}
+
+// Folds with the semantics of the generated x86-64 code. Division that would
+// trap at run time is left alone so that it still traps.
+pub fn fold_binary(op: &BinaryOperator, a: i32, b: i32) -> Option<i32> {
+    let result = match op {
+        BinaryOperator::Add => a.wrapping_add(b),
+        BinaryOperator::Subtract => a.wrapping_sub(b),
+        BinaryOperator::Multiply => a.wrapping_mul(b),
+        BinaryOperator::Divide => a.checked_div(b)?,
+        BinaryOperator::Modulo => a.checked_rem(b)?,
+
+        BinaryOperator::BitwiseAnd => a & b,
+        BinaryOperator::BitwiseOr => a | b,
+        BinaryOperator::BitwiseXor => a ^ b,
+
+        // x86 masks 32-bit shift counts to five bits
+        BinaryOperator::LeftShift => a.wrapping_shl(b as u32),
+        BinaryOperator::RightShift => a.wrapping_shr(b as u32),
+
+        BinaryOperator::Equal => (a == b) as i32,
+        BinaryOperator::NotEqual => (a != b) as i32,
+        BinaryOperator::LessThan => (a < b) as i32,
+        BinaryOperator::LessOrEqual => (a <= b) as i32,
+        BinaryOperator::GreaterThan => (a > b) as i32,
+        BinaryOperator::GreaterOrEqual => (a >= b) as i32,
+    };
+
+    return Some(result);
+}
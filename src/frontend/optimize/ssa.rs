@@ -0,0 +1,441 @@
+// Static single assignment construction (Cytron et al.) and destruction
+
+use super::cfg::{self, Cfg, Phi, Terminator};
+use super::dominance::{Dominance, Walk};
+use crate::frontend::ir::*;
+use std::collections::{HashMap, HashSet};
+
+pub fn construct(cfg: &mut Cfg) {
+    let reachable = cfg.reachable();
+    cfg.retain(&reachable);
+
+    let dom = Dominance::compute(cfg);
+    let phi_vars = place_phis(cfg, &dom);
+
+    let mut renamer = Renamer {
+        stacks: HashMap::new(),
+        versions: HashMap::new(),
+    };
+    renamer.rename(cfg, &dom, &phi_vars);
+}
+
+// Semi-pruned placement: only variables that are read in some block before
+// being written there can need a phi, since every other value dies locally.
+fn place_phis(cfg: &mut Cfg, dom: &Dominance) -> Vec<Vec<String>> {
+    let mut globals: Vec<String> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut defsites: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for (b, block) in cfg.blocks.iter().enumerate() {
+        let mut killed: HashSet<&String> = HashSet::new();
+
+        let terminator_use = block.terminator.uses();
+        for inst in &block.instructions {
+            for val in cfg::uses(inst) {
+                if let Value::Variable(name) = val
+                    && !killed.contains(name)
+                    && seen.insert(name.clone())
+                {
+                    globals.push(name.clone());
+                }
+            }
+
+            if let Some(dst) = cfg::defined(inst) {
+                killed.insert(dst);
+                let sites = defsites.entry(dst.clone()).or_default();
+                if sites.last() != Some(&b) {
+                    sites.push(b);
+                }
+            }
+        }
+
+        if let Some(Value::Variable(name)) = terminator_use
+            && !killed.contains(name)
+            && seen.insert(name.clone())
+        {
+            globals.push(name.clone());
+        }
+    }
+
+    let mut phi_vars: Vec<Vec<String>> = vec![Vec::new(); cfg.blocks.len()];
+
+    for var in globals {
+        let Some(sites) = defsites.get(&var) else {
+            continue;
+        };
+
+        let mut worklist = sites.clone();
+        let mut defined: HashSet<usize> = sites.iter().copied().collect();
+        let mut has_phi: HashSet<usize> = HashSet::new();
+
+        while let Some(b) = worklist.pop() {
+            for &d in &dom.frontier[b] {
+                if !has_phi.insert(d) {
+                    continue;
+                }
+
+                cfg.blocks[d].phis.push(Phi {
+                    dst: var.clone(),
+                    args: Vec::new(),
+                });
+                phi_vars[d].push(var.clone());
+
+                if defined.insert(d) {
+                    worklist.push(d);
+                }
+            }
+        }
+    }
+
+    return phi_vars;
+}
+
+struct Renamer {
+    stacks: HashMap<String, Vec<String>>,
+    versions: HashMap<String, u32>,
+}
+
+impl Renamer {
+    fn fresh(&mut self, var: &str) -> String {
+        let version = self.versions.entry(var.to_string()).or_insert(0);
+        let name = format!("{}.{}", var, *version);
+        *version += 1;
+
+        self.stacks
+            .entry(var.to_string())
+            .or_default()
+            .push(name.clone());
+        return name;
+    }
+
+    // A variable read before any definition keeps its original name
+    fn current(&self, var: &str) -> Value {
+        match self.stacks.get(var).and_then(|s| s.last()) {
+            Some(name) => Value::Variable(name.clone()),
+            None => Value::Variable(var.to_string()),
+        }
+    }
+
+    fn rewrite(&self, val: &mut Value) {
+        if let Value::Variable(name) = val {
+            *val = self.current(name);
+        }
+    }
+
+    // Each block's names are popped when the walk leaves its subtree
+    fn rename(&mut self, cfg: &mut Cfg, dom: &Dominance, phi_vars: &[Vec<String>]) {
+        let mut scopes: Vec<Vec<String>> = Vec::new();
+        for step in dom.walk() {
+            match step {
+                Walk::Enter(b) => scopes.push(self.rename_block(b, cfg, phi_vars)),
+                Walk::Leave(_) => {
+                    for var in scopes.pop().unwrap() {
+                        self.stacks.get_mut(&var).unwrap().pop();
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns the variables it pushed a name for
+    fn rename_block(&mut self, b: usize, cfg: &mut Cfg, phi_vars: &[Vec<String>]) -> Vec<String> {
+        let mut pushed: Vec<String> = Vec::new();
+
+        for (i, var) in phi_vars[b].iter().enumerate() {
+            cfg.blocks[b].phis[i].dst = self.fresh(var);
+            pushed.push(var.clone());
+        }
+
+        for inst in &mut cfg.blocks[b].instructions {
+            for val in cfg::uses_mut(inst) {
+                self.rewrite(val);
+            }
+
+            if let Some(dst) = cfg::defined_mut(inst) {
+                let var = dst.clone();
+                *dst = self.fresh(&var);
+                pushed.push(var);
+            }
+        }
+
+        if let Some(val) = cfg.blocks[b].terminator.uses_mut() {
+            self.rewrite(val);
+        }
+
+        for succ in cfg.blocks[b].terminator.successors() {
+            for (i, var) in phi_vars[succ].iter().enumerate() {
+                let val = self.current(var);
+                cfg.blocks[succ].phis[i].args.push((b, val));
+            }
+        }
+
+        return pushed;
+    }
+}
+
+// Replaces every phi with copies at the end of its predecessors. Edges out of
+// a branching block are split first, so that the copies only run on the edge
+// they belong to.
+pub fn destruct(cfg: &mut Cfg) {
+    split_critical_edges(cfg);
+
+    for s in 0..cfg.blocks.len() {
+        let phis = std::mem::take(&mut cfg.blocks[s].phis);
+        if phis.is_empty() {
+            continue;
+        }
+
+        let mut preds: Vec<usize> = Vec::new();
+        for (pred, _) in &phis[0].args {
+            if !preds.contains(pred) {
+                preds.push(*pred);
+            }
+        }
+
+        for pred in preds {
+            let copies: Vec<(String, Value)> = phis
+                .iter()
+                .filter_map(|phi| {
+                    let (_, val) = phi.args.iter().find(|(p, _)| *p == pred)?;
+                    Some((phi.dst.clone(), val.clone()))
+                })
+                .collect();
+
+            let sequence = sequentialize(copies, cfg);
+            cfg.blocks[pred].instructions.extend(sequence);
+        }
+    }
+}
+
+fn split_critical_edges(cfg: &mut Cfg) {
+    for p in 0..cfg.blocks.len() {
+        if cfg.blocks[p].terminator.successors().len() < 2 {
+            continue;
+        }
+
+        for succ in cfg.blocks[p].terminator.successors() {
+            if cfg.blocks[succ].phis.is_empty() {
+                continue;
+            }
+
+            let middle = cfg.add_block(Terminator::Jump(succ));
+
+            for target in cfg.blocks[p].terminator.targets_mut() {
+                if *target == succ {
+                    *target = middle;
+                }
+            }
+
+            for phi in &mut cfg.blocks[succ].phis {
+                for (pred, _) in &mut phi.args {
+                    if *pred == p {
+                        *pred = middle;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Phi copies happen in parallel, so a destination may only be written once no
+// other pending copy still reads it; cycles are broken with a temporary.
+fn sequentialize(copies: Vec<(String, Value)>, cfg: &mut Cfg) -> Vec<Instruction> {
+    let mut pending: Vec<(String, Value)> = copies
+        .into_iter()
+        .filter(|(dst, src)| !matches!(src, Value::Variable(name) if name == dst))
+        .collect();
+    let mut out = Vec::new();
+
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|(dst, _)| {
+            !pending
+                .iter()
+                .any(|(_, src)| matches!(src, Value::Variable(name) if name == dst))
+        });
+
+        match ready {
+            Some(i) => {
+                let (dst, src) = pending.remove(i);
+                out.push(Instruction::Copy { src, dst });
+            }
+
+            None => {
+                let saved = pending[0].0.clone();
+                let tmp = cfg.alloc_var("phi");
+
+                out.push(Instruction::Copy {
+                    src: Value::Variable(saved.clone()),
+                    dst: tmp.clone(),
+                });
+
+                for (_, src) in &mut pending {
+                    if matches!(src, Value::Variable(name) if *name == saved) {
+                        *src = Value::Variable(tmp.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    return out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> Value {
+        return Value::Variable(name.to_string());
+    }
+
+    fn copy(dst: &str, src: Value) -> Instruction {
+        return Instruction::Copy {
+            src,
+            dst: dst.to_string(),
+        };
+    }
+
+    fn jump_if_zero(condition: &str, target: &str) -> Instruction {
+        return Instruction::JumpIfZero {
+            condition: var(condition),
+            target: target.to_string(),
+        };
+    }
+
+    // Runs straight-line copies over `values`
+    fn run(copies: &[Instruction], values: &mut HashMap<String, i32>) {
+        for inst in copies {
+            let Instruction::Copy { src, dst } = inst else {
+                panic!("expected a copy, got {:?}", inst);
+            };
+            let value = match src {
+                Value::Constant(c) => *c,
+                Value::Variable(name) => values[name],
+            };
+            values.insert(dst.clone(), value);
+        }
+    }
+
+    // Each pair must end up with what the other held before, whatever order
+    // the copies were put in
+    fn check_parallel(copies: Vec<(String, Value)>) {
+        let mut values: HashMap<String, i32> = HashMap::new();
+        for (i, name) in ["a", "b", "c"].into_iter().enumerate() {
+            values.insert(name.to_string(), i as i32);
+        }
+        let before = values.clone();
+        let expected: Vec<(String, i32)> = copies
+            .iter()
+            .map(|(dst, src)| match src {
+                Value::Variable(name) => (dst.clone(), before[name]),
+                Value::Constant(c) => (dst.clone(), *c),
+            })
+            .collect();
+
+        let mut cfg = Cfg::build(Vec::new());
+        let sequence = sequentialize(copies, &mut cfg);
+        run(&sequence, &mut values);
+        for (dst, value) in expected {
+            assert_eq!(values[&dst], value, "{} after {:?}", dst, sequence);
+        }
+    }
+
+    #[test]
+    fn swap() {
+        check_parallel(vec![
+            ("a".to_string(), var("b")),
+            ("b".to_string(), var("a")),
+        ]);
+    }
+
+    #[test]
+    fn cycle() {
+        check_parallel(vec![
+            ("a".to_string(), var("b")),
+            ("b".to_string(), var("c")),
+            ("c".to_string(), var("a")),
+        ]);
+        // A chain hanging off the cycle, and a copy to itself that vanishes
+        check_parallel(vec![
+            ("a".to_string(), var("b")),
+            ("b".to_string(), var("a")),
+            ("c".to_string(), var("a")),
+        ]);
+        let mut cfg = Cfg::build(Vec::new());
+        assert!(sequentialize(vec![("a".to_string(), var("a"))], &mut cfg).is_empty());
+    }
+
+    // x = 1; if (c) x = 2; else x = 3; return x;
+    #[test]
+    fn phi_placement() {
+        let mut cfg = Cfg::build(vec![
+            copy("x", Value::Constant(1)),
+            jump_if_zero("c", "else"),
+            copy("t", Value::Constant(2)),
+            copy("x", var("t")),
+            Instruction::Jump {
+                target: "end".to_string(),
+            },
+            Instruction::Label("else".to_string()),
+            copy("x", Value::Constant(3)),
+            Instruction::Label("end".to_string()),
+            Instruction::Return(var("x")),
+        ]);
+        construct(&mut cfg);
+
+        // Only the join gets a phi, and only for `x`: `t` never leaves its
+        // block, and `c` is never written
+        let phis: Vec<usize> = cfg.blocks.iter().map(|block| block.phis.len()).collect();
+        assert_eq!(phis, [0, 0, 0, 1]);
+
+        let phi = &cfg.blocks[3].phis[0];
+        let defined = |b: usize| match cfg.blocks[b].instructions.last() {
+            Some(Instruction::Copy { dst, .. }) => var(dst),
+            other => panic!("expected a copy, got {:?}", other),
+        };
+        let mut args = phi.args.clone();
+        args.sort_by_key(|(pred, _)| *pred);
+        assert_eq!(args, [(1, defined(1)), (2, defined(2))]);
+        assert!(matches!(
+            &cfg.blocks[3].terminator,
+            Terminator::Return(Value::Variable(name)) if *name == phi.dst
+        ));
+    }
+
+    // x = 1; if (c) x = 2; return x;
+    #[test]
+    fn critical_edges() {
+        let mut cfg = Cfg::build(vec![
+            copy("x", Value::Constant(1)),
+            jump_if_zero("c", "end"),
+            copy("x", Value::Constant(2)),
+            Instruction::Label("end".to_string()),
+            Instruction::Return(var("x")),
+        ]);
+        construct(&mut cfg);
+        let dst = cfg.blocks[2].phis[0].dst.clone();
+        destruct(&mut cfg);
+
+        // The edge from the branch straight to the join gets a block of its
+        // own for the copy, and the branch's other side keeps it at its end
+        assert_eq!(cfg.blocks.len(), 4);
+        assert!(matches!(
+            cfg.blocks[0].terminator,
+            Terminator::Branch {
+                zero: 3,
+                nonzero: 1,
+                ..
+            }
+        ));
+        assert!(matches!(cfg.blocks[3].terminator, Terminator::Jump(2)));
+        assert_eq!(cfg.blocks[0].instructions.len(), 1);
+
+        let last = |b: usize| cfg.blocks[b].instructions.last().unwrap();
+        assert!(matches!(
+            last(3),
+            Instruction::Copy { src: Value::Variable(src), dst: to } if *to == dst && src.starts_with("x.")
+        ));
+        assert!(matches!(last(1), Instruction::Copy { dst: to, .. } if *to == dst));
+        assert!(cfg.blocks.iter().all(|block| block.phis.is_empty()));
+    }
+}
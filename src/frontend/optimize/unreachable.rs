@@ -0,0 +1,127 @@
+use super::cfg::{Cfg, Terminator};
+use crate::frontend::ir::{Instruction, Value};
+
+pub fn run(cfg: &mut Cfg) {
+    fold_constant_branches(cfg);
+    thread_jumps(cfg);
+    remove_unreachable(cfg);
+    merge_blocks(cfg);
+}
+
+// `while (1)` and friends branch on a constant; only one edge can ever be taken
+fn fold_constant_branches(cfg: &mut Cfg) {
+    for block in &mut cfg.blocks {
+        if let Terminator::Branch {
+            condition: Value::Constant(val),
+            zero,
+            nonzero,
+        } = block.terminator
+        {
+            let target = if val == 0 { zero } else { nonzero };
+            block.terminator = Terminator::Jump(target);
+        }
+    }
+}
+
+// Retargets every edge that lands on an empty block ending in an unconditional
+// jump, so chains like `continue_label -> Jump -> header` collapse to one hop.
+// Blocks with phis are left alone, since their arguments are keyed by the
+// predecessor the edge comes from. A block that only marks a source location
+// still counts as empty, so `-g` doesn't change the code.
+fn thread_jumps(cfg: &mut Cfg) {
+    let forward: Vec<Option<usize>> = cfg
+        .blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| match block.terminator {
+            Terminator::Jump(target)
+                if block
+                    .instructions
+                    .iter()
+                    .all(|inst| matches!(inst, Instruction::Location(_)))
+                    && block.phis.is_empty()
+                    && cfg.blocks[target].phis.is_empty()
+                    && target != i =>
+            {
+                Some(target)
+            }
+            _ => None,
+        })
+        .collect();
+
+    let resolve = |start: usize| -> usize {
+        let mut current = start;
+        let mut hops = 0;
+
+        // An empty infinite loop would otherwise thread forever
+        while let Some(next) = forward[current] {
+            if hops > forward.len() {
+                return start;
+            }
+            current = next;
+            hops += 1;
+        }
+
+        return current;
+    };
+
+    for block in &mut cfg.blocks {
+        for target in block.terminator.targets_mut() {
+            *target = resolve(*target);
+        }
+
+        if let Terminator::Branch { zero, nonzero, .. } = block.terminator
+            && zero == nonzero
+        {
+            block.terminator = Terminator::Jump(zero);
+        }
+    }
+}
+
+fn remove_unreachable(cfg: &mut Cfg) {
+    let reachable = cfg.reachable();
+    cfg.retain(&reachable);
+    cfg.prune_phis();
+}
+
+// Appends a block to its predecessor when the two are joined by a plain jump
+// and neither has any other edge between them.
+fn merge_blocks(cfg: &mut Cfg) {
+    let mut preds = cfg.predecessors();
+
+    for i in 0..cfg.blocks.len() {
+        while let Terminator::Jump(target) = cfg.blocks[i].terminator {
+            if target == i || target == 0 || preds[target].len() != 1 {
+                break;
+            }
+
+            // With a single predecessor every phi just forwards its argument
+            let phis = std::mem::take(&mut cfg.blocks[target].phis);
+            for phi in phis {
+                let (_, src) = phi.args.into_iter().next().expect("phi without arguments");
+                cfg.blocks[i]
+                    .instructions
+                    .push(Instruction::Copy { src, dst: phi.dst });
+            }
+
+            // Leave the absorbed block as a self-loop so it becomes unreachable
+            let instructions = std::mem::take(&mut cfg.blocks[target].instructions);
+            let terminator =
+                std::mem::replace(&mut cfg.blocks[target].terminator, Terminator::Jump(target));
+
+            for succ in terminator.successors() {
+                for pred in &mut preds[succ] {
+                    if *pred == target {
+                        *pred = i;
+                    }
+                }
+            }
+
+            preds[target].clear();
+            cfg.blocks[i].instructions.extend(instructions);
+            cfg.blocks[i].terminator = terminator;
+        }
+    }
+
+    remove_unreachable(cfg);
+}
@@ -1,26 +1,83 @@
 use super::ast::*;
-use super::token::Token;
+use super::printer::binary;
+use super::token::{Comment, Located, Span, Token, Warned};
+use crate::Warning;
+use std::collections::HashMap;
 
 struct Parser {
     tokens: Vec<Token>,
+    spans: Vec<Span>,
     current: usize,
+    // In source order, with the next one to attach at `comment`
+    comments: Vec<Comment>,
+    comment: usize,
+    warnings: Vec<Warned>,
+    // Whether the expression just parsed was written in parentheses, which
+    // is how C silences -Wparentheses
+    grouped: bool,
 }
 
 // Main parse function that starts the parsing process
-pub fn parse(tokens: Vec<Token>) -> Result<Program, String> {
-    let mut parser = Parser::new(tokens);
-    parser.parse_program()
+pub fn parse(tokens: Vec<(Token, Span)>) -> Result<Program, Located> {
+    parse_with_warnings(tokens).map(|(program, _)| program)
+}
+
+// Also returns what -Wparentheses, -Wshift-count-overflow, -Wdiv-by-zero
+// and -Wempty-body found, since only the parser still sees the parentheses
+// and where each operator is
+pub fn parse_with_warnings(tokens: Vec<(Token, Span)>) -> Result<(Program, Vec<Warned>), Located> {
+    let mut parser = Parser::new(tokens, Vec::new());
+    let program = parser.parse_source()?;
+    Ok((program, parser.warnings))
+}
+
+// Attaches each comment to the node after it, or to the node it trails on
+// the same line, for the formatter
+pub fn parse_with_comments(
+    tokens: Vec<(Token, Span)>,
+    comments: Vec<Comment>,
+) -> Result<Program, Located> {
+    let mut parser = Parser::new(tokens, comments);
+    parser.parse_source()
+}
+
+// How tightly a binary operator binds, for the formatter's parentheses
+pub fn precedence(token: &Token) -> Option<u8> {
+    Parser::get_precedence(token)
 }
 
 impl Parser {
-    fn new(tokens: Vec<Token>) -> Self {
-        return Parser { tokens, current: 0 };
+    fn new(tokens: Vec<(Token, Span)>, comments: Vec<Comment>) -> Self {
+        let (tokens, spans) = tokens.into_iter().unzip();
+        return Parser {
+            tokens,
+            spans,
+            current: 0,
+            comments,
+            comment: 0,
+            warnings: Vec::new(),
+            grouped: false,
+        };
+    }
+
+    // An error is at the last token taken, the one that did not fit
+    fn parse_source(&mut self) -> Result<Program, Located> {
+        self.parse_program()
+            .map_err(|message| (message, self.spans[self.current.max(1) - 1]))
+    }
+
+    fn warn(&mut self, warning: Warning, span: Span, message: String) {
+        self.warnings.push((warning, (message, span)));
     }
 
     fn peek(&self) -> &Token {
         &self.tokens[self.current]
     }
 
+    fn span(&self) -> Span {
+        self.spans[self.current]
+    }
+
     fn advance(&mut self) -> &Token {
         let token = &self.tokens[self.current];
         self.current += 1;
@@ -41,6 +98,43 @@ impl Parser {
         }
     }
 
+    // The comments left before the current token
+    fn take_comments(&mut self) -> Vec<Comment> {
+        let mut comments = Vec::new();
+        while let Some(comment) = self.comments.get(self.comment)
+            && comment.span < self.span()
+        {
+            comments.push(comment.clone());
+            self.comment += 1;
+        }
+        return comments;
+    }
+
+    // Taken before a node is parsed
+    fn leading(&mut self) -> Trivia {
+        let start = match self.comments.get(self.comment) {
+            Some(comment) if comment.span < self.span() => comment.span,
+            _ => self.span(),
+        };
+        let blank_line = self.current > 0 && start.line > self.spans[self.current - 1].line + 1;
+        return Trivia {
+            leading: self.take_comments(),
+            trailing: None,
+            blank_line,
+        };
+    }
+
+    // Taken after a node is parsed: a comment on the line of its last token
+    fn trailing(&mut self) -> Option<Comment> {
+        let line = self.spans[self.current - 1].line;
+        let comment = self.comments.get(self.comment)?;
+        if comment.span.line != line || comment.span >= self.span() {
+            return None;
+        }
+        self.comment += 1;
+        return Some(comment.clone());
+    }
+
     fn get_precedence(token: &Token) -> Option<u8> {
         match token {
             Token::Star | Token::Slash | Token::Percent => Some(50),
@@ -128,12 +222,19 @@ impl Parser {
 impl Parser {
     fn parse_program(&mut self) -> Result<Program, String> {
         let function = self.parse_function()?;
-        self.expect(Token::EOF, "Expected end of file")?;
-        return Ok(Program { function });
+        let comments = self.take_comments();
+        self.expect(Token::Eof, "Expected end of file")?;
+        return Ok(Program {
+            function,
+            comments,
+            names: HashMap::new(),
+        });
     }
 
     fn parse_function(&mut self) -> Result<Function, String> {
+        let mut trivia = self.leading();
         self.expect(Token::Int, "Expected 'int' keyword")?;
+        let span = self.span();
         let name = self.expect_identifier("Expected function name")?;
 
         self.expect(Token::OpenParen, "Expected '('")?;
@@ -141,7 +242,13 @@ impl Parser {
         self.expect(Token::CloseParen, "Expected ')'")?;
 
         let body = self.parse_block()?;
-        return Ok(Function { name, body });
+        trivia.trailing = self.trailing();
+        return Ok(Function {
+            name,
+            body,
+            span,
+            trivia,
+        });
     }
 
     fn parse_block(&mut self) -> Result<Block, String> {
@@ -154,21 +261,29 @@ impl Parser {
             items.push(block_item);
         }
 
+        let comments = self.take_comments();
         self.expect(Token::CloseBrace, "Expected '}'")?;
 
-        return Ok(Block { items });
+        return Ok(Block { items, comments });
     }
 
     fn parse_block_item(&mut self) -> Result<BlockItem, String> {
         if self.peek() == &Token::Int {
-            Ok(BlockItem::Declaration(self.parse_declaration()?))
+            // Declarations in a `for` leave their comments to the body
+            let mut trivia = self.leading();
+            let mut declaration = self.parse_declaration()?;
+            trivia.trailing = self.trailing();
+            declaration.trivia = trivia;
+            Ok(BlockItem::Declaration(declaration))
         } else {
             Ok(BlockItem::Statement(self.parse_statement()?))
         }
     }
 
     fn parse_declaration(&mut self) -> Result<Declaration, String> {
+        let span = self.span();
         self.expect(Token::Int, "Expected 'int' keyword")?;
+        let name_span = self.span();
         let name = self.expect_identifier("Expected variable name")?;
 
         // optional initializer: "=" <exp>
@@ -180,10 +295,24 @@ impl Parser {
         };
 
         self.expect(Token::Semicolon, "Expected ';'")?;
-        return Ok(Declaration { name, init });
+        return Ok(Declaration {
+            name,
+            name_span,
+            init,
+            span,
+            trivia: Trivia::default(),
+        });
     }
 
     fn parse_statement(&mut self) -> Result<Statement, String> {
+        let mut trivia = self.leading();
+        let span = self.span();
+        let kind = self.parse_statement_kind()?;
+        trivia.trailing = self.trailing();
+        return Ok(Statement { kind, span, trivia });
+    }
+
+    fn parse_statement_kind(&mut self) -> Result<StatementKind, String> {
         match self.peek() {
             Token::Return => self.parse_return(),
             Token::If => self.parse_if(),
@@ -196,55 +325,59 @@ impl Parser {
             Token::Goto => self.parse_goto(),
             Token::Semicolon => {
                 self.advance();
-                Ok(Statement::Null)
+                Ok(StatementKind::Null)
             }
             _ => {
                 // Check for labeled statement: <identifier> ":"
-                if let Token::Identifier(name) = self.peek() {
-                    if self.tokens.get(self.current + 1) == Some(&Token::Colon) {
-                        let name = name.clone();
+                if let Token::Identifier(name) = self.peek()
+                    && self.tokens.get(self.current + 1) == Some(&Token::Colon)
+                {
+                    let name = name.clone();
+                    let span = self.span();
 
-                        self.advance();
-                        self.advance();
+                    self.advance();
+                    self.advance();
 
-                        let stmt = self.parse_statement()?;
-                        return Ok(Statement::Labeled(name, Box::new(stmt)));
-                    }
+                    let stmt = self.parse_statement()?;
+                    return Ok(StatementKind::Labeled(name, span, Box::new(stmt)));
                 }
 
                 // Otherwise it's an expression statement
                 let exp = self.parse_exp(0)?;
                 self.expect(Token::Semicolon, "Expected ';'")?;
-                Ok(Statement::Expression(exp))
+                Ok(StatementKind::Expression(exp))
             }
         }
     }
 
     // "return" <exp> ";"
-    fn parse_return(&mut self) -> Result<Statement, String> {
+    fn parse_return(&mut self) -> Result<StatementKind, String> {
         self.advance();
         let exp = self.parse_exp(0)?;
         self.expect(Token::Semicolon, "Expected ';'")?;
-        Ok(Statement::Return(exp))
+        Ok(StatementKind::Return(exp))
     }
 
     // "if" "(" <exp> ")" <statement>, Optional: "else" <statement>
-    fn parse_if(&mut self) -> Result<Statement, String> {
+    fn parse_if(&mut self) -> Result<StatementKind, String> {
         self.advance();
         self.expect(Token::OpenParen, "Expected '('")?;
-        let condition = self.parse_exp(0)?;
+        let condition = self.parse_condition()?;
         self.expect(Token::CloseParen, "Expected ')'")?;
 
         let then_branch = self.parse_statement()?;
+        self.check_body(&then_branch, "an 'if' statement");
 
         let else_branch = if self.peek() == &Token::Else {
             self.advance();
-            Some(Box::new(self.parse_statement()?))
+            let else_branch = self.parse_statement()?;
+            self.check_body(&else_branch, "an 'else' statement");
+            Some(Box::new(else_branch))
         } else {
             None
         };
 
-        Ok(Statement::If {
+        Ok(StatementKind::If {
             condition,
             then_branch: Box::new(then_branch),
             else_branch,
@@ -252,34 +385,34 @@ impl Parser {
     }
 
     // "{" <block_item>* "}"
-    fn parse_compound(&mut self) -> Result<Statement, String> {
+    fn parse_compound(&mut self) -> Result<StatementKind, String> {
         let block = self.parse_block()?;
-        Ok(Statement::Compound(block))
+        Ok(StatementKind::Compound(block))
     }
 
     // "break" ";"
-    fn parse_break(&mut self) -> Result<Statement, String> {
+    fn parse_break(&mut self) -> Result<StatementKind, String> {
         self.advance();
         self.expect(Token::Semicolon, "Expected ';'")?;
-        Ok(Statement::Break(String::new()))
+        Ok(StatementKind::Break(String::new()))
     }
 
     // "continue" ";"
-    fn parse_continue(&mut self) -> Result<Statement, String> {
+    fn parse_continue(&mut self) -> Result<StatementKind, String> {
         self.advance();
         self.expect(Token::Semicolon, "Expected ';'")?;
-        Ok(Statement::Continue(String::new()))
+        Ok(StatementKind::Continue(String::new()))
     }
 
     // "while" "(" <exp> ")" <statement>
-    fn parse_while(&mut self) -> Result<Statement, String> {
+    fn parse_while(&mut self) -> Result<StatementKind, String> {
         self.advance();
         self.expect(Token::OpenParen, "Expected '('")?;
-        let condition = self.parse_exp(0)?;
+        let condition = self.parse_condition()?;
         self.expect(Token::CloseParen, "Expected ')'")?;
         let body = self.parse_statement()?;
 
-        Ok(Statement::While {
+        Ok(StatementKind::While {
             condition,
             body: Box::new(body),
             label: String::new(),
@@ -287,17 +420,18 @@ impl Parser {
     }
 
     // "do" <statement> "while" "(" <exp> ")" ";"
-    fn parse_do_while(&mut self) -> Result<Statement, String> {
+    fn parse_do_while(&mut self) -> Result<StatementKind, String> {
         self.advance();
         let body = self.parse_statement()?;
+        self.check_body(&body, "'do' statement");
 
         self.expect(Token::While, "Expected 'while'")?;
         self.expect(Token::OpenParen, "Expected '('")?;
-        let condition = self.parse_exp(0)?;
+        let condition = self.parse_condition()?;
         self.expect(Token::CloseParen, "Expected ')'")?;
         self.expect(Token::Semicolon, "Expected ';'")?;
 
-        Ok(Statement::DoWhile {
+        Ok(StatementKind::DoWhile {
             body: Box::new(body),
             condition,
             label: String::new(),
@@ -305,7 +439,7 @@ impl Parser {
     }
 
     // "for" "(" <for-init> [ <exp> ] ";" [ <exp> ] ")" <statement>
-    fn parse_for(&mut self) -> Result<Statement, String> {
+    fn parse_for(&mut self) -> Result<StatementKind, String> {
         self.advance();
         self.expect(Token::OpenParen, "Expected '('")?;
 
@@ -315,7 +449,7 @@ impl Parser {
         let condition = if self.peek() == &Token::Semicolon {
             None
         } else {
-            Some(self.parse_exp(0)?)
+            Some(self.parse_condition()?)
         };
         self.expect(Token::Semicolon, "Expected ';'")?;
 
@@ -328,7 +462,7 @@ impl Parser {
         self.expect(Token::CloseParen, "Expected ')'")?;
 
         let body = self.parse_statement()?;
-        Ok(Statement::For {
+        Ok(StatementKind::For {
             init,
             condition,
             post,
@@ -361,15 +495,17 @@ impl Parser {
     }
 
     // "goto" <identifier> ";"
-    fn parse_goto(&mut self) -> Result<Statement, String> {
+    fn parse_goto(&mut self) -> Result<StatementKind, String> {
         self.advance();
+        let span = self.span();
         let label = self.expect_identifier("Expected label name after 'goto'")?;
         self.expect(Token::Semicolon, "Expected ';'")?;
-        Ok(Statement::Goto(label))
+        Ok(StatementKind::Goto(label, span))
     }
 
     fn parse_exp(&mut self, min_prec: u8) -> Result<Expr, String> {
         let mut left = self.parse_factor()?;
+        let mut grouped = self.grouped;
 
         loop {
             let token = self.peek();
@@ -381,6 +517,7 @@ impl Parser {
             if token_prec < min_prec {
                 break;
             }
+            let span = self.span();
 
             // Assignment as right-associative
             if token == &Token::Equal {
@@ -408,6 +545,7 @@ impl Parser {
             else if let Some(binary_op) = Self::compound_to_binop(token) {
                 self.advance();
                 let right = self.parse_exp(token_prec)?;
+                self.check_constant(&binary_op, &right, span);
                 left = Expr::CompoundAssignment {
                     target: Box::new(left),
                     op: binary_op,
@@ -418,42 +556,131 @@ impl Parser {
             else {
                 let operator = self.token_to_binary_op()?;
                 let right = self.parse_exp(token_prec + 1)?;
+                let operands = [(&left, grouped), (&right, self.grouped)];
+                self.check_parentheses(&operator, operands, span);
+                self.check_constant(&operator, &right, span);
                 left = Expr::Binary {
                     op: operator,
                     left: Box::new(left),
                     right: Box::new(right),
                 };
             }
+            grouped = false;
         }
 
+        self.grouped = grouped;
         return Ok(left);
     }
 
+    // The condition of an `if` or a loop. An assignment there is more often
+    // a mistyped `==`, unless it is put in parentheses again
+    fn parse_condition(&mut self) -> Result<Expr, String> {
+        let span = self.span();
+        let condition = self.parse_exp(0)?;
+        if !self.grouped && matches!(condition, Expr::Assignment { .. }) {
+            let message = "suggest parentheses around assignment used as truth value";
+            self.warn(Warning::Parentheses, span, message.to_string());
+        }
+        return Ok(condition);
+    }
+
+    // Operators whose relative precedence is easy to get wrong
+    fn check_parentheses(&mut self, op: &BinaryOperator, operands: [(&Expr, bool); 2], span: Span) {
+        use BinaryOperator::*;
+        for (operand, grouped) in operands {
+            let Expr::Binary { op: inner, .. } = operand else {
+                continue;
+            };
+            if grouped {
+                continue;
+            }
+            let message = match (op, inner) {
+                (LogicalOr, LogicalAnd) => {
+                    "suggest parentheses around '&&' within '||'".to_string()
+                }
+                (
+                    BitwiseAnd | BitwiseOr | BitwiseXor,
+                    Equal | NotEqual | LessThan | LessOrEqual | GreaterThan | GreaterOrEqual,
+                ) => format!(
+                    "suggest parentheses around comparison in operand of '{}'",
+                    binary(op).1
+                ),
+                (LeftShift | RightShift, Add | Subtract) => format!(
+                    "suggest parentheses around '{}' inside '{}'",
+                    binary(inner).1,
+                    binary(op).1
+                ),
+                _ => continue,
+            };
+            self.warn(Warning::Parentheses, span, message);
+        }
+    }
+
+    // Shifts by a constant as wide as an `int`, 32 bits, or wider, and
+    // division by a constant zero
+    fn check_constant(&mut self, op: &BinaryOperator, right: &Expr, span: Span) {
+        let Expr::Constant(value) = right else {
+            return;
+        };
+        match op {
+            BinaryOperator::LeftShift if *value >= 32 => {
+                let message = "left shift count >= width of type".to_string();
+                self.warn(Warning::ShiftCountOverflow, span, message);
+            }
+            BinaryOperator::RightShift if *value >= 32 => {
+                let message = "right shift count >= width of type".to_string();
+                self.warn(Warning::ShiftCountOverflow, span, message);
+            }
+            BinaryOperator::Divide | BinaryOperator::Modulo if *value == 0 => {
+                self.warn(Warning::DivByZero, span, "division by zero".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    // A lone `;` as the body of an `if`, an `else` or a `do` is most likely
+    // a stray one
+    fn check_body(&mut self, body: &Statement, statement: &str) {
+        if matches!(body.kind, StatementKind::Null) {
+            let message = format!("suggest braces around empty body in {}", statement);
+            self.warn(Warning::EmptyBody, body.span, message);
+        }
+    }
+
     fn parse_factor(&mut self) -> Result<Expr, String> {
         // Unary operators: <op> <factor>
         if let Some(op) = Self::token_to_unary_op(self.peek()) {
             self.advance();
             let inner = self.parse_factor()?;
+            self.grouped = false;
             return Ok(Expr::Unary(op, Box::new(inner)));
         }
 
         // Primary expressions
+        let grouped = self.peek() == &Token::OpenParen;
         let mut expr = match self.peek() {
             Token::Constant(_) => self.parse_constant()?,
             Token::Identifier(_) => self.parse_variable()?,
             Token::OpenParen => self.parse_paren_expr()?,
-            _ => return Err("Expected number, unary operator, or '('".to_string()),
+            _ => {
+                self.advance();
+                return Err("Expected number, unary operator, or '('".to_string());
+            }
         };
 
+        self.grouped = grouped;
+
         // Postfix ++ and --
         loop {
             match self.peek() {
                 Token::PlusPlus => {
                     self.advance();
+                    self.grouped = false;
                     expr = Expr::PostfixIncrement(Box::new(expr));
                 }
                 Token::MinusMinus => {
                     self.advance();
+                    self.grouped = false;
                     expr = Expr::PostfixDecrement(Box::new(expr));
                 }
                 _ => break,
@@ -476,8 +703,9 @@ impl Parser {
     }
 
     fn parse_variable(&mut self) -> Result<Expr, String> {
+        let span = self.span();
         match self.advance() {
-            Token::Identifier(name) => Ok(Expr::Variable(name.clone())),
+            Token::Identifier(name) => Ok(Expr::Variable(name.clone(), span)),
             tok => Err(format!("Expected identifier, got {:?}", tok)),
         }
     }
@@ -0,0 +1,343 @@
+// Prints the AST back as C in one layout: four-space indentation, opening
+// braces on the line of their statement, and only the parentheses that the
+// parser needs to build the same tree again. Comments go back where the
+// parser attached them.
+
+use super::ast::*;
+use super::parser::precedence;
+use super::token::{Comment, Token};
+
+struct Printer {
+    output: String,
+    depth: usize,
+}
+
+pub fn print(program: &Program) -> String {
+    let mut printer = Printer {
+        output: String::new(),
+        depth: 0,
+    };
+    printer.function(&program.function);
+    printer.comments(&program.comments);
+    return printer.output;
+}
+
+impl Printer {
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&"    ".repeat(self.depth));
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    // Continues the last line, as `} else` and `} while` do
+    fn open(&mut self, text: &str, join: bool) {
+        if join {
+            self.output.pop();
+            self.output.push(' ');
+            self.output.push_str(text);
+            self.output.push('\n');
+        } else {
+            self.line(text);
+        }
+    }
+
+    // Block comments keep their own lines as they were
+    fn comments(&mut self, comments: &[Comment]) {
+        for comment in comments {
+            self.line(&comment.text);
+        }
+    }
+
+    fn trailing(&mut self, trivia: &Trivia) {
+        if let Some(comment) = &trivia.trailing {
+            self.output.pop();
+            self.output.push(' ');
+            self.output.push_str(&comment.text);
+            self.output.push('\n');
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.comments(&function.trivia.leading);
+        self.line(&format!("int {}(void) {{", function.name));
+        self.items(&function.body);
+        self.line("}");
+        self.trailing(&function.trivia);
+    }
+
+    // The items of a block, one level in. A blank line between two items
+    // in the source is kept, and runs of them become one
+    fn items(&mut self, block: &Block) {
+        self.depth += 1;
+        for (i, item) in block.items.iter().enumerate() {
+            match item {
+                BlockItem::Declaration(declaration) => {
+                    if i > 0 && declaration.trivia.blank_line {
+                        self.output.push('\n');
+                    }
+                    self.comments(&declaration.trivia.leading);
+                    self.line(&declare(declaration));
+                    self.trailing(&declaration.trivia);
+                }
+                BlockItem::Statement(statement) => {
+                    if i > 0 && statement.trivia.blank_line {
+                        self.output.push('\n');
+                    }
+                    self.statement(statement);
+                }
+            }
+        }
+        self.comments(&block.comments);
+        self.depth -= 1;
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        self.comments(&statement.trivia.leading);
+        self.statement_kind(&statement.kind, false);
+        self.trailing(&statement.trivia);
+    }
+
+    fn statement_kind(&mut self, kind: &StatementKind, join: bool) {
+        match kind {
+            StatementKind::Return(value) => self.line(&format!("return {};", expression(value))),
+            StatementKind::Expression(value) => self.line(&format!("{};", expression(value))),
+            StatementKind::Break(_) => self.line("break;"),
+            StatementKind::Continue(_) => self.line("continue;"),
+            StatementKind::Goto(label, _) => self.line(&format!("goto {};", label)),
+            StatementKind::Null => self.line(";"),
+
+            StatementKind::Compound(block) => {
+                self.line("{");
+                self.items(block);
+                self.line("}");
+            }
+
+            StatementKind::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let head = format!("if ({})", expression(condition));
+                let joined = self.controlled(&head, then_branch, join);
+                let Some(else_branch) = else_branch else {
+                    return;
+                };
+
+                // `else if` chains stay flat
+                if let StatementKind::If { .. } = else_branch.kind
+                    && else_branch.trivia.leading.is_empty()
+                {
+                    self.open("else", joined);
+                    self.statement_kind(&else_branch.kind, true);
+                    self.trailing(&else_branch.trivia);
+                } else {
+                    self.controlled("else", else_branch, joined);
+                }
+            }
+
+            StatementKind::While {
+                condition, body, ..
+            } => {
+                self.controlled(&format!("while ({})", expression(condition)), body, false);
+            }
+
+            StatementKind::DoWhile {
+                body, condition, ..
+            } => {
+                let joined = self.controlled("do", body, false);
+                self.open(&format!("while ({});", expression(condition)), joined);
+            }
+
+            StatementKind::For {
+                init,
+                condition,
+                post,
+                body,
+                ..
+            } => {
+                let init = match init {
+                    ForInit::InitDecl(declaration) => declare(declaration),
+                    ForInit::InitExpr(Some(value)) => format!("{};", expression(value)),
+                    ForInit::InitExpr(None) => ";".to_string(),
+                };
+                let condition = match condition {
+                    Some(condition) => format!(" {};", expression(condition)),
+                    None => ";".to_string(),
+                };
+                let post = match post {
+                    Some(post) => format!(" {}", expression(post)),
+                    None => String::new(),
+                };
+                let head = format!("for ({}{}{})", init, condition, post);
+                self.controlled(&head, body, false);
+            }
+
+            // Labels stand one level out from their statement
+            StatementKind::Labeled(label, _, body) => {
+                self.depth -= 1;
+                self.line(&format!("{}:", label));
+                self.depth += 1;
+                self.statement(body);
+            }
+        }
+    }
+
+    // Prints `head` and the statement it controls: a block opens on the same
+    // line and an empty statement ends it, while anything else goes on the
+    // next line, indented. Returns whether the head of what follows, an
+    // `else` or a `while`, can join the closing brace
+    fn controlled(&mut self, head: &str, body: &Statement, join: bool) -> bool {
+        match &body.kind {
+            StatementKind::Compound(block) => {
+                self.open(&format!("{} {{", head), join);
+                self.depth += 1;
+                self.comments(&body.trivia.leading);
+                self.depth -= 1;
+                self.items(block);
+                self.line("}");
+                self.trailing(&body.trivia);
+                return body.trivia.trailing.is_none();
+            }
+            StatementKind::Null if body.trivia.leading.is_empty() => {
+                self.open(&format!("{};", head), join);
+                self.trailing(&body.trivia);
+                return false;
+            }
+            _ => {
+                self.open(head, join);
+                self.depth += 1;
+                self.statement(body);
+                self.depth -= 1;
+                return false;
+            }
+        }
+    }
+}
+
+fn declare(declaration: &Declaration) -> String {
+    return match &declaration.init {
+        Some(init) => format!("int {} = {};", declaration.name, expression(init)),
+        None => format!("int {};", declaration.name),
+    };
+}
+
+// Operators bind as the parser's precedence climbing says. Prefix operators
+// bind tighter than any binary one, and postfix operators tighter still
+const UNARY: u8 = u8::MAX - 2;
+const POSTFIX: u8 = u8::MAX - 1;
+const PRIMARY: u8 = u8::MAX;
+
+fn binding(expr: &Expr) -> u8 {
+    return match expr {
+        Expr::Constant(_) | Expr::Variable(..) => PRIMARY,
+        Expr::PostfixIncrement(_) | Expr::PostfixDecrement(_) => POSTFIX,
+        Expr::Unary(..) => UNARY,
+        Expr::Binary { op, .. } => precedence(&binary(op).0).unwrap(),
+        Expr::Assignment { .. } | Expr::CompoundAssignment { .. } => {
+            precedence(&Token::Equal).unwrap()
+        }
+        Expr::Conditional { .. } => precedence(&Token::Question).unwrap(),
+    };
+}
+
+// `expr` where the parser only accepts operators that bind at least as
+// tightly as `min`, in parentheses when it binds more loosely
+fn operand(expr: &Expr, min: u8) -> String {
+    if binding(expr) < min {
+        return format!("({})", expression(expr));
+    }
+    return expression(expr);
+}
+
+fn expression(expr: &Expr) -> String {
+    return match expr {
+        Expr::Constant(value) => value.to_string(),
+        Expr::Variable(name, _) => name.clone(),
+
+        Expr::Unary(op, inner) => {
+            let inner = operand(inner, UNARY);
+            match op {
+                // `- -a` and `- --a` must not lex as a decrement
+                UnaryOperator::Negate if inner.starts_with('-') => format!("-({})", inner),
+                UnaryOperator::Negate => format!("-{}", inner),
+                UnaryOperator::LogicalNot => format!("!{}", inner),
+                UnaryOperator::Complement => format!("~{}", inner),
+                UnaryOperator::PrefixIncrement => format!("++{}", inner),
+                UnaryOperator::PrefixDecrement => format!("--{}", inner),
+            }
+        }
+        Expr::PostfixIncrement(inner) => format!("{}++", operand(inner, POSTFIX)),
+        Expr::PostfixDecrement(inner) => format!("{}--", operand(inner, POSTFIX)),
+
+        // Left-associative, so only the right operand needs parentheses at
+        // the same precedence
+        Expr::Binary { op, left, right } => {
+            let (token, spelling) = binary(op);
+            let precedence = precedence(&token).unwrap();
+            format!(
+                "{} {} {}",
+                operand(left, precedence),
+                spelling,
+                operand(right, precedence + 1)
+            )
+        }
+
+        // Right-associative, the other way around
+        Expr::Assignment { target, value } => {
+            let precedence = binding(expr);
+            format!(
+                "{} = {}",
+                operand(target, precedence + 1),
+                operand(value, precedence)
+            )
+        }
+        Expr::CompoundAssignment { target, op, value } => {
+            let precedence = binding(expr);
+            format!(
+                "{} {}= {}",
+                operand(target, precedence + 1),
+                binary(op).1,
+                operand(value, precedence)
+            )
+        }
+
+        // The middle operand is parsed on its own, up to the `:`
+        Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            let precedence = binding(expr);
+            format!(
+                "{} ? {} : {}",
+                operand(condition, precedence + 1),
+                expression(then_branch),
+                operand(else_branch, precedence)
+            )
+        }
+    };
+}
+
+// The token of a binary operator and how it is written
+pub fn binary(op: &BinaryOperator) -> (Token, &'static str) {
+    return match op {
+        BinaryOperator::Add => (Token::Plus, "+"),
+        BinaryOperator::Subtract => (Token::Minus, "-"),
+        BinaryOperator::Multiply => (Token::Star, "*"),
+        BinaryOperator::Divide => (Token::Slash, "/"),
+        BinaryOperator::Modulo => (Token::Percent, "%"),
+        BinaryOperator::BitwiseAnd => (Token::Ampersand, "&"),
+        BinaryOperator::BitwiseOr => (Token::Pipe, "|"),
+        BinaryOperator::BitwiseXor => (Token::Caret, "^"),
+        BinaryOperator::LeftShift => (Token::LessLess, "<<"),
+        BinaryOperator::RightShift => (Token::GreaterGreater, ">>"),
+        BinaryOperator::LogicalAnd => (Token::AmpAmp, "&&"),
+        BinaryOperator::LogicalOr => (Token::PipePipe, "||"),
+        BinaryOperator::Equal => (Token::EqualEqual, "=="),
+        BinaryOperator::NotEqual => (Token::ExclaimEqual, "!="),
+        BinaryOperator::LessThan => (Token::Less, "<"),
+        BinaryOperator::LessOrEqual => (Token::LessEqual, "<="),
+        BinaryOperator::GreaterThan => (Token::Greater, ">"),
+        BinaryOperator::GreaterOrEqual => (Token::GreaterEqual, ">="),
+    };
+}
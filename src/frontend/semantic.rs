@@ -3,10 +3,14 @@ mod loops;
 mod variable;
 
 use crate::frontend::ast::Program;
+use crate::frontend::token::{Located, Warned};
 
-pub fn analyze(program: &mut Program) -> Result<(), String> {
-    variable::resolve(program)?;
-    gotos::resolve(program)?;
+// Errors are at the statement or declaration they were found in. Returns
+// the unused variables and labels
+pub fn analyze(program: &mut Program) -> Result<Vec<Warned>, Located> {
+    let mut warnings = Vec::new();
+    variable::resolve(program, &mut warnings)?;
+    gotos::resolve(program, &mut warnings)?;
     loops::resolve(program)?;
-    return Ok(());
+    return Ok(warnings);
 }
@@ -1,17 +1,31 @@
+use crate::Warning;
 use crate::frontend::ast::*;
-use std::collections::HashMap;
+use crate::frontend::token::{Located, Span, Warned};
+use std::collections::{HashMap, HashSet};
 
-type LabelMap = HashMap<String, String>;
+// Each label's unique name and where it is defined
+type LabelMap = HashMap<String, (String, Span)>;
 
-pub fn resolve(program: &mut Program) -> Result<(), String> {
+pub fn resolve(program: &mut Program, warnings: &mut Vec<Warned>) -> Result<(), Located> {
     let mut labels: LabelMap = HashMap::new();
     let mut counter: usize = 0;
+    let mut targets = HashSet::new();
 
     collect_block(&program.function.body, &mut labels, &mut counter)?;
-    rewrite_block(&mut program.function.body, &labels)
+    rewrite_block(&mut program.function.body, &labels, &mut targets)?;
+
+    // Labels no `goto` names, for -Wunused-label
+    for (name, (unique, span)) in labels {
+        if !targets.contains(&unique) {
+            let message = format!("label '{}' defined but not used", name);
+            warnings.push((Warning::UnusedLabel, (message, span)));
+        }
+        program.names.insert(unique, name);
+    }
+    return Ok(());
 }
 
-fn collect_block(block: &Block, labels: &mut LabelMap, counter: &mut usize) -> Result<(), String> {
+fn collect_block(block: &Block, labels: &mut LabelMap, counter: &mut usize) -> Result<(), Located> {
     for item in &block.items {
         if let BlockItem::Statement(stmt) = item {
             collect_stmt(stmt, labels, counter)?;
@@ -24,21 +38,21 @@ fn collect_stmt(
     stmt: &Statement,
     labels: &mut LabelMap,
     counter: &mut usize,
-) -> Result<(), String> {
-    match stmt {
-        Statement::Labeled(name, inner) => {
+) -> Result<(), Located> {
+    match &stmt.kind {
+        StatementKind::Labeled(name, _, inner) => {
             if labels.contains_key(name) {
-                return Err(format!("duplicate label: '{}'", name));
+                return Err((format!("duplicate label: '{}'", name), stmt.span));
             }
 
             let unique = format!("label.{}.{}", name, *counter);
             *counter += 1;
 
-            labels.insert(name.clone(), unique);
+            labels.insert(name.clone(), (unique, stmt.span));
             collect_stmt(inner, labels, counter)
         }
 
-        Statement::If {
+        StatementKind::If {
             then_branch: then_s,
             else_branch: else_s,
             ..
@@ -52,69 +66,78 @@ fn collect_stmt(
             return Ok(());
         }
 
-        Statement::Compound(block) => collect_block(block, labels, counter),
-        Statement::While { body, .. } => collect_stmt(body, labels, counter),
-        Statement::DoWhile { body, .. } => collect_stmt(body, labels, counter),
-        Statement::For { body, .. } => collect_stmt(body, labels, counter),
-
-        Statement::Return(_)
-        | Statement::Expression(_)
-        | Statement::Null
-        | Statement::Break(_)
-        | Statement::Continue(_)
-        | Statement::Goto(_) => Ok(()),
+        StatementKind::Compound(block) => collect_block(block, labels, counter),
+        StatementKind::While { body, .. } => collect_stmt(body, labels, counter),
+        StatementKind::DoWhile { body, .. } => collect_stmt(body, labels, counter),
+        StatementKind::For { body, .. } => collect_stmt(body, labels, counter),
+
+        StatementKind::Return(_)
+        | StatementKind::Expression(_)
+        | StatementKind::Null
+        | StatementKind::Break(_)
+        | StatementKind::Continue(_)
+        | StatementKind::Goto(..) => Ok(()),
     }
 }
 
-fn rewrite_block(block: &mut Block, labels: &LabelMap) -> Result<(), String> {
+fn rewrite_block(
+    block: &mut Block,
+    labels: &LabelMap,
+    targets: &mut HashSet<String>,
+) -> Result<(), Located> {
     for item in &mut block.items {
         if let BlockItem::Statement(stmt) = item {
-            rewrite_stmt(stmt, labels)?;
+            rewrite_stmt(stmt, labels, targets)?;
         }
     }
 
     return Ok(());
 }
 
-fn rewrite_stmt(stmt: &mut Statement, labels: &LabelMap) -> Result<(), String> {
-    match stmt {
-        Statement::Labeled(name, inner) => {
-            *name = labels.get(name).unwrap().clone();
-            return rewrite_stmt(inner, labels);
+fn rewrite_stmt(
+    stmt: &mut Statement,
+    labels: &LabelMap,
+    targets: &mut HashSet<String>,
+) -> Result<(), Located> {
+    match &mut stmt.kind {
+        StatementKind::Labeled(name, _, inner) => {
+            *name = labels.get(name).unwrap().0.clone();
+            return rewrite_stmt(inner, labels, targets);
         }
 
-        Statement::Goto(target) => match labels.get(target) {
-            Some(unique) => {
+        StatementKind::Goto(target, _) => match labels.get(target) {
+            Some((unique, _)) => {
+                targets.insert(unique.clone());
                 *target = unique.clone();
                 return Ok(());
             }
 
-            None => Err(format!("undefined label: '{}'", target)),
+            None => Err((format!("undefined label: '{}'", target), stmt.span)),
         },
 
-        Statement::If {
+        StatementKind::If {
             then_branch: then_s,
             else_branch: else_s,
             ..
         } => {
-            rewrite_stmt(then_s, labels)?;
+            rewrite_stmt(then_s, labels, targets)?;
 
             if let Some(e) = else_s {
-                rewrite_stmt(e, labels)?;
+                rewrite_stmt(e, labels, targets)?;
             }
 
             return Ok(());
         }
 
-        Statement::Compound(block) => rewrite_block(block, labels),
-        Statement::While { body, .. } => rewrite_stmt(body, labels),
-        Statement::DoWhile { body, .. } => rewrite_stmt(body, labels),
-        Statement::For { body, .. } => rewrite_stmt(body, labels),
+        StatementKind::Compound(block) => rewrite_block(block, labels, targets),
+        StatementKind::While { body, .. } => rewrite_stmt(body, labels, targets),
+        StatementKind::DoWhile { body, .. } => rewrite_stmt(body, labels, targets),
+        StatementKind::For { body, .. } => rewrite_stmt(body, labels, targets),
 
-        Statement::Return(_)
-        | Statement::Expression(_)
-        | Statement::Null
-        | Statement::Break(_)
-        | Statement::Continue(_) => Ok(()),
+        StatementKind::Return(_)
+        | StatementKind::Expression(_)
+        | StatementKind::Null
+        | StatementKind::Break(_)
+        | StatementKind::Continue(_) => Ok(()),
     }
 }
@@ -1,6 +1,7 @@
 use crate::frontend::ast::*;
+use crate::frontend::token::Located;
 
-pub fn resolve(program: &mut Program) -> Result<(), String> {
+pub fn resolve(program: &mut Program) -> Result<(), Located> {
     let mut counter: usize = 0;
     label_block(&mut program.function.body, &mut counter, None)
 }
@@ -15,7 +16,7 @@ fn label_block(
     block: &mut Block,
     counter: &mut usize,
     current_loop: Option<&str>,
-) -> Result<(), String> {
+) -> Result<(), Located> {
     for item in &mut block.items {
         if let BlockItem::Statement(stmt) = item {
             label_stmt(stmt, counter, current_loop)?;
@@ -29,48 +30,51 @@ fn label_stmt(
     stmt: &mut Statement,
     counter: &mut usize,
     current_loop: Option<&str>,
-) -> Result<(), String> {
-    match stmt {
-        Statement::Break(label) => match current_loop {
+) -> Result<(), Located> {
+    match &mut stmt.kind {
+        StatementKind::Break(label) => match current_loop {
             Some(l) => {
                 *label = l.to_string();
                 return Ok(());
             }
 
-            None => Err("'break' statement outside of loop".to_string()),
+            None => Err(("'break' statement outside of loop".to_string(), stmt.span)),
         },
 
-        Statement::Continue(label) => match current_loop {
+        StatementKind::Continue(label) => match current_loop {
             Some(l) => {
                 *label = l.to_string();
                 return Ok(());
             }
 
-            None => Err("'continue' statement outside of loop".to_string()),
+            None => Err((
+                "'continue' statement outside of loop".to_string(),
+                stmt.span,
+            )),
         },
 
-        Statement::While { body, label, .. } => {
+        StatementKind::While { body, label, .. } => {
             let new_label = fresh_label(counter);
             label_stmt(body, counter, Some(&new_label))?;
             *label = new_label;
             return Ok(());
         }
 
-        Statement::DoWhile { body, label, .. } => {
+        StatementKind::DoWhile { body, label, .. } => {
             let new_label = fresh_label(counter);
             label_stmt(body, counter, Some(&new_label))?;
             *label = new_label;
             return Ok(());
         }
 
-        Statement::For { body, label, .. } => {
+        StatementKind::For { body, label, .. } => {
             let new_label = fresh_label(counter);
             label_stmt(body, counter, Some(&new_label))?;
             *label = new_label;
             return Ok(());
         }
 
-        Statement::If {
+        StatementKind::If {
             then_branch: then_s,
             else_branch: else_s,
             ..
@@ -84,11 +88,14 @@ fn label_stmt(
             return Ok(());
         }
 
-        Statement::Compound(block) => label_block(block, counter, current_loop),
+        StatementKind::Compound(block) => label_block(block, counter, current_loop),
 
-        Statement::Labeled(_, inner) => label_stmt(inner, counter, current_loop),
+        StatementKind::Labeled(_, _, inner) => label_stmt(inner, counter, current_loop),
 
-        Statement::Return(_) | Statement::Expression(_) | Statement::Goto(_) | Statement::Null => {
+        StatementKind::Return(_)
+        | StatementKind::Expression(_)
+        | StatementKind::Goto(..)
+        | StatementKind::Null => {
             return Ok(());
         }
     }
@@ -1,9 +1,15 @@
+use crate::Warning;
 use crate::frontend::ast::*;
-use std::collections::HashMap;
+use crate::frontend::token::{Located, Span, Warned};
+use std::collections::{HashMap, HashSet};
 
 struct ScopeStack {
     scopes: Vec<HashMap<String, String>>,
     counter: usize,
+    // Every variable declared, with its unique name, and the unique names
+    // looked up since, for -Wunused-variable
+    declared: Vec<(String, String, Span)>,
+    used: HashSet<String>,
 }
 
 impl ScopeStack {
@@ -11,6 +17,8 @@ impl ScopeStack {
         Self {
             scopes: vec![HashMap::new()],
             counter: 0,
+            declared: Vec::new(),
+            used: HashSet::new(),
         }
     }
 
@@ -26,8 +34,9 @@ impl ScopeStack {
         self.scopes.last().unwrap().contains_key(name)
     }
 
-    fn declare(&mut self, name: &str) -> String {
+    fn declare(&mut self, name: &str, span: Span) -> String {
         let unique = format!("{}.{}", name, self.counter);
+        self.declared.push((name.to_string(), unique.clone(), span));
 
         self.counter += 1;
         self.scopes
@@ -38,9 +47,10 @@ impl ScopeStack {
         return unique;
     }
 
-    fn lookup(&self, name: &str) -> Option<String> {
+    fn lookup(&mut self, name: &str) -> Option<String> {
         for scope in self.scopes.iter().rev() {
             if let Some(info) = scope.get(name) {
+                self.used.insert(info.clone());
                 return Some(info.clone());
             }
         }
@@ -48,12 +58,21 @@ impl ScopeStack {
     }
 }
 
-pub fn resolve(program: &mut Program) -> Result<(), String> {
+pub fn resolve(program: &mut Program, warnings: &mut Vec<Warned>) -> Result<(), Located> {
     let mut scopes = ScopeStack::new();
-    return resolve_block(&mut program.function.body, &mut scopes);
+    resolve_block(&mut program.function.body, &mut scopes)?;
+
+    for (name, unique, span) in scopes.declared {
+        if !scopes.used.contains(&unique) {
+            let message = format!("unused variable '{}'", name);
+            warnings.push((Warning::UnusedVariable, (message, span)));
+        }
+        program.names.insert(unique, name);
+    }
+    return Ok(());
 }
 
-fn resolve_block(block: &mut Block, scopes: &mut ScopeStack) -> Result<(), String> {
+fn resolve_block(block: &mut Block, scopes: &mut ScopeStack) -> Result<(), Located> {
     for item in &mut block.items {
         match item {
             BlockItem::Declaration(decl) => resolve_decl(decl, scopes)?,
@@ -63,35 +82,37 @@ fn resolve_block(block: &mut Block, scopes: &mut ScopeStack) -> Result<(), Strin
     return Ok(());
 }
 
-fn resolve_decl(decl: &mut Declaration, scopes: &mut ScopeStack) -> Result<(), String> {
+fn resolve_decl(decl: &mut Declaration, scopes: &mut ScopeStack) -> Result<(), Located> {
     if scopes.declared_here(&decl.name) {
-        return Err(format!("duplicate variable declaration: '{}'", decl.name));
+        let message = format!("duplicate variable declaration: '{}'", decl.name);
+        return Err((message, decl.span));
     }
 
-    let unique = scopes.declare(&decl.name);
+    let unique = scopes.declare(&decl.name, decl.span);
 
     if let Some(init) = &mut decl.init {
-        resolve_expr(init, scopes)?;
+        resolve_at(init, scopes, decl.span)?;
     }
 
     decl.name = unique;
     return Ok(());
 }
 
-fn resolve_stmt(stmt: &mut Statement, scopes: &mut ScopeStack) -> Result<(), String> {
-    match stmt {
-        Statement::Return(e) | Statement::Expression(e) => resolve_expr(e, scopes),
+fn resolve_stmt(stmt: &mut Statement, scopes: &mut ScopeStack) -> Result<(), Located> {
+    let span = stmt.span;
+    match &mut stmt.kind {
+        StatementKind::Return(e) | StatementKind::Expression(e) => resolve_at(e, scopes, span),
 
-        Statement::Null => Ok(()),
+        StatementKind::Null => Ok(()),
 
-        Statement::Break(_) | Statement::Continue(_) | Statement::Goto(_) => Ok(()),
+        StatementKind::Break(_) | StatementKind::Continue(_) | StatementKind::Goto(..) => Ok(()),
 
-        Statement::If {
+        StatementKind::If {
             condition: cond,
             then_branch: then_s,
             else_branch: else_s,
         } => {
-            resolve_expr(cond, scopes)?;
+            resolve_at(cond, scopes, span)?;
             resolve_stmt(then_s, scopes)?;
 
             if let Some(e) = else_s {
@@ -101,32 +122,32 @@ fn resolve_stmt(stmt: &mut Statement, scopes: &mut ScopeStack) -> Result<(), Str
             return Ok(());
         }
 
-        Statement::Compound(block) => {
+        StatementKind::Compound(block) => {
             scopes.enter();
             let result = resolve_block(block, scopes);
             scopes.exit();
             return result;
         }
 
-        Statement::While {
+        StatementKind::While {
             condition: cond,
             body,
             ..
         } => {
-            resolve_expr(cond, scopes)?;
+            resolve_at(cond, scopes, span)?;
             resolve_stmt(body, scopes)
         }
 
-        Statement::DoWhile {
+        StatementKind::DoWhile {
             body,
             condition: cond,
             ..
         } => {
             resolve_stmt(body, scopes)?;
-            resolve_expr(cond, scopes)
+            resolve_at(cond, scopes, span)
         }
 
-        Statement::For {
+        StatementKind::For {
             init,
             condition: cond,
             post,
@@ -134,14 +155,14 @@ fn resolve_stmt(stmt: &mut Statement, scopes: &mut ScopeStack) -> Result<(), Str
             ..
         } => {
             scopes.enter();
-            resolve_for_init(init, scopes)?;
+            resolve_for_init(init, scopes, span)?;
 
             if let Some(c) = cond {
-                resolve_expr(c, scopes)?;
+                resolve_at(c, scopes, span)?;
             }
 
             if let Some(p) = post {
-                resolve_expr(p, scopes)?;
+                resolve_at(p, scopes, span)?;
             }
 
             resolve_stmt(body, scopes)?;
@@ -150,23 +171,32 @@ fn resolve_stmt(stmt: &mut Statement, scopes: &mut ScopeStack) -> Result<(), Str
             return Ok(());
         }
 
-        Statement::Labeled(_, inner) => resolve_stmt(inner, scopes),
+        StatementKind::Labeled(_, _, inner) => resolve_stmt(inner, scopes),
     }
 }
 
-fn resolve_for_init(init: &mut ForInit, scopes: &mut ScopeStack) -> Result<(), String> {
+fn resolve_for_init(
+    init: &mut ForInit,
+    scopes: &mut ScopeStack,
+    span: Span,
+) -> Result<(), Located> {
     match init {
         ForInit::InitDecl(decl) => resolve_decl(decl, scopes),
-        ForInit::InitExpr(Some(e)) => resolve_expr(e, scopes),
+        ForInit::InitExpr(Some(e)) => resolve_at(e, scopes, span),
         ForInit::InitExpr(None) => Ok(()),
     }
 }
 
+// Errors in expressions are at their statement
+fn resolve_at(expr: &mut Expr, scopes: &mut ScopeStack, span: Span) -> Result<(), Located> {
+    return resolve_expr(expr, scopes).map_err(|message| (message, span));
+}
+
 fn resolve_expr(expr: &mut Expr, scopes: &mut ScopeStack) -> Result<(), String> {
     match expr {
         Expr::Constant(_) => Ok(()),
 
-        Expr::Variable(name) => match scopes.lookup(name) {
+        Expr::Variable(name, _) => match scopes.lookup(name) {
             Some(unique) => {
                 *name = unique;
                 return Ok(());
@@ -221,7 +251,7 @@ fn resolve_expr(expr: &mut Expr, scopes: &mut ScopeStack) -> Result<(), String>
 }
 
 fn require_lvalue(expr: &Expr, context: &str) -> Result<(), String> {
-    if matches!(expr, Expr::Variable(_)) {
+    if matches!(expr, Expr::Variable(..)) {
         return Ok(());
     } else {
         Err(format!("invalid lvalue in {}", context))
@@ -1,3 +1,5 @@
+use crate::Warning;
+
 #[derive(Debug, PartialEq)]
 pub enum Token {
     Int,
@@ -74,5 +76,26 @@ pub enum Token {
     LessLessEqual,
     GreaterGreaterEqual,
 
-    EOF,
+    Eof,
+}
+
+// Where a token starts in the source. Lines and columns count from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    pub line: u32,
+    pub column: u32,
+}
+
+// An error and the place in the source it is about
+pub type Located = (String, Span);
+
+// A warning found while parsing or analyzing a program, kept whether
+// or not it is turned on
+pub type Warned = (Warning, Located);
+
+// A comment as written, `//` or `/*` included, for the formatter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub text: String,
+    pub span: Span,
 }
@@ -0,0 +1,291 @@
+// A JSON value, built by hand since the dumps and the language server need
+// nothing more than this. Object members keep their order, so the output is
+// stable. Numbers are integers, which is all either of them uses.
+
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // Builds an object from borrowed keys, which is how every dump writes one
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        return Json::Object(
+            members
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        );
+    }
+
+    pub fn string(value: &str) -> Json {
+        return Json::String(value.to_string());
+    }
+
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser {
+            chars: text.chars().peekable(),
+        };
+        let value = parser.value()?;
+        parser.whitespace();
+        if let Some(ch) = parser.chars.next() {
+            return Err(format!("unexpected '{}' after the value", ch));
+        }
+        return Ok(value);
+    }
+
+    // The member `key` of an object, or nothing for other values
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        let Json::Object(members) = self else {
+            return None;
+        };
+        return members
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value);
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        };
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        return match self {
+            Json::Number(value) => Some(*value),
+            _ => None,
+        };
+    }
+
+    // Two-space indentation, with arrays and objects that fit in 80 columns
+    // kept on one line, so a token or an instruction is one line of output
+    pub fn pretty(&self) -> String {
+        let mut output = String::new();
+        self.write(&mut output, 0);
+        output.push('\n');
+        return output;
+    }
+
+    fn write(&self, output: &mut String, depth: usize) {
+        let (open, close, entries): (char, char, Vec<(Option<&String>, &Json)>) = match self {
+            Json::Array(items) => ('[', ']', items.iter().map(|item| (None, item)).collect()),
+            Json::Object(members) => (
+                '{',
+                '}',
+                members.iter().map(|(k, v)| (Some(k), v)).collect(),
+            ),
+            _ => (' ', ' ', Vec::new()),
+        };
+        let compact = self.compact();
+        if entries.is_empty() || depth * 2 + compact.len() <= 80 {
+            output.push_str(&compact);
+            return;
+        }
+
+        output.push(open);
+        for (i, (key, value)) in entries.iter().enumerate() {
+            output.push('\n');
+            output.push_str(&"  ".repeat(depth + 1));
+            if let Some(key) = key {
+                write!(output, "{}: ", quote(key)).unwrap();
+            }
+            value.write(output, depth + 1);
+            if i + 1 < entries.len() {
+                output.push(',');
+            }
+        }
+        output.push('\n');
+        output.push_str(&"  ".repeat(depth));
+        output.push(close);
+    }
+
+    // On one line, with a space after each separator
+    pub fn compact(&self) -> String {
+        return match self {
+            Json::Null => "null".to_string(),
+            Json::Bool(value) => value.to_string(),
+            Json::Number(value) => value.to_string(),
+            Json::String(value) => quote(value),
+            Json::Array(items) => {
+                let items: Vec<String> = items.iter().map(Json::compact).collect();
+                format!("[{}]", items.join(", "))
+            }
+            Json::Object(members) => {
+                let members: Vec<String> = members
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", quote(key), value.compact()))
+                    .collect();
+                format!("{{{}}}", members.join(", "))
+            }
+        };
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => write!(quoted, "\\u{:04x}", ch as u32).unwrap(),
+            ch => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    return quoted;
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn whitespace(&mut self) {
+        while self.chars.next_if(|ch| ch.is_ascii_whitespace()).is_some() {}
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        return match self.chars.next() {
+            Some(ch) if ch == expected => Ok(()),
+            Some(ch) => Err(format!("expected '{}', found '{}'", expected, ch)),
+            None => Err(format!("expected '{}', found the end", expected)),
+        };
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.whitespace();
+        return match self.chars.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('-' | '0'..='9') => self.number(),
+            Some('t') => self.word("true", Json::Bool(true)),
+            Some('f') => self.word("false", Json::Bool(false)),
+            Some('n') => self.word("null", Json::Null),
+            Some(ch) => Err(format!("unexpected '{}'", ch)),
+            None => Err("unexpected end of input".to_string()),
+        };
+    }
+
+    fn word(&mut self, word: &str, value: Json) -> Result<Json, String> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        return Ok(value);
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let mut digits = String::new();
+        while let Some(ch) = self
+            .chars
+            .next_if(|ch| ch.is_ascii_digit() || "-+.eE".contains(*ch))
+        {
+            digits.push(ch);
+        }
+        return match digits.parse() {
+            Ok(value) => Ok(Json::Number(value)),
+            Err(_) => Err(format!("unsupported number: {}", digits)),
+        };
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => {
+                    let escaped = match self.chars.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.unicode()?,
+                        _ => return Err("invalid escape in a string".to_string()),
+                    };
+                    value.push(escaped);
+                }
+                Some(ch) => value.push(ch),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
+    }
+
+    // After `\u`: a code point, or a surrogate pair written as two escapes
+    fn unicode(&mut self) -> Result<char, String> {
+        let high = self.hex()?;
+        if !(0xd800..0xdc00).contains(&high) {
+            return char::from_u32(high).ok_or("invalid \\u escape".to_string());
+        }
+        self.expect('\\')?;
+        self.expect('u')?;
+        let low = self.hex()?;
+        let code = 0x10000 + ((high - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff);
+        return char::from_u32(code).ok_or("invalid surrogate pair".to_string());
+    }
+
+    fn hex(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self.chars.next().and_then(|ch| ch.to_digit(16));
+            code = code * 16 + digit.ok_or("invalid \\u escape".to_string())?;
+        }
+        return Ok(code);
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.chars.next_if_eq(&']').is_some() {
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            if self.chars.next_if_eq(&']').is_some() {
+                return Ok(Json::Array(items));
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+        self.whitespace();
+        if self.chars.next_if_eq(&'}').is_some() {
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.whitespace();
+            self.expect(':')?;
+            members.push((key, self.value()?));
+            self.whitespace();
+            if self.chars.next_if_eq(&'}').is_some() {
+                return Ok(Json::Object(members));
+            }
+            self.expect(',')?;
+        }
+    }
+}
@@ -0,0 +1,107 @@
+#![allow(clippy::needless_return)]
+
+// Crucible as a library. A `Compiler` takes C source text and returns what
+// the stage it stops at produces; the `crucible` binary is a driver around it.
+
+mod backend;
+mod compiler;
+pub mod dump;
+mod frontend;
+pub mod json;
+
+pub use compiler::{Artifact, Compilation, Compiler, DEFAULT_TARGET, Diagnostic};
+pub use frontend::{ast, format, ir, token};
+
+// Where compilation stops
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Stage {
+    Lex,
+    Parse,
+    Validate,
+    Ir,
+    Ssa,
+    Codegen,
+    Assembly,
+    Object,
+    Full,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OptLevel {
+    O0,
+    O1,
+    O2,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RegAllocator {
+    None,
+    Linear,
+    Graph,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Linker {
+    System,
+    Builtin,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Syntax {
+    Intel,
+    Att,
+}
+
+// Whether a diagnostic stops compilation
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// Code that compiles but is likely a mistake. Each is named like GCC's and
+// turned on and off with `-W<name>` and `-Wno-<name>`
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Warning {
+    UnusedVariable,
+    UnusedLabel,
+    Parentheses,
+    ShiftCountOverflow,
+    DivByZero,
+    EmptyBody,
+}
+
+impl Warning {
+    // On without any flag, as in GCC
+    pub const DEFAULT: [Warning; 2] = [Warning::ShiftCountOverflow, Warning::DivByZero];
+
+    // What `-Wall` turns on
+    pub const ALL: [Warning; 5] = [
+        Warning::UnusedVariable,
+        Warning::UnusedLabel,
+        Warning::Parentheses,
+        Warning::ShiftCountOverflow,
+        Warning::DivByZero,
+    ];
+
+    // What `-Wextra` turns on besides
+    pub const EXTRA: [Warning; 1] = [Warning::EmptyBody];
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            Warning::UnusedVariable => "unused-variable",
+            Warning::UnusedLabel => "unused-label",
+            Warning::Parentheses => "parentheses",
+            Warning::ShiftCountOverflow => "shift-count-overflow",
+            Warning::DivByZero => "div-by-zero",
+            Warning::EmptyBody => "empty-body",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Warning> {
+        return Warning::ALL
+            .into_iter()
+            .chain(Warning::EXTRA)
+            .find(|warning| warning.name() == name);
+    }
+}
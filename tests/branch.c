@@ -0,0 +1,32 @@
+int main(void) {
+    int a = -3;
+    int b = 4;
+    int r = 0;
+
+    // Comparisons feeding branches directly, with and without negation
+    if (a < b) r = r + 1;
+    if (a <= b) r = r + 2;
+    if (a > b) r = r + 100;
+    if (a >= b) r = r + 100;
+    if (a == b) r = r + 100;
+    if (a != b) r = r + 4;
+    if (!(a < b)) r = r + 100;
+    if (!(a > b)) r = r + 8;
+    if (!!(a != b)) r = r + 16;
+    if (!a) r = r + 100;
+    if (!!a) r = r + 32;
+
+    // Negated comparisons kept as values
+    int x = !(a >= b);
+    int y = !(b == 4);
+    int z = !!b;
+
+    int n = 0;
+    while (!(n >= 5)) n = n + 1;
+
+    do {
+        n = n - 1;
+    } while (!(n == 2));
+
+    return r + x + y * 100 + z * 2 + n * 64;
+}
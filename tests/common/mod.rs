@@ -0,0 +1,75 @@
+// What the integration tests share: the programs in `tests/` and what they
+// exit with, and a loop that checks every one of them and reports all the
+// failures at once. Each test uses what it needs of it.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The exit status of each program in `tests/`, as built by GCC
+const STATUSES: [(&str, i32); 23] = [
+    ("assign", 28),
+    ("binary", 5),
+    ("bitwise", 6),
+    ("branch", 194),
+    ("compound", 123),
+    ("conditional", 5),
+    ("cse", 4),
+    ("divconst", 0),
+    ("goto", 5),
+    ("incdec", 12),
+    ("licm", 119),
+    ("logical", 0),
+    ("loop", 18),
+    ("mulconst", 0),
+    ("peephole", 42),
+    ("regalloc", 106),
+    ("relational", 1),
+    ("return", 2),
+    ("sccp", 12),
+    ("split", 182),
+    ("ternary", 4),
+    ("unary", 1),
+    ("variable", 20),
+];
+
+pub fn expected_status(program: &Path) -> i32 {
+    let name = program.file_stem().unwrap().to_str().unwrap();
+    match STATUSES.iter().find(|(program, _)| *program == name) {
+        Some((_, status)) => *status,
+        None => panic!("no expected status for {}", program.display()),
+    }
+}
+
+// Every `tests/*.c`, sorted
+fn programs() -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let mut programs: Vec<_> = fs::read_dir(&root)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "c"))
+        .collect();
+    programs.sort();
+    assert!(!programs.is_empty());
+    programs
+}
+
+// Runs `check` on every program under each set of flags, and fails with
+// `message` and the programs and flags it returned false for
+pub fn each_program(
+    flags: &[&[&str]],
+    message: &str,
+    mut check: impl FnMut(&Path, &[&str]) -> bool,
+) {
+    let programs = programs();
+    let mut failures = Vec::new();
+    for flags in flags {
+        for program in &programs {
+            if !check(program, flags) {
+                failures.push(format!("{} {}", program.display(), flags.join(" ")));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{} for {:?}", message, failures);
+}
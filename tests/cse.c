@@ -0,0 +1,27 @@
+int main(void) {
+    int a = 6;
+    int b = 7;
+    int x = 100;
+    for (int i = 0; i < 3; i++) {
+        a = a + i;
+        x = x - b;
+    }
+    int r = a * b + a * b;
+    if (x > 50)
+        r = r + x % 7;
+    else
+        r = r - x % 7;
+    r = r + b * a + x % 7;
+    int c = a;
+    r = r + c * b;
+    // Both sides compute x - 1, but only one of them x / 3 from it
+    int t;
+    if (r > 100) {
+        int u = x - 1;
+        t = u * 3 + u / 3;
+    } else {
+        t = (x - 1) * 3;
+    }
+    r = r + t;
+    return r & 255;
+}
@@ -0,0 +1,305 @@
+// Checks that `-g` only adds debug directives: with them and the debug
+// sections stripped, the assembly must match a build without `-g`. The
+// debug info itself, and the unwind tables the CFI directives make, are
+// assembled with `llvm-mc` for ELF and Mach-O and read back with
+// `llvm-dwarfdump`, as is the object `-g -c` makes for the host.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn emit(program: &Path, flags: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_crucible"))
+        .args(flags)
+        .args(["-S", "-o", "-"])
+        .arg(program)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "failed to compile {}",
+        program.display()
+    );
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// Drops the directives, and the debug sections from the end of the function
+// up to the ELF stack note, if there is one
+fn strip_debug_info(assembly: &str) -> String {
+    let mut sections = false;
+    let mut code = String::new();
+    for line in assembly.lines() {
+        if line.ends_with("func_end:") {
+            sections = true;
+        } else if line.starts_with("\t.section .note.GNU-stack") {
+            sections = false;
+        }
+        if !sections && !line.starts_with("\t.file") && !line.starts_with("\t.loc") {
+            code += line;
+            code += "\n";
+        }
+    }
+    code
+}
+
+#[test]
+fn debug_info_leaves_code_alone() {
+    let flags: [&[&str]; 5] = [
+        &["-O0", "-fregalloc=none"],
+        &["-O1"],
+        &["-O2"],
+        &["-O2", "-fregalloc=linear"],
+        &["--target", "x86_64-apple-darwin", "-O2"],
+    ];
+    common::each_program(&flags, "-g changed the code", |program, flags| {
+        let plain = emit(program, flags);
+        let debug = emit(program, &[flags, &["-g"]].concat());
+
+        assert!(
+            debug.contains("\t.loc 1 "),
+            "no .loc in {}",
+            program.display()
+        );
+        strip_debug_info(&debug) == strip_debug_info(&plain)
+    });
+}
+
+fn run(command: &mut Command) -> String {
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{:?} failed:\n{}{}",
+        command,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+const TRIPLES: [&str; 2] = ["x86_64-linux-gnu", "x86_64-apple-darwin"];
+
+fn scratch(name: &str) -> PathBuf {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("debug");
+    fs::create_dir_all(&directory).unwrap();
+    directory.join(name)
+}
+
+// Assembles `program` built for `triple` with `flags` into an object in the
+// test's scratch directory
+fn object(program: &Path, triple: &str, flags: &[&str]) -> PathBuf {
+    let stem = program.file_stem().unwrap().to_str().unwrap();
+    let name = format!("{}-{}{}", stem, triple, flags.concat());
+
+    let assembly = scratch(&format!("{}.s", name));
+    let flags = [flags, &["--target", triple]].concat();
+    fs::write(&assembly, emit(program, &flags)).unwrap();
+    let object = scratch(&format!("{}.o", name));
+    run(Command::new("llvm-mc")
+        .args(["-triple", triple, "-filetype=obj", "-o"])
+        .arg(&object)
+        .arg(&assembly));
+    object
+}
+
+#[test]
+fn debug_info_verifies() {
+    let flags: [&[&str]; 4] = [
+        &["-g", "-O0", "-fregalloc=none"],
+        &["-g", "-O1"],
+        &["-g", "-O2"],
+        &["-g", "-O2", "-fomit-frame-pointer"],
+    ];
+    common::each_program(
+        &flags,
+        "llvm-dwarfdump --verify failed",
+        |program, flags| {
+            TRIPLES.iter().all(|triple| {
+                let object = object(program, triple, flags);
+                let output = Command::new("llvm-dwarfdump")
+                    .arg("--verify")
+                    .arg(&object)
+                    .output()
+                    .unwrap();
+                output.status.success()
+            })
+        },
+    );
+}
+
+fn variable_program() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/variable.c")
+}
+
+// The frame base of `main` in `object` and where each variable lives
+// relative to it
+fn locations(object: &Path) -> (String, Vec<(String, String)>) {
+    let dump = run(Command::new("llvm-dwarfdump")
+        .arg("--debug-info")
+        .arg(object));
+
+    let value = |line: &str| {
+        let start = line.find('(').unwrap() + 1;
+        line[start..line.rfind(')').unwrap()].to_string()
+    };
+    let mut frame_base = String::new();
+    let mut name = String::new();
+    let mut variables = Vec::new();
+    for line in dump.lines() {
+        if line.contains("DW_AT_frame_base") {
+            frame_base = value(line);
+        } else if line.contains("DW_AT_name") {
+            name = value(line).trim_matches('"').to_string();
+        } else if line.contains("DW_AT_location") {
+            variables.push((name.clone(), value(line)));
+        }
+    }
+    (frame_base, variables)
+}
+
+fn variables(a: &str, b: &str) -> Vec<(String, String)> {
+    vec![
+        ("a".to_string(), a.to_string()),
+        ("b".to_string(), b.to_string()),
+    ]
+}
+
+#[test]
+fn variable_locations() {
+    for triple in TRIPLES {
+        let located = |flags: &[&str]| {
+            locations(&object(
+                &variable_program(),
+                triple,
+                &[flags, &["-g"]].concat(),
+            ))
+        };
+
+        assert_eq!(
+            located(&["-O0", "-fregalloc=none"]),
+            (
+                "DW_OP_reg6 RBP".to_string(),
+                variables("DW_OP_fbreg -4", "DW_OP_fbreg -12")
+            ),
+            "{}",
+            triple
+        );
+
+        // Without a frame pointer the frame base is the CFA, 8 bytes of
+        // return address and 24 of frame above the slots at `rsp + 20` and
+        // `rsp + 12`
+        assert_eq!(
+            located(&["-O0", "-fregalloc=none", "-fomit-frame-pointer"]),
+            (
+                "DW_OP_call_frame_cfa".to_string(),
+                variables("DW_OP_fbreg -12", "DW_OP_fbreg -20")
+            ),
+            "{}",
+            triple
+        );
+    }
+}
+
+// `-g -c` for the host goes through the system assembler rather than the
+// encoder, so the object the driver writes keeps its debug info, and so
+// does an executable linked from it
+#[test]
+#[cfg(target_arch = "x86_64")]
+fn host_objects() {
+    let object = scratch("host.o");
+    let executable = scratch("host");
+    let program = variable_program();
+    let program = program.to_str().unwrap();
+    run(Command::new(env!("CARGO_BIN_EXE_crucible")).args([
+        "-g",
+        "-c",
+        program,
+        "-o",
+        object.to_str().unwrap(),
+    ]));
+    run(Command::new(env!("CARGO_BIN_EXE_crucible")).args([
+        "-g",
+        program,
+        "-o",
+        executable.to_str().unwrap(),
+    ]));
+
+    for built in [&object, &executable] {
+        run(Command::new("llvm-dwarfdump").arg("--verify").arg(built));
+        assert_eq!(
+            locations(built),
+            (
+                "DW_OP_reg6 RBP".to_string(),
+                variables("DW_OP_fbreg -4", "DW_OP_fbreg -12")
+            ),
+            "{}",
+            built.display()
+        );
+    }
+}
+
+// The CFA of each row of `main`'s unwind table, in order and without
+// repeats, and the registers saved in the last row
+fn unwind_rows(flags: &[&str]) -> (Vec<String>, String) {
+    let program = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/regalloc.c");
+    let dump = run(Command::new("llvm-dwarfdump")
+        .arg("--eh-frame")
+        .arg(object(&program, TRIPLES[0], flags)));
+
+    // The FDE's rows read `  0x4: CFA=RSP+56: RBX=[CFA-64], RIP=[CFA-8]`;
+    // the CIE's has no address
+    let rows: Vec<_> = dump
+        .lines()
+        .filter(|line| line.trim_start().starts_with("0x"))
+        .collect();
+    let mut cfas: Vec<String> = Vec::new();
+    for row in &rows {
+        let cfa = row.split(": ").nth(1).unwrap().to_string();
+        if cfas.last() != Some(&cfa) {
+            cfas.push(cfa);
+        }
+    }
+    let saved = rows.last().unwrap().splitn(3, ": ").nth(2).unwrap();
+    (cfas, saved.trim().to_string())
+}
+
+#[test]
+fn unwind_tables() {
+    let cfas = |cfas: &[&str]| cfas.iter().map(|cfa| format!("CFA={}", cfa)).collect();
+
+    // The CFA moves to `rbp` once it is set, so the pushes and `sub rsp`
+    // after that need no more directives
+    assert_eq!(
+        unwind_rows(&["-O0", "-fregalloc=none"]),
+        (
+            cfas(&["RSP+8", "RSP+16", "RBP+16", "RSP+8"]),
+            "RBP=[CFA-16], RIP=[CFA-8]".to_string()
+        )
+    );
+    assert_eq!(
+        unwind_rows(&["-O2"]),
+        (
+            cfas(&["RSP+8", "RSP+16", "RBP+16", "RSP+8"]),
+            "RBX=[CFA-64], RBP=[CFA-16], R12=[CFA-72], R13=[CFA-80], R14=[CFA-88], \
+             R15=[CFA-96], RIP=[CFA-8]"
+                .to_string()
+        )
+    );
+
+    // Without it, the CFA follows `rsp`: 48 bytes of frame below the return
+    // address, 8 more for each push, and back down through the epilogue
+    assert_eq!(
+        unwind_rows(&["-O2", "-fomit-frame-pointer"]),
+        (
+            cfas(&[
+                "RSP+8", "RSP+56", "RSP+64", "RSP+72", "RSP+80", "RSP+88", "RSP+96", "RSP+88",
+                "RSP+80", "RSP+72", "RSP+64", "RSP+56", "RSP+8",
+            ]),
+            "RBX=[CFA-64], R12=[CFA-72], R13=[CFA-80], R14=[CFA-88], R15=[CFA-96], \
+             RIP=[CFA-8]"
+                .to_string()
+        )
+    );
+}
@@ -0,0 +1,69 @@
+int main(void) {
+    int failures = 0;
+    int i = 0;
+
+    while (i < 14) {
+        int n = 0;
+        if (i == 0) n = -2147483647 - 1;
+        if (i == 1) n = -2147483647;
+        if (i == 2) n = -1000000007;
+        if (i == 3) n = -65537;
+        if (i == 4) n = -100;
+        if (i == 5) n = -7;
+        if (i == 6) n = -1;
+        if (i == 7) n = 0;
+        if (i == 8) n = 1;
+        if (i == 9) n = 7;
+        if (i == 10) n = 100;
+        if (i == 11) n = 65537;
+        if (i == 12) n = 1000000007;
+        if (i == 13) n = 2147483647;
+
+        // Opaque divisors that the compiler cannot see through
+        int d1 = i - i + 1;
+        int d2 = d1 + 1;
+        int d3 = d1 + 2;
+        int d4 = d2 * 2;
+        int d7 = d3 + 4;
+        int d10 = d3 + 7;
+
+        if (n / 1 != n / d1) failures = failures + 1;
+        if (n % 1 != n % d1) failures = failures + 1;
+        if (n / 2 != n / d2) failures = failures + 1;
+        if (n % 2 != n % d2) failures = failures + 1;
+        if (n / -2 != n / -d2) failures = failures + 1;
+        if (n % -2 != n % -d2) failures = failures + 1;
+        if (n / 3 != n / d3) failures = failures + 1;
+        if (n % 3 != n % d3) failures = failures + 1;
+        if (n / -3 != n / -d3) failures = failures + 1;
+        if (n % -3 != n % -d3) failures = failures + 1;
+        if (n / 4 != n / d4) failures = failures + 1;
+        if (n % 4 != n % d4) failures = failures + 1;
+        if (n / 7 != n / d7) failures = failures + 1;
+        if (n % 7 != n % d7) failures = failures + 1;
+        if (n / -7 != n / -d7) failures = failures + 1;
+        if (n % -7 != n % -d7) failures = failures + 1;
+        if (n / 10 != n / d10) failures = failures + 1;
+        if (n % 10 != n % d10) failures = failures + 1;
+        if (n / 1024 != n / (d4 * 256)) failures = failures + 1;
+        if (n % 1024 != n % (d4 * 256)) failures = failures + 1;
+        if (n / -1024 != n / -(d4 * 256)) failures = failures + 1;
+        if (n % -1024 != n % -(d4 * 256)) failures = failures + 1;
+        if (n / 641 != n / (d1 * 641)) failures = failures + 1;
+        if (n % 641 != n % (d1 * 641)) failures = failures + 1;
+        if (n / 1000 != n / (d10 * 100)) failures = failures + 1;
+        if (n % 1000 != n % (d10 * 100)) failures = failures + 1;
+        if (n / 65537 != n / (d1 * 65537)) failures = failures + 1;
+        if (n % 65537 != n % (d1 * 65537)) failures = failures + 1;
+        if (n / 1073741824 != n / (d1 * 1073741824)) failures = failures + 1;
+        if (n % 1073741824 != n % (d1 * 1073741824)) failures = failures + 1;
+        if (n / 2147483647 != n / (d1 * 2147483647)) failures = failures + 1;
+        if (n % 2147483647 != n % (d1 * 2147483647)) failures = failures + 1;
+        if (n / -2147483647 != n / -(d1 * 2147483647)) failures = failures + 1;
+        if (n % -2147483647 != n % -(d1 * 2147483647)) failures = failures + 1;
+
+        i = i + 1;
+    }
+
+    return failures;
+}
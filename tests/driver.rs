@@ -0,0 +1,614 @@
+// Checks the driver's outputs and exit statuses, on the paths that need no
+// external tools: -S, -c with the built-in encoder, standard input and
+// `crucible fmt`. The toolchain is checked with stand-in preprocessors,
+// assemblers and linkers, and detection with the host's own compilers.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::os::unix::fs::{PermissionsExt, symlink};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+const PROGRAM: &str = "int main(void) {\n    return 6 * 7;\n}\n";
+
+// How the default target, the host's, spells the label of `main`
+const MAIN: &str = if cfg!(target_os = "macos") {
+    "\n_main:"
+} else {
+    "\nmain:"
+};
+
+fn crucible(args: &[&str], stdin: &str) -> Output {
+    finish(
+        Command::new(env!("CARGO_BIN_EXE_crucible")).args(args),
+        stdin,
+    )
+}
+
+fn finish(command: &mut Command, stdin: &str) -> Output {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // The driver may exit before reading its input, as on a bad flag
+    let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+    child.wait_with_output().unwrap()
+}
+
+// A fresh directory with the program in it as `name`
+fn scratch(test: &str, name: &str) -> PathBuf {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(test);
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join(name), PROGRAM).unwrap();
+    directory
+}
+
+fn path(directory: &Path, name: &str) -> String {
+    directory.join(name).display().to_string()
+}
+
+#[test]
+fn assembly_goes_to_a_file() {
+    let directory = scratch("assembly", "program.c");
+    let source = path(&directory, "program.c");
+
+    let printed = crucible(&["-S", "-o", "-", &source], "");
+    assert!(printed.status.success());
+    assert!(String::from_utf8_lossy(&printed.stdout).contains(MAIN));
+
+    // Next to the input by default, or wherever -o says
+    assert!(crucible(&["-S", &source], "").status.success());
+    assert_eq!(
+        fs::read(path(&directory, "program.s")).unwrap(),
+        printed.stdout
+    );
+
+    let named = path(&directory, "named.s");
+    assert!(
+        crucible(&["-S", &source, "-o", &named], "")
+            .status
+            .success()
+    );
+    assert_eq!(fs::read(named).unwrap(), printed.stdout);
+}
+
+#[test]
+fn objects_go_to_a_file() {
+    let directory = scratch("objects", "program.c");
+    let source = path(&directory, "program.c");
+
+    assert!(crucible(&["-c", &source], "").status.success());
+    let object = fs::read(path(&directory, "program.o")).unwrap();
+    assert!(object.starts_with(b"\x7fELF"));
+
+    let named = path(&directory, "named.o");
+    assert!(
+        crucible(&["-c", "-o", &named, &source], "")
+            .status
+            .success()
+    );
+    assert_eq!(fs::read(named).unwrap(), object);
+}
+
+#[test]
+fn several_inputs() {
+    let directory = scratch("several", "first.c");
+    fs::write(directory.join("second.c"), PROGRAM).unwrap();
+    let first = path(&directory, "first.c");
+    let second = path(&directory, "second.c");
+
+    assert!(crucible(&["-S", &first, &second], "").status.success());
+    assert!(directory.join("first.s").exists());
+    assert!(directory.join("second.s").exists());
+
+    let output = crucible(&["-c", &first, &second, "-o", "both.o"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn standard_input() {
+    let output = crucible(&["-S", "-"], PROGRAM);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MAIN));
+
+    // The preprocessor strips comments and joins spliced lines even when
+    // there are no directives
+    let source = "int main(void) { // six\n    return 6 * \\\n7;\n}\n";
+    let output = crucible(&["-E", "-"], source);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("return 6 *"));
+    assert!(!stdout.contains("six") && !stdout.contains('\\'));
+
+    let output = crucible(&["-c", "-"], PROGRAM);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn exit_statuses() {
+    assert_eq!(crucible(&[], "").status.code(), Some(2));
+    assert_eq!(crucible(&["-O2"], "").status.code(), Some(2));
+    assert_eq!(crucible(&["--bogus", "-"], "").status.code(), Some(2));
+    assert_eq!(
+        crucible(&["--target", "pdp11", "-"], "").status.code(),
+        Some(2)
+    );
+
+    let output = crucible(&["-S", "-"], "int main(void) { return 1 }");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<stdin>:1:27: Syntax error"));
+
+    assert_eq!(crucible(&["-S", "missing.c"], "").status.code(), Some(1));
+}
+
+// Neither has an assembler to carry the debug info, so `-g` is dropped
+// with a warning rather than quietly
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[test]
+fn debug_info_ignored() {
+    let directory = scratch("debug_ignored", "program.c");
+    let source = path(&directory, "program.c");
+    let executable = path(&directory, "program");
+
+    let linked = crucible(&["-g", "-fuse-ld=builtin", &source, "-o", &executable], "");
+    assert!(linked.status.success(), "{:?}", linked);
+    assert!(
+        String::from_utf8_lossy(&linked.stderr)
+            .contains("crucible: warning: -g is ignored by the built-in linker")
+    );
+
+    let ran = crucible(&["run", "-g", &source], "");
+    assert_eq!(ran.status.code(), Some(42));
+    assert!(
+        String::from_utf8_lossy(&ran.stderr)
+            .contains("crucible: warning: -g is ignored when running in memory")
+    );
+}
+
+#[test]
+fn warnings() {
+    let source = "int main(void) {\n    int a = 1;\n    int b;\n    return a << 40;\n}\n";
+    let stderr = |args: &[&str]| {
+        let output = crucible(args, source);
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        )
+    };
+
+    // Only what is on by default, and warnings alone do not fail
+    let (status, printed) = stderr(&["-S", "-o", "-", "-"]);
+    assert_eq!(status, Some(0));
+    assert_eq!(
+        printed,
+        "crucible: warning: <stdin>:4:14: left shift count >= width of type [-Wshift-count-overflow]\n"
+    );
+
+    let (_, printed) = stderr(&["-Wall", "-Wno-shift-count-overflow", "-S", "-o", "-", "-"]);
+    assert_eq!(
+        printed,
+        "crucible: warning: <stdin>:3:5: unused variable 'b' [-Wunused-variable]\n"
+    );
+
+    let (status, printed) = stderr(&["-Werror", "-S", "-o", "-", "-"]);
+    assert_eq!(status, Some(1));
+    assert!(printed.contains("[-Werror=shift-count-overflow]"));
+
+    // -w wins wherever it is
+    let (status, printed) = stderr(&["-w", "-Wall", "-Werror", "-S", "-o", "-", "-"]);
+    assert_eq!((status, printed.as_str()), (Some(0), ""));
+
+    assert_eq!(stderr(&["-Wbogus", "-S", "-"]).0, Some(2));
+}
+
+#[test]
+fn named_warnings() {
+    let source = "\
+int main(void) {
+    int a = 1;
+unused:
+    if (a = 2);
+    return a & 1 == 1;
+}
+";
+    let stderr = |args: &[&str]| {
+        let output = crucible(&[args, &["-S", "-o", "-", "-"]].concat(), source);
+        (
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        )
+    };
+
+    assert_eq!(
+        stderr(&["-Wunused-label"]),
+        (
+            Some(0),
+            "crucible: warning: <stdin>:3:1: label 'unused' defined but not used [-Wunused-label]\n"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        stderr(&["-Wparentheses"]),
+        (
+            Some(0),
+            "crucible: warning: <stdin>:4:9: suggest parentheses around assignment used as truth value [-Wparentheses]\n\
+             crucible: warning: <stdin>:5:14: suggest parentheses around comparison in operand of '&' [-Wparentheses]\n"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        stderr(&["-Wempty-body"]),
+        (
+            Some(0),
+            "crucible: warning: <stdin>:4:15: suggest braces around empty body in an 'if' statement [-Wempty-body]\n"
+                .to_string()
+        )
+    );
+
+    // Every promoted warning is an error of its own
+    let (status, printed) = stderr(&["-Wall", "-Wextra", "-Werror"]);
+    assert_eq!(status, Some(1));
+    let lines: Vec<_> = printed.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(
+        lines
+            .iter()
+            .all(|line| line.starts_with("crucible: error: <stdin>:"))
+    );
+    for name in ["unused-label", "parentheses", "empty-body"] {
+        assert!(printed.contains(&format!("[-Werror={}]", name)));
+    }
+}
+
+// Drops the directives, which is all the preprocessor has to do for the
+// program in `toolchain`
+const PREPROCESSOR: &str = "\
+#!/bin/sh
+for last; do :; done
+while IFS= read -r line; do
+    case \"$line\" in '#'*) ;; *) printf '%s\\n' \"$line\" ;; esac
+done < \"$last\"
+";
+
+#[test]
+fn format() {
+    let directory = scratch("format", "program.c");
+    let source = path(&directory, "program.c");
+    let unformatted = "int main(void){return 6*7;}\n";
+    fs::write(&source, unformatted).unwrap();
+
+    // --check changes nothing, and lists what it would
+    let checked = crucible(&["fmt", "--check", &source], "");
+    assert_eq!(checked.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&checked.stdout).trim(), source);
+    assert_eq!(fs::read_to_string(&source).unwrap(), unformatted);
+
+    assert!(crucible(&["fmt", &source], "").status.success());
+    assert_eq!(fs::read_to_string(&source).unwrap(), PROGRAM);
+    assert!(crucible(&["fmt", "--check", &source], "").status.success());
+
+    let printed = crucible(&["fmt", "-"], unformatted);
+    assert_eq!(String::from_utf8_lossy(&printed.stdout), PROGRAM);
+
+    let broken = crucible(&["fmt", "-"], "int main(void) {");
+    assert_eq!(broken.status.code(), Some(1));
+    assert_eq!(crucible(&["fmt"], "").status.code(), Some(2));
+}
+
+#[test]
+fn toolchain() {
+    let directory = scratch("toolchain", "program.c");
+    fs::write(
+        directory.join("program.c"),
+        format!("#pragma once\n{}", PROGRAM),
+    )
+    .unwrap();
+    let preprocessor = directory.join("preprocessor");
+    fs::write(&preprocessor, PREPROCESSOR).unwrap();
+    fs::set_permissions(&preprocessor, fs::Permissions::from_mode(0o755)).unwrap();
+    let preprocessor = preprocessor.display().to_string();
+
+    let crucible = |args: &[&str], compiler: &str| {
+        finish(
+            Command::new(env!("CARGO_BIN_EXE_crucible"))
+                .args(args)
+                .current_dir(&directory)
+                .env("PATH", &directory)
+                .env("CRUCIBLE_CC", compiler)
+                .env_remove("CRUCIBLE_AS")
+                .env_remove("CRUCIBLE_LD"),
+            "",
+        )
+    };
+
+    // Nothing on PATH and nothing configured
+    let output = crucible(&["-E", "program.c"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no C compiler found"));
+
+    let output = crucible(&["-E", "program.c"], &preprocessor);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), PROGRAM);
+
+    // The flag wins over the environment
+    let output = crucible(&["-E", "--cc", &preprocessor, "program.c"], "missing-cc");
+    assert!(output.status.success());
+    let output = crucible(&["-E", "--cc=missing-cc", "program.c"], &preprocessor);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("failed to run missing-cc"));
+
+    // Only kept when asked for
+    assert!(
+        crucible(&["-S", "program.c"], &preprocessor)
+            .status
+            .success()
+    );
+    assert!(!directory.join("program.i").exists());
+    assert!(
+        crucible(&["-S", "--save-temps", "program.c"], &preprocessor)
+            .status
+            .success()
+    );
+    assert_eq!(
+        fs::read_to_string(directory.join("program.i")).unwrap(),
+        PROGRAM
+    );
+}
+
+// Makes an empty file of its last argument, the output of `-c x.s -o x.o`
+// and of `x.o -o program` alike
+const TOOL: &str = "\
+#!/bin/sh
+for last; do :; done
+: > \"$last\"
+";
+
+#[test]
+#[cfg(not(target_arch = "riscv64"))]
+fn cross_compilers() {
+    let directory = scratch("cross", "program.c");
+    let crucible = |args: &[&str]| {
+        finish(
+            Command::new(env!("CARGO_BIN_EXE_crucible"))
+                .args(args)
+                .current_dir(&directory)
+                .env("PATH", &directory)
+                .env_remove("CRUCIBLE_CC")
+                .env_remove("CRUCIBLE_AS")
+                .env_remove("CRUCIBLE_LD"),
+            "",
+        )
+    };
+
+    let build = ["--target", "riscv64-linux", "program.c", "-o", "program"];
+    let output = crucible(&build);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("no C compiler for riscv64-linux-gnu found")
+    );
+
+    // The host's gcc is no use for another architecture
+    let compiler = directory.join("riscv64-linux-gnu-gcc");
+    fs::write(&compiler, TOOL).unwrap();
+    fs::set_permissions(&compiler, fs::Permissions::from_mode(0o755)).unwrap();
+    let output = crucible(&build);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(directory.join("program").exists());
+}
+
+// Logs how it was called, then makes its output like `TOOL`
+const LOGGED_TOOL: &str = "\
+#!/bin/sh
+echo \"${0##*/} $*\" >> tools.log
+for last; do :; done
+: > \"$last\"
+";
+
+#[test]
+fn assembler_and_linker() {
+    let directory = scratch("tools", "program.c");
+    for tool in ["assembler", "linker"] {
+        let path = directory.join(tool);
+        fs::write(&path, LOGGED_TOOL).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    let log = directory.join("tools.log");
+
+    let crucible = |args: &[&str], environment: &[(&str, &str)]| {
+        let _ = fs::remove_file(&log);
+        let mut command = Command::new(env!("CARGO_BIN_EXE_crucible"));
+        command
+            .args(args)
+            .args(["program.c", "-o", "program"])
+            .current_dir(&directory)
+            .env("PATH", &directory)
+            .env_remove("CRUCIBLE_CC")
+            .env_remove("CRUCIBLE_AS")
+            .env_remove("CRUCIBLE_LD")
+            .envs(environment.iter().copied());
+        let output = finish(&mut command, "");
+        assert!(output.status.success(), "{:?}", output);
+        let log = fs::read_to_string(&log).unwrap();
+        log.lines()
+            .map(|line| line.split(' ').next().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let both = vec!["assembler".to_string(), "linker".to_string()];
+    assert_eq!(crucible(&["--as", "assembler", "--ld=linker"], &[]), both);
+    assert_eq!(
+        crucible(
+            &[],
+            &[("CRUCIBLE_AS", "assembler"), ("CRUCIBLE_LD", "linker")]
+        ),
+        both
+    );
+
+    // The flags win over the environment
+    assert_eq!(
+        crucible(
+            &["--as", "assembler", "--ld", "linker"],
+            &[("CRUCIBLE_AS", "missing-as"), ("CRUCIBLE_LD", "missing-ld")]
+        ),
+        both
+    );
+
+    // Without a preprocessor, the source is saved as it was compiled
+    crucible(
+        &["--save-temps", "--as", "assembler", "--ld", "linker"],
+        &[],
+    );
+    assert_eq!(
+        fs::read_to_string(directory.join("program.i")).unwrap(),
+        PROGRAM
+    );
+    assert!(directory.join("program.s").exists());
+}
+
+// `-Wl,` flags reach the linker as written, in order with the inputs,
+// instead of being taken for an unknown warning
+#[test]
+fn linker_flags() {
+    let directory = scratch("linker_flags", "program.c");
+    for tool in ["assembler", "linker"] {
+        let path = directory.join(tool);
+        fs::write(&path, LOGGED_TOOL).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    let output = finish(
+        Command::new(env!("CARGO_BIN_EXE_crucible"))
+            .args(["--as=assembler", "--ld=linker", "-Wall"])
+            .args(["program.c", "-Wl,--gc-sections,-s", "-lm", "-o", "program"])
+            .current_dir(&directory)
+            .env("PATH", &directory)
+            .env_remove("CRUCIBLE_CC")
+            .env_remove("CRUCIBLE_AS")
+            .env_remove("CRUCIBLE_LD"),
+        "",
+    );
+    assert!(output.status.success(), "{:?}", output);
+    assert!(output.stderr.is_empty(), "{:?}", output);
+
+    let log = fs::read_to_string(directory.join("tools.log")).unwrap();
+    let link = log.lines().last().unwrap();
+    assert!(
+        link.starts_with("linker ") && link.ends_with(" -Wl,--gc-sections,-s -lm -o program"),
+        "{}",
+        log
+    );
+}
+
+#[test]
+fn flag_spellings() {
+    // Long flags take their value after `=` or as the next argument, and
+    // are not prefixes of other flags
+    for flag in ["--ccache", "--assert", "--ldflags", "--targets"] {
+        let output = crucible(&[flag, "-S", "-"], PROGRAM);
+        assert_eq!(output.status.code(), Some(2), "{}", flag);
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains(&format!("Unknown flag: {}", flag)),
+            "{:?}",
+            output
+        );
+    }
+    let output = crucible(
+        &["--target=x86_64-linux-gnu", "-S", "-o", "-", "-"],
+        PROGRAM,
+    );
+    assert!(output.status.success(), "{:?}", output);
+
+    // -O3 and -Os are -O2
+    let optimized = |level: &str| crucible(&[level, "-S", "-o", "-", "-"], PROGRAM).stdout;
+    assert_eq!(optimized("-O3"), optimized("-O2"));
+    assert_eq!(optimized("-Os"), optimized("-O2"));
+    assert_ne!(optimized("-O2"), optimized("-O0"));
+}
+
+// The default build with whatever compiler detection finds, as a user gets
+// it: the executable has to run
+#[test]
+fn host_toolchain() {
+    let directory = scratch("host", "program.c");
+    for flags in [&[][..], &["-O2"], &["-masm=att"], &["-fomit-frame-pointer"]] {
+        let output = finish(
+            Command::new(env!("CARGO_BIN_EXE_crucible"))
+                .args(flags)
+                .args(["program.c", "-o", "program"])
+                .current_dir(&directory)
+                .env_remove("CRUCIBLE_CC")
+                .env_remove("CRUCIBLE_AS")
+                .env_remove("CRUCIBLE_LD"),
+            "",
+        );
+        assert!(output.status.success(), "{:?}: {:?}", flags, output);
+
+        let status = Command::new(directory.join("program")).status().unwrap();
+        assert_eq!(status.code(), Some(42), "{:?}", flags);
+    }
+}
+
+// Detection with only one of the compilers it looks for on the PATH, along
+// with the binutils that compiler runs. The source needs the preprocessor,
+// and `-g` needs the assembler, so each tool is used as detected
+#[cfg(all(target_arch = "x86_64", target_os = "linux"))]
+#[test]
+fn detected_compilers() {
+    let found = |tool: &str| {
+        let path = env::var_os("PATH").unwrap();
+        env::split_paths(&path)
+            .map(|directory| directory.join(tool))
+            .find(|candidate| candidate.is_file())
+    };
+
+    for compiler in ["gcc", "cc"] {
+        let Some(real) = found(compiler) else {
+            eprintln!("skipping {}: not installed", compiler);
+            continue;
+        };
+        let directory = scratch(&format!("detected_{}", compiler), "program.c");
+        fs::write(
+            directory.join("program.c"),
+            "#define ANSWER 6 * 7\nint main(void) {\n    return ANSWER;\n}\n",
+        )
+        .unwrap();
+        let bin = directory.join("bin");
+        fs::create_dir(&bin).unwrap();
+        symlink(real, bin.join(compiler)).unwrap();
+        for tool in ["as", "ld"] {
+            symlink(found(tool).unwrap(), bin.join(tool)).unwrap();
+        }
+
+        for flags in [&[][..], &["-g"], &["-c"]] {
+            let output = finish(
+                Command::new(env!("CARGO_BIN_EXE_crucible"))
+                    .args(flags)
+                    .args(["program.c", "-o", "program"])
+                    .current_dir(&directory)
+                    .env("PATH", &bin)
+                    .env_remove("CRUCIBLE_CC")
+                    .env_remove("CRUCIBLE_AS")
+                    .env_remove("CRUCIBLE_LD"),
+                "",
+            );
+            assert!(
+                output.status.success(),
+                "{} {:?}: {:?}",
+                compiler,
+                flags,
+                output
+            );
+            if flags == ["-c"] {
+                continue;
+            }
+
+            let status = Command::new(directory.join("program")).status().unwrap();
+            assert_eq!(status.code(), Some(42), "{} {:?}", compiler, flags);
+        }
+    }
+}
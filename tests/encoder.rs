@@ -0,0 +1,160 @@
+// Checks the integrated assembler against GNU `as`: the `.text` that `-c`
+// encodes must be byte for byte what `as` makes of the `-S` output, with the
+// same symbols, and the objects must link and run. Needs `as`, `objcopy`,
+// `nm` and `cc` on the PATH.
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn run(command: &mut Command) {
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{:?} failed:\n{}",
+        command,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+// A name in the test's scratch directory for `program` built with `flags`
+fn scratch(program: &Path, flags: &[&str], extension: &str) -> PathBuf {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("encoder");
+    fs::create_dir_all(&directory).unwrap();
+    let stem = program.file_stem().unwrap().to_str().unwrap();
+    directory.join(format!("{}{}.{}", stem, flags.concat(), extension))
+}
+
+fn text(object: &Path) -> Vec<u8> {
+    let text = object.with_extension("text");
+    run(Command::new("objcopy")
+        .args(["-O", "binary", "-j", ".text"])
+        .arg(object)
+        .arg(&text));
+    fs::read(text).unwrap()
+}
+
+// The `.text` of `program` as encoded by `-c` and as assembled by `as`
+fn encode_both(program: &Path, flags: &[&str]) -> (Vec<u8>, Vec<u8>) {
+    let crucible = || {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_crucible"));
+        command.args(flags);
+        command
+    };
+
+    let object = scratch(program, flags, "o");
+    run(crucible().arg("-c").arg(program).arg("-o").arg(&object));
+
+    let assembly = scratch(program, flags, "s");
+    let assembled = scratch(program, flags, "as.o");
+    run(crucible().arg("-S").arg(program).arg("-o").arg(&assembly));
+    run(Command::new("as").arg("-o").arg(&assembled).arg(&assembly));
+
+    (text(&object), text(&assembled))
+}
+
+const FLAGS: [&[&str]; 5] = [
+    &["-O0"],
+    &["-O0", "-fregalloc=none"],
+    &["-O1", "-fregalloc=graph"],
+    &["-O2"],
+    &["-O2", "-fomit-frame-pointer"],
+];
+
+#[test]
+fn matches_the_assembler() {
+    common::each_program(&FLAGS, "`.text` differs from `as`", |program, flags| {
+        let (ours, theirs) = encode_both(program, flags);
+        ours == theirs
+    });
+}
+
+// `nm`'s listing of the symbols in `object`
+fn symbols(object: &Path) -> String {
+    let output = Command::new("nm").arg(object).output().unwrap();
+    assert!(output.status.success(), "nm failed on {}", object.display());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// `-c` and `-S` name `main` alike for each target: the integrated assembler
+// for ELF, and the system assembler for Mach-O
+#[test]
+fn symbols_match_the_assembler() {
+    let targets: [&[&str]; 3] = [
+        &["-O0"],
+        &["--target", "x86_64-linux-gnu", "-O2"],
+        &["--target", "x86_64-apple-darwin", "-O2"],
+    ];
+    common::each_program(&targets, "symbols differ from `as`", |program, flags| {
+        let crucible = |stage: &str, output: &Path| {
+            run(Command::new(env!("CARGO_BIN_EXE_crucible"))
+                .args(flags)
+                .arg(stage)
+                .arg(program)
+                .arg("-o")
+                .arg(output));
+        };
+        let object = scratch(program, flags, "symbols.o");
+        crucible("-c", &object);
+        let assembly = scratch(program, flags, "symbols.s");
+        crucible("-S", &assembly);
+        let assembled = scratch(program, flags, "symbols.as.o");
+        run(Command::new("as").arg("-o").arg(&assembled).arg(&assembly));
+
+        let ours = symbols(&object);
+        let main = if flags.contains(&"x86_64-apple-darwin") {
+            " T _main\n"
+        } else {
+            " T main\n"
+        };
+        ours.ends_with(main) && ours == symbols(&assembled)
+    });
+}
+
+// A loop body far longer than 127 bytes needs the 32-bit forms of both the
+// exit branch and the jump back
+#[test]
+fn relaxation() {
+    let mut source = String::from("int main(void) {\n    int a = 0;\n    int i = 0;\n");
+    source.push_str("    while (i < 10) {\n");
+    for k in 0..40 {
+        source.push_str(&format!("        a = a * 3 + {};\n", k));
+    }
+    source.push_str("        i = i + 1;\n    }\n    return a & 255;\n}\n");
+    let program = scratch(Path::new("relaxation"), &[], "c");
+    fs::write(&program, source).unwrap();
+
+    for flags in FLAGS {
+        let (ours, theirs) = encode_both(&program, flags);
+        assert!(ours == theirs, "`.text` differs from `as` with {:?}", flags);
+        assert!(ours.len() > 256);
+        // jmp rel32, and jcc rel32
+        assert!(ours.contains(&0xE9), "no 32-bit jmp with {:?}", flags);
+        assert!(
+            ours.windows(2)
+                .any(|pair| pair[0] == 0x0F && (0x80..=0x8F).contains(&pair[1])),
+            "no 32-bit jcc with {:?}",
+            flags
+        );
+    }
+}
+
+#[test]
+fn objects_link_and_run() {
+    common::each_program(&FLAGS, "wrong exit status", |program, flags| {
+        let object = scratch(program, flags, "linked.o");
+        let executable = scratch(program, flags, "out");
+        run(Command::new(env!("CARGO_BIN_EXE_crucible"))
+            .args(flags)
+            .arg("-c")
+            .arg(program)
+            .arg("-o")
+            .arg(&object));
+        run(Command::new("cc").arg(&object).arg("-o").arg(&executable));
+
+        let status = Command::new(&executable).status().unwrap();
+        status.code() == Some(common::expected_status(program))
+    });
+}
@@ -0,0 +1,92 @@
+// Checks the formatter: its layout and where comments go on a sample, and
+// that every program in `tests/` formats into a source that parses back into
+// the same tree and formats to itself.
+
+use crucible::dump::Format;
+use crucible::{Compiler, Stage, format};
+use std::fs;
+use std::path::Path;
+
+const UNFORMATTED: &str = "\
+// Counts down
+int main(void){
+  int a=6;   // start
+  int b ;
+
+
+  while(a>0)a--;
+  if(a){b=1;}else if(b)b=2;else{b=(a+b)*-(-a);}
+  do { a++; } while (a<3);
+  for(int i=0;;i++) { break; }
+  end:
+  return a?b:(a=2)+1; /* done */
+}
+";
+
+const FORMATTED: &str = "\
+// Counts down
+int main(void) {
+    int a = 6; // start
+    int b;
+
+    while (a > 0)
+        a--;
+    if (a) {
+        b = 1;
+    } else if (b)
+        b = 2;
+    else {
+        b = (a + b) * -(-a);
+    }
+    do {
+        a++;
+    } while (a < 3);
+    for (int i = 0;; i++) {
+        break;
+    }
+end:
+    return a ? b : (a = 2) + 1; /* done */
+}
+";
+
+// The parsed tree, positions aside
+fn tree(source: &str) -> String {
+    let compilation = Compiler::new().stop_at(Stage::Parse).compile(source);
+    compilation.output.unwrap().dump(Format::Text)
+}
+
+#[test]
+fn layout() {
+    assert_eq!(format(UNFORMATTED).unwrap(), FORMATTED);
+    assert_eq!(format(FORMATTED).unwrap(), FORMATTED);
+}
+
+#[test]
+fn round_trip() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let programs: Vec<_> = fs::read_dir(&root)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "c"))
+        .collect();
+    assert!(!programs.is_empty());
+
+    for program in &programs {
+        let source = fs::read_to_string(program).unwrap();
+        let formatted = format(&source).unwrap();
+        assert_eq!(tree(&formatted), tree(&source), "{}", program.display());
+        assert_eq!(
+            format(&formatted).unwrap(),
+            formatted,
+            "{}",
+            program.display()
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let error = format("int main(void) { return 1 }").unwrap_err();
+    assert_eq!(error.stage, Stage::Parse);
+    assert!(error.message.starts_with("Syntax error"));
+}
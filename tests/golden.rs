@@ -0,0 +1,164 @@
+// Golden-file tests for the assembly of every program in `tests/`, and for
+// the dumps of its stages. Each target compares against its own directory
+// under `tests/golden/`, and its assembly must also be accepted by
+// `llvm-mc`, so that a bad instruction cannot be blessed into a golden file.
+// Where `qemu-aarch64` or `qemu-riscv64` is installed, the programs of that
+// target are also run.
+// Run with `BLESS=1` to rewrite the golden files after an intended change.
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// `target` holds `llvm-mc`'s flags for the output, if it is assembly
+fn check(args: &[&str], directory: &str, extension: &str, target: Option<&[&str]>) {
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(directory);
+    let bless = env::var_os("BLESS").is_some();
+
+    common::each_program(
+        &[args],
+        "output differs from its golden file",
+        |program, args| {
+            let output = Command::new(env!("CARGO_BIN_EXE_crucible"))
+                .args(args)
+                .arg(program)
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "failed to compile {}",
+                program.display()
+            );
+
+            let text = String::from_utf8(output.stdout).unwrap();
+            let expected = golden.join(program.with_extension(extension).file_name().unwrap());
+            if let Some(target) = target {
+                assemble(&expected, &text, target);
+            }
+
+            if bless {
+                fs::create_dir_all(&golden).unwrap();
+                fs::write(&expected, &text).unwrap();
+                return true;
+            }
+            fs::read_to_string(&expected).ok().as_deref() == Some(text.as_str())
+        },
+    );
+}
+
+fn assemble(golden: &Path, assembly: &str, target: &[&str]) {
+    let directory = golden.parent().unwrap().file_name().unwrap();
+    let source = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join(directory)
+        .join(golden.file_name().unwrap());
+    fs::create_dir_all(source.parent().unwrap()).unwrap();
+    fs::write(&source, assembly).unwrap();
+
+    let output = Command::new("llvm-mc")
+        .args(target)
+        .args(["-filetype=obj", "-o", "/dev/null"])
+        .arg(&source)
+        .output()
+        .expect("failed to run llvm-mc");
+    assert!(
+        output.status.success(),
+        "llvm-mc rejects the assembly for {}:\n{}",
+        golden.display(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn aarch64() {
+    check(
+        &["--target", "aarch64-linux", "-S", "-o", "-"],
+        "aarch64",
+        "s",
+        Some(&["-triple=aarch64-linux-gnu"]),
+    );
+}
+
+#[test]
+fn riscv64() {
+    check(
+        &["--target", "riscv64-linux", "-S", "-o", "-"],
+        "riscv64",
+        "s",
+        Some(&["-triple=riscv64-linux-gnu", "-mattr=+m"]),
+    );
+}
+
+// Builds every program for `target` and runs it under `qemu`, since
+// assembly that `llvm-mc` accepts can still compute the wrong thing. Skipped
+// without the emulator, or without a compiler to link with
+fn emulate(target: &str, qemu: &str) {
+    let path = env::var_os("PATH").unwrap_or_default();
+    if !env::split_paths(&path).any(|directory| directory.join(qemu).is_file()) {
+        eprintln!("skipping: {} is not installed", qemu);
+        return;
+    }
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join(qemu);
+    fs::create_dir_all(&directory).unwrap();
+
+    // The dynamic loader and libc of the cross compiler, where Debian and
+    // Ubuntu install them
+    let architecture = target.split('-').next().unwrap();
+    let sysroot =
+        env::var("QEMU_LD_PREFIX").unwrap_or_else(|_| format!("/usr/{}-linux-gnu", architecture));
+
+    let mut linker = true;
+    let flags: [&[&str]; 2] = [&["-O0"], &["-O2"]];
+    common::each_program(&flags, "wrong exit status under qemu", |program, flags| {
+        if !linker {
+            return true;
+        }
+        let stem = program.file_stem().unwrap().to_str().unwrap();
+        let executable = directory.join(format!("{}{}", stem, flags.concat()));
+        let output = Command::new(env!("CARGO_BIN_EXE_crucible"))
+            .args(["--target", target])
+            .args(flags)
+            .arg(program)
+            .arg("-o")
+            .arg(&executable)
+            .output()
+            .unwrap();
+        if String::from_utf8_lossy(&output.stderr).contains("no C compiler for") {
+            eprintln!("skipping: no compiler to link {} programs with", target);
+            linker = false;
+            return true;
+        }
+        if !output.status.success() {
+            return false;
+        }
+
+        let status = Command::new(qemu)
+            .env("QEMU_LD_PREFIX", &sysroot)
+            .arg(&executable)
+            .status()
+            .unwrap();
+        status.code() == Some(common::expected_status(program))
+    });
+}
+
+#[test]
+fn aarch64_runs() {
+    emulate("aarch64-linux", "qemu-aarch64");
+}
+
+#[test]
+fn riscv64_runs() {
+    emulate("riscv64-linux", "qemu-riscv64");
+}
+
+// The validated AST, the IR at -O0 and the selected x86-64 instructions
+#[test]
+fn dumps() {
+    check(&["--validate"], "dumps", "ast", None);
+    check(&["--ir"], "dumps", "ir", None);
+    check(&["--codegen"], "dumps", "codegen", None);
+}
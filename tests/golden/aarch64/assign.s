@@ -0,0 +1,84 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #64
+
+	mov w9, #10
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	add w9, w9, #5
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	sub w9, w9, #3
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	mov w10, #4
+	sdiv w9, w9, w10
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	mov w10, #3
+	sdiv w11, w9, w10
+	mov w9, #3
+	ldr w10, [sp, #0]
+	msub w9, w11, w9, w10
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #0]
+	mov w9, #255
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	mov w10, #15
+	and w9, w9, w10
+	str w9, [sp, #28]
+	ldr w9, [sp, #28]
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	mov w10, #48
+	orr w9, w9, w10
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	mov w10, #7
+	eor w9, w9, w10
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	mov w10, #1
+	lsl w9, w9, w10
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	mov w10, #2
+	asr w9, w9, w10
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	str w9, [sp, #24]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #24]
+	add w9, w9, w10
+	str w9, [sp, #48]
+	ldr w0, [sp, #48]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
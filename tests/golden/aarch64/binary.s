@@ -0,0 +1,37 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #32
+
+	mov w9, #1
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #0]
+	mov w9, #4
+	add w9, w9, #5
+	str w9, [sp, #4]
+	mov w9, #3
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #8]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #8]
+	sub w9, w9, w10
+	str w9, [sp, #12]
+	mov w9, #5
+	neg w9, w9
+	str w9, [sp, #16]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #16]
+	sdiv w9, w9, w10
+	str w9, [sp, #20]
+	ldr w0, [sp, #20]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,35 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #32
+
+	mov w9, #16
+	mov w10, #2
+	asr w9, w9, w10
+	str w9, [sp, #0]
+	mov w9, #3
+	mov w10, #1
+	lsl w9, w9, w10
+	str w9, [sp, #4]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	and w9, w9, w10
+	str w9, [sp, #8]
+	mov w9, #5
+	mov w10, #3
+	eor w9, w9, w10
+	str w9, [sp, #12]
+	ldr w9, [sp, #8]
+	ldr w10, [sp, #12]
+	orr w9, w9, w10
+	str w9, [sp, #16]
+	ldr w0, [sp, #16]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
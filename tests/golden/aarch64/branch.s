@@ -0,0 +1,298 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #224
+
+	mov w9, #3
+	neg w9, w9
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	str w9, [sp, #4]
+	mov w9, #4
+	str w9, [sp, #8]
+	mov w9, #0
+	str w9, [sp, #12]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, lt
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lif_end.0
+	ldr w9, [sp, #12]
+	add w9, w9, #1
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #12]
+
+.Lif_end.0:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, le
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	cbz w9, .Lif_end.1
+	ldr w9, [sp, #12]
+	add w9, w9, #2
+	str w9, [sp, #28]
+	ldr w9, [sp, #28]
+	str w9, [sp, #12]
+
+.Lif_end.1:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, gt
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	cbz w9, .Lif_end.2
+	ldr w9, [sp, #12]
+	add w9, w9, #100
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	str w9, [sp, #12]
+
+.Lif_end.2:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, ge
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	cbz w9, .Lif_end.3
+	ldr w9, [sp, #12]
+	add w9, w9, #100
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	str w9, [sp, #12]
+
+.Lif_end.3:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, eq
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	cbz w9, .Lif_end.4
+	ldr w9, [sp, #12]
+	add w9, w9, #100
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	str w9, [sp, #12]
+
+.Lif_end.4:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	cbz w9, .Lif_end.5
+	ldr w9, [sp, #12]
+	add w9, w9, #4
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	str w9, [sp, #12]
+
+.Lif_end.5:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, lt
+	str w9, [sp, #64]
+	ldr w9, [sp, #64]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	cbz w9, .Lif_end.6
+	ldr w9, [sp, #12]
+	add w9, w9, #100
+	str w9, [sp, #72]
+	ldr w9, [sp, #72]
+	str w9, [sp, #12]
+
+.Lif_end.6:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, gt
+	str w9, [sp, #76]
+	ldr w9, [sp, #76]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	cbz w9, .Lif_end.7
+	ldr w9, [sp, #12]
+	add w9, w9, #8
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	str w9, [sp, #12]
+
+.Lif_end.7:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #92]
+	ldr w9, [sp, #92]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	cbz w9, .Lif_end.8
+	ldr w9, [sp, #12]
+	add w9, w9, #16
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	str w9, [sp, #12]
+
+.Lif_end.8:
+	ldr w9, [sp, #4]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #104]
+	ldr w9, [sp, #104]
+	cbz w9, .Lif_end.9
+	ldr w9, [sp, #12]
+	add w9, w9, #100
+	str w9, [sp, #108]
+	ldr w9, [sp, #108]
+	str w9, [sp, #12]
+
+.Lif_end.9:
+	ldr w9, [sp, #4]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #112]
+	ldr w9, [sp, #112]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #116]
+	ldr w9, [sp, #116]
+	cbz w9, .Lif_end.10
+	ldr w9, [sp, #12]
+	add w9, w9, #32
+	str w9, [sp, #120]
+	ldr w9, [sp, #120]
+	str w9, [sp, #12]
+
+.Lif_end.10:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	cmp w9, w10
+	cset w9, ge
+	str w9, [sp, #124]
+	ldr w9, [sp, #124]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #128]
+	ldr w9, [sp, #128]
+	str w9, [sp, #132]
+	ldr w9, [sp, #8]
+	cmp w9, #4
+	cset w9, eq
+	str w9, [sp, #136]
+	ldr w9, [sp, #136]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #140]
+	ldr w9, [sp, #140]
+	str w9, [sp, #144]
+	ldr w9, [sp, #8]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #148]
+	ldr w9, [sp, #148]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #152]
+	ldr w9, [sp, #152]
+	str w9, [sp, #156]
+	mov w9, #0
+	str w9, [sp, #160]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #160]
+	cmp w9, #5
+	cset w9, ge
+	str w9, [sp, #164]
+	ldr w9, [sp, #164]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #168]
+	ldr w9, [sp, #168]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #160]
+	add w9, w9, #1
+	str w9, [sp, #172]
+	ldr w9, [sp, #172]
+	str w9, [sp, #160]
+	b .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+
+.Ldo_start.11:
+	ldr w9, [sp, #160]
+	sub w9, w9, #1
+	str w9, [sp, #176]
+	ldr w9, [sp, #176]
+	str w9, [sp, #160]
+
+.Lcontinue_loop.1:
+	ldr w9, [sp, #160]
+	cmp w9, #2
+	cset w9, eq
+	str w9, [sp, #180]
+	ldr w9, [sp, #180]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #184]
+	ldr w9, [sp, #184]
+	cbnz w9, .Ldo_start.11
+
+.Lbreak_loop.1:
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #132]
+	add w9, w9, w10
+	str w9, [sp, #188]
+	ldr w9, [sp, #144]
+	mov w10, #100
+	mul w9, w9, w10
+	str w9, [sp, #192]
+	ldr w9, [sp, #188]
+	ldr w10, [sp, #192]
+	add w9, w9, w10
+	str w9, [sp, #196]
+	ldr w9, [sp, #156]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #200]
+	ldr w9, [sp, #196]
+	ldr w10, [sp, #200]
+	add w9, w9, w10
+	str w9, [sp, #204]
+	ldr w9, [sp, #160]
+	mov w10, #64
+	mul w9, w9, w10
+	str w9, [sp, #208]
+	ldr w9, [sp, #204]
+	ldr w10, [sp, #208]
+	add w9, w9, w10
+	str w9, [sp, #212]
+	ldr w0, [sp, #212]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
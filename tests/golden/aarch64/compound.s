@@ -0,0 +1,45 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #32
+
+	mov w9, #1
+	str w9, [sp, #0]
+	mov w9, #2
+	str w9, [sp, #4]
+	mov w9, #10
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	ldr w10, [sp, #4]
+	add w9, w9, w10
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	str w9, [sp, #4]
+	mov w9, #100
+	str w9, [sp, #16]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #16]
+	add w9, w9, w10
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #8]
+	add w9, w9, w10
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #0]
+	add w9, w9, w10
+	str w9, [sp, #28]
+	ldr w0, [sp, #28]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,47 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #16
+
+	mov w9, #1
+	str w9, [sp, #0]
+	mov w9, #0
+	str w9, [sp, #4]
+	ldr w9, [sp, #0]
+	cmp w9, #2
+	cset w9, eq
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cbz w9, .Lif_else.0
+	mov w9, #1
+	str w9, [sp, #4]
+	b .Lif_end.1
+
+.Lif_else.0:
+	ldr w9, [sp, #0]
+	cmp w9, #4
+	cset w9, ne
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	cbz w9, .Lif_else.2
+	mov w9, #5
+	str w9, [sp, #4]
+	b .Lif_end.3
+
+.Lif_else.2:
+	mov w9, #7
+	str w9, [sp, #4]
+
+.Lif_end.3:
+
+.Lif_end.1:
+	ldr w0, [sp, #4]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
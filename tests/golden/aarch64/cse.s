@@ -0,0 +1,186 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #144
+
+	mov w9, #6
+	str w9, [sp, #0]
+	mov w9, #7
+	str w9, [sp, #4]
+	mov w9, #100
+	str w9, [sp, #8]
+	mov w9, #0
+	str w9, [sp, #12]
+
+.Lfor_start.0:
+	ldr w9, [sp, #12]
+	cmp w9, #3
+	cset w9, lt
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #12]
+	add w9, w9, w10
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #0]
+	ldr w9, [sp, #8]
+	ldr w10, [sp, #4]
+	sub w9, w9, w10
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #8]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #12]
+	str w9, [sp, #28]
+	ldr w9, [sp, #12]
+	add w9, w9, #1
+	str w9, [sp, #12]
+	b .Lfor_start.0
+
+.Lbreak_loop.0:
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #32]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #36]
+	ldr w9, [sp, #32]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #44]
+	ldr w9, [sp, #8]
+	cmp w9, #50
+	cset w9, gt
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	cbz w9, .Lif_else.1
+	ldr w9, [sp, #8]
+	mov w10, #7
+	sdiv w11, w9, w10
+	mov w9, #7
+	ldr w10, [sp, #8]
+	msub w9, w11, w9, w10
+	str w9, [sp, #52]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #52]
+	add w9, w9, w10
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	str w9, [sp, #44]
+	b .Lif_end.2
+
+.Lif_else.1:
+	ldr w9, [sp, #8]
+	mov w10, #7
+	sdiv w11, w9, w10
+	mov w9, #7
+	ldr w10, [sp, #8]
+	msub w9, w11, w9, w10
+	str w9, [sp, #60]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #60]
+	sub w9, w9, w10
+	str w9, [sp, #64]
+	ldr w9, [sp, #64]
+	str w9, [sp, #44]
+
+.Lif_end.2:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #0]
+	mul w9, w9, w10
+	str w9, [sp, #68]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #68]
+	add w9, w9, w10
+	str w9, [sp, #72]
+	ldr w9, [sp, #8]
+	mov w10, #7
+	sdiv w11, w9, w10
+	mov w9, #7
+	ldr w10, [sp, #8]
+	msub w9, w11, w9, w10
+	str w9, [sp, #76]
+	ldr w9, [sp, #72]
+	ldr w10, [sp, #76]
+	add w9, w9, w10
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	str w9, [sp, #44]
+	ldr w9, [sp, #0]
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #88]
+	add w9, w9, w10
+	str w9, [sp, #92]
+	ldr w9, [sp, #92]
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cmp w9, #100
+	cset w9, gt
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	cbz w9, .Lif_else.3
+	ldr w9, [sp, #8]
+	sub w9, w9, #1
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	str w9, [sp, #104]
+	ldr w9, [sp, #104]
+	mov w10, #3
+	mul w9, w9, w10
+	str w9, [sp, #108]
+	ldr w9, [sp, #104]
+	mov w10, #3
+	sdiv w9, w9, w10
+	str w9, [sp, #112]
+	ldr w9, [sp, #108]
+	ldr w10, [sp, #112]
+	add w9, w9, w10
+	str w9, [sp, #116]
+	ldr w9, [sp, #116]
+	str w9, [sp, #120]
+	b .Lif_end.4
+
+.Lif_else.3:
+	ldr w9, [sp, #8]
+	sub w9, w9, #1
+	str w9, [sp, #124]
+	ldr w9, [sp, #124]
+	mov w10, #3
+	mul w9, w9, w10
+	str w9, [sp, #128]
+	ldr w9, [sp, #128]
+	str w9, [sp, #120]
+
+.Lif_end.4:
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #120]
+	add w9, w9, w10
+	str w9, [sp, #132]
+	ldr w9, [sp, #132]
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	mov w10, #255
+	and w9, w9, w10
+	str w9, [sp, #136]
+	ldr w0, [sp, #136]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
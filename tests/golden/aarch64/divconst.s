@@ -0,0 +1,1236 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #848
+
+	mov w9, #0
+	str w9, [sp, #0]
+	mov w9, #0
+	str w9, [sp, #4]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #4]
+	cmp w9, #14
+	cset w9, lt
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cbz w9, .Lbreak_loop.0
+	mov w9, #0
+	str w9, [sp, #12]
+	ldr w9, [sp, #4]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lif_end.0
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	sub w9, w9, #1
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #12]
+
+.Lif_end.0:
+	ldr w9, [sp, #4]
+	cmp w9, #1
+	cset w9, eq
+	str w9, [sp, #28]
+	ldr w9, [sp, #28]
+	cbz w9, .Lif_end.1
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	str w9, [sp, #12]
+
+.Lif_end.1:
+	ldr w9, [sp, #4]
+	cmp w9, #2
+	cset w9, eq
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	cbz w9, .Lif_end.2
+	movz w9, #51719
+	movk w9, #15258, lsl #16
+	neg w9, w9
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #12]
+
+.Lif_end.2:
+	ldr w9, [sp, #4]
+	cmp w9, #3
+	cset w9, eq
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cbz w9, .Lif_end.3
+	movz w9, #1
+	movk w9, #1, lsl #16
+	neg w9, w9
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	str w9, [sp, #12]
+
+.Lif_end.3:
+	ldr w9, [sp, #4]
+	cmp w9, #4
+	cset w9, eq
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	cbz w9, .Lif_end.4
+	mov w9, #100
+	neg w9, w9
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	str w9, [sp, #12]
+
+.Lif_end.4:
+	ldr w9, [sp, #4]
+	cmp w9, #5
+	cset w9, eq
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	cbz w9, .Lif_end.5
+	mov w9, #7
+	neg w9, w9
+	str w9, [sp, #64]
+	ldr w9, [sp, #64]
+	str w9, [sp, #12]
+
+.Lif_end.5:
+	ldr w9, [sp, #4]
+	cmp w9, #6
+	cset w9, eq
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	cbz w9, .Lif_end.6
+	mov w9, #1
+	neg w9, w9
+	str w9, [sp, #72]
+	ldr w9, [sp, #72]
+	str w9, [sp, #12]
+
+.Lif_end.6:
+	ldr w9, [sp, #4]
+	cmp w9, #7
+	cset w9, eq
+	str w9, [sp, #76]
+	ldr w9, [sp, #76]
+	cbz w9, .Lif_end.7
+	mov w9, #0
+	str w9, [sp, #12]
+
+.Lif_end.7:
+	ldr w9, [sp, #4]
+	cmp w9, #8
+	cset w9, eq
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	cbz w9, .Lif_end.8
+	mov w9, #1
+	str w9, [sp, #12]
+
+.Lif_end.8:
+	ldr w9, [sp, #4]
+	cmp w9, #9
+	cset w9, eq
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	cbz w9, .Lif_end.9
+	mov w9, #7
+	str w9, [sp, #12]
+
+.Lif_end.9:
+	ldr w9, [sp, #4]
+	cmp w9, #10
+	cset w9, eq
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	cbz w9, .Lif_end.10
+	mov w9, #100
+	str w9, [sp, #12]
+
+.Lif_end.10:
+	ldr w9, [sp, #4]
+	cmp w9, #11
+	cset w9, eq
+	str w9, [sp, #92]
+	ldr w9, [sp, #92]
+	cbz w9, .Lif_end.11
+	movz w9, #1
+	movk w9, #1, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.11:
+	ldr w9, [sp, #4]
+	cmp w9, #12
+	cset w9, eq
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	cbz w9, .Lif_end.12
+	movz w9, #51719
+	movk w9, #15258, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.12:
+	ldr w9, [sp, #4]
+	cmp w9, #13
+	cset w9, eq
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	cbz w9, .Lif_end.13
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.13:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #4]
+	sub w9, w9, w10
+	str w9, [sp, #104]
+	ldr w9, [sp, #104]
+	add w9, w9, #1
+	str w9, [sp, #108]
+	ldr w9, [sp, #108]
+	str w9, [sp, #112]
+	ldr w9, [sp, #112]
+	add w9, w9, #1
+	str w9, [sp, #116]
+	ldr w9, [sp, #116]
+	str w9, [sp, #120]
+	ldr w9, [sp, #112]
+	add w9, w9, #2
+	str w9, [sp, #124]
+	ldr w9, [sp, #124]
+	str w9, [sp, #128]
+	ldr w9, [sp, #120]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #132]
+	ldr w9, [sp, #132]
+	str w9, [sp, #136]
+	ldr w9, [sp, #128]
+	add w9, w9, #4
+	str w9, [sp, #140]
+	ldr w9, [sp, #140]
+	str w9, [sp, #144]
+	ldr w9, [sp, #128]
+	add w9, w9, #7
+	str w9, [sp, #148]
+	ldr w9, [sp, #148]
+	str w9, [sp, #152]
+	ldr w9, [sp, #12]
+	mov w10, #1
+	sdiv w9, w9, w10
+	str w9, [sp, #156]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #112]
+	sdiv w9, w9, w10
+	str w9, [sp, #160]
+	ldr w9, [sp, #156]
+	ldr w10, [sp, #160]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #164]
+	ldr w9, [sp, #164]
+	cbz w9, .Lif_end.14
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #168]
+	ldr w9, [sp, #168]
+	str w9, [sp, #0]
+
+.Lif_end.14:
+	ldr w9, [sp, #12]
+	mov w10, #1
+	sdiv w11, w9, w10
+	mov w9, #1
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #172]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #112]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #112]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #176]
+	ldr w9, [sp, #172]
+	ldr w10, [sp, #176]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #180]
+	ldr w9, [sp, #180]
+	cbz w9, .Lif_end.15
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #184]
+	ldr w9, [sp, #184]
+	str w9, [sp, #0]
+
+.Lif_end.15:
+	ldr w9, [sp, #12]
+	mov w10, #2
+	sdiv w9, w9, w10
+	str w9, [sp, #188]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #120]
+	sdiv w9, w9, w10
+	str w9, [sp, #192]
+	ldr w9, [sp, #188]
+	ldr w10, [sp, #192]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #196]
+	ldr w9, [sp, #196]
+	cbz w9, .Lif_end.16
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #200]
+	ldr w9, [sp, #200]
+	str w9, [sp, #0]
+
+.Lif_end.16:
+	ldr w9, [sp, #12]
+	mov w10, #2
+	sdiv w11, w9, w10
+	mov w9, #2
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #204]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #120]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #120]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #208]
+	ldr w9, [sp, #204]
+	ldr w10, [sp, #208]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #212]
+	ldr w9, [sp, #212]
+	cbz w9, .Lif_end.17
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #216]
+	ldr w9, [sp, #216]
+	str w9, [sp, #0]
+
+.Lif_end.17:
+	mov w9, #2
+	neg w9, w9
+	str w9, [sp, #220]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #220]
+	sdiv w9, w9, w10
+	str w9, [sp, #224]
+	ldr w9, [sp, #120]
+	neg w9, w9
+	str w9, [sp, #228]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #228]
+	sdiv w9, w9, w10
+	str w9, [sp, #232]
+	ldr w9, [sp, #224]
+	ldr w10, [sp, #232]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #236]
+	ldr w9, [sp, #236]
+	cbz w9, .Lif_end.18
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #240]
+	ldr w9, [sp, #240]
+	str w9, [sp, #0]
+
+.Lif_end.18:
+	mov w9, #2
+	neg w9, w9
+	str w9, [sp, #244]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #244]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #244]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #248]
+	ldr w9, [sp, #120]
+	neg w9, w9
+	str w9, [sp, #252]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #252]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #252]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #256]
+	ldr w9, [sp, #248]
+	ldr w10, [sp, #256]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #260]
+	ldr w9, [sp, #260]
+	cbz w9, .Lif_end.19
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #264]
+	ldr w9, [sp, #264]
+	str w9, [sp, #0]
+
+.Lif_end.19:
+	ldr w9, [sp, #12]
+	mov w10, #3
+	sdiv w9, w9, w10
+	str w9, [sp, #268]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #128]
+	sdiv w9, w9, w10
+	str w9, [sp, #272]
+	ldr w9, [sp, #268]
+	ldr w10, [sp, #272]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #276]
+	ldr w9, [sp, #276]
+	cbz w9, .Lif_end.20
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #280]
+	ldr w9, [sp, #280]
+	str w9, [sp, #0]
+
+.Lif_end.20:
+	ldr w9, [sp, #12]
+	mov w10, #3
+	sdiv w11, w9, w10
+	mov w9, #3
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #284]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #128]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #128]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #288]
+	ldr w9, [sp, #284]
+	ldr w10, [sp, #288]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #292]
+	ldr w9, [sp, #292]
+	cbz w9, .Lif_end.21
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #296]
+	ldr w9, [sp, #296]
+	str w9, [sp, #0]
+
+.Lif_end.21:
+	mov w9, #3
+	neg w9, w9
+	str w9, [sp, #300]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #300]
+	sdiv w9, w9, w10
+	str w9, [sp, #304]
+	ldr w9, [sp, #128]
+	neg w9, w9
+	str w9, [sp, #308]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #308]
+	sdiv w9, w9, w10
+	str w9, [sp, #312]
+	ldr w9, [sp, #304]
+	ldr w10, [sp, #312]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #316]
+	ldr w9, [sp, #316]
+	cbz w9, .Lif_end.22
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #320]
+	ldr w9, [sp, #320]
+	str w9, [sp, #0]
+
+.Lif_end.22:
+	mov w9, #3
+	neg w9, w9
+	str w9, [sp, #324]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #324]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #324]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #328]
+	ldr w9, [sp, #128]
+	neg w9, w9
+	str w9, [sp, #332]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #332]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #332]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #336]
+	ldr w9, [sp, #328]
+	ldr w10, [sp, #336]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #340]
+	ldr w9, [sp, #340]
+	cbz w9, .Lif_end.23
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #344]
+	ldr w9, [sp, #344]
+	str w9, [sp, #0]
+
+.Lif_end.23:
+	ldr w9, [sp, #12]
+	mov w10, #4
+	sdiv w9, w9, w10
+	str w9, [sp, #348]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #136]
+	sdiv w9, w9, w10
+	str w9, [sp, #352]
+	ldr w9, [sp, #348]
+	ldr w10, [sp, #352]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #356]
+	ldr w9, [sp, #356]
+	cbz w9, .Lif_end.24
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #360]
+	ldr w9, [sp, #360]
+	str w9, [sp, #0]
+
+.Lif_end.24:
+	ldr w9, [sp, #12]
+	mov w10, #4
+	sdiv w11, w9, w10
+	mov w9, #4
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #364]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #136]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #136]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #368]
+	ldr w9, [sp, #364]
+	ldr w10, [sp, #368]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #372]
+	ldr w9, [sp, #372]
+	cbz w9, .Lif_end.25
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #376]
+	ldr w9, [sp, #376]
+	str w9, [sp, #0]
+
+.Lif_end.25:
+	ldr w9, [sp, #12]
+	mov w10, #7
+	sdiv w9, w9, w10
+	str w9, [sp, #380]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #144]
+	sdiv w9, w9, w10
+	str w9, [sp, #384]
+	ldr w9, [sp, #380]
+	ldr w10, [sp, #384]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #388]
+	ldr w9, [sp, #388]
+	cbz w9, .Lif_end.26
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #392]
+	ldr w9, [sp, #392]
+	str w9, [sp, #0]
+
+.Lif_end.26:
+	ldr w9, [sp, #12]
+	mov w10, #7
+	sdiv w11, w9, w10
+	mov w9, #7
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #396]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #144]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #144]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #400]
+	ldr w9, [sp, #396]
+	ldr w10, [sp, #400]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #404]
+	ldr w9, [sp, #404]
+	cbz w9, .Lif_end.27
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #408]
+	ldr w9, [sp, #408]
+	str w9, [sp, #0]
+
+.Lif_end.27:
+	mov w9, #7
+	neg w9, w9
+	str w9, [sp, #412]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #412]
+	sdiv w9, w9, w10
+	str w9, [sp, #416]
+	ldr w9, [sp, #144]
+	neg w9, w9
+	str w9, [sp, #420]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #420]
+	sdiv w9, w9, w10
+	str w9, [sp, #424]
+	ldr w9, [sp, #416]
+	ldr w10, [sp, #424]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #428]
+	ldr w9, [sp, #428]
+	cbz w9, .Lif_end.28
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #432]
+	ldr w9, [sp, #432]
+	str w9, [sp, #0]
+
+.Lif_end.28:
+	mov w9, #7
+	neg w9, w9
+	str w9, [sp, #436]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #436]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #436]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #440]
+	ldr w9, [sp, #144]
+	neg w9, w9
+	str w9, [sp, #444]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #444]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #444]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #448]
+	ldr w9, [sp, #440]
+	ldr w10, [sp, #448]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #452]
+	ldr w9, [sp, #452]
+	cbz w9, .Lif_end.29
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #456]
+	ldr w9, [sp, #456]
+	str w9, [sp, #0]
+
+.Lif_end.29:
+	ldr w9, [sp, #12]
+	mov w10, #10
+	sdiv w9, w9, w10
+	str w9, [sp, #460]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #152]
+	sdiv w9, w9, w10
+	str w9, [sp, #464]
+	ldr w9, [sp, #460]
+	ldr w10, [sp, #464]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #468]
+	ldr w9, [sp, #468]
+	cbz w9, .Lif_end.30
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #472]
+	ldr w9, [sp, #472]
+	str w9, [sp, #0]
+
+.Lif_end.30:
+	ldr w9, [sp, #12]
+	mov w10, #10
+	sdiv w11, w9, w10
+	mov w9, #10
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #476]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #152]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #152]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #480]
+	ldr w9, [sp, #476]
+	ldr w10, [sp, #480]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #484]
+	ldr w9, [sp, #484]
+	cbz w9, .Lif_end.31
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #488]
+	ldr w9, [sp, #488]
+	str w9, [sp, #0]
+
+.Lif_end.31:
+	ldr w9, [sp, #12]
+	mov w10, #1024
+	sdiv w9, w9, w10
+	str w9, [sp, #492]
+	ldr w9, [sp, #136]
+	mov w10, #256
+	mul w9, w9, w10
+	str w9, [sp, #496]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #496]
+	sdiv w9, w9, w10
+	str w9, [sp, #500]
+	ldr w9, [sp, #492]
+	ldr w10, [sp, #500]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #504]
+	ldr w9, [sp, #504]
+	cbz w9, .Lif_end.32
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #508]
+	ldr w9, [sp, #508]
+	str w9, [sp, #0]
+
+.Lif_end.32:
+	ldr w9, [sp, #12]
+	mov w10, #1024
+	sdiv w11, w9, w10
+	mov w9, #1024
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #512]
+	ldr w9, [sp, #136]
+	mov w10, #256
+	mul w9, w9, w10
+	str w9, [sp, #516]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #516]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #516]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #520]
+	ldr w9, [sp, #512]
+	ldr w10, [sp, #520]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #524]
+	ldr w9, [sp, #524]
+	cbz w9, .Lif_end.33
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #528]
+	ldr w9, [sp, #528]
+	str w9, [sp, #0]
+
+.Lif_end.33:
+	mov w9, #1024
+	neg w9, w9
+	str w9, [sp, #532]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #532]
+	sdiv w9, w9, w10
+	str w9, [sp, #536]
+	ldr w9, [sp, #136]
+	mov w10, #256
+	mul w9, w9, w10
+	str w9, [sp, #540]
+	ldr w9, [sp, #540]
+	neg w9, w9
+	str w9, [sp, #544]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #544]
+	sdiv w9, w9, w10
+	str w9, [sp, #548]
+	ldr w9, [sp, #536]
+	ldr w10, [sp, #548]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #552]
+	ldr w9, [sp, #552]
+	cbz w9, .Lif_end.34
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #556]
+	ldr w9, [sp, #556]
+	str w9, [sp, #0]
+
+.Lif_end.34:
+	mov w9, #1024
+	neg w9, w9
+	str w9, [sp, #560]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #560]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #560]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #564]
+	ldr w9, [sp, #136]
+	mov w10, #256
+	mul w9, w9, w10
+	str w9, [sp, #568]
+	ldr w9, [sp, #568]
+	neg w9, w9
+	str w9, [sp, #572]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #572]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #572]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #576]
+	ldr w9, [sp, #564]
+	ldr w10, [sp, #576]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #580]
+	ldr w9, [sp, #580]
+	cbz w9, .Lif_end.35
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #584]
+	ldr w9, [sp, #584]
+	str w9, [sp, #0]
+
+.Lif_end.35:
+	ldr w9, [sp, #12]
+	mov w10, #641
+	sdiv w9, w9, w10
+	str w9, [sp, #588]
+	ldr w9, [sp, #112]
+	mov w10, #641
+	mul w9, w9, w10
+	str w9, [sp, #592]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #592]
+	sdiv w9, w9, w10
+	str w9, [sp, #596]
+	ldr w9, [sp, #588]
+	ldr w10, [sp, #596]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #600]
+	ldr w9, [sp, #600]
+	cbz w9, .Lif_end.36
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #604]
+	ldr w9, [sp, #604]
+	str w9, [sp, #0]
+
+.Lif_end.36:
+	ldr w9, [sp, #12]
+	mov w10, #641
+	sdiv w11, w9, w10
+	mov w9, #641
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #608]
+	ldr w9, [sp, #112]
+	mov w10, #641
+	mul w9, w9, w10
+	str w9, [sp, #612]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #612]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #612]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #616]
+	ldr w9, [sp, #608]
+	ldr w10, [sp, #616]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #620]
+	ldr w9, [sp, #620]
+	cbz w9, .Lif_end.37
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #624]
+	ldr w9, [sp, #624]
+	str w9, [sp, #0]
+
+.Lif_end.37:
+	ldr w9, [sp, #12]
+	mov w10, #1000
+	sdiv w9, w9, w10
+	str w9, [sp, #628]
+	ldr w9, [sp, #152]
+	mov w10, #100
+	mul w9, w9, w10
+	str w9, [sp, #632]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #632]
+	sdiv w9, w9, w10
+	str w9, [sp, #636]
+	ldr w9, [sp, #628]
+	ldr w10, [sp, #636]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #640]
+	ldr w9, [sp, #640]
+	cbz w9, .Lif_end.38
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #644]
+	ldr w9, [sp, #644]
+	str w9, [sp, #0]
+
+.Lif_end.38:
+	ldr w9, [sp, #12]
+	mov w10, #1000
+	sdiv w11, w9, w10
+	mov w9, #1000
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #648]
+	ldr w9, [sp, #152]
+	mov w10, #100
+	mul w9, w9, w10
+	str w9, [sp, #652]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #652]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #652]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #656]
+	ldr w9, [sp, #648]
+	ldr w10, [sp, #656]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #660]
+	ldr w9, [sp, #660]
+	cbz w9, .Lif_end.39
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #664]
+	ldr w9, [sp, #664]
+	str w9, [sp, #0]
+
+.Lif_end.39:
+	ldr w9, [sp, #12]
+	movz w10, #1
+	movk w10, #1, lsl #16
+	sdiv w9, w9, w10
+	str w9, [sp, #668]
+	ldr w9, [sp, #112]
+	movz w10, #1
+	movk w10, #1, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #672]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #672]
+	sdiv w9, w9, w10
+	str w9, [sp, #676]
+	ldr w9, [sp, #668]
+	ldr w10, [sp, #676]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #680]
+	ldr w9, [sp, #680]
+	cbz w9, .Lif_end.40
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #684]
+	ldr w9, [sp, #684]
+	str w9, [sp, #0]
+
+.Lif_end.40:
+	ldr w9, [sp, #12]
+	movz w10, #1
+	movk w10, #1, lsl #16
+	sdiv w11, w9, w10
+	movz w9, #1
+	movk w9, #1, lsl #16
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #688]
+	ldr w9, [sp, #112]
+	movz w10, #1
+	movk w10, #1, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #692]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #692]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #692]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #696]
+	ldr w9, [sp, #688]
+	ldr w10, [sp, #696]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #700]
+	ldr w9, [sp, #700]
+	cbz w9, .Lif_end.41
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #704]
+	ldr w9, [sp, #704]
+	str w9, [sp, #0]
+
+.Lif_end.41:
+	ldr w9, [sp, #12]
+	movz w10, #0
+	movk w10, #16384, lsl #16
+	sdiv w9, w9, w10
+	str w9, [sp, #708]
+	ldr w9, [sp, #112]
+	movz w10, #0
+	movk w10, #16384, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #712]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #712]
+	sdiv w9, w9, w10
+	str w9, [sp, #716]
+	ldr w9, [sp, #708]
+	ldr w10, [sp, #716]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #720]
+	ldr w9, [sp, #720]
+	cbz w9, .Lif_end.42
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #724]
+	ldr w9, [sp, #724]
+	str w9, [sp, #0]
+
+.Lif_end.42:
+	ldr w9, [sp, #12]
+	movz w10, #0
+	movk w10, #16384, lsl #16
+	sdiv w11, w9, w10
+	movz w9, #0
+	movk w9, #16384, lsl #16
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #728]
+	ldr w9, [sp, #112]
+	movz w10, #0
+	movk w10, #16384, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #732]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #732]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #732]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #736]
+	ldr w9, [sp, #728]
+	ldr w10, [sp, #736]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #740]
+	ldr w9, [sp, #740]
+	cbz w9, .Lif_end.43
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #744]
+	ldr w9, [sp, #744]
+	str w9, [sp, #0]
+
+.Lif_end.43:
+	ldr w9, [sp, #12]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	sdiv w9, w9, w10
+	str w9, [sp, #748]
+	ldr w9, [sp, #112]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #752]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #752]
+	sdiv w9, w9, w10
+	str w9, [sp, #756]
+	ldr w9, [sp, #748]
+	ldr w10, [sp, #756]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #760]
+	ldr w9, [sp, #760]
+	cbz w9, .Lif_end.44
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #764]
+	ldr w9, [sp, #764]
+	str w9, [sp, #0]
+
+.Lif_end.44:
+	ldr w9, [sp, #12]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	sdiv w11, w9, w10
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #768]
+	ldr w9, [sp, #112]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #772]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #772]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #772]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #776]
+	ldr w9, [sp, #768]
+	ldr w10, [sp, #776]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #780]
+	ldr w9, [sp, #780]
+	cbz w9, .Lif_end.45
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #784]
+	ldr w9, [sp, #784]
+	str w9, [sp, #0]
+
+.Lif_end.45:
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #788]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #788]
+	sdiv w9, w9, w10
+	str w9, [sp, #792]
+	ldr w9, [sp, #112]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #796]
+	ldr w9, [sp, #796]
+	neg w9, w9
+	str w9, [sp, #800]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #800]
+	sdiv w9, w9, w10
+	str w9, [sp, #804]
+	ldr w9, [sp, #792]
+	ldr w10, [sp, #804]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #808]
+	ldr w9, [sp, #808]
+	cbz w9, .Lif_end.46
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #812]
+	ldr w9, [sp, #812]
+	str w9, [sp, #0]
+
+.Lif_end.46:
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #816]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #816]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #816]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #820]
+	ldr w9, [sp, #112]
+	movz w10, #65535
+	movk w10, #32767, lsl #16
+	mul w9, w9, w10
+	str w9, [sp, #824]
+	ldr w9, [sp, #824]
+	neg w9, w9
+	str w9, [sp, #828]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #828]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #828]
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #832]
+	ldr w9, [sp, #820]
+	ldr w10, [sp, #832]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #836]
+	ldr w9, [sp, #836]
+	cbz w9, .Lif_end.47
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #840]
+	ldr w9, [sp, #840]
+	str w9, [sp, #0]
+
+.Lif_end.47:
+	ldr w9, [sp, #4]
+	add w9, w9, #1
+	str w9, [sp, #844]
+	ldr w9, [sp, #844]
+	str w9, [sp, #4]
+	b .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	ldr w0, [sp, #0]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
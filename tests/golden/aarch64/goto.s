@@ -0,0 +1,22 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #16
+
+	mov w9, #5
+	str w9, [sp, #0]
+	b .Llabel.end.0
+	mov w9, #99
+	str w9, [sp, #0]
+
+.Llabel.end.0:
+	ldr w0, [sp, #0]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,158 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #112
+
+	mov w9, #3
+	str w9, [sp, #0]
+	mov w9, #4
+	str w9, [sp, #4]
+	mov w9, #0
+	str w9, [sp, #8]
+
+.Lfor_start.0:
+	ldr w9, [sp, #8]
+	cmp w9, #2
+	cset w9, lt
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #8]
+	add w9, w9, w10
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	str w9, [sp, #0]
+	ldr w9, [sp, #4]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #4]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #8]
+	str w9, [sp, #24]
+	ldr w9, [sp, #8]
+	add w9, w9, #1
+	str w9, [sp, #8]
+	b .Lfor_start.0
+
+.Lbreak_loop.0:
+	mov w9, #0
+	str w9, [sp, #28]
+	mov w9, #0
+	str w9, [sp, #32]
+
+.Lfor_start.1:
+	ldr w9, [sp, #32]
+	cmp w9, #10
+	cset w9, lt
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	cbz w9, .Lbreak_loop.1
+	mov w9, #0
+	str w9, [sp, #40]
+
+.Lfor_start.2:
+	ldr w9, [sp, #40]
+	cmp w9, #5
+	cset w9, lt
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cbz w9, .Lbreak_loop.2
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #48]
+	ldr w9, [sp, #28]
+	ldr w10, [sp, #48]
+	add w9, w9, w10
+	str w9, [sp, #52]
+	ldr w9, [sp, #0]
+	mov w10, #2
+	lsl w9, w9, w10
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	mov w10, #3
+	sdiv w9, w9, w10
+	str w9, [sp, #60]
+	ldr w9, [sp, #52]
+	ldr w10, [sp, #60]
+	add w9, w9, w10
+	str w9, [sp, #64]
+	ldr w9, [sp, #64]
+	ldr w10, [sp, #32]
+	add w9, w9, w10
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	str w9, [sp, #28]
+
+.Lcontinue_loop.2:
+	ldr w9, [sp, #40]
+	str w9, [sp, #72]
+	ldr w9, [sp, #40]
+	add w9, w9, #1
+	str w9, [sp, #40]
+	b .Lfor_start.2
+
+.Lbreak_loop.2:
+
+.Lcontinue_loop.1:
+	ldr w9, [sp, #32]
+	str w9, [sp, #76]
+	ldr w9, [sp, #32]
+	add w9, w9, #1
+	str w9, [sp, #32]
+	b .Lfor_start.1
+
+.Lbreak_loop.1:
+	mov w9, #7
+	str w9, [sp, #80]
+
+.Lcontinue_loop.3:
+	ldr w9, [sp, #80]
+	cmp w9, #0
+	cset w9, gt
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	cbz w9, .Lbreak_loop.3
+	ldr w9, [sp, #0]
+	mov w10, #100
+	mul w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	mov w10, #9
+	sdiv w11, w9, w10
+	mov w9, #9
+	ldr w10, [sp, #88]
+	msub w9, w11, w9, w10
+	str w9, [sp, #92]
+	ldr w9, [sp, #28]
+	ldr w10, [sp, #92]
+	add w9, w9, w10
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	str w9, [sp, #28]
+	ldr w9, [sp, #80]
+	str w9, [sp, #100]
+	ldr w9, [sp, #80]
+	sub w9, w9, #1
+	str w9, [sp, #80]
+	b .Lcontinue_loop.3
+
+.Lbreak_loop.3:
+	ldr w9, [sp, #28]
+	mov w10, #255
+	and w9, w9, w10
+	str w9, [sp, #104]
+	ldr w0, [sp, #104]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,40 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #32
+
+	mov w9, #0
+	add w9, w9, #1
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	cbz w9, .Land_false.0
+	mov w9, #1
+	add w9, w9, #0
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cmp w9, #0
+	cset w9, ne
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	str w9, [sp, #16]
+	b .Land_end.1
+
+.Land_false.0:
+	mov w9, #0
+	str w9, [sp, #16]
+
+.Land_end.1:
+	ldr w0, [sp, #16]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
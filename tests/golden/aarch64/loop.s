@@ -0,0 +1,116 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #64
+
+	mov w9, #0
+	str w9, [sp, #0]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #0]
+	cmp w9, #10
+	cset w9, lt
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	cmp w9, #5
+	cset w9, eq
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	cbz w9, .Lif_end.0
+	b .Lcontinue_loop.0
+
+.Lif_end.0:
+	ldr w9, [sp, #0]
+	cmp w9, #8
+	cset w9, eq
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lif_end.1
+	b .Lbreak_loop.0
+
+.Lif_end.1:
+	b .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	mov w9, #0
+	str w9, [sp, #20]
+
+.Ldo_start.2:
+	ldr w9, [sp, #20]
+	add w9, w9, #1
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #20]
+
+.Lcontinue_loop.1:
+	ldr w9, [sp, #20]
+	cmp w9, #5
+	cset w9, lt
+	str w9, [sp, #28]
+	ldr w9, [sp, #28]
+	cbnz w9, .Ldo_start.2
+
+.Lbreak_loop.1:
+	mov w9, #0
+	str w9, [sp, #32]
+	mov w9, #0
+	str w9, [sp, #36]
+
+.Lfor_start.3:
+	ldr w9, [sp, #36]
+	cmp w9, #10
+	cset w9, lt
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	cbz w9, .Lbreak_loop.2
+	ldr w9, [sp, #36]
+	cmp w9, #3
+	cset w9, eq
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cbz w9, .Lif_end.4
+	b .Lcontinue_loop.2
+
+.Lif_end.4:
+	ldr w9, [sp, #36]
+	cmp w9, #7
+	cset w9, eq
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	cbz w9, .Lif_end.5
+	b .Lbreak_loop.2
+
+.Lif_end.5:
+	ldr w9, [sp, #32]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	str w9, [sp, #32]
+
+.Lcontinue_loop.2:
+	ldr w9, [sp, #36]
+	add w9, w9, #1
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	str w9, [sp, #36]
+	b .Lfor_start.3
+
+.Lbreak_loop.2:
+	ldr w0, [sp, #32]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,658 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #496
+
+	mov w9, #0
+	str w9, [sp, #0]
+	mov w9, #0
+	str w9, [sp, #4]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #4]
+	cmp w9, #10
+	cset w9, lt
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cbz w9, .Lbreak_loop.0
+	mov w9, #0
+	str w9, [sp, #12]
+	ldr w9, [sp, #4]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lif_end.0
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	sub w9, w9, #1
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #12]
+
+.Lif_end.0:
+	ldr w9, [sp, #4]
+	cmp w9, #1
+	cset w9, eq
+	str w9, [sp, #28]
+	ldr w9, [sp, #28]
+	cbz w9, .Lif_end.1
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	str w9, [sp, #12]
+
+.Lif_end.1:
+	ldr w9, [sp, #4]
+	cmp w9, #2
+	cset w9, eq
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	cbz w9, .Lif_end.2
+	movz w9, #57920
+	movk w9, #1, lsl #16
+	neg w9, w9
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #12]
+
+.Lif_end.2:
+	ldr w9, [sp, #4]
+	cmp w9, #3
+	cset w9, eq
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cbz w9, .Lif_end.3
+	mov w9, #1
+	neg w9, w9
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	str w9, [sp, #12]
+
+.Lif_end.3:
+	ldr w9, [sp, #4]
+	cmp w9, #4
+	cset w9, eq
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	cbz w9, .Lif_end.4
+	mov w9, #0
+	str w9, [sp, #12]
+
+.Lif_end.4:
+	ldr w9, [sp, #4]
+	cmp w9, #5
+	cset w9, eq
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	cbz w9, .Lif_end.5
+	mov w9, #1
+	str w9, [sp, #12]
+
+.Lif_end.5:
+	ldr w9, [sp, #4]
+	cmp w9, #6
+	cset w9, eq
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	cbz w9, .Lif_end.6
+	mov w9, #3
+	str w9, [sp, #12]
+
+.Lif_end.6:
+	ldr w9, [sp, #4]
+	cmp w9, #7
+	cset w9, eq
+	str w9, [sp, #64]
+	ldr w9, [sp, #64]
+	cbz w9, .Lif_end.7
+	movz w9, #57920
+	movk w9, #1, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.7:
+	ldr w9, [sp, #4]
+	cmp w9, #8
+	cset w9, eq
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	cbz w9, .Lif_end.8
+	movz w9, #0
+	movk w9, #16384, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.8:
+	ldr w9, [sp, #4]
+	cmp w9, #9
+	cset w9, eq
+	str w9, [sp, #72]
+	ldr w9, [sp, #72]
+	cbz w9, .Lif_end.9
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	str w9, [sp, #12]
+
+.Lif_end.9:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #4]
+	sub w9, w9, w10
+	str w9, [sp, #76]
+	ldr w9, [sp, #76]
+	add w9, w9, #1
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	str w9, [sp, #84]
+	ldr w9, [sp, #12]
+	mov w10, #0
+	mul w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #84]
+	sub w9, w9, #1
+	str w9, [sp, #92]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #92]
+	mul w9, w9, w10
+	str w9, [sp, #96]
+	ldr w9, [sp, #88]
+	ldr w10, [sp, #96]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	cbz w9, .Lif_end.10
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #104]
+	ldr w9, [sp, #104]
+	str w9, [sp, #0]
+
+.Lif_end.10:
+	ldr w9, [sp, #12]
+	mov w10, #1
+	mul w9, w9, w10
+	str w9, [sp, #108]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #84]
+	mul w9, w9, w10
+	str w9, [sp, #112]
+	ldr w9, [sp, #108]
+	ldr w10, [sp, #112]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #116]
+	ldr w9, [sp, #116]
+	cbz w9, .Lif_end.11
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #120]
+	ldr w9, [sp, #120]
+	str w9, [sp, #0]
+
+.Lif_end.11:
+	mov w9, #1
+	neg w9, w9
+	str w9, [sp, #124]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #124]
+	mul w9, w9, w10
+	str w9, [sp, #128]
+	ldr w9, [sp, #84]
+	neg w9, w9
+	str w9, [sp, #132]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #132]
+	mul w9, w9, w10
+	str w9, [sp, #136]
+	ldr w9, [sp, #128]
+	ldr w10, [sp, #136]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #140]
+	ldr w9, [sp, #140]
+	cbz w9, .Lif_end.12
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #144]
+	ldr w9, [sp, #144]
+	str w9, [sp, #0]
+
+.Lif_end.12:
+	ldr w9, [sp, #12]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #148]
+	ldr w9, [sp, #84]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #152]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #152]
+	mul w9, w9, w10
+	str w9, [sp, #156]
+	ldr w9, [sp, #148]
+	ldr w10, [sp, #156]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #160]
+	ldr w9, [sp, #160]
+	cbz w9, .Lif_end.13
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #164]
+	ldr w9, [sp, #164]
+	str w9, [sp, #0]
+
+.Lif_end.13:
+	ldr w9, [sp, #12]
+	mov w10, #3
+	mul w9, w9, w10
+	str w9, [sp, #168]
+	ldr w9, [sp, #84]
+	mov w10, #3
+	mul w9, w9, w10
+	str w9, [sp, #172]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #172]
+	mul w9, w9, w10
+	str w9, [sp, #176]
+	ldr w9, [sp, #168]
+	ldr w10, [sp, #176]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #180]
+	ldr w9, [sp, #180]
+	cbz w9, .Lif_end.14
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #184]
+	ldr w9, [sp, #184]
+	str w9, [sp, #0]
+
+.Lif_end.14:
+	mov w9, #5
+	ldr w10, [sp, #12]
+	mul w9, w9, w10
+	str w9, [sp, #188]
+	ldr w9, [sp, #84]
+	mov w10, #5
+	mul w9, w9, w10
+	str w9, [sp, #192]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #192]
+	mul w9, w9, w10
+	str w9, [sp, #196]
+	ldr w9, [sp, #188]
+	ldr w10, [sp, #196]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #200]
+	ldr w9, [sp, #200]
+	cbz w9, .Lif_end.15
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #204]
+	ldr w9, [sp, #204]
+	str w9, [sp, #0]
+
+.Lif_end.15:
+	ldr w9, [sp, #12]
+	mov w10, #6
+	mul w9, w9, w10
+	str w9, [sp, #208]
+	ldr w9, [sp, #84]
+	mov w10, #6
+	mul w9, w9, w10
+	str w9, [sp, #212]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #212]
+	mul w9, w9, w10
+	str w9, [sp, #216]
+	ldr w9, [sp, #208]
+	ldr w10, [sp, #216]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #220]
+	ldr w9, [sp, #220]
+	cbz w9, .Lif_end.16
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #224]
+	ldr w9, [sp, #224]
+	str w9, [sp, #0]
+
+.Lif_end.16:
+	ldr w9, [sp, #12]
+	mov w10, #9
+	mul w9, w9, w10
+	str w9, [sp, #228]
+	ldr w9, [sp, #84]
+	mov w10, #9
+	mul w9, w9, w10
+	str w9, [sp, #232]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #232]
+	mul w9, w9, w10
+	str w9, [sp, #236]
+	ldr w9, [sp, #228]
+	ldr w10, [sp, #236]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #240]
+	ldr w9, [sp, #240]
+	cbz w9, .Lif_end.17
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #244]
+	ldr w9, [sp, #244]
+	str w9, [sp, #0]
+
+.Lif_end.17:
+	ldr w9, [sp, #12]
+	mov w10, #10
+	mul w9, w9, w10
+	str w9, [sp, #248]
+	ldr w9, [sp, #84]
+	mov w10, #10
+	mul w9, w9, w10
+	str w9, [sp, #252]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #252]
+	mul w9, w9, w10
+	str w9, [sp, #256]
+	ldr w9, [sp, #248]
+	ldr w10, [sp, #256]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #260]
+	ldr w9, [sp, #260]
+	cbz w9, .Lif_end.18
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #264]
+	ldr w9, [sp, #264]
+	str w9, [sp, #0]
+
+.Lif_end.18:
+	ldr w9, [sp, #12]
+	mov w10, #12
+	mul w9, w9, w10
+	str w9, [sp, #268]
+	ldr w9, [sp, #84]
+	mov w10, #12
+	mul w9, w9, w10
+	str w9, [sp, #272]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #272]
+	mul w9, w9, w10
+	str w9, [sp, #276]
+	ldr w9, [sp, #268]
+	ldr w10, [sp, #276]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #280]
+	ldr w9, [sp, #280]
+	cbz w9, .Lif_end.19
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #284]
+	ldr w9, [sp, #284]
+	str w9, [sp, #0]
+
+.Lif_end.19:
+	ldr w9, [sp, #12]
+	mov w10, #16
+	mul w9, w9, w10
+	str w9, [sp, #288]
+	ldr w9, [sp, #84]
+	mov w10, #16
+	mul w9, w9, w10
+	str w9, [sp, #292]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #292]
+	mul w9, w9, w10
+	str w9, [sp, #296]
+	ldr w9, [sp, #288]
+	ldr w10, [sp, #296]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #300]
+	ldr w9, [sp, #300]
+	cbz w9, .Lif_end.20
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #304]
+	ldr w9, [sp, #304]
+	str w9, [sp, #0]
+
+.Lif_end.20:
+	mov w9, #4
+	neg w9, w9
+	str w9, [sp, #308]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #308]
+	mul w9, w9, w10
+	str w9, [sp, #312]
+	mov w9, #4
+	neg w9, w9
+	str w9, [sp, #316]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #316]
+	mul w9, w9, w10
+	str w9, [sp, #320]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #320]
+	mul w9, w9, w10
+	str w9, [sp, #324]
+	ldr w9, [sp, #312]
+	ldr w10, [sp, #324]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #328]
+	ldr w9, [sp, #328]
+	cbz w9, .Lif_end.21
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #332]
+	ldr w9, [sp, #332]
+	str w9, [sp, #0]
+
+.Lif_end.21:
+	mov w9, #9
+	neg w9, w9
+	str w9, [sp, #336]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #336]
+	mul w9, w9, w10
+	str w9, [sp, #340]
+	mov w9, #9
+	neg w9, w9
+	str w9, [sp, #344]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #344]
+	mul w9, w9, w10
+	str w9, [sp, #348]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #348]
+	mul w9, w9, w10
+	str w9, [sp, #352]
+	ldr w9, [sp, #340]
+	ldr w10, [sp, #352]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #356]
+	ldr w9, [sp, #356]
+	cbz w9, .Lif_end.22
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #360]
+	ldr w9, [sp, #360]
+	str w9, [sp, #0]
+
+.Lif_end.22:
+	ldr w9, [sp, #12]
+	mov w10, #36
+	mul w9, w9, w10
+	str w9, [sp, #364]
+	ldr w9, [sp, #84]
+	mov w10, #36
+	mul w9, w9, w10
+	str w9, [sp, #368]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #368]
+	mul w9, w9, w10
+	str w9, [sp, #372]
+	ldr w9, [sp, #364]
+	ldr w10, [sp, #372]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #376]
+	ldr w9, [sp, #376]
+	cbz w9, .Lif_end.23
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #380]
+	ldr w9, [sp, #380]
+	str w9, [sp, #0]
+
+.Lif_end.23:
+	ldr w9, [sp, #12]
+	mov w10, #72
+	mul w9, w9, w10
+	str w9, [sp, #384]
+	ldr w9, [sp, #84]
+	mov w10, #72
+	mul w9, w9, w10
+	str w9, [sp, #388]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #388]
+	mul w9, w9, w10
+	str w9, [sp, #392]
+	ldr w9, [sp, #384]
+	ldr w10, [sp, #392]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #396]
+	ldr w9, [sp, #396]
+	cbz w9, .Lif_end.24
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #400]
+	ldr w9, [sp, #400]
+	str w9, [sp, #0]
+
+.Lif_end.24:
+	ldr w9, [sp, #12]
+	mov w10, #7
+	mul w9, w9, w10
+	str w9, [sp, #404]
+	ldr w9, [sp, #84]
+	mov w10, #7
+	mul w9, w9, w10
+	str w9, [sp, #408]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #408]
+	mul w9, w9, w10
+	str w9, [sp, #412]
+	ldr w9, [sp, #404]
+	ldr w10, [sp, #412]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #416]
+	ldr w9, [sp, #416]
+	cbz w9, .Lif_end.25
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #420]
+	ldr w9, [sp, #420]
+	str w9, [sp, #0]
+
+.Lif_end.25:
+	ldr w9, [sp, #12]
+	mov w10, #1024
+	mul w9, w9, w10
+	str w9, [sp, #424]
+	ldr w9, [sp, #84]
+	mov w10, #1024
+	mul w9, w9, w10
+	str w9, [sp, #428]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #428]
+	mul w9, w9, w10
+	str w9, [sp, #432]
+	ldr w9, [sp, #424]
+	ldr w10, [sp, #432]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #436]
+	ldr w9, [sp, #436]
+	cbz w9, .Lif_end.26
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #440]
+	ldr w9, [sp, #440]
+	str w9, [sp, #0]
+
+.Lif_end.26:
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #444]
+	ldr w9, [sp, #444]
+	sub w9, w9, #1
+	str w9, [sp, #448]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #448]
+	mul w9, w9, w10
+	str w9, [sp, #452]
+	movz w9, #65535
+	movk w9, #32767, lsl #16
+	neg w9, w9
+	str w9, [sp, #456]
+	ldr w9, [sp, #456]
+	sub w9, w9, #1
+	str w9, [sp, #460]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #460]
+	mul w9, w9, w10
+	str w9, [sp, #464]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #464]
+	mul w9, w9, w10
+	str w9, [sp, #468]
+	ldr w9, [sp, #452]
+	ldr w10, [sp, #468]
+	cmp w9, w10
+	cset w9, ne
+	str w9, [sp, #472]
+	ldr w9, [sp, #472]
+	cbz w9, .Lif_end.27
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #476]
+	ldr w9, [sp, #476]
+	str w9, [sp, #0]
+
+.Lif_end.27:
+	ldr w9, [sp, #4]
+	add w9, w9, #1
+	str w9, [sp, #480]
+	ldr w9, [sp, #480]
+	str w9, [sp, #4]
+	b .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	ldr w0, [sp, #0]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
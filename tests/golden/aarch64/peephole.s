@@ -0,0 +1,135 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #128
+
+	mov w9, #0
+	str w9, [sp, #0]
+	mov w9, #5
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	cmp w9, #3
+	cset w9, gt
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cbz w9, .Lif_else.0
+	ldr w9, [sp, #0]
+	add w9, w9, #1
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	str w9, [sp, #0]
+	b .Lif_end.1
+
+.Lif_else.0:
+
+.Lif_end.1:
+	ldr w9, [sp, #4]
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #24]
+	mov w9, #1
+	str w9, [sp, #28]
+	mov w9, #2
+	str w9, [sp, #28]
+	ldr w9, [sp, #20]
+	ldr w10, [sp, #24]
+	add w9, w9, w10
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	ldr w10, [sp, #28]
+	sub w9, w9, w10
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #20]
+	cmp w9, w10
+	cset w9, lt
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	cbz w9, .Lif_end.2
+	ldr w9, [sp, #0]
+	add w9, w9, #2
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	str w9, [sp, #0]
+
+.Lif_end.2:
+	mov w9, #0
+	str w9, [sp, #56]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #16]
+	cmp w9, w10
+	cset w9, lt
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	str w9, [sp, #64]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #16]
+	cmp w9, w10
+	cset w9, gt
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #72]
+	ldr w9, [sp, #72]
+	str w9, [sp, #76]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	add w9, w9, w10
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	ldr w10, [sp, #16]
+	add w9, w9, w10
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #20]
+	add w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	ldr w10, [sp, #24]
+	add w9, w9, w10
+	str w9, [sp, #92]
+	ldr w9, [sp, #92]
+	ldr w10, [sp, #28]
+	add w9, w9, w10
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	ldr w10, [sp, #44]
+	add w9, w9, w10
+	str w9, [sp, #104]
+	ldr w9, [sp, #104]
+	ldr w10, [sp, #56]
+	add w9, w9, w10
+	str w9, [sp, #108]
+	ldr w9, [sp, #108]
+	ldr w10, [sp, #64]
+	add w9, w9, w10
+	str w9, [sp, #112]
+	ldr w9, [sp, #112]
+	ldr w10, [sp, #76]
+	add w9, w9, w10
+	str w9, [sp, #116]
+	ldr w0, [sp, #116]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
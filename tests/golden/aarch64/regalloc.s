@@ -0,0 +1,233 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #208
+
+	mov w9, #1
+	str w9, [sp, #0]
+	mov w9, #2
+	str w9, [sp, #4]
+	mov w9, #3
+	str w9, [sp, #8]
+	mov w9, #4
+	str w9, [sp, #12]
+	mov w9, #5
+	str w9, [sp, #16]
+	mov w9, #6
+	str w9, [sp, #20]
+	mov w9, #7
+	str w9, [sp, #24]
+	mov w9, #8
+	str w9, [sp, #28]
+	mov w9, #9
+	str w9, [sp, #32]
+	mov w9, #10
+	str w9, [sp, #36]
+	mov w9, #11
+	str w9, [sp, #40]
+	mov w9, #12
+	str w9, [sp, #44]
+	mov w9, #0
+	str w9, [sp, #48]
+
+.Lfor_start.0:
+	ldr w9, [sp, #48]
+	cmp w9, #20
+	cset w9, lt
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #4]
+	mov w10, #7
+	sdiv w11, w9, w10
+	mov w9, #7
+	ldr w10, [sp, #4]
+	msub w9, w11, w9, w10
+	str w9, [sp, #56]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #56]
+	add w9, w9, w10
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	str w9, [sp, #0]
+	ldr w9, [sp, #8]
+	mov w10, #3
+	sdiv w9, w9, w10
+	str w9, [sp, #64]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #64]
+	add w9, w9, w10
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	str w9, [sp, #4]
+	ldr w9, [sp, #12]
+	mov w10, #1
+	lsl w9, w9, w10
+	str w9, [sp, #72]
+	ldr w9, [sp, #8]
+	ldr w10, [sp, #72]
+	add w9, w9, w10
+	str w9, [sp, #76]
+	ldr w9, [sp, #76]
+	str w9, [sp, #8]
+	ldr w9, [sp, #16]
+	mov w10, #1
+	asr w9, w9, w10
+	str w9, [sp, #80]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #80]
+	add w9, w9, w10
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	str w9, [sp, #12]
+	ldr w9, [sp, #16]
+	ldr w10, [sp, #20]
+	eor w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	str w9, [sp, #16]
+	ldr w9, [sp, #24]
+	mov w10, #3
+	mul w9, w9, w10
+	str w9, [sp, #92]
+	ldr w9, [sp, #20]
+	ldr w10, [sp, #92]
+	add w9, w9, w10
+	str w9, [sp, #96]
+	ldr w9, [sp, #96]
+	str w9, [sp, #20]
+	ldr w9, [sp, #48]
+	add w9, w9, #1
+	str w9, [sp, #100]
+	ldr w9, [sp, #28]
+	ldr w10, [sp, #100]
+	sdiv w9, w9, w10
+	str w9, [sp, #104]
+	ldr w9, [sp, #24]
+	ldr w10, [sp, #104]
+	sub w9, w9, w10
+	str w9, [sp, #108]
+	ldr w9, [sp, #108]
+	str w9, [sp, #24]
+	ldr w9, [sp, #48]
+	add w9, w9, #2
+	str w9, [sp, #112]
+	ldr w9, [sp, #32]
+	ldr w10, [sp, #112]
+	sdiv w11, w9, w10
+	ldr w9, [sp, #112]
+	ldr w10, [sp, #32]
+	msub w9, w11, w9, w10
+	str w9, [sp, #116]
+	ldr w9, [sp, #28]
+	ldr w10, [sp, #116]
+	add w9, w9, w10
+	str w9, [sp, #120]
+	ldr w9, [sp, #120]
+	str w9, [sp, #28]
+	ldr w9, [sp, #36]
+	mov w10, #255
+	and w9, w9, w10
+	str w9, [sp, #124]
+	ldr w9, [sp, #32]
+	ldr w10, [sp, #124]
+	add w9, w9, w10
+	str w9, [sp, #128]
+	ldr w9, [sp, #128]
+	str w9, [sp, #32]
+	ldr w9, [sp, #36]
+	ldr w10, [sp, #40]
+	orr w9, w9, w10
+	str w9, [sp, #132]
+	ldr w9, [sp, #132]
+	str w9, [sp, #36]
+	ldr w9, [sp, #40]
+	ldr w10, [sp, #44]
+	add w9, w9, w10
+	str w9, [sp, #136]
+	ldr w9, [sp, #136]
+	str w9, [sp, #40]
+	ldr w9, [sp, #44]
+	ldr w10, [sp, #0]
+	sub w9, w9, w10
+	str w9, [sp, #140]
+	ldr w9, [sp, #140]
+	str w9, [sp, #44]
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #48]
+	add w9, w9, #1
+	str w9, [sp, #144]
+	ldr w9, [sp, #144]
+	str w9, [sp, #48]
+	b .Lfor_start.0
+
+.Lbreak_loop.0:
+	ldr w9, [sp, #0]
+	str w9, [sp, #148]
+	ldr w9, [sp, #4]
+	str w9, [sp, #0]
+	ldr w9, [sp, #148]
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #152]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #152]
+	add w9, w9, w10
+	str w9, [sp, #156]
+	ldr w9, [sp, #156]
+	ldr w10, [sp, #8]
+	add w9, w9, w10
+	str w9, [sp, #160]
+	ldr w9, [sp, #160]
+	ldr w10, [sp, #12]
+	add w9, w9, w10
+	str w9, [sp, #164]
+	ldr w9, [sp, #164]
+	ldr w10, [sp, #16]
+	add w9, w9, w10
+	str w9, [sp, #168]
+	ldr w9, [sp, #168]
+	ldr w10, [sp, #20]
+	add w9, w9, w10
+	str w9, [sp, #172]
+	ldr w9, [sp, #172]
+	ldr w10, [sp, #24]
+	add w9, w9, w10
+	str w9, [sp, #176]
+	ldr w9, [sp, #176]
+	ldr w10, [sp, #28]
+	add w9, w9, w10
+	str w9, [sp, #180]
+	ldr w9, [sp, #180]
+	ldr w10, [sp, #32]
+	add w9, w9, w10
+	str w9, [sp, #184]
+	ldr w9, [sp, #184]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #188]
+	ldr w9, [sp, #188]
+	ldr w10, [sp, #40]
+	add w9, w9, w10
+	str w9, [sp, #192]
+	ldr w9, [sp, #192]
+	ldr w10, [sp, #44]
+	add w9, w9, w10
+	str w9, [sp, #196]
+	ldr w9, [sp, #196]
+	mov w10, #255
+	and w9, w9, w10
+	str w9, [sp, #200]
+	ldr w0, [sp, #200]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
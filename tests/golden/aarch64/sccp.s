@@ -0,0 +1,90 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #64
+
+	mov w9, #2
+	str w9, [sp, #0]
+	mov w9, #0
+	str w9, [sp, #4]
+	ldr w9, [sp, #0]
+	mov w10, #4
+	mul w9, w9, w10
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	str w9, [sp, #12]
+	ldr w9, [sp, #0]
+	cmp w9, #2
+	cset w9, eq
+	str w9, [sp, #16]
+	ldr w9, [sp, #16]
+	cbz w9, .Lif_else.0
+	ldr w9, [sp, #12]
+	add w9, w9, #1
+	str w9, [sp, #20]
+	ldr w9, [sp, #20]
+	str w9, [sp, #4]
+	b .Lif_end.1
+
+.Lif_else.0:
+	ldr w9, [sp, #12]
+	mov w10, #0
+	sdiv w9, w9, w10
+	str w9, [sp, #24]
+	ldr w9, [sp, #24]
+	str w9, [sp, #4]
+
+.Lif_end.1:
+	mov w9, #0
+	str w9, [sp, #28]
+
+.Lfor_start.2:
+	ldr w9, [sp, #28]
+	cmp w9, #3
+	cset w9, lt
+	str w9, [sp, #32]
+	ldr w9, [sp, #32]
+	cbz w9, .Lbreak_loop.0
+	ldr w9, [sp, #0]
+	cmp w9, #2
+	cset w9, ne
+	str w9, [sp, #36]
+	ldr w9, [sp, #36]
+	cbz w9, .Lif_else.3
+	ldr w9, [sp, #4]
+	mov w10, #100
+	mul w9, w9, w10
+	str w9, [sp, #40]
+	ldr w9, [sp, #40]
+	str w9, [sp, #4]
+	b .Lif_end.4
+
+.Lif_else.3:
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #28]
+	add w9, w9, w10
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	str w9, [sp, #4]
+
+.Lif_end.4:
+
+.Lcontinue_loop.0:
+	ldr w9, [sp, #28]
+	str w9, [sp, #48]
+	ldr w9, [sp, #28]
+	add w9, w9, #1
+	str w9, [sp, #28]
+	b .Lfor_start.2
+
+.Lbreak_loop.0:
+	ldr w0, [sp, #4]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
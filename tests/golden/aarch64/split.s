@@ -0,0 +1,217 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #176
+
+	mov w9, #3
+	str w9, [sp, #0]
+	mov w9, #5
+	str w9, [sp, #4]
+	mov w9, #7
+	str w9, [sp, #8]
+	mov w9, #11
+	str w9, [sp, #12]
+	mov w9, #13
+	str w9, [sp, #16]
+	mov w9, #17
+	str w9, [sp, #20]
+	mov w9, #19
+	str w9, [sp, #24]
+	mov w9, #23
+	str w9, [sp, #28]
+	mov w9, #29
+	str w9, [sp, #32]
+	mov w9, #31
+	str w9, [sp, #36]
+	mov w9, #37
+	str w9, [sp, #40]
+	mov w9, #0
+	str w9, [sp, #44]
+
+.Llabel.again.0:
+	ldr w9, [sp, #44]
+	mov w10, #3
+	sdiv w11, w9, w10
+	mov w9, #3
+	ldr w10, [sp, #44]
+	msub w9, w11, w9, w10
+	str w9, [sp, #48]
+	ldr w9, [sp, #48]
+	cmp w9, #0
+	cset w9, eq
+	str w9, [sp, #52]
+	ldr w9, [sp, #52]
+	cbz w9, .Lif_else.0
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	mul w9, w9, w10
+	str w9, [sp, #56]
+	ldr w9, [sp, #56]
+	ldr w10, [sp, #8]
+	add w9, w9, w10
+	str w9, [sp, #60]
+	ldr w9, [sp, #60]
+	str w9, [sp, #0]
+	ldr w9, [sp, #16]
+	ldr w10, [sp, #20]
+	mul w9, w9, w10
+	str w9, [sp, #64]
+	ldr w9, [sp, #12]
+	ldr w10, [sp, #64]
+	add w9, w9, w10
+	str w9, [sp, #68]
+	ldr w9, [sp, #68]
+	str w9, [sp, #12]
+	b .Lif_end.1
+
+.Lif_else.0:
+	ldr w9, [sp, #44]
+	mov w10, #3
+	sdiv w11, w9, w10
+	mov w9, #3
+	ldr w10, [sp, #44]
+	msub w9, w11, w9, w10
+	str w9, [sp, #72]
+	ldr w9, [sp, #72]
+	cmp w9, #1
+	cset w9, eq
+	str w9, [sp, #76]
+	ldr w9, [sp, #76]
+	cbz w9, .Lif_else.2
+	ldr w9, [sp, #24]
+	ldr w10, [sp, #28]
+	eor w9, w9, w10
+	str w9, [sp, #80]
+	ldr w9, [sp, #80]
+	str w9, [sp, #24]
+	ldr w9, [sp, #32]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #84]
+	ldr w9, [sp, #84]
+	ldr w10, [sp, #40]
+	sub w9, w9, w10
+	str w9, [sp, #88]
+	ldr w9, [sp, #88]
+	str w9, [sp, #32]
+	b .Llabel.skip.1
+	b .Lif_end.3
+
+.Lif_else.2:
+	ldr w9, [sp, #40]
+	ldr w10, [sp, #0]
+	add w9, w9, w10
+	str w9, [sp, #92]
+	ldr w9, [sp, #92]
+	str w9, [sp, #40]
+
+.Lif_end.3:
+
+.Lif_end.1:
+	ldr w9, [sp, #12]
+	mov w10, #5
+	sdiv w11, w9, w10
+	mov w9, #5
+	ldr w10, [sp, #12]
+	msub w9, w11, w9, w10
+	str w9, [sp, #96]
+	ldr w9, [sp, #4]
+	ldr w10, [sp, #96]
+	add w9, w9, w10
+	str w9, [sp, #100]
+	ldr w9, [sp, #100]
+	str w9, [sp, #4]
+	ldr w9, [sp, #24]
+	mov w10, #3
+	sdiv w9, w9, w10
+	str w9, [sp, #104]
+	ldr w9, [sp, #8]
+	ldr w10, [sp, #104]
+	sub w9, w9, w10
+	str w9, [sp, #108]
+	ldr w9, [sp, #108]
+	str w9, [sp, #8]
+
+.Llabel.skip.1:
+	ldr w9, [sp, #16]
+	ldr w10, [sp, #32]
+	add w9, w9, w10
+	str w9, [sp, #112]
+	ldr w9, [sp, #112]
+	str w9, [sp, #16]
+	ldr w9, [sp, #20]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #116]
+	ldr w9, [sp, #116]
+	str w9, [sp, #20]
+	ldr w9, [sp, #44]
+	add w9, w9, #1
+	str w9, [sp, #120]
+	ldr w9, [sp, #120]
+	str w9, [sp, #44]
+	ldr w9, [sp, #44]
+	cmp w9, #25
+	cset w9, lt
+	str w9, [sp, #124]
+	ldr w9, [sp, #124]
+	cbz w9, .Lif_end.4
+	b .Llabel.again.0
+
+.Lif_end.4:
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	add w9, w9, w10
+	str w9, [sp, #128]
+	ldr w9, [sp, #128]
+	ldr w10, [sp, #8]
+	add w9, w9, w10
+	str w9, [sp, #132]
+	ldr w9, [sp, #132]
+	ldr w10, [sp, #12]
+	add w9, w9, w10
+	str w9, [sp, #136]
+	ldr w9, [sp, #136]
+	ldr w10, [sp, #16]
+	add w9, w9, w10
+	str w9, [sp, #140]
+	ldr w9, [sp, #140]
+	ldr w10, [sp, #20]
+	add w9, w9, w10
+	str w9, [sp, #144]
+	ldr w9, [sp, #144]
+	ldr w10, [sp, #24]
+	add w9, w9, w10
+	str w9, [sp, #148]
+	ldr w9, [sp, #148]
+	ldr w10, [sp, #28]
+	add w9, w9, w10
+	str w9, [sp, #152]
+	ldr w9, [sp, #152]
+	ldr w10, [sp, #32]
+	add w9, w9, w10
+	str w9, [sp, #156]
+	ldr w9, [sp, #156]
+	ldr w10, [sp, #36]
+	add w9, w9, w10
+	str w9, [sp, #160]
+	ldr w9, [sp, #160]
+	ldr w10, [sp, #40]
+	add w9, w9, w10
+	str w9, [sp, #164]
+	ldr w9, [sp, #164]
+	str w9, [sp, #168]
+	ldr w9, [sp, #168]
+	mov w10, #255
+	and w9, w9, w10
+	str w9, [sp, #172]
+	ldr w0, [sp, #172]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
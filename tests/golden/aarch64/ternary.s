@@ -0,0 +1,36 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #16
+
+	mov w9, #0
+	str w9, [sp, #0]
+	mov w9, #1
+	neg w9, w9
+	str w9, [sp, #4]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #4]
+	cmp w9, w10
+	cset w9, gt
+	str w9, [sp, #8]
+	ldr w9, [sp, #8]
+	cbz w9, .Lcond_else.0
+	mov w9, #4
+	str w9, [sp, #12]
+	b .Lcond_end.1
+
+.Lcond_else.0:
+	mov w9, #5
+	str w9, [sp, #12]
+
+.Lcond_end.1:
+	ldr w0, [sp, #12]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,21 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #16
+
+	mov w9, #2
+	neg w9, w9
+	str w9, [sp, #0]
+	ldr w9, [sp, #0]
+	mvn w9, w9
+	str w9, [sp, #4]
+	ldr w0, [sp, #4]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
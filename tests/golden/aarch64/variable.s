@@ -0,0 +1,30 @@
+	.text
+	.globl main
+	.type main, %function
+main:
+	stp x29, x30, [sp, #-16]!
+	mov x29, sp
+	sub sp, sp, #32
+
+	mov w9, #2
+	str w9, [sp, #0]
+	mov w9, #3
+	add w9, w9, #2
+	str w9, [sp, #4]
+	ldr w9, [sp, #4]
+	str w9, [sp, #8]
+	ldr w9, [sp, #0]
+	ldr w10, [sp, #8]
+	mul w9, w9, w10
+	str w9, [sp, #12]
+	ldr w9, [sp, #12]
+	mov w10, #2
+	mul w9, w9, w10
+	str w9, [sp, #16]
+	ldr w0, [sp, #16]
+
+	mov sp, x29
+	ldp x29, x30, [sp], #16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",%progbits
@@ -0,0 +1,80 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -64
+
+	li t0, 10
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	addiw t0, t0, 5
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	addiw t0, t0, -3
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	li t1, 4
+	divw t0, t0, t1
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	li t1, 3
+	remw t0, t0, t1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 0(sp)
+	li t0, 255
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	andi t0, t0, 15
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	ori t0, t0, 48
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	xori t0, t0, 7
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	slliw t0, t0, 1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sraiw t0, t0, 2
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	sw t0, 24(sp)
+	lw t0, 0(sp)
+	lw t1, 24(sp)
+	addw t0, t0, t1
+	sw t0, 48(sp)
+	lw a0, 48(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
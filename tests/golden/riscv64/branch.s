@@ -0,0 +1,303 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -224
+
+	li t0, 3
+	negw t0, t0
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	sw t0, 4(sp)
+	li t0, 4
+	sw t0, 8(sp)
+	li t0, 0
+	sw t0, 12(sp)
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	slt t0, t0, t1
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lif_end.0
+	lw t0, 12(sp)
+	addiw t0, t0, 1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 12(sp)
+
+.Lif_end.0:
+	lw t0, 8(sp)
+	lw t1, 4(sp)
+	slt t0, t0, t1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	xori t0, t0, 1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	beqz t0, .Lif_end.1
+	lw t0, 12(sp)
+	addiw t0, t0, 2
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	sw t0, 12(sp)
+
+.Lif_end.1:
+	lw t0, 8(sp)
+	lw t1, 4(sp)
+	slt t0, t0, t1
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	beqz t0, .Lif_end.2
+	lw t0, 12(sp)
+	addiw t0, t0, 100
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	sw t0, 12(sp)
+
+.Lif_end.2:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	slt t0, t0, t1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	xori t0, t0, 1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	beqz t0, .Lif_end.3
+	lw t0, 12(sp)
+	addiw t0, t0, 100
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	sw t0, 12(sp)
+
+.Lif_end.3:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	xor t0, t0, t1
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	seqz t0, t0
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	beqz t0, .Lif_end.4
+	lw t0, 12(sp)
+	addiw t0, t0, 100
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	sw t0, 12(sp)
+
+.Lif_end.4:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	xor t0, t0, t1
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	snez t0, t0
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	beqz t0, .Lif_end.5
+	lw t0, 12(sp)
+	addiw t0, t0, 4
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	sw t0, 12(sp)
+
+.Lif_end.5:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	slt t0, t0, t1
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	seqz t0, t0
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	beqz t0, .Lif_end.6
+	lw t0, 12(sp)
+	addiw t0, t0, 100
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	sw t0, 12(sp)
+
+.Lif_end.6:
+	lw t0, 8(sp)
+	lw t1, 4(sp)
+	slt t0, t0, t1
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	seqz t0, t0
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	beqz t0, .Lif_end.7
+	lw t0, 12(sp)
+	addiw t0, t0, 8
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	sw t0, 12(sp)
+
+.Lif_end.7:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	xor t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	snez t0, t0
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	seqz t0, t0
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	seqz t0, t0
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	beqz t0, .Lif_end.8
+	lw t0, 12(sp)
+	addiw t0, t0, 16
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	sw t0, 12(sp)
+
+.Lif_end.8:
+	lw t0, 4(sp)
+	seqz t0, t0
+	sw t0, 104(sp)
+	lw t0, 104(sp)
+	beqz t0, .Lif_end.9
+	lw t0, 12(sp)
+	addiw t0, t0, 100
+	sw t0, 108(sp)
+	lw t0, 108(sp)
+	sw t0, 12(sp)
+
+.Lif_end.9:
+	lw t0, 4(sp)
+	seqz t0, t0
+	sw t0, 112(sp)
+	lw t0, 112(sp)
+	seqz t0, t0
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	beqz t0, .Lif_end.10
+	lw t0, 12(sp)
+	addiw t0, t0, 32
+	sw t0, 120(sp)
+	lw t0, 120(sp)
+	sw t0, 12(sp)
+
+.Lif_end.10:
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	slt t0, t0, t1
+	sw t0, 124(sp)
+	lw t0, 124(sp)
+	xori t0, t0, 1
+	sw t0, 124(sp)
+	lw t0, 124(sp)
+	seqz t0, t0
+	sw t0, 128(sp)
+	lw t0, 128(sp)
+	sw t0, 132(sp)
+	lw t0, 8(sp)
+	xori t0, t0, 4
+	sw t0, 136(sp)
+	lw t0, 136(sp)
+	seqz t0, t0
+	sw t0, 136(sp)
+	lw t0, 136(sp)
+	seqz t0, t0
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	sw t0, 144(sp)
+	lw t0, 8(sp)
+	seqz t0, t0
+	sw t0, 148(sp)
+	lw t0, 148(sp)
+	seqz t0, t0
+	sw t0, 152(sp)
+	lw t0, 152(sp)
+	sw t0, 156(sp)
+	li t0, 0
+	sw t0, 160(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 160(sp)
+	slti t0, t0, 5
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	xori t0, t0, 1
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	seqz t0, t0
+	sw t0, 168(sp)
+	lw t0, 168(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 160(sp)
+	addiw t0, t0, 1
+	sw t0, 172(sp)
+	lw t0, 172(sp)
+	sw t0, 160(sp)
+	j .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+
+.Ldo_start.11:
+	lw t0, 160(sp)
+	addiw t0, t0, -1
+	sw t0, 176(sp)
+	lw t0, 176(sp)
+	sw t0, 160(sp)
+
+.Lcontinue_loop.1:
+	lw t0, 160(sp)
+	xori t0, t0, 2
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	seqz t0, t0
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	seqz t0, t0
+	sw t0, 184(sp)
+	lw t0, 184(sp)
+	bnez t0, .Ldo_start.11
+
+.Lbreak_loop.1:
+	lw t0, 12(sp)
+	lw t1, 132(sp)
+	addw t0, t0, t1
+	sw t0, 188(sp)
+	lw t0, 144(sp)
+	li t1, 100
+	mulw t0, t0, t1
+	sw t0, 192(sp)
+	lw t0, 188(sp)
+	lw t1, 192(sp)
+	addw t0, t0, t1
+	sw t0, 196(sp)
+	lw t0, 156(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 200(sp)
+	lw t0, 196(sp)
+	lw t1, 200(sp)
+	addw t0, t0, t1
+	sw t0, 204(sp)
+	lw t0, 160(sp)
+	li t1, 64
+	mulw t0, t0, t1
+	sw t0, 208(sp)
+	lw t0, 204(sp)
+	lw t1, 208(sp)
+	addw t0, t0, t1
+	sw t0, 212(sp)
+	lw a0, 212(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
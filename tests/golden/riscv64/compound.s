@@ -0,0 +1,49 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -32
+
+	li t0, 1
+	sw t0, 0(sp)
+	li t0, 2
+	sw t0, 4(sp)
+	li t0, 10
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	lw t1, 4(sp)
+	addw t0, t0, t1
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	sw t0, 4(sp)
+	li t0, 100
+	sw t0, 16(sp)
+	lw t0, 4(sp)
+	lw t1, 16(sp)
+	addw t0, t0, t1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	lw t1, 8(sp)
+	addw t0, t0, t1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	lw t1, 0(sp)
+	addw t0, t0, t1
+	sw t0, 28(sp)
+	lw a0, 28(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,55 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -16
+
+	li t0, 1
+	sw t0, 0(sp)
+	li t0, 0
+	sw t0, 4(sp)
+	lw t0, 0(sp)
+	xori t0, t0, 2
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	seqz t0, t0
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	beqz t0, .Lif_else.0
+	li t0, 1
+	sw t0, 4(sp)
+	j .Lif_end.1
+
+.Lif_else.0:
+	lw t0, 0(sp)
+	xori t0, t0, 4
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	snez t0, t0
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	beqz t0, .Lif_else.2
+	li t0, 5
+	sw t0, 4(sp)
+	j .Lif_end.3
+
+.Lif_else.2:
+	li t0, 7
+	sw t0, 4(sp)
+
+.Lif_end.3:
+
+.Lif_end.1:
+	lw a0, 4(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,179 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -144
+
+	li t0, 6
+	sw t0, 0(sp)
+	li t0, 7
+	sw t0, 4(sp)
+	li t0, 100
+	sw t0, 8(sp)
+	li t0, 0
+	sw t0, 12(sp)
+
+.Lfor_start.0:
+	lw t0, 12(sp)
+	slti t0, t0, 3
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 0(sp)
+	lw t1, 12(sp)
+	addw t0, t0, t1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 0(sp)
+	lw t0, 8(sp)
+	lw t1, 4(sp)
+	subw t0, t0, t1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 8(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 12(sp)
+	sw t0, 28(sp)
+	lw t0, 12(sp)
+	addiw t0, t0, 1
+	sw t0, 12(sp)
+	j .Lfor_start.0
+
+.Lbreak_loop.0:
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	mulw t0, t0, t1
+	sw t0, 32(sp)
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	mulw t0, t0, t1
+	sw t0, 36(sp)
+	lw t0, 32(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 44(sp)
+	li t0, 50
+	lw t1, 8(sp)
+	slt t0, t0, t1
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	beqz t0, .Lif_else.1
+	lw t0, 8(sp)
+	li t1, 7
+	remw t0, t0, t1
+	sw t0, 52(sp)
+	lw t0, 44(sp)
+	lw t1, 52(sp)
+	addw t0, t0, t1
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	sw t0, 44(sp)
+	j .Lif_end.2
+
+.Lif_else.1:
+	lw t0, 8(sp)
+	li t1, 7
+	remw t0, t0, t1
+	sw t0, 60(sp)
+	lw t0, 44(sp)
+	lw t1, 60(sp)
+	subw t0, t0, t1
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	sw t0, 44(sp)
+
+.Lif_end.2:
+	lw t0, 4(sp)
+	lw t1, 0(sp)
+	mulw t0, t0, t1
+	sw t0, 68(sp)
+	lw t0, 44(sp)
+	lw t1, 68(sp)
+	addw t0, t0, t1
+	sw t0, 72(sp)
+	lw t0, 8(sp)
+	li t1, 7
+	remw t0, t0, t1
+	sw t0, 76(sp)
+	lw t0, 72(sp)
+	lw t1, 76(sp)
+	addw t0, t0, t1
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	sw t0, 44(sp)
+	lw t0, 0(sp)
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	lw t1, 4(sp)
+	mulw t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 44(sp)
+	lw t1, 88(sp)
+	addw t0, t0, t1
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	sw t0, 44(sp)
+	li t0, 100
+	lw t1, 44(sp)
+	slt t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	beqz t0, .Lif_else.3
+	lw t0, 8(sp)
+	addiw t0, t0, -1
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	sw t0, 104(sp)
+	lw t0, 104(sp)
+	li t1, 3
+	mulw t0, t0, t1
+	sw t0, 108(sp)
+	lw t0, 104(sp)
+	li t1, 3
+	divw t0, t0, t1
+	sw t0, 112(sp)
+	lw t0, 108(sp)
+	lw t1, 112(sp)
+	addw t0, t0, t1
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	sw t0, 120(sp)
+	j .Lif_end.4
+
+.Lif_else.3:
+	lw t0, 8(sp)
+	addiw t0, t0, -1
+	sw t0, 124(sp)
+	lw t0, 124(sp)
+	li t1, 3
+	mulw t0, t0, t1
+	sw t0, 128(sp)
+	lw t0, 128(sp)
+	sw t0, 120(sp)
+
+.Lif_end.4:
+	lw t0, 44(sp)
+	lw t1, 120(sp)
+	addw t0, t0, t1
+	sw t0, 132(sp)
+	lw t0, 132(sp)
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	andi t0, t0, 255
+	sw t0, 136(sp)
+	lw a0, 136(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
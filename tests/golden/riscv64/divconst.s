@@ -0,0 +1,1209 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -848
+
+	li t0, 0
+	sw t0, 0(sp)
+	li t0, 0
+	sw t0, 4(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 4(sp)
+	slti t0, t0, 14
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	bnez t0, 1f
+	j .Lbreak_loop.0
+1:
+	li t0, 0
+	sw t0, 12(sp)
+	lw t0, 4(sp)
+	xori t0, t0, 0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	seqz t0, t0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lif_end.0
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	addiw t0, t0, -1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 12(sp)
+
+.Lif_end.0:
+	lw t0, 4(sp)
+	xori t0, t0, 1
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	seqz t0, t0
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	beqz t0, .Lif_end.1
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	sw t0, 12(sp)
+
+.Lif_end.1:
+	lw t0, 4(sp)
+	xori t0, t0, 2
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	seqz t0, t0
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	beqz t0, .Lif_end.2
+	li t0, 1000000007
+	negw t0, t0
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 12(sp)
+
+.Lif_end.2:
+	lw t0, 4(sp)
+	xori t0, t0, 3
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	seqz t0, t0
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	beqz t0, .Lif_end.3
+	li t0, 65537
+	negw t0, t0
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	sw t0, 12(sp)
+
+.Lif_end.3:
+	lw t0, 4(sp)
+	xori t0, t0, 4
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	seqz t0, t0
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	beqz t0, .Lif_end.4
+	li t0, 100
+	negw t0, t0
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	sw t0, 12(sp)
+
+.Lif_end.4:
+	lw t0, 4(sp)
+	xori t0, t0, 5
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	seqz t0, t0
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	beqz t0, .Lif_end.5
+	li t0, 7
+	negw t0, t0
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	sw t0, 12(sp)
+
+.Lif_end.5:
+	lw t0, 4(sp)
+	xori t0, t0, 6
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	seqz t0, t0
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	beqz t0, .Lif_end.6
+	li t0, 1
+	negw t0, t0
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	sw t0, 12(sp)
+
+.Lif_end.6:
+	lw t0, 4(sp)
+	xori t0, t0, 7
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	seqz t0, t0
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	beqz t0, .Lif_end.7
+	li t0, 0
+	sw t0, 12(sp)
+
+.Lif_end.7:
+	lw t0, 4(sp)
+	xori t0, t0, 8
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	seqz t0, t0
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	beqz t0, .Lif_end.8
+	li t0, 1
+	sw t0, 12(sp)
+
+.Lif_end.8:
+	lw t0, 4(sp)
+	xori t0, t0, 9
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	seqz t0, t0
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	beqz t0, .Lif_end.9
+	li t0, 7
+	sw t0, 12(sp)
+
+.Lif_end.9:
+	lw t0, 4(sp)
+	xori t0, t0, 10
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	seqz t0, t0
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	beqz t0, .Lif_end.10
+	li t0, 100
+	sw t0, 12(sp)
+
+.Lif_end.10:
+	lw t0, 4(sp)
+	xori t0, t0, 11
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	seqz t0, t0
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	beqz t0, .Lif_end.11
+	li t0, 65537
+	sw t0, 12(sp)
+
+.Lif_end.11:
+	lw t0, 4(sp)
+	xori t0, t0, 12
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	seqz t0, t0
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	beqz t0, .Lif_end.12
+	li t0, 1000000007
+	sw t0, 12(sp)
+
+.Lif_end.12:
+	lw t0, 4(sp)
+	xori t0, t0, 13
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	seqz t0, t0
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	beqz t0, .Lif_end.13
+	li t0, 2147483647
+	sw t0, 12(sp)
+
+.Lif_end.13:
+	lw t0, 4(sp)
+	lw t1, 4(sp)
+	subw t0, t0, t1
+	sw t0, 104(sp)
+	lw t0, 104(sp)
+	addiw t0, t0, 1
+	sw t0, 108(sp)
+	lw t0, 108(sp)
+	sw t0, 112(sp)
+	lw t0, 112(sp)
+	addiw t0, t0, 1
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	sw t0, 120(sp)
+	lw t0, 112(sp)
+	addiw t0, t0, 2
+	sw t0, 124(sp)
+	lw t0, 124(sp)
+	sw t0, 128(sp)
+	lw t0, 120(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 132(sp)
+	lw t0, 132(sp)
+	sw t0, 136(sp)
+	lw t0, 128(sp)
+	addiw t0, t0, 4
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	sw t0, 144(sp)
+	lw t0, 128(sp)
+	addiw t0, t0, 7
+	sw t0, 148(sp)
+	lw t0, 148(sp)
+	sw t0, 152(sp)
+	lw t0, 12(sp)
+	li t1, 1
+	divw t0, t0, t1
+	sw t0, 156(sp)
+	lw t0, 12(sp)
+	lw t1, 112(sp)
+	divw t0, t0, t1
+	sw t0, 160(sp)
+	lw t0, 156(sp)
+	lw t1, 160(sp)
+	xor t0, t0, t1
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	snez t0, t0
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	beqz t0, .Lif_end.14
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 168(sp)
+	lw t0, 168(sp)
+	sw t0, 0(sp)
+
+.Lif_end.14:
+	lw t0, 12(sp)
+	li t1, 1
+	remw t0, t0, t1
+	sw t0, 172(sp)
+	lw t0, 12(sp)
+	lw t1, 112(sp)
+	remw t0, t0, t1
+	sw t0, 176(sp)
+	lw t0, 172(sp)
+	lw t1, 176(sp)
+	xor t0, t0, t1
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	snez t0, t0
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	beqz t0, .Lif_end.15
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 184(sp)
+	lw t0, 184(sp)
+	sw t0, 0(sp)
+
+.Lif_end.15:
+	lw t0, 12(sp)
+	li t1, 2
+	divw t0, t0, t1
+	sw t0, 188(sp)
+	lw t0, 12(sp)
+	lw t1, 120(sp)
+	divw t0, t0, t1
+	sw t0, 192(sp)
+	lw t0, 188(sp)
+	lw t1, 192(sp)
+	xor t0, t0, t1
+	sw t0, 196(sp)
+	lw t0, 196(sp)
+	snez t0, t0
+	sw t0, 196(sp)
+	lw t0, 196(sp)
+	beqz t0, .Lif_end.16
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 200(sp)
+	lw t0, 200(sp)
+	sw t0, 0(sp)
+
+.Lif_end.16:
+	lw t0, 12(sp)
+	li t1, 2
+	remw t0, t0, t1
+	sw t0, 204(sp)
+	lw t0, 12(sp)
+	lw t1, 120(sp)
+	remw t0, t0, t1
+	sw t0, 208(sp)
+	lw t0, 204(sp)
+	lw t1, 208(sp)
+	xor t0, t0, t1
+	sw t0, 212(sp)
+	lw t0, 212(sp)
+	snez t0, t0
+	sw t0, 212(sp)
+	lw t0, 212(sp)
+	beqz t0, .Lif_end.17
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 216(sp)
+	lw t0, 216(sp)
+	sw t0, 0(sp)
+
+.Lif_end.17:
+	li t0, 2
+	negw t0, t0
+	sw t0, 220(sp)
+	lw t0, 12(sp)
+	lw t1, 220(sp)
+	divw t0, t0, t1
+	sw t0, 224(sp)
+	lw t0, 120(sp)
+	negw t0, t0
+	sw t0, 228(sp)
+	lw t0, 12(sp)
+	lw t1, 228(sp)
+	divw t0, t0, t1
+	sw t0, 232(sp)
+	lw t0, 224(sp)
+	lw t1, 232(sp)
+	xor t0, t0, t1
+	sw t0, 236(sp)
+	lw t0, 236(sp)
+	snez t0, t0
+	sw t0, 236(sp)
+	lw t0, 236(sp)
+	beqz t0, .Lif_end.18
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 240(sp)
+	lw t0, 240(sp)
+	sw t0, 0(sp)
+
+.Lif_end.18:
+	li t0, 2
+	negw t0, t0
+	sw t0, 244(sp)
+	lw t0, 12(sp)
+	lw t1, 244(sp)
+	remw t0, t0, t1
+	sw t0, 248(sp)
+	lw t0, 120(sp)
+	negw t0, t0
+	sw t0, 252(sp)
+	lw t0, 12(sp)
+	lw t1, 252(sp)
+	remw t0, t0, t1
+	sw t0, 256(sp)
+	lw t0, 248(sp)
+	lw t1, 256(sp)
+	xor t0, t0, t1
+	sw t0, 260(sp)
+	lw t0, 260(sp)
+	snez t0, t0
+	sw t0, 260(sp)
+	lw t0, 260(sp)
+	beqz t0, .Lif_end.19
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 264(sp)
+	lw t0, 264(sp)
+	sw t0, 0(sp)
+
+.Lif_end.19:
+	lw t0, 12(sp)
+	li t1, 3
+	divw t0, t0, t1
+	sw t0, 268(sp)
+	lw t0, 12(sp)
+	lw t1, 128(sp)
+	divw t0, t0, t1
+	sw t0, 272(sp)
+	lw t0, 268(sp)
+	lw t1, 272(sp)
+	xor t0, t0, t1
+	sw t0, 276(sp)
+	lw t0, 276(sp)
+	snez t0, t0
+	sw t0, 276(sp)
+	lw t0, 276(sp)
+	beqz t0, .Lif_end.20
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 280(sp)
+	lw t0, 280(sp)
+	sw t0, 0(sp)
+
+.Lif_end.20:
+	lw t0, 12(sp)
+	li t1, 3
+	remw t0, t0, t1
+	sw t0, 284(sp)
+	lw t0, 12(sp)
+	lw t1, 128(sp)
+	remw t0, t0, t1
+	sw t0, 288(sp)
+	lw t0, 284(sp)
+	lw t1, 288(sp)
+	xor t0, t0, t1
+	sw t0, 292(sp)
+	lw t0, 292(sp)
+	snez t0, t0
+	sw t0, 292(sp)
+	lw t0, 292(sp)
+	beqz t0, .Lif_end.21
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 296(sp)
+	lw t0, 296(sp)
+	sw t0, 0(sp)
+
+.Lif_end.21:
+	li t0, 3
+	negw t0, t0
+	sw t0, 300(sp)
+	lw t0, 12(sp)
+	lw t1, 300(sp)
+	divw t0, t0, t1
+	sw t0, 304(sp)
+	lw t0, 128(sp)
+	negw t0, t0
+	sw t0, 308(sp)
+	lw t0, 12(sp)
+	lw t1, 308(sp)
+	divw t0, t0, t1
+	sw t0, 312(sp)
+	lw t0, 304(sp)
+	lw t1, 312(sp)
+	xor t0, t0, t1
+	sw t0, 316(sp)
+	lw t0, 316(sp)
+	snez t0, t0
+	sw t0, 316(sp)
+	lw t0, 316(sp)
+	beqz t0, .Lif_end.22
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 320(sp)
+	lw t0, 320(sp)
+	sw t0, 0(sp)
+
+.Lif_end.22:
+	li t0, 3
+	negw t0, t0
+	sw t0, 324(sp)
+	lw t0, 12(sp)
+	lw t1, 324(sp)
+	remw t0, t0, t1
+	sw t0, 328(sp)
+	lw t0, 128(sp)
+	negw t0, t0
+	sw t0, 332(sp)
+	lw t0, 12(sp)
+	lw t1, 332(sp)
+	remw t0, t0, t1
+	sw t0, 336(sp)
+	lw t0, 328(sp)
+	lw t1, 336(sp)
+	xor t0, t0, t1
+	sw t0, 340(sp)
+	lw t0, 340(sp)
+	snez t0, t0
+	sw t0, 340(sp)
+	lw t0, 340(sp)
+	beqz t0, .Lif_end.23
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 344(sp)
+	lw t0, 344(sp)
+	sw t0, 0(sp)
+
+.Lif_end.23:
+	lw t0, 12(sp)
+	li t1, 4
+	divw t0, t0, t1
+	sw t0, 348(sp)
+	lw t0, 12(sp)
+	lw t1, 136(sp)
+	divw t0, t0, t1
+	sw t0, 352(sp)
+	lw t0, 348(sp)
+	lw t1, 352(sp)
+	xor t0, t0, t1
+	sw t0, 356(sp)
+	lw t0, 356(sp)
+	snez t0, t0
+	sw t0, 356(sp)
+	lw t0, 356(sp)
+	beqz t0, .Lif_end.24
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 360(sp)
+	lw t0, 360(sp)
+	sw t0, 0(sp)
+
+.Lif_end.24:
+	lw t0, 12(sp)
+	li t1, 4
+	remw t0, t0, t1
+	sw t0, 364(sp)
+	lw t0, 12(sp)
+	lw t1, 136(sp)
+	remw t0, t0, t1
+	sw t0, 368(sp)
+	lw t0, 364(sp)
+	lw t1, 368(sp)
+	xor t0, t0, t1
+	sw t0, 372(sp)
+	lw t0, 372(sp)
+	snez t0, t0
+	sw t0, 372(sp)
+	lw t0, 372(sp)
+	beqz t0, .Lif_end.25
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 376(sp)
+	lw t0, 376(sp)
+	sw t0, 0(sp)
+
+.Lif_end.25:
+	lw t0, 12(sp)
+	li t1, 7
+	divw t0, t0, t1
+	sw t0, 380(sp)
+	lw t0, 12(sp)
+	lw t1, 144(sp)
+	divw t0, t0, t1
+	sw t0, 384(sp)
+	lw t0, 380(sp)
+	lw t1, 384(sp)
+	xor t0, t0, t1
+	sw t0, 388(sp)
+	lw t0, 388(sp)
+	snez t0, t0
+	sw t0, 388(sp)
+	lw t0, 388(sp)
+	beqz t0, .Lif_end.26
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 392(sp)
+	lw t0, 392(sp)
+	sw t0, 0(sp)
+
+.Lif_end.26:
+	lw t0, 12(sp)
+	li t1, 7
+	remw t0, t0, t1
+	sw t0, 396(sp)
+	lw t0, 12(sp)
+	lw t1, 144(sp)
+	remw t0, t0, t1
+	sw t0, 400(sp)
+	lw t0, 396(sp)
+	lw t1, 400(sp)
+	xor t0, t0, t1
+	sw t0, 404(sp)
+	lw t0, 404(sp)
+	snez t0, t0
+	sw t0, 404(sp)
+	lw t0, 404(sp)
+	beqz t0, .Lif_end.27
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 408(sp)
+	lw t0, 408(sp)
+	sw t0, 0(sp)
+
+.Lif_end.27:
+	li t0, 7
+	negw t0, t0
+	sw t0, 412(sp)
+	lw t0, 12(sp)
+	lw t1, 412(sp)
+	divw t0, t0, t1
+	sw t0, 416(sp)
+	lw t0, 144(sp)
+	negw t0, t0
+	sw t0, 420(sp)
+	lw t0, 12(sp)
+	lw t1, 420(sp)
+	divw t0, t0, t1
+	sw t0, 424(sp)
+	lw t0, 416(sp)
+	lw t1, 424(sp)
+	xor t0, t0, t1
+	sw t0, 428(sp)
+	lw t0, 428(sp)
+	snez t0, t0
+	sw t0, 428(sp)
+	lw t0, 428(sp)
+	beqz t0, .Lif_end.28
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 432(sp)
+	lw t0, 432(sp)
+	sw t0, 0(sp)
+
+.Lif_end.28:
+	li t0, 7
+	negw t0, t0
+	sw t0, 436(sp)
+	lw t0, 12(sp)
+	lw t1, 436(sp)
+	remw t0, t0, t1
+	sw t0, 440(sp)
+	lw t0, 144(sp)
+	negw t0, t0
+	sw t0, 444(sp)
+	lw t0, 12(sp)
+	lw t1, 444(sp)
+	remw t0, t0, t1
+	sw t0, 448(sp)
+	lw t0, 440(sp)
+	lw t1, 448(sp)
+	xor t0, t0, t1
+	sw t0, 452(sp)
+	lw t0, 452(sp)
+	snez t0, t0
+	sw t0, 452(sp)
+	lw t0, 452(sp)
+	beqz t0, .Lif_end.29
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 456(sp)
+	lw t0, 456(sp)
+	sw t0, 0(sp)
+
+.Lif_end.29:
+	lw t0, 12(sp)
+	li t1, 10
+	divw t0, t0, t1
+	sw t0, 460(sp)
+	lw t0, 12(sp)
+	lw t1, 152(sp)
+	divw t0, t0, t1
+	sw t0, 464(sp)
+	lw t0, 460(sp)
+	lw t1, 464(sp)
+	xor t0, t0, t1
+	sw t0, 468(sp)
+	lw t0, 468(sp)
+	snez t0, t0
+	sw t0, 468(sp)
+	lw t0, 468(sp)
+	beqz t0, .Lif_end.30
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 472(sp)
+	lw t0, 472(sp)
+	sw t0, 0(sp)
+
+.Lif_end.30:
+	lw t0, 12(sp)
+	li t1, 10
+	remw t0, t0, t1
+	sw t0, 476(sp)
+	lw t0, 12(sp)
+	lw t1, 152(sp)
+	remw t0, t0, t1
+	sw t0, 480(sp)
+	lw t0, 476(sp)
+	lw t1, 480(sp)
+	xor t0, t0, t1
+	sw t0, 484(sp)
+	lw t0, 484(sp)
+	snez t0, t0
+	sw t0, 484(sp)
+	lw t0, 484(sp)
+	beqz t0, .Lif_end.31
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 488(sp)
+	lw t0, 488(sp)
+	sw t0, 0(sp)
+
+.Lif_end.31:
+	lw t0, 12(sp)
+	li t1, 1024
+	divw t0, t0, t1
+	sw t0, 492(sp)
+	lw t0, 136(sp)
+	li t1, 256
+	mulw t0, t0, t1
+	sw t0, 496(sp)
+	lw t0, 12(sp)
+	lw t1, 496(sp)
+	divw t0, t0, t1
+	sw t0, 500(sp)
+	lw t0, 492(sp)
+	lw t1, 500(sp)
+	xor t0, t0, t1
+	sw t0, 504(sp)
+	lw t0, 504(sp)
+	snez t0, t0
+	sw t0, 504(sp)
+	lw t0, 504(sp)
+	beqz t0, .Lif_end.32
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 508(sp)
+	lw t0, 508(sp)
+	sw t0, 0(sp)
+
+.Lif_end.32:
+	lw t0, 12(sp)
+	li t1, 1024
+	remw t0, t0, t1
+	sw t0, 512(sp)
+	lw t0, 136(sp)
+	li t1, 256
+	mulw t0, t0, t1
+	sw t0, 516(sp)
+	lw t0, 12(sp)
+	lw t1, 516(sp)
+	remw t0, t0, t1
+	sw t0, 520(sp)
+	lw t0, 512(sp)
+	lw t1, 520(sp)
+	xor t0, t0, t1
+	sw t0, 524(sp)
+	lw t0, 524(sp)
+	snez t0, t0
+	sw t0, 524(sp)
+	lw t0, 524(sp)
+	beqz t0, .Lif_end.33
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 528(sp)
+	lw t0, 528(sp)
+	sw t0, 0(sp)
+
+.Lif_end.33:
+	li t0, 1024
+	negw t0, t0
+	sw t0, 532(sp)
+	lw t0, 12(sp)
+	lw t1, 532(sp)
+	divw t0, t0, t1
+	sw t0, 536(sp)
+	lw t0, 136(sp)
+	li t1, 256
+	mulw t0, t0, t1
+	sw t0, 540(sp)
+	lw t0, 540(sp)
+	negw t0, t0
+	sw t0, 544(sp)
+	lw t0, 12(sp)
+	lw t1, 544(sp)
+	divw t0, t0, t1
+	sw t0, 548(sp)
+	lw t0, 536(sp)
+	lw t1, 548(sp)
+	xor t0, t0, t1
+	sw t0, 552(sp)
+	lw t0, 552(sp)
+	snez t0, t0
+	sw t0, 552(sp)
+	lw t0, 552(sp)
+	beqz t0, .Lif_end.34
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 556(sp)
+	lw t0, 556(sp)
+	sw t0, 0(sp)
+
+.Lif_end.34:
+	li t0, 1024
+	negw t0, t0
+	sw t0, 560(sp)
+	lw t0, 12(sp)
+	lw t1, 560(sp)
+	remw t0, t0, t1
+	sw t0, 564(sp)
+	lw t0, 136(sp)
+	li t1, 256
+	mulw t0, t0, t1
+	sw t0, 568(sp)
+	lw t0, 568(sp)
+	negw t0, t0
+	sw t0, 572(sp)
+	lw t0, 12(sp)
+	lw t1, 572(sp)
+	remw t0, t0, t1
+	sw t0, 576(sp)
+	lw t0, 564(sp)
+	lw t1, 576(sp)
+	xor t0, t0, t1
+	sw t0, 580(sp)
+	lw t0, 580(sp)
+	snez t0, t0
+	sw t0, 580(sp)
+	lw t0, 580(sp)
+	beqz t0, .Lif_end.35
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 584(sp)
+	lw t0, 584(sp)
+	sw t0, 0(sp)
+
+.Lif_end.35:
+	lw t0, 12(sp)
+	li t1, 641
+	divw t0, t0, t1
+	sw t0, 588(sp)
+	lw t0, 112(sp)
+	li t1, 641
+	mulw t0, t0, t1
+	sw t0, 592(sp)
+	lw t0, 12(sp)
+	lw t1, 592(sp)
+	divw t0, t0, t1
+	sw t0, 596(sp)
+	lw t0, 588(sp)
+	lw t1, 596(sp)
+	xor t0, t0, t1
+	sw t0, 600(sp)
+	lw t0, 600(sp)
+	snez t0, t0
+	sw t0, 600(sp)
+	lw t0, 600(sp)
+	beqz t0, .Lif_end.36
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 604(sp)
+	lw t0, 604(sp)
+	sw t0, 0(sp)
+
+.Lif_end.36:
+	lw t0, 12(sp)
+	li t1, 641
+	remw t0, t0, t1
+	sw t0, 608(sp)
+	lw t0, 112(sp)
+	li t1, 641
+	mulw t0, t0, t1
+	sw t0, 612(sp)
+	lw t0, 12(sp)
+	lw t1, 612(sp)
+	remw t0, t0, t1
+	sw t0, 616(sp)
+	lw t0, 608(sp)
+	lw t1, 616(sp)
+	xor t0, t0, t1
+	sw t0, 620(sp)
+	lw t0, 620(sp)
+	snez t0, t0
+	sw t0, 620(sp)
+	lw t0, 620(sp)
+	beqz t0, .Lif_end.37
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 624(sp)
+	lw t0, 624(sp)
+	sw t0, 0(sp)
+
+.Lif_end.37:
+	lw t0, 12(sp)
+	li t1, 1000
+	divw t0, t0, t1
+	sw t0, 628(sp)
+	lw t0, 152(sp)
+	li t1, 100
+	mulw t0, t0, t1
+	sw t0, 632(sp)
+	lw t0, 12(sp)
+	lw t1, 632(sp)
+	divw t0, t0, t1
+	sw t0, 636(sp)
+	lw t0, 628(sp)
+	lw t1, 636(sp)
+	xor t0, t0, t1
+	sw t0, 640(sp)
+	lw t0, 640(sp)
+	snez t0, t0
+	sw t0, 640(sp)
+	lw t0, 640(sp)
+	beqz t0, .Lif_end.38
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 644(sp)
+	lw t0, 644(sp)
+	sw t0, 0(sp)
+
+.Lif_end.38:
+	lw t0, 12(sp)
+	li t1, 1000
+	remw t0, t0, t1
+	sw t0, 648(sp)
+	lw t0, 152(sp)
+	li t1, 100
+	mulw t0, t0, t1
+	sw t0, 652(sp)
+	lw t0, 12(sp)
+	lw t1, 652(sp)
+	remw t0, t0, t1
+	sw t0, 656(sp)
+	lw t0, 648(sp)
+	lw t1, 656(sp)
+	xor t0, t0, t1
+	sw t0, 660(sp)
+	lw t0, 660(sp)
+	snez t0, t0
+	sw t0, 660(sp)
+	lw t0, 660(sp)
+	beqz t0, .Lif_end.39
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 664(sp)
+	lw t0, 664(sp)
+	sw t0, 0(sp)
+
+.Lif_end.39:
+	lw t0, 12(sp)
+	li t1, 65537
+	divw t0, t0, t1
+	sw t0, 668(sp)
+	lw t0, 112(sp)
+	li t1, 65537
+	mulw t0, t0, t1
+	sw t0, 672(sp)
+	lw t0, 12(sp)
+	lw t1, 672(sp)
+	divw t0, t0, t1
+	sw t0, 676(sp)
+	lw t0, 668(sp)
+	lw t1, 676(sp)
+	xor t0, t0, t1
+	sw t0, 680(sp)
+	lw t0, 680(sp)
+	snez t0, t0
+	sw t0, 680(sp)
+	lw t0, 680(sp)
+	beqz t0, .Lif_end.40
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 684(sp)
+	lw t0, 684(sp)
+	sw t0, 0(sp)
+
+.Lif_end.40:
+	lw t0, 12(sp)
+	li t1, 65537
+	remw t0, t0, t1
+	sw t0, 688(sp)
+	lw t0, 112(sp)
+	li t1, 65537
+	mulw t0, t0, t1
+	sw t0, 692(sp)
+	lw t0, 12(sp)
+	lw t1, 692(sp)
+	remw t0, t0, t1
+	sw t0, 696(sp)
+	lw t0, 688(sp)
+	lw t1, 696(sp)
+	xor t0, t0, t1
+	sw t0, 700(sp)
+	lw t0, 700(sp)
+	snez t0, t0
+	sw t0, 700(sp)
+	lw t0, 700(sp)
+	beqz t0, .Lif_end.41
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 704(sp)
+	lw t0, 704(sp)
+	sw t0, 0(sp)
+
+.Lif_end.41:
+	lw t0, 12(sp)
+	li t1, 1073741824
+	divw t0, t0, t1
+	sw t0, 708(sp)
+	lw t0, 112(sp)
+	li t1, 1073741824
+	mulw t0, t0, t1
+	sw t0, 712(sp)
+	lw t0, 12(sp)
+	lw t1, 712(sp)
+	divw t0, t0, t1
+	sw t0, 716(sp)
+	lw t0, 708(sp)
+	lw t1, 716(sp)
+	xor t0, t0, t1
+	sw t0, 720(sp)
+	lw t0, 720(sp)
+	snez t0, t0
+	sw t0, 720(sp)
+	lw t0, 720(sp)
+	beqz t0, .Lif_end.42
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 724(sp)
+	lw t0, 724(sp)
+	sw t0, 0(sp)
+
+.Lif_end.42:
+	lw t0, 12(sp)
+	li t1, 1073741824
+	remw t0, t0, t1
+	sw t0, 728(sp)
+	lw t0, 112(sp)
+	li t1, 1073741824
+	mulw t0, t0, t1
+	sw t0, 732(sp)
+	lw t0, 12(sp)
+	lw t1, 732(sp)
+	remw t0, t0, t1
+	sw t0, 736(sp)
+	lw t0, 728(sp)
+	lw t1, 736(sp)
+	xor t0, t0, t1
+	sw t0, 740(sp)
+	lw t0, 740(sp)
+	snez t0, t0
+	sw t0, 740(sp)
+	lw t0, 740(sp)
+	beqz t0, .Lif_end.43
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 744(sp)
+	lw t0, 744(sp)
+	sw t0, 0(sp)
+
+.Lif_end.43:
+	lw t0, 12(sp)
+	li t1, 2147483647
+	divw t0, t0, t1
+	sw t0, 748(sp)
+	lw t0, 112(sp)
+	li t1, 2147483647
+	mulw t0, t0, t1
+	sw t0, 752(sp)
+	lw t0, 12(sp)
+	lw t1, 752(sp)
+	divw t0, t0, t1
+	sw t0, 756(sp)
+	lw t0, 748(sp)
+	lw t1, 756(sp)
+	xor t0, t0, t1
+	sw t0, 760(sp)
+	lw t0, 760(sp)
+	snez t0, t0
+	sw t0, 760(sp)
+	lw t0, 760(sp)
+	beqz t0, .Lif_end.44
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 764(sp)
+	lw t0, 764(sp)
+	sw t0, 0(sp)
+
+.Lif_end.44:
+	lw t0, 12(sp)
+	li t1, 2147483647
+	remw t0, t0, t1
+	sw t0, 768(sp)
+	lw t0, 112(sp)
+	li t1, 2147483647
+	mulw t0, t0, t1
+	sw t0, 772(sp)
+	lw t0, 12(sp)
+	lw t1, 772(sp)
+	remw t0, t0, t1
+	sw t0, 776(sp)
+	lw t0, 768(sp)
+	lw t1, 776(sp)
+	xor t0, t0, t1
+	sw t0, 780(sp)
+	lw t0, 780(sp)
+	snez t0, t0
+	sw t0, 780(sp)
+	lw t0, 780(sp)
+	beqz t0, .Lif_end.45
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 784(sp)
+	lw t0, 784(sp)
+	sw t0, 0(sp)
+
+.Lif_end.45:
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 788(sp)
+	lw t0, 12(sp)
+	lw t1, 788(sp)
+	divw t0, t0, t1
+	sw t0, 792(sp)
+	lw t0, 112(sp)
+	li t1, 2147483647
+	mulw t0, t0, t1
+	sw t0, 796(sp)
+	lw t0, 796(sp)
+	negw t0, t0
+	sw t0, 800(sp)
+	lw t0, 12(sp)
+	lw t1, 800(sp)
+	divw t0, t0, t1
+	sw t0, 804(sp)
+	lw t0, 792(sp)
+	lw t1, 804(sp)
+	xor t0, t0, t1
+	sw t0, 808(sp)
+	lw t0, 808(sp)
+	snez t0, t0
+	sw t0, 808(sp)
+	lw t0, 808(sp)
+	beqz t0, .Lif_end.46
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 812(sp)
+	lw t0, 812(sp)
+	sw t0, 0(sp)
+
+.Lif_end.46:
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 816(sp)
+	lw t0, 12(sp)
+	lw t1, 816(sp)
+	remw t0, t0, t1
+	sw t0, 820(sp)
+	lw t0, 112(sp)
+	li t1, 2147483647
+	mulw t0, t0, t1
+	sw t0, 824(sp)
+	lw t0, 824(sp)
+	negw t0, t0
+	sw t0, 828(sp)
+	lw t0, 12(sp)
+	lw t1, 828(sp)
+	remw t0, t0, t1
+	sw t0, 832(sp)
+	lw t0, 820(sp)
+	lw t1, 832(sp)
+	xor t0, t0, t1
+	sw t0, 836(sp)
+	lw t0, 836(sp)
+	snez t0, t0
+	sw t0, 836(sp)
+	lw t0, 836(sp)
+	beqz t0, .Lif_end.47
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 840(sp)
+	lw t0, 840(sp)
+	sw t0, 0(sp)
+
+.Lif_end.47:
+	lw t0, 4(sp)
+	addiw t0, t0, 1
+	sw t0, 844(sp)
+	lw t0, 844(sp)
+	sw t0, 4(sp)
+	j .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	lw a0, 0(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
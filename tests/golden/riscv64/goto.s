@@ -0,0 +1,26 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -16
+
+	li t0, 5
+	sw t0, 0(sp)
+	j .Llabel.end.0
+	li t0, 99
+	sw t0, 0(sp)
+
+.Llabel.end.0:
+	lw a0, 0(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
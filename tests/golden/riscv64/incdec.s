@@ -0,0 +1,39 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -32
+
+	li t0, 5
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	sw t0, 4(sp)
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 0(sp)
+	lw t0, 4(sp)
+	sw t0, 8(sp)
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	sw t0, 0(sp)
+	lw t0, 12(sp)
+	sw t0, 16(sp)
+	lw t0, 8(sp)
+	lw t1, 16(sp)
+	addw t0, t0, t1
+	sw t0, 20(sp)
+	lw a0, 20(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,154 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -112
+
+	li t0, 3
+	sw t0, 0(sp)
+	li t0, 4
+	sw t0, 4(sp)
+	li t0, 0
+	sw t0, 8(sp)
+
+.Lfor_start.0:
+	lw t0, 8(sp)
+	slti t0, t0, 2
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 0(sp)
+	lw t1, 8(sp)
+	addw t0, t0, t1
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	sw t0, 0(sp)
+	lw t0, 4(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 4(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 8(sp)
+	sw t0, 24(sp)
+	lw t0, 8(sp)
+	addiw t0, t0, 1
+	sw t0, 8(sp)
+	j .Lfor_start.0
+
+.Lbreak_loop.0:
+	li t0, 0
+	sw t0, 28(sp)
+	li t0, 0
+	sw t0, 32(sp)
+
+.Lfor_start.1:
+	lw t0, 32(sp)
+	slti t0, t0, 10
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	beqz t0, .Lbreak_loop.1
+	li t0, 0
+	sw t0, 40(sp)
+
+.Lfor_start.2:
+	lw t0, 40(sp)
+	slti t0, t0, 5
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	beqz t0, .Lbreak_loop.2
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	mulw t0, t0, t1
+	sw t0, 48(sp)
+	lw t0, 28(sp)
+	lw t1, 48(sp)
+	addw t0, t0, t1
+	sw t0, 52(sp)
+	lw t0, 0(sp)
+	slliw t0, t0, 2
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	li t1, 3
+	divw t0, t0, t1
+	sw t0, 60(sp)
+	lw t0, 52(sp)
+	lw t1, 60(sp)
+	addw t0, t0, t1
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	lw t1, 32(sp)
+	addw t0, t0, t1
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	sw t0, 28(sp)
+
+.Lcontinue_loop.2:
+	lw t0, 40(sp)
+	sw t0, 72(sp)
+	lw t0, 40(sp)
+	addiw t0, t0, 1
+	sw t0, 40(sp)
+	j .Lfor_start.2
+
+.Lbreak_loop.2:
+
+.Lcontinue_loop.1:
+	lw t0, 32(sp)
+	sw t0, 76(sp)
+	lw t0, 32(sp)
+	addiw t0, t0, 1
+	sw t0, 32(sp)
+	j .Lfor_start.1
+
+.Lbreak_loop.1:
+	li t0, 7
+	sw t0, 80(sp)
+
+.Lcontinue_loop.3:
+	li t0, 0
+	lw t1, 80(sp)
+	slt t0, t0, t1
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	beqz t0, .Lbreak_loop.3
+	lw t0, 0(sp)
+	li t1, 100
+	mulw t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	li t1, 9
+	remw t0, t0, t1
+	sw t0, 92(sp)
+	lw t0, 28(sp)
+	lw t1, 92(sp)
+	addw t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	sw t0, 28(sp)
+	lw t0, 80(sp)
+	sw t0, 100(sp)
+	lw t0, 80(sp)
+	addiw t0, t0, -1
+	sw t0, 80(sp)
+	j .Lcontinue_loop.3
+
+.Lbreak_loop.3:
+	lw t0, 28(sp)
+	andi t0, t0, 255
+	sw t0, 104(sp)
+	lw a0, 104(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
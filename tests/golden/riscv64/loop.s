@@ -0,0 +1,125 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -64
+
+	li t0, 0
+	sw t0, 0(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 0(sp)
+	slti t0, t0, 10
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	xori t0, t0, 5
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	seqz t0, t0
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	beqz t0, .Lif_end.0
+	j .Lcontinue_loop.0
+
+.Lif_end.0:
+	lw t0, 0(sp)
+	xori t0, t0, 8
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	seqz t0, t0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lif_end.1
+	j .Lbreak_loop.0
+
+.Lif_end.1:
+	j .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	li t0, 0
+	sw t0, 20(sp)
+
+.Ldo_start.2:
+	lw t0, 20(sp)
+	addiw t0, t0, 1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 20(sp)
+
+.Lcontinue_loop.1:
+	lw t0, 20(sp)
+	slti t0, t0, 5
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	bnez t0, .Ldo_start.2
+
+.Lbreak_loop.1:
+	li t0, 0
+	sw t0, 32(sp)
+	li t0, 0
+	sw t0, 36(sp)
+
+.Lfor_start.3:
+	lw t0, 36(sp)
+	slti t0, t0, 10
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	beqz t0, .Lbreak_loop.2
+	lw t0, 36(sp)
+	xori t0, t0, 3
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	seqz t0, t0
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	beqz t0, .Lif_end.4
+	j .Lcontinue_loop.2
+
+.Lif_end.4:
+	lw t0, 36(sp)
+	xori t0, t0, 7
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	seqz t0, t0
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	beqz t0, .Lif_end.5
+	j .Lbreak_loop.2
+
+.Lif_end.5:
+	lw t0, 32(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	sw t0, 32(sp)
+
+.Lcontinue_loop.2:
+	lw t0, 36(sp)
+	addiw t0, t0, 1
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	sw t0, 36(sp)
+	j .Lfor_start.3
+
+.Lbreak_loop.2:
+	lw a0, 32(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,709 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -496
+
+	li t0, 0
+	sw t0, 0(sp)
+	li t0, 0
+	sw t0, 4(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 4(sp)
+	slti t0, t0, 10
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	beqz t0, .Lbreak_loop.0
+	li t0, 0
+	sw t0, 12(sp)
+	lw t0, 4(sp)
+	xori t0, t0, 0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	seqz t0, t0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lif_end.0
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	addiw t0, t0, -1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 12(sp)
+
+.Lif_end.0:
+	lw t0, 4(sp)
+	xori t0, t0, 1
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	seqz t0, t0
+	sw t0, 28(sp)
+	lw t0, 28(sp)
+	beqz t0, .Lif_end.1
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	sw t0, 12(sp)
+
+.Lif_end.1:
+	lw t0, 4(sp)
+	xori t0, t0, 2
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	seqz t0, t0
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	beqz t0, .Lif_end.2
+	li t0, 123456
+	negw t0, t0
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 12(sp)
+
+.Lif_end.2:
+	lw t0, 4(sp)
+	xori t0, t0, 3
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	seqz t0, t0
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	beqz t0, .Lif_end.3
+	li t0, 1
+	negw t0, t0
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	sw t0, 12(sp)
+
+.Lif_end.3:
+	lw t0, 4(sp)
+	xori t0, t0, 4
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	seqz t0, t0
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	beqz t0, .Lif_end.4
+	li t0, 0
+	sw t0, 12(sp)
+
+.Lif_end.4:
+	lw t0, 4(sp)
+	xori t0, t0, 5
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	seqz t0, t0
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	beqz t0, .Lif_end.5
+	li t0, 1
+	sw t0, 12(sp)
+
+.Lif_end.5:
+	lw t0, 4(sp)
+	xori t0, t0, 6
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	seqz t0, t0
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	beqz t0, .Lif_end.6
+	li t0, 3
+	sw t0, 12(sp)
+
+.Lif_end.6:
+	lw t0, 4(sp)
+	xori t0, t0, 7
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	seqz t0, t0
+	sw t0, 64(sp)
+	lw t0, 64(sp)
+	beqz t0, .Lif_end.7
+	li t0, 123456
+	sw t0, 12(sp)
+
+.Lif_end.7:
+	lw t0, 4(sp)
+	xori t0, t0, 8
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	seqz t0, t0
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	beqz t0, .Lif_end.8
+	li t0, 1073741824
+	sw t0, 12(sp)
+
+.Lif_end.8:
+	lw t0, 4(sp)
+	xori t0, t0, 9
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	seqz t0, t0
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	beqz t0, .Lif_end.9
+	li t0, 2147483647
+	sw t0, 12(sp)
+
+.Lif_end.9:
+	lw t0, 4(sp)
+	lw t1, 4(sp)
+	subw t0, t0, t1
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	addiw t0, t0, 1
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	sw t0, 84(sp)
+	lw t0, 12(sp)
+	li t1, 0
+	mulw t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 84(sp)
+	addiw t0, t0, -1
+	sw t0, 92(sp)
+	lw t0, 12(sp)
+	lw t1, 92(sp)
+	mulw t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 88(sp)
+	lw t1, 96(sp)
+	xor t0, t0, t1
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	snez t0, t0
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	beqz t0, .Lif_end.10
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 104(sp)
+	lw t0, 104(sp)
+	sw t0, 0(sp)
+
+.Lif_end.10:
+	lw t0, 12(sp)
+	li t1, 1
+	mulw t0, t0, t1
+	sw t0, 108(sp)
+	lw t0, 12(sp)
+	lw t1, 84(sp)
+	mulw t0, t0, t1
+	sw t0, 112(sp)
+	lw t0, 108(sp)
+	lw t1, 112(sp)
+	xor t0, t0, t1
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	snez t0, t0
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	beqz t0, .Lif_end.11
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 120(sp)
+	lw t0, 120(sp)
+	sw t0, 0(sp)
+
+.Lif_end.11:
+	li t0, 1
+	negw t0, t0
+	sw t0, 124(sp)
+	lw t0, 12(sp)
+	lw t1, 124(sp)
+	mulw t0, t0, t1
+	sw t0, 128(sp)
+	lw t0, 84(sp)
+	negw t0, t0
+	sw t0, 132(sp)
+	lw t0, 12(sp)
+	lw t1, 132(sp)
+	mulw t0, t0, t1
+	sw t0, 136(sp)
+	lw t0, 128(sp)
+	lw t1, 136(sp)
+	xor t0, t0, t1
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	snez t0, t0
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	beqz t0, .Lif_end.12
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 144(sp)
+	lw t0, 144(sp)
+	sw t0, 0(sp)
+
+.Lif_end.12:
+	lw t0, 12(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 148(sp)
+	lw t0, 84(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 152(sp)
+	lw t0, 12(sp)
+	lw t1, 152(sp)
+	mulw t0, t0, t1
+	sw t0, 156(sp)
+	lw t0, 148(sp)
+	lw t1, 156(sp)
+	xor t0, t0, t1
+	sw t0, 160(sp)
+	lw t0, 160(sp)
+	snez t0, t0
+	sw t0, 160(sp)
+	lw t0, 160(sp)
+	beqz t0, .Lif_end.13
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	sw t0, 0(sp)
+
+.Lif_end.13:
+	lw t0, 12(sp)
+	li t1, 3
+	mulw t0, t0, t1
+	sw t0, 168(sp)
+	lw t0, 84(sp)
+	li t1, 3
+	mulw t0, t0, t1
+	sw t0, 172(sp)
+	lw t0, 12(sp)
+	lw t1, 172(sp)
+	mulw t0, t0, t1
+	sw t0, 176(sp)
+	lw t0, 168(sp)
+	lw t1, 176(sp)
+	xor t0, t0, t1
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	snez t0, t0
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	beqz t0, .Lif_end.14
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 184(sp)
+	lw t0, 184(sp)
+	sw t0, 0(sp)
+
+.Lif_end.14:
+	li t0, 5
+	lw t1, 12(sp)
+	mulw t0, t0, t1
+	sw t0, 188(sp)
+	lw t0, 84(sp)
+	li t1, 5
+	mulw t0, t0, t1
+	sw t0, 192(sp)
+	lw t0, 12(sp)
+	lw t1, 192(sp)
+	mulw t0, t0, t1
+	sw t0, 196(sp)
+	lw t0, 188(sp)
+	lw t1, 196(sp)
+	xor t0, t0, t1
+	sw t0, 200(sp)
+	lw t0, 200(sp)
+	snez t0, t0
+	sw t0, 200(sp)
+	lw t0, 200(sp)
+	beqz t0, .Lif_end.15
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 204(sp)
+	lw t0, 204(sp)
+	sw t0, 0(sp)
+
+.Lif_end.15:
+	lw t0, 12(sp)
+	li t1, 6
+	mulw t0, t0, t1
+	sw t0, 208(sp)
+	lw t0, 84(sp)
+	li t1, 6
+	mulw t0, t0, t1
+	sw t0, 212(sp)
+	lw t0, 12(sp)
+	lw t1, 212(sp)
+	mulw t0, t0, t1
+	sw t0, 216(sp)
+	lw t0, 208(sp)
+	lw t1, 216(sp)
+	xor t0, t0, t1
+	sw t0, 220(sp)
+	lw t0, 220(sp)
+	snez t0, t0
+	sw t0, 220(sp)
+	lw t0, 220(sp)
+	beqz t0, .Lif_end.16
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 224(sp)
+	lw t0, 224(sp)
+	sw t0, 0(sp)
+
+.Lif_end.16:
+	lw t0, 12(sp)
+	li t1, 9
+	mulw t0, t0, t1
+	sw t0, 228(sp)
+	lw t0, 84(sp)
+	li t1, 9
+	mulw t0, t0, t1
+	sw t0, 232(sp)
+	lw t0, 12(sp)
+	lw t1, 232(sp)
+	mulw t0, t0, t1
+	sw t0, 236(sp)
+	lw t0, 228(sp)
+	lw t1, 236(sp)
+	xor t0, t0, t1
+	sw t0, 240(sp)
+	lw t0, 240(sp)
+	snez t0, t0
+	sw t0, 240(sp)
+	lw t0, 240(sp)
+	beqz t0, .Lif_end.17
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 244(sp)
+	lw t0, 244(sp)
+	sw t0, 0(sp)
+
+.Lif_end.17:
+	lw t0, 12(sp)
+	li t1, 10
+	mulw t0, t0, t1
+	sw t0, 248(sp)
+	lw t0, 84(sp)
+	li t1, 10
+	mulw t0, t0, t1
+	sw t0, 252(sp)
+	lw t0, 12(sp)
+	lw t1, 252(sp)
+	mulw t0, t0, t1
+	sw t0, 256(sp)
+	lw t0, 248(sp)
+	lw t1, 256(sp)
+	xor t0, t0, t1
+	sw t0, 260(sp)
+	lw t0, 260(sp)
+	snez t0, t0
+	sw t0, 260(sp)
+	lw t0, 260(sp)
+	beqz t0, .Lif_end.18
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 264(sp)
+	lw t0, 264(sp)
+	sw t0, 0(sp)
+
+.Lif_end.18:
+	lw t0, 12(sp)
+	li t1, 12
+	mulw t0, t0, t1
+	sw t0, 268(sp)
+	lw t0, 84(sp)
+	li t1, 12
+	mulw t0, t0, t1
+	sw t0, 272(sp)
+	lw t0, 12(sp)
+	lw t1, 272(sp)
+	mulw t0, t0, t1
+	sw t0, 276(sp)
+	lw t0, 268(sp)
+	lw t1, 276(sp)
+	xor t0, t0, t1
+	sw t0, 280(sp)
+	lw t0, 280(sp)
+	snez t0, t0
+	sw t0, 280(sp)
+	lw t0, 280(sp)
+	beqz t0, .Lif_end.19
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 284(sp)
+	lw t0, 284(sp)
+	sw t0, 0(sp)
+
+.Lif_end.19:
+	lw t0, 12(sp)
+	li t1, 16
+	mulw t0, t0, t1
+	sw t0, 288(sp)
+	lw t0, 84(sp)
+	li t1, 16
+	mulw t0, t0, t1
+	sw t0, 292(sp)
+	lw t0, 12(sp)
+	lw t1, 292(sp)
+	mulw t0, t0, t1
+	sw t0, 296(sp)
+	lw t0, 288(sp)
+	lw t1, 296(sp)
+	xor t0, t0, t1
+	sw t0, 300(sp)
+	lw t0, 300(sp)
+	snez t0, t0
+	sw t0, 300(sp)
+	lw t0, 300(sp)
+	beqz t0, .Lif_end.20
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 304(sp)
+	lw t0, 304(sp)
+	sw t0, 0(sp)
+
+.Lif_end.20:
+	li t0, 4
+	negw t0, t0
+	sw t0, 308(sp)
+	lw t0, 12(sp)
+	lw t1, 308(sp)
+	mulw t0, t0, t1
+	sw t0, 312(sp)
+	li t0, 4
+	negw t0, t0
+	sw t0, 316(sp)
+	lw t0, 84(sp)
+	lw t1, 316(sp)
+	mulw t0, t0, t1
+	sw t0, 320(sp)
+	lw t0, 12(sp)
+	lw t1, 320(sp)
+	mulw t0, t0, t1
+	sw t0, 324(sp)
+	lw t0, 312(sp)
+	lw t1, 324(sp)
+	xor t0, t0, t1
+	sw t0, 328(sp)
+	lw t0, 328(sp)
+	snez t0, t0
+	sw t0, 328(sp)
+	lw t0, 328(sp)
+	beqz t0, .Lif_end.21
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 332(sp)
+	lw t0, 332(sp)
+	sw t0, 0(sp)
+
+.Lif_end.21:
+	li t0, 9
+	negw t0, t0
+	sw t0, 336(sp)
+	lw t0, 12(sp)
+	lw t1, 336(sp)
+	mulw t0, t0, t1
+	sw t0, 340(sp)
+	li t0, 9
+	negw t0, t0
+	sw t0, 344(sp)
+	lw t0, 84(sp)
+	lw t1, 344(sp)
+	mulw t0, t0, t1
+	sw t0, 348(sp)
+	lw t0, 12(sp)
+	lw t1, 348(sp)
+	mulw t0, t0, t1
+	sw t0, 352(sp)
+	lw t0, 340(sp)
+	lw t1, 352(sp)
+	xor t0, t0, t1
+	sw t0, 356(sp)
+	lw t0, 356(sp)
+	snez t0, t0
+	sw t0, 356(sp)
+	lw t0, 356(sp)
+	beqz t0, .Lif_end.22
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 360(sp)
+	lw t0, 360(sp)
+	sw t0, 0(sp)
+
+.Lif_end.22:
+	lw t0, 12(sp)
+	li t1, 36
+	mulw t0, t0, t1
+	sw t0, 364(sp)
+	lw t0, 84(sp)
+	li t1, 36
+	mulw t0, t0, t1
+	sw t0, 368(sp)
+	lw t0, 12(sp)
+	lw t1, 368(sp)
+	mulw t0, t0, t1
+	sw t0, 372(sp)
+	lw t0, 364(sp)
+	lw t1, 372(sp)
+	xor t0, t0, t1
+	sw t0, 376(sp)
+	lw t0, 376(sp)
+	snez t0, t0
+	sw t0, 376(sp)
+	lw t0, 376(sp)
+	beqz t0, .Lif_end.23
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 380(sp)
+	lw t0, 380(sp)
+	sw t0, 0(sp)
+
+.Lif_end.23:
+	lw t0, 12(sp)
+	li t1, 72
+	mulw t0, t0, t1
+	sw t0, 384(sp)
+	lw t0, 84(sp)
+	li t1, 72
+	mulw t0, t0, t1
+	sw t0, 388(sp)
+	lw t0, 12(sp)
+	lw t1, 388(sp)
+	mulw t0, t0, t1
+	sw t0, 392(sp)
+	lw t0, 384(sp)
+	lw t1, 392(sp)
+	xor t0, t0, t1
+	sw t0, 396(sp)
+	lw t0, 396(sp)
+	snez t0, t0
+	sw t0, 396(sp)
+	lw t0, 396(sp)
+	beqz t0, .Lif_end.24
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 400(sp)
+	lw t0, 400(sp)
+	sw t0, 0(sp)
+
+.Lif_end.24:
+	lw t0, 12(sp)
+	li t1, 7
+	mulw t0, t0, t1
+	sw t0, 404(sp)
+	lw t0, 84(sp)
+	li t1, 7
+	mulw t0, t0, t1
+	sw t0, 408(sp)
+	lw t0, 12(sp)
+	lw t1, 408(sp)
+	mulw t0, t0, t1
+	sw t0, 412(sp)
+	lw t0, 404(sp)
+	lw t1, 412(sp)
+	xor t0, t0, t1
+	sw t0, 416(sp)
+	lw t0, 416(sp)
+	snez t0, t0
+	sw t0, 416(sp)
+	lw t0, 416(sp)
+	beqz t0, .Lif_end.25
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 420(sp)
+	lw t0, 420(sp)
+	sw t0, 0(sp)
+
+.Lif_end.25:
+	lw t0, 12(sp)
+	li t1, 1024
+	mulw t0, t0, t1
+	sw t0, 424(sp)
+	lw t0, 84(sp)
+	li t1, 1024
+	mulw t0, t0, t1
+	sw t0, 428(sp)
+	lw t0, 12(sp)
+	lw t1, 428(sp)
+	mulw t0, t0, t1
+	sw t0, 432(sp)
+	lw t0, 424(sp)
+	lw t1, 432(sp)
+	xor t0, t0, t1
+	sw t0, 436(sp)
+	lw t0, 436(sp)
+	snez t0, t0
+	sw t0, 436(sp)
+	lw t0, 436(sp)
+	beqz t0, .Lif_end.26
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 440(sp)
+	lw t0, 440(sp)
+	sw t0, 0(sp)
+
+.Lif_end.26:
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 444(sp)
+	lw t0, 444(sp)
+	addiw t0, t0, -1
+	sw t0, 448(sp)
+	lw t0, 12(sp)
+	lw t1, 448(sp)
+	mulw t0, t0, t1
+	sw t0, 452(sp)
+	li t0, 2147483647
+	negw t0, t0
+	sw t0, 456(sp)
+	lw t0, 456(sp)
+	addiw t0, t0, -1
+	sw t0, 460(sp)
+	lw t0, 84(sp)
+	lw t1, 460(sp)
+	mulw t0, t0, t1
+	sw t0, 464(sp)
+	lw t0, 12(sp)
+	lw t1, 464(sp)
+	mulw t0, t0, t1
+	sw t0, 468(sp)
+	lw t0, 452(sp)
+	lw t1, 468(sp)
+	xor t0, t0, t1
+	sw t0, 472(sp)
+	lw t0, 472(sp)
+	snez t0, t0
+	sw t0, 472(sp)
+	lw t0, 472(sp)
+	beqz t0, .Lif_end.27
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 476(sp)
+	lw t0, 476(sp)
+	sw t0, 0(sp)
+
+.Lif_end.27:
+	lw t0, 4(sp)
+	addiw t0, t0, 1
+	sw t0, 480(sp)
+	lw t0, 480(sp)
+	sw t0, 4(sp)
+	j .Lcontinue_loop.0
+
+.Lbreak_loop.0:
+	lw a0, 0(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,135 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -128
+
+	li t0, 0
+	sw t0, 0(sp)
+	li t0, 5
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	sw t0, 4(sp)
+	li t0, 3
+	lw t1, 4(sp)
+	slt t0, t0, t1
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	beqz t0, .Lif_else.0
+	lw t0, 0(sp)
+	addiw t0, t0, 1
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	sw t0, 0(sp)
+	j .Lif_end.1
+
+.Lif_else.0:
+
+.Lif_end.1:
+	lw t0, 4(sp)
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 24(sp)
+	li t0, 1
+	sw t0, 28(sp)
+	li t0, 2
+	sw t0, 28(sp)
+	lw t0, 20(sp)
+	lw t1, 24(sp)
+	addw t0, t0, t1
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	lw t1, 28(sp)
+	subw t0, t0, t1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	lw t1, 20(sp)
+	slt t0, t0, t1
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	beqz t0, .Lif_end.2
+	lw t0, 0(sp)
+	addiw t0, t0, 2
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	sw t0, 0(sp)
+
+.Lif_end.2:
+	li t0, 0
+	sw t0, 56(sp)
+	lw t0, 4(sp)
+	lw t1, 16(sp)
+	slt t0, t0, t1
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	sw t0, 64(sp)
+	lw t0, 16(sp)
+	lw t1, 4(sp)
+	slt t0, t0, t1
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	seqz t0, t0
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	sw t0, 76(sp)
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	addw t0, t0, t1
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	lw t1, 16(sp)
+	addw t0, t0, t1
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	lw t1, 20(sp)
+	addw t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	lw t1, 24(sp)
+	addw t0, t0, t1
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	lw t1, 28(sp)
+	addw t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	lw t1, 44(sp)
+	addw t0, t0, t1
+	sw t0, 104(sp)
+	lw t0, 104(sp)
+	lw t1, 56(sp)
+	addw t0, t0, t1
+	sw t0, 108(sp)
+	lw t0, 108(sp)
+	lw t1, 64(sp)
+	addw t0, t0, t1
+	sw t0, 112(sp)
+	lw t0, 112(sp)
+	lw t1, 76(sp)
+	addw t0, t0, t1
+	sw t0, 116(sp)
+	lw a0, 116(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,226 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -208
+
+	li t0, 1
+	sw t0, 0(sp)
+	li t0, 2
+	sw t0, 4(sp)
+	li t0, 3
+	sw t0, 8(sp)
+	li t0, 4
+	sw t0, 12(sp)
+	li t0, 5
+	sw t0, 16(sp)
+	li t0, 6
+	sw t0, 20(sp)
+	li t0, 7
+	sw t0, 24(sp)
+	li t0, 8
+	sw t0, 28(sp)
+	li t0, 9
+	sw t0, 32(sp)
+	li t0, 10
+	sw t0, 36(sp)
+	li t0, 11
+	sw t0, 40(sp)
+	li t0, 12
+	sw t0, 44(sp)
+	li t0, 0
+	sw t0, 48(sp)
+
+.Lfor_start.0:
+	lw t0, 48(sp)
+	slti t0, t0, 20
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 4(sp)
+	li t1, 7
+	remw t0, t0, t1
+	sw t0, 56(sp)
+	lw t0, 0(sp)
+	lw t1, 56(sp)
+	addw t0, t0, t1
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	sw t0, 0(sp)
+	lw t0, 8(sp)
+	li t1, 3
+	divw t0, t0, t1
+	sw t0, 64(sp)
+	lw t0, 4(sp)
+	lw t1, 64(sp)
+	addw t0, t0, t1
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	sw t0, 4(sp)
+	lw t0, 12(sp)
+	slliw t0, t0, 1
+	sw t0, 72(sp)
+	lw t0, 8(sp)
+	lw t1, 72(sp)
+	addw t0, t0, t1
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	sw t0, 8(sp)
+	lw t0, 16(sp)
+	sraiw t0, t0, 1
+	sw t0, 80(sp)
+	lw t0, 12(sp)
+	lw t1, 80(sp)
+	addw t0, t0, t1
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	sw t0, 12(sp)
+	lw t0, 16(sp)
+	lw t1, 20(sp)
+	xor t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	sw t0, 16(sp)
+	lw t0, 24(sp)
+	li t1, 3
+	mulw t0, t0, t1
+	sw t0, 92(sp)
+	lw t0, 20(sp)
+	lw t1, 92(sp)
+	addw t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 96(sp)
+	sw t0, 20(sp)
+	lw t0, 48(sp)
+	addiw t0, t0, 1
+	sw t0, 100(sp)
+	lw t0, 28(sp)
+	lw t1, 100(sp)
+	divw t0, t0, t1
+	sw t0, 104(sp)
+	lw t0, 24(sp)
+	lw t1, 104(sp)
+	subw t0, t0, t1
+	sw t0, 108(sp)
+	lw t0, 108(sp)
+	sw t0, 24(sp)
+	lw t0, 48(sp)
+	addiw t0, t0, 2
+	sw t0, 112(sp)
+	lw t0, 32(sp)
+	lw t1, 112(sp)
+	remw t0, t0, t1
+	sw t0, 116(sp)
+	lw t0, 28(sp)
+	lw t1, 116(sp)
+	addw t0, t0, t1
+	sw t0, 120(sp)
+	lw t0, 120(sp)
+	sw t0, 28(sp)
+	lw t0, 36(sp)
+	andi t0, t0, 255
+	sw t0, 124(sp)
+	lw t0, 32(sp)
+	lw t1, 124(sp)
+	addw t0, t0, t1
+	sw t0, 128(sp)
+	lw t0, 128(sp)
+	sw t0, 32(sp)
+	lw t0, 36(sp)
+	lw t1, 40(sp)
+	or t0, t0, t1
+	sw t0, 132(sp)
+	lw t0, 132(sp)
+	sw t0, 36(sp)
+	lw t0, 40(sp)
+	lw t1, 44(sp)
+	addw t0, t0, t1
+	sw t0, 136(sp)
+	lw t0, 136(sp)
+	sw t0, 40(sp)
+	lw t0, 44(sp)
+	lw t1, 0(sp)
+	subw t0, t0, t1
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	sw t0, 44(sp)
+
+.Lcontinue_loop.0:
+	lw t0, 48(sp)
+	addiw t0, t0, 1
+	sw t0, 144(sp)
+	lw t0, 144(sp)
+	sw t0, 48(sp)
+	j .Lfor_start.0
+
+.Lbreak_loop.0:
+	lw t0, 0(sp)
+	sw t0, 148(sp)
+	lw t0, 4(sp)
+	sw t0, 0(sp)
+	lw t0, 148(sp)
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 152(sp)
+	lw t0, 0(sp)
+	lw t1, 152(sp)
+	addw t0, t0, t1
+	sw t0, 156(sp)
+	lw t0, 156(sp)
+	lw t1, 8(sp)
+	addw t0, t0, t1
+	sw t0, 160(sp)
+	lw t0, 160(sp)
+	lw t1, 12(sp)
+	addw t0, t0, t1
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	lw t1, 16(sp)
+	addw t0, t0, t1
+	sw t0, 168(sp)
+	lw t0, 168(sp)
+	lw t1, 20(sp)
+	addw t0, t0, t1
+	sw t0, 172(sp)
+	lw t0, 172(sp)
+	lw t1, 24(sp)
+	addw t0, t0, t1
+	sw t0, 176(sp)
+	lw t0, 176(sp)
+	lw t1, 28(sp)
+	addw t0, t0, t1
+	sw t0, 180(sp)
+	lw t0, 180(sp)
+	lw t1, 32(sp)
+	addw t0, t0, t1
+	sw t0, 184(sp)
+	lw t0, 184(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 188(sp)
+	lw t0, 188(sp)
+	lw t1, 40(sp)
+	addw t0, t0, t1
+	sw t0, 192(sp)
+	lw t0, 192(sp)
+	lw t1, 44(sp)
+	addw t0, t0, t1
+	sw t0, 196(sp)
+	lw t0, 196(sp)
+	andi t0, t0, 255
+	sw t0, 200(sp)
+	lw a0, 200(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,51 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -32
+
+	li t0, 5
+	slti t0, t0, 10
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	xori t0, t0, 1
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	seqz t0, t0
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	beqz t0, .Land_false.0
+	li t0, 5
+	slti t0, t0, 3
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	xori t0, t0, 1
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	xori t0, t0, 0
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	snez t0, t0
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	sw t0, 16(sp)
+	j .Land_end.1
+
+.Land_false.0:
+	li t0, 0
+	sw t0, 16(sp)
+
+.Land_end.1:
+	lw a0, 16(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,97 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -64
+
+	li t0, 2
+	sw t0, 0(sp)
+	li t0, 0
+	sw t0, 4(sp)
+	lw t0, 0(sp)
+	li t1, 4
+	mulw t0, t0, t1
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	sw t0, 12(sp)
+	lw t0, 0(sp)
+	xori t0, t0, 2
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	seqz t0, t0
+	sw t0, 16(sp)
+	lw t0, 16(sp)
+	beqz t0, .Lif_else.0
+	lw t0, 12(sp)
+	addiw t0, t0, 1
+	sw t0, 20(sp)
+	lw t0, 20(sp)
+	sw t0, 4(sp)
+	j .Lif_end.1
+
+.Lif_else.0:
+	lw t0, 12(sp)
+	li t1, 0
+	divw t0, t0, t1
+	sw t0, 24(sp)
+	lw t0, 24(sp)
+	sw t0, 4(sp)
+
+.Lif_end.1:
+	li t0, 0
+	sw t0, 28(sp)
+
+.Lfor_start.2:
+	lw t0, 28(sp)
+	slti t0, t0, 3
+	sw t0, 32(sp)
+	lw t0, 32(sp)
+	beqz t0, .Lbreak_loop.0
+	lw t0, 0(sp)
+	xori t0, t0, 2
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	snez t0, t0
+	sw t0, 36(sp)
+	lw t0, 36(sp)
+	beqz t0, .Lif_else.3
+	lw t0, 4(sp)
+	li t1, 100
+	mulw t0, t0, t1
+	sw t0, 40(sp)
+	lw t0, 40(sp)
+	sw t0, 4(sp)
+	j .Lif_end.4
+
+.Lif_else.3:
+	lw t0, 4(sp)
+	lw t1, 28(sp)
+	addw t0, t0, t1
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	sw t0, 4(sp)
+
+.Lif_end.4:
+
+.Lcontinue_loop.0:
+	lw t0, 28(sp)
+	sw t0, 48(sp)
+	lw t0, 28(sp)
+	addiw t0, t0, 1
+	sw t0, 28(sp)
+	j .Lfor_start.2
+
+.Lbreak_loop.0:
+	lw a0, 4(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
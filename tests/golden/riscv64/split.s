@@ -0,0 +1,214 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -176
+
+	li t0, 3
+	sw t0, 0(sp)
+	li t0, 5
+	sw t0, 4(sp)
+	li t0, 7
+	sw t0, 8(sp)
+	li t0, 11
+	sw t0, 12(sp)
+	li t0, 13
+	sw t0, 16(sp)
+	li t0, 17
+	sw t0, 20(sp)
+	li t0, 19
+	sw t0, 24(sp)
+	li t0, 23
+	sw t0, 28(sp)
+	li t0, 29
+	sw t0, 32(sp)
+	li t0, 31
+	sw t0, 36(sp)
+	li t0, 37
+	sw t0, 40(sp)
+	li t0, 0
+	sw t0, 44(sp)
+
+.Llabel.again.0:
+	lw t0, 44(sp)
+	li t1, 3
+	remw t0, t0, t1
+	sw t0, 48(sp)
+	lw t0, 48(sp)
+	xori t0, t0, 0
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	seqz t0, t0
+	sw t0, 52(sp)
+	lw t0, 52(sp)
+	beqz t0, .Lif_else.0
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	mulw t0, t0, t1
+	sw t0, 56(sp)
+	lw t0, 56(sp)
+	lw t1, 8(sp)
+	addw t0, t0, t1
+	sw t0, 60(sp)
+	lw t0, 60(sp)
+	sw t0, 0(sp)
+	lw t0, 16(sp)
+	lw t1, 20(sp)
+	mulw t0, t0, t1
+	sw t0, 64(sp)
+	lw t0, 12(sp)
+	lw t1, 64(sp)
+	addw t0, t0, t1
+	sw t0, 68(sp)
+	lw t0, 68(sp)
+	sw t0, 12(sp)
+	j .Lif_end.1
+
+.Lif_else.0:
+	lw t0, 44(sp)
+	li t1, 3
+	remw t0, t0, t1
+	sw t0, 72(sp)
+	lw t0, 72(sp)
+	xori t0, t0, 1
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	seqz t0, t0
+	sw t0, 76(sp)
+	lw t0, 76(sp)
+	beqz t0, .Lif_else.2
+	lw t0, 24(sp)
+	lw t1, 28(sp)
+	xor t0, t0, t1
+	sw t0, 80(sp)
+	lw t0, 80(sp)
+	sw t0, 24(sp)
+	lw t0, 32(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 84(sp)
+	lw t0, 84(sp)
+	lw t1, 40(sp)
+	subw t0, t0, t1
+	sw t0, 88(sp)
+	lw t0, 88(sp)
+	sw t0, 32(sp)
+	j .Llabel.skip.1
+	j .Lif_end.3
+
+.Lif_else.2:
+	lw t0, 40(sp)
+	lw t1, 0(sp)
+	addw t0, t0, t1
+	sw t0, 92(sp)
+	lw t0, 92(sp)
+	sw t0, 40(sp)
+
+.Lif_end.3:
+
+.Lif_end.1:
+	lw t0, 12(sp)
+	li t1, 5
+	remw t0, t0, t1
+	sw t0, 96(sp)
+	lw t0, 4(sp)
+	lw t1, 96(sp)
+	addw t0, t0, t1
+	sw t0, 100(sp)
+	lw t0, 100(sp)
+	sw t0, 4(sp)
+	lw t0, 24(sp)
+	li t1, 3
+	divw t0, t0, t1
+	sw t0, 104(sp)
+	lw t0, 8(sp)
+	lw t1, 104(sp)
+	subw t0, t0, t1
+	sw t0, 108(sp)
+	lw t0, 108(sp)
+	sw t0, 8(sp)
+
+.Llabel.skip.1:
+	lw t0, 16(sp)
+	lw t1, 32(sp)
+	addw t0, t0, t1
+	sw t0, 112(sp)
+	lw t0, 112(sp)
+	sw t0, 16(sp)
+	lw t0, 20(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 116(sp)
+	lw t0, 116(sp)
+	sw t0, 20(sp)
+	lw t0, 44(sp)
+	addiw t0, t0, 1
+	sw t0, 120(sp)
+	lw t0, 120(sp)
+	sw t0, 44(sp)
+	lw t0, 44(sp)
+	slti t0, t0, 25
+	sw t0, 124(sp)
+	lw t0, 124(sp)
+	beqz t0, .Lif_end.4
+	j .Llabel.again.0
+
+.Lif_end.4:
+	lw t0, 0(sp)
+	lw t1, 4(sp)
+	addw t0, t0, t1
+	sw t0, 128(sp)
+	lw t0, 128(sp)
+	lw t1, 8(sp)
+	addw t0, t0, t1
+	sw t0, 132(sp)
+	lw t0, 132(sp)
+	lw t1, 12(sp)
+	addw t0, t0, t1
+	sw t0, 136(sp)
+	lw t0, 136(sp)
+	lw t1, 16(sp)
+	addw t0, t0, t1
+	sw t0, 140(sp)
+	lw t0, 140(sp)
+	lw t1, 20(sp)
+	addw t0, t0, t1
+	sw t0, 144(sp)
+	lw t0, 144(sp)
+	lw t1, 24(sp)
+	addw t0, t0, t1
+	sw t0, 148(sp)
+	lw t0, 148(sp)
+	lw t1, 28(sp)
+	addw t0, t0, t1
+	sw t0, 152(sp)
+	lw t0, 152(sp)
+	lw t1, 32(sp)
+	addw t0, t0, t1
+	sw t0, 156(sp)
+	lw t0, 156(sp)
+	lw t1, 36(sp)
+	addw t0, t0, t1
+	sw t0, 160(sp)
+	lw t0, 160(sp)
+	lw t1, 40(sp)
+	addw t0, t0, t1
+	sw t0, 164(sp)
+	lw t0, 164(sp)
+	sw t0, 168(sp)
+	lw t0, 168(sp)
+	andi t0, t0, 255
+	sw t0, 172(sp)
+	lw a0, 172(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
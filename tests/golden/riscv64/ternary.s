@@ -0,0 +1,39 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -16
+
+	li t0, 0
+	sw t0, 0(sp)
+	li t0, 1
+	negw t0, t0
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	lw t1, 0(sp)
+	slt t0, t0, t1
+	sw t0, 8(sp)
+	lw t0, 8(sp)
+	beqz t0, .Lcond_else.0
+	li t0, 4
+	sw t0, 12(sp)
+	j .Lcond_end.1
+
+.Lcond_else.0:
+	li t0, 5
+	sw t0, 12(sp)
+
+.Lcond_end.1:
+	lw a0, 12(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
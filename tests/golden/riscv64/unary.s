@@ -0,0 +1,25 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -16
+
+	li t0, 2
+	negw t0, t0
+	sw t0, 0(sp)
+	lw t0, 0(sp)
+	not t0, t0
+	sw t0, 4(sp)
+	lw a0, 4(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,34 @@
+	.text
+	.globl main
+	.type main, @function
+main:
+	addi sp, sp, -16
+	sd ra, 8(sp)
+	sd s0, 0(sp)
+	addi s0, sp, 16
+	addi sp, sp, -32
+
+	li t0, 2
+	sw t0, 0(sp)
+	li t0, 3
+	addiw t0, t0, 2
+	sw t0, 4(sp)
+	lw t0, 4(sp)
+	sw t0, 8(sp)
+	lw t0, 0(sp)
+	lw t1, 8(sp)
+	mulw t0, t0, t1
+	sw t0, 12(sp)
+	lw t0, 12(sp)
+	li t1, 2
+	mulw t0, t0, t1
+	sw t0, 16(sp)
+	lw a0, 16(sp)
+
+	addi sp, s0, -16
+	ld ra, 8(sp)
+	ld s0, 0(sp)
+	addi sp, sp, 16
+	ret
+	.size main, .-main
+	.section .note.GNU-stack,"",@progbits
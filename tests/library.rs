@@ -0,0 +1,169 @@
+// Checks the library API: each stage's artifact and its dumps, and the
+// diagnostics of a source that does not compile.
+
+use crucible::dump::Format;
+use crucible::ir::{Instruction, Value};
+use crucible::token::{Span, Token};
+use crucible::{Artifact, Compiler, Linker, OptLevel, Severity, Stage, Warning};
+
+const PROGRAM: &str = "int main(void) {\n    int a = 6;\n    return a * 7;\n}\n";
+
+fn compile(compiler: Compiler) -> Artifact {
+    let compilation = compiler.compile(PROGRAM);
+    assert!(compilation.diagnostics.is_empty());
+    compilation.output.unwrap()
+}
+
+#[test]
+fn frontend_artifacts() {
+    let Artifact::Tokens(tokens) = compile(Compiler::new().stop_at(Stage::Lex)) else {
+        panic!("expected tokens");
+    };
+    assert_eq!(tokens[0].0, Token::Int);
+    assert_eq!((tokens[0].1.line, tokens[0].1.column), (1, 1));
+    assert_eq!(tokens.last().unwrap().0, Token::Eof);
+
+    let Artifact::Ast(ast) = compile(Compiler::new().stop_at(Stage::Parse)) else {
+        panic!("expected an AST");
+    };
+    assert_eq!(ast.function.name, "main");
+    assert_eq!(ast.function.body.items.len(), 2);
+
+    // Constant propagation leaves nothing to compute
+    let optimized = Compiler::new().stop_at(Stage::Ir).opt_level(OptLevel::O2);
+    let Artifact::Ir(ir) = compile(optimized) else {
+        panic!("expected IR");
+    };
+    assert!(matches!(
+        ir.function.body.as_slice(),
+        [Instruction::Return(Value::Constant(42))]
+    ));
+}
+
+#[test]
+fn backend_artifacts() {
+    for (target, label) in [
+        ("x86_64-linux-gnu", "\nmain:"),
+        ("x86_64-apple-darwin", "\n_main:"),
+        ("aarch64-linux", "main:"),
+        ("riscv64-linux", "main:"),
+    ] {
+        let Artifact::Assembly(code) = compile(Compiler::new().target(target)) else {
+            panic!("expected assembly for {}", target);
+        };
+        assert!(code.contains(label), "no {} for {}", label, target);
+    }
+
+    let linux = Compiler::new().target("x86_64-linux-gnu");
+    let Artifact::Object(object) = compile(linux.clone().stop_at(Stage::Object)) else {
+        panic!("expected an object");
+    };
+    assert!(object.starts_with(b"\x7fELF"));
+
+    let builtin = linux.stop_at(Stage::Full).linker(Linker::Builtin);
+    let Artifact::Executable(executable) = compile(builtin) else {
+        panic!("expected an executable");
+    };
+    assert!(executable.starts_with(b"\x7fELF"));
+}
+
+#[test]
+fn dumps() {
+    let tokens = compile(Compiler::new().stop_at(Stage::Lex));
+    let text = tokens.dump(Format::Text);
+    assert!(text.starts_with("1:1     keyword     int\n1:5     identifier  main\n"));
+    assert!(text.ends_with("5:1     eof\n"));
+    assert!(
+        tokens
+            .dump(Format::Json)
+            .contains(r#"{"kind": "constant", "text": "6", "line": 2, "column": 13}"#)
+    );
+
+    let ast = compile(Compiler::new().stop_at(Stage::Validate));
+    assert_eq!(
+        ast.dump(Format::Text),
+        "(function main\n  (declare a.0 6)\n  (return (* a.0 7)))\n"
+    );
+
+    let ir = compile(Compiler::new().stop_at(Stage::Ir));
+    assert_eq!(
+        ir.dump(Format::Text),
+        "function main {\n    a.0 = 6\n    tmp.0 = a.0 * 7\n    return tmp.0\n}\n"
+    );
+    assert!(
+        ir.dump(Format::Json)
+            .contains(r#"{"kind": "binary", "op": "*", "dst": "tmp.0", "src1": "a.0", "src2": 7}"#)
+    );
+
+    let riscv = Compiler::new()
+        .target("riscv64-linux")
+        .stop_at(Stage::Codegen);
+    assert_eq!(
+        compile(riscv).dump(Format::Text),
+        "main:\n    li %a.0, 6\n    mulw %tmp.0, %a.0, 7\n    mv a0, %tmp.0\n    ret\n"
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let compilation = Compiler::new().compile("int main(void) { return 1 }");
+    assert!(compilation.output.is_none());
+    assert_eq!(compilation.diagnostics.len(), 1);
+    assert_eq!(compilation.diagnostics[0].stage, Stage::Parse);
+    assert!(
+        compilation.diagnostics[0]
+            .message
+            .starts_with("Syntax error")
+    );
+    // At the token that did not fit
+    assert_eq!(
+        compilation.diagnostics[0].span,
+        Some(Span {
+            line: 1,
+            column: 27
+        })
+    );
+
+    let compilation = Compiler::new().compile("int main(void) {\n    return b;\n}");
+    assert_eq!(compilation.diagnostics[0].stage, Stage::Validate);
+    // At the statement, since expressions have no spans
+    assert_eq!(
+        compilation.diagnostics[0].span,
+        Some(Span { line: 2, column: 5 })
+    );
+
+    let compiler = Compiler::new().target("pdp11");
+    assert!(compiler.triple().is_err());
+    assert!(compiler.compile(PROGRAM).output.is_none());
+}
+
+#[test]
+fn warnings() {
+    let source = "int main(void) {\n    int a;\n    return 1 / 0;\n}\n";
+    let compilation = Compiler::new().compile(source);
+    assert!(compilation.output.is_some());
+    assert_eq!(compilation.diagnostics.len(), 1);
+    let warning = &compilation.diagnostics[0];
+    assert_eq!(warning.severity, Severity::Warning);
+    assert_eq!(warning.warning, Some(Warning::DivByZero));
+    assert_eq!(warning.to_string(), "division by zero [-Wdiv-by-zero]");
+
+    let compilation = Compiler::new()
+        .warning(Warning::UnusedVariable, true)
+        .warning(Warning::DivByZero, false)
+        .warnings_as_errors(true)
+        .compile(source);
+    assert!(compilation.output.is_none());
+    assert_eq!(compilation.diagnostics.len(), 1);
+    let error = &compilation.diagnostics[0];
+    assert_eq!(error.severity, Severity::Error);
+    assert_eq!(error.span, Some(Span { line: 2, column: 5 }));
+    assert!(error.to_string().ends_with("[-Werror=unused-variable]"));
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn run_in_process() {
+    let compilation = Compiler::new().run(PROGRAM);
+    assert_eq!(compilation.output, Some(42));
+}
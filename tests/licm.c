@@ -0,0 +1,20 @@
+int main(void) {
+    int a = 3;
+    int b = 4;
+    for (int k = 0; k < 2; k++) {
+        a = a + k;
+        b = b * 2;
+    }
+    int sum = 0;
+    for (int i = 0; i < 10; i++) {
+        for (int j = 0; j < 5; j++) {
+            sum = sum + a * b + (a << 2) / 3 + i;
+        }
+    }
+    int n = 7;
+    while (n > 0) {
+        sum = sum + a * 100 % 9;
+        n--;
+    }
+    return sum & 255;
+}
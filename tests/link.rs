@@ -0,0 +1,81 @@
+// Checks the built-in linker: `-fuse-ld=builtin` must make a working
+// executable with no tools at all, so these run with an empty PATH.
+
+#![cfg(all(target_arch = "x86_64", target_os = "linux"))]
+
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn scratch(name: &str) -> PathBuf {
+    let directory = Path::new(env!("CARGO_TARGET_TMPDIR")).join("link");
+    fs::create_dir_all(directory.join("bin")).unwrap();
+    directory.join(name)
+}
+
+// Builds `program` with the built-in linker and no PATH to find tools on
+fn build(program: &Path, flags: &[&str], executable: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_crucible"))
+        .env("PATH", scratch("bin"))
+        .env_remove("CRUCIBLE_CC")
+        .env_remove("CRUCIBLE_AS")
+        .env_remove("CRUCIBLE_LD")
+        .args(flags)
+        .arg("-fuse-ld=builtin")
+        .arg(program)
+        .arg("-o")
+        .arg(executable)
+        .output()
+        .unwrap()
+}
+
+const FLAGS: [&[&str]; 3] = [&["-O0"], &["-O1"], &["-O2", "-fomit-frame-pointer"]];
+
+#[test]
+fn programs_run() {
+    common::each_program(&FLAGS, "wrong exit status", |program, flags| {
+        let stem = program.file_stem().unwrap().to_str().unwrap();
+        let executable = scratch(&format!("{}{}", stem, flags.concat()));
+        let output = build(program, flags, &executable);
+        if !output.status.success() {
+            return false;
+        }
+
+        let status = Command::new(&executable).status().unwrap();
+        status.code() == Some(common::expected_status(program))
+    });
+}
+
+#[test]
+fn undefined_reference() {
+    let program = scratch("undefined.c");
+    fs::write(&program, "int start(void) { return 0; }\n").unwrap();
+    let output = build(&program, &[], &scratch("undefined"));
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("undefined reference to `main`"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+// Without a preprocessor, the lexer still joins spliced lines
+#[test]
+fn line_splices() {
+    let program = scratch("splice.c");
+    fs::write(
+        &program,
+        "int main(void) { // one \\\n return 4;\n    return 1 + \\\n 2;\n}\n",
+    )
+    .unwrap();
+    let executable = scratch("splice");
+    let output = build(&program, &[], &executable);
+    assert!(output.status.success(), "{:?}", output);
+
+    let status = Command::new(&executable).status().unwrap();
+    assert_eq!(status.code(), Some(3));
+}
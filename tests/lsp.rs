@@ -0,0 +1,323 @@
+// Talks to `crucible lsp` the way an editor does: a whole session written to
+// its standard input, and the responses and notifications read back in order.
+
+use crucible::json::Json;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const URI: &str = "file:///program.c";
+
+const PROGRAM: &str = "\
+int main(void) {
+    int a = 1;
+    int b = a + 2;
+    {
+        int a = b;
+        a = a * 2;
+    }
+    goto end;
+end:
+    return a + b;
+}
+";
+
+fn request(id: i64, method: &str, params: Json) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("id", Json::Number(id)),
+        ("method", Json::string(method)),
+        ("params", params),
+    ])
+}
+
+fn notify(method: &str, params: Json) -> Json {
+    Json::object(vec![
+        ("jsonrpc", Json::string("2.0")),
+        ("method", Json::string(method)),
+        ("params", params),
+    ])
+}
+
+fn document() -> (&'static str, Json) {
+    (
+        "textDocument",
+        Json::object(vec![("uri", Json::string(URI))]),
+    )
+}
+
+// Lines and characters count from 0
+fn at(line: i64, character: i64) -> Json {
+    Json::object(vec![
+        document(),
+        (
+            "position",
+            Json::object(vec![
+                ("line", Json::Number(line)),
+                ("character", Json::Number(character)),
+            ]),
+        ),
+    ])
+}
+
+fn change(text: &str) -> Json {
+    let change = Json::object(vec![("text", Json::string(text))]);
+    notify(
+        "textDocument/didChange",
+        Json::object(vec![
+            document(),
+            ("contentChanges", Json::Array(vec![change])),
+        ]),
+    )
+}
+
+// Runs the session and returns the exit status and every message sent back
+fn session(messages: &[Json]) -> (i32, Vec<Json>) {
+    let mut input = String::new();
+    for message in messages {
+        let body = message.compact();
+        input.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+    }
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_crucible"))
+        .arg("lsp")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let mut rest = std::str::from_utf8(&output.stdout).unwrap();
+    let mut replies = Vec::new();
+    while !rest.is_empty() {
+        let (header, body) = rest.split_once("\r\n\r\n").unwrap();
+        let length: usize = header
+            .strip_prefix("Content-Length: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        replies.push(Json::parse(&body[..length]).unwrap());
+        rest = &body[length..];
+    }
+    (output.status.code().unwrap(), replies)
+}
+
+fn result(reply: &Json) -> &Json {
+    reply.get("result").unwrap()
+}
+
+// `line:character` of where each range starts
+fn starts(locations: &Json) -> Vec<String> {
+    let Json::Array(locations) = locations else {
+        panic!(
+            "expected an array of locations, got {}",
+            locations.compact()
+        );
+    };
+    locations.iter().map(start).collect()
+}
+
+fn start(location: &Json) -> String {
+    let start = location.get("range").unwrap().get("start").unwrap();
+    format!(
+        "{}:{}",
+        start.get("line").unwrap().as_i64().unwrap(),
+        start.get("character").unwrap().as_i64().unwrap()
+    )
+}
+
+fn diagnostics(notification: &Json) -> &Json {
+    assert_eq!(
+        notification.get("method").and_then(Json::as_str),
+        Some("textDocument/publishDiagnostics")
+    );
+    notification
+        .get("params")
+        .unwrap()
+        .get("diagnostics")
+        .unwrap()
+}
+
+#[test]
+fn navigation() {
+    let open = Json::object(vec![(
+        "textDocument",
+        Json::object(vec![
+            ("uri", Json::string(URI)),
+            ("languageId", Json::string("c")),
+            ("version", Json::Number(1)),
+            ("text", Json::string(PROGRAM)),
+        ]),
+    )]);
+    let mut references = at(4, 12);
+    if let Json::Object(members) = &mut references {
+        let context = Json::object(vec![("includeDeclaration", Json::Bool(false))]);
+        members.push(("context".to_string(), context));
+    }
+
+    let (status, replies) = session(&[
+        request(1, "initialize", Json::object(Vec::new())),
+        notify("initialized", Json::object(Vec::new())),
+        notify("textDocument/didOpen", open),
+        request(2, "textDocument/definition", at(9, 11)),
+        request(3, "textDocument/references", references),
+        request(4, "textDocument/definition", at(7, 10)),
+        request(5, "textDocument/hover", at(2, 12)),
+        request(
+            6,
+            "textDocument/documentSymbol",
+            Json::object(vec![document()]),
+        ),
+        request(7, "shutdown", Json::Null),
+        notify("exit", Json::Null),
+    ]);
+    assert_eq!(status, 0);
+    assert_eq!(replies.len(), 8);
+
+    let capabilities = result(&replies[0]).get("capabilities").unwrap();
+    assert_eq!(capabilities.get("hoverProvider"), Some(&Json::Bool(true)));
+    assert_eq!(diagnostics(&replies[1]), &Json::Array(Vec::new()));
+
+    // The `a` in `return a + b` is the outer one, not the one in the block
+    assert_eq!(start(result(&replies[2])), "1:8");
+    assert_eq!(starts(result(&replies[3])), ["5:8", "5:12"]);
+    assert_eq!(start(result(&replies[4])), "8:0");
+
+    let hover = result(&replies[5]).get("contents").unwrap();
+    assert_eq!(
+        hover.get("value").and_then(Json::as_str),
+        Some("```c\nint a\n```")
+    );
+
+    let Json::Array(symbols) = result(&replies[6]) else {
+        panic!("expected document symbols");
+    };
+    assert_eq!(symbols[0].get("name").and_then(Json::as_str), Some("main"));
+    let Some(Json::Array(children)) = symbols[0].get("children") else {
+        panic!("expected the function's symbols");
+    };
+    let names: Vec<&str> = children
+        .iter()
+        .map(|child| child.get("name").and_then(Json::as_str).unwrap())
+        .collect();
+    assert_eq!(names, ["a", "b", "a", "end"]);
+
+    assert_eq!(result(&replies[7]), &Json::Null);
+}
+
+// Each name is found where its own identifier is, whatever the statement
+// around it, and a label and a variable with the same name stay apart
+#[test]
+fn references_at_each_name() {
+    let text = "\
+int main(void) {
+    int x = 0;
+    for (int i = 0; i < 3; i += 1)
+        do x += i; while (x < 0);
+    goto x;
+x:
+    return x ? x : 0;
+}
+";
+    let open = Json::object(vec![(
+        "textDocument",
+        Json::object(vec![
+            ("uri", Json::string(URI)),
+            ("text", Json::string(text)),
+        ]),
+    )]);
+    let (status, replies) = session(&[
+        notify("textDocument/didOpen", open),
+        request(1, "textDocument/references", at(6, 15)),
+        request(2, "textDocument/references", at(2, 27)),
+        request(3, "textDocument/references", at(4, 9)),
+        notify("exit", Json::Null),
+    ]);
+    assert_eq!(status, 1);
+
+    assert_eq!(
+        starts(result(&replies[1])),
+        ["1:8", "3:11", "3:26", "6:11", "6:15"]
+    );
+    assert_eq!(
+        starts(result(&replies[2])),
+        ["2:13", "2:20", "2:27", "3:16"]
+    );
+    assert_eq!(starts(result(&replies[3])), ["4:9", "5:0"]);
+}
+
+#[test]
+fn diagnostics_follow_changes() {
+    let open = Json::object(vec![(
+        "textDocument",
+        Json::object(vec![
+            ("uri", Json::string(URI)),
+            ("text", Json::string("int main(void) {\n    return c;\n}\n")),
+        ]),
+    )]);
+    let (status, replies) = session(&[
+        notify("textDocument/didOpen", open),
+        change("int main(void) {\n    return 1 $ 2;\n}\n"),
+        change("int main(void) {\n    return 1\n}\n"),
+        change(PROGRAM),
+        request(1, "textDocument/rename", at(1, 8)),
+        notify("exit", Json::Null),
+    ]);
+    // Exiting without a shutdown request
+    assert_eq!(status, 1);
+
+    let message = |notification: &Json| {
+        let Json::Array(found) = diagnostics(notification) else {
+            panic!("expected an array of diagnostics");
+        };
+        assert_eq!(found.len(), 1);
+        let message = found[0].get("message").and_then(Json::as_str).unwrap();
+        format!("{} {}", start(&found[0]), message)
+    };
+    assert_eq!(
+        message(&replies[0]),
+        "1:4 Semantic error: undeclared variable: 'c'"
+    );
+    assert_eq!(
+        message(&replies[1]),
+        "1:13 Lexical error: Unexpected character: '$'"
+    );
+    assert_eq!(message(&replies[2]), "2:0 Syntax error: Expected ';'");
+    assert_eq!(diagnostics(&replies[3]), &Json::Array(Vec::new()));
+
+    let error = replies[4].get("error").unwrap();
+    assert_eq!(error.get("code").and_then(Json::as_i64), Some(-32601));
+}
+
+#[test]
+fn warnings() {
+    let open = Json::object(vec![(
+        "textDocument",
+        Json::object(vec![
+            ("uri", Json::string(URI)),
+            (
+                "text",
+                Json::string("int main(void) {\n    int a;\n    return 1;\n}\n"),
+            ),
+        ]),
+    )]);
+    let (_, replies) = session(&[notify("textDocument/didOpen", open)]);
+
+    // Every warning is on, and each says which it is
+    let Json::Array(found) = diagnostics(&replies[0]) else {
+        panic!("expected an array of diagnostics");
+    };
+    assert_eq!(found.len(), 1);
+    // At the declaration, whose first word is its type
+    assert_eq!(start(&found[0]), "1:4");
+    assert_eq!(found[0].get("severity").and_then(Json::as_i64), Some(2));
+    assert_eq!(
+        found[0].get("code").and_then(Json::as_str),
+        Some("unused-variable")
+    );
+}
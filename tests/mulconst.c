@@ -0,0 +1,44 @@
+int main(void) {
+    int failures = 0;
+    int i = 0;
+
+    while (i < 10) {
+        int n = 0;
+        if (i == 0) n = -2147483647 - 1;
+        if (i == 1) n = -2147483647;
+        if (i == 2) n = -123456;
+        if (i == 3) n = -1;
+        if (i == 4) n = 0;
+        if (i == 5) n = 1;
+        if (i == 6) n = 3;
+        if (i == 7) n = 123456;
+        if (i == 8) n = 1073741824;
+        if (i == 9) n = 2147483647;
+
+        // Opaque multipliers that the compiler cannot see through
+        int one = i - i + 1;
+
+        if (n * 0 != n * (one - 1)) failures = failures + 1;
+        if (n * 1 != n * one) failures = failures + 1;
+        if (n * -1 != n * -one) failures = failures + 1;
+        if (n * 2 != n * (one * 2)) failures = failures + 1;
+        if (n * 3 != n * (one * 3)) failures = failures + 1;
+        if (5 * n != n * (one * 5)) failures = failures + 1;
+        if (n * 6 != n * (one * 6)) failures = failures + 1;
+        if (n * 9 != n * (one * 9)) failures = failures + 1;
+        if (n * 10 != n * (one * 10)) failures = failures + 1;
+        if (n * 12 != n * (one * 12)) failures = failures + 1;
+        if (n * 16 != n * (one * 16)) failures = failures + 1;
+        if (n * -4 != n * (one * -4)) failures = failures + 1;
+        if (n * -9 != n * (one * -9)) failures = failures + 1;
+        if (n * 36 != n * (one * 36)) failures = failures + 1;
+        if (n * 72 != n * (one * 72)) failures = failures + 1;
+        if (n * 7 != n * (one * 7)) failures = failures + 1;
+        if (n * 1024 != n * (one * 1024)) failures = failures + 1;
+        if (n * (-2147483647 - 1) != n * (one * (-2147483647 - 1))) failures = failures + 1;
+
+        i = i + 1;
+    }
+
+    return failures;
+}
@@ -0,0 +1,272 @@
+// Checks what the optimizer removes, on the IR and on the assembly, through
+// the library.
+
+use crucible::dump::Format;
+use crucible::ir::{Instruction, Value};
+use crucible::{Artifact, Compiler, OptLevel, RegAllocator, Stage};
+use std::fs;
+
+fn assembly(source: &str, opt_level: OptLevel) -> String {
+    let compiler = Compiler::new()
+        .opt_level(opt_level)
+        .allocator(RegAllocator::None)
+        .peephole(false)
+        .stop_at(Stage::Assembly);
+    let Some(Artifact::Assembly(code)) = compiler.compile(source).output else {
+        panic!("expected assembly");
+    };
+    code
+}
+
+#[test]
+fn unreachable_code() {
+    let source = "\
+int main(void) {
+    int a = 0;
+    for (int i = 0; i < 10; i = i + 1) {
+        if (i == 5)
+            continue;
+        a = a + i;
+    }
+    return a;
+    a = 12345;
+    goto end;
+end:
+    return a;
+}
+";
+    // The same allocator and no peephole pass, so only the CFG passes differ
+    let plain = assembly(source, OptLevel::O0);
+    let optimized = assembly(source, OptLevel::O1);
+
+    assert!(plain.contains("12345"));
+    assert!(!optimized.contains("12345"));
+    assert!(
+        optimized.lines().count() < plain.lines().count(),
+        "-O1 did not shrink the assembly:\n{}",
+        optimized
+    );
+}
+
+fn ir(source: &str, opt_level: OptLevel) -> String {
+    let compiler = Compiler::new().stop_at(Stage::Ir).opt_level(opt_level);
+    compiler.compile(source).output.unwrap().dump(Format::Text)
+}
+
+// How many times the IR at -O2 computes `expression`
+fn computed(source: &str, expression: &str) -> usize {
+    ir(source, OptLevel::O2).matches(expression).count()
+}
+
+// A constant that reaches the condition through another local is only known
+// once constants are followed through the copy. The loop keeps `limit` from
+// being one
+#[test]
+fn constant_branches() {
+    let source = "\
+int main(void) {
+    int mode = 2;
+    int copy = mode;
+    int limit = 0;
+    for (int i = 0; i < 10; i = i + 1)
+        limit = limit + i;
+    if (copy == 2)
+        limit = limit + 1;
+    else
+        limit = limit / 0;
+    return limit;
+}
+";
+    let plain = ir(source, OptLevel::O0);
+    assert!(plain.contains(" == 2"));
+    assert!(plain.contains(" / 0"));
+    assert_eq!(plain.matches("jump_if_zero").count(), 2);
+
+    // Only the loop still branches, and the division was never reached
+    let optimized = ir(source, OptLevel::O1);
+    assert!(!optimized.contains(" == 2"), "{}", optimized);
+    assert!(!optimized.contains(" / 0"), "{}", optimized);
+    assert_eq!(
+        optimized.matches("jump_if_zero").count(),
+        1,
+        "{}",
+        optimized
+    );
+}
+
+// Loops keep the values from being known constants
+#[test]
+fn redundant_expressions() {
+    // Dominated by the first computation
+    let source = "\
+int main(void) {
+    int a = 6;
+    int b = 7;
+    for (int i = 0; i < 3; i = i + 1)
+        a = a + b;
+    return a * b + b * a;
+}
+";
+    assert_eq!(computed(source, " * "), 1);
+
+    // On both sides of a branch and again after it: computed once, before it
+    let source = "\
+int main(void) {
+    int x = 100;
+    for (int i = 0; i < 3; i = i + 1)
+        x = x - 7;
+    int r = 0;
+    if (x > 50)
+        r = x % 7;
+    else
+        r = 1 - x % 7;
+    return r + x % 7;
+}
+";
+    assert_eq!(computed(source, " % 7"), 1);
+
+    // On one side only, nothing moves
+    let source = "\
+int main(void) {
+    int x = 100;
+    for (int i = 0; i < 3; i = i + 1)
+        x = x - 7;
+    int r = 0;
+    if (x > 50)
+        r = x % 7;
+    return r + x % 7;
+}
+";
+    assert_eq!(computed(source, " % 7"), 2);
+}
+
+// The `Binary` instructions inside a loop that compute the same value on
+// every iteration, and the `Binary` instructions outside every loop. A loop
+// is the code from a label to the first jump back up to it, as the IR is
+// laid out, since preheaders may be placed after their loop and jump up
+// too. An instruction counts for the innermost loop around it
+fn invariants(source: &str, opt_level: OptLevel) -> (usize, usize) {
+    let compiler = Compiler::new().stop_at(Stage::Ir).opt_level(opt_level);
+    let Some(Artifact::Ir(program)) = compiler.compile(source).output else {
+        panic!("expected IR");
+    };
+    let body = &program.function.body;
+
+    let mut loops = Vec::new();
+    for (header, instruction) in body.iter().enumerate() {
+        let Instruction::Label(label) = instruction else {
+            continue;
+        };
+        let back = body[header..]
+            .iter()
+            .position(|instruction| match instruction {
+                Instruction::Jump { target }
+                | Instruction::JumpIfZero { target, .. }
+                | Instruction::JumpIfNotZero { target, .. } => target == label,
+                _ => false,
+            });
+        if let Some(back) = back {
+            loops.push(header..header + back + 1);
+        }
+    }
+
+    let (mut inside, mut outside) = (0, 0);
+    for (index, instruction) in body.iter().enumerate() {
+        if !matches!(instruction, Instruction::Binary { .. }) {
+            continue;
+        }
+        let innermost = loops
+            .iter()
+            .filter(|range| range.contains(&index))
+            .min_by_key(|range| range.len());
+        match innermost {
+            Some(range) if invariant(&body[range.clone()]).contains(&(index - range.start)) => {
+                inside += 1
+            }
+            Some(_) => {}
+            None => outside += 1,
+        }
+    }
+    (inside, outside)
+}
+
+// The indices of the `Binary` instructions in `code` whose operands are
+// constants, defined outside it, or defined once in it by such an
+// instruction
+fn invariant(code: &[Instruction]) -> Vec<usize> {
+    let definitions = |name: &String| {
+        code.iter()
+            .filter(|instruction| match instruction {
+                Instruction::Unary { dst, .. }
+                | Instruction::Binary { dst, .. }
+                | Instruction::Copy { dst, .. } => dst == name,
+                _ => false,
+            })
+            .count()
+    };
+
+    let mut found: Vec<&String> = Vec::new();
+    loop {
+        let before = found.len();
+        for instruction in code {
+            let Instruction::Binary {
+                dst, src1, src2, ..
+            } = instruction
+            else {
+                continue;
+            };
+            let known = [src1, src2].into_iter().all(|value| match value {
+                Value::Constant(_) => true,
+                Value::Variable(name) => definitions(name) == 0 || found.contains(&name),
+            });
+            if known && definitions(dst) == 1 && !found.contains(&dst) {
+                found.push(dst);
+            }
+        }
+        if found.len() == before {
+            break;
+        }
+    }
+    code.iter()
+        .enumerate()
+        .filter(|(_, instruction)| match instruction {
+            Instruction::Binary { dst, .. } => found.contains(&dst),
+            _ => false,
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// `a * b`, `a << 2` and its `/ 3` in the inner loop, and `a * 100` and its
+// `% 9` in the last one, end up in front of the loops, next to the `& 255`
+// that was already outside
+#[test]
+fn loop_invariants() {
+    let source = fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/licm.c")).unwrap();
+    assert_eq!(invariants(&source, OptLevel::O1), (5, 1));
+    assert_eq!(invariants(&source, OptLevel::O2), (0, 6));
+}
+
+// Thousands of `if`s in a row nest the dominator tree as deep, which the
+// passes that walk it must not take as recursion depth
+fn chain_of_ifs(count: usize) -> String {
+    let mut source = String::from("int main(void) {\n    int x = 0;\n");
+    for i in 0..count {
+        source.push_str(&format!("    if (x == {}) x = x + 1;\n", i));
+    }
+    source.push_str("    return x;\n}\n");
+    source
+}
+
+#[test]
+fn deep_dominator_tree() {
+    let source = chain_of_ifs(3000);
+    let ssa = Compiler::new().stop_at(Stage::Ssa).compile(&source);
+    assert!(ssa.output.is_some());
+
+    let optimized = Compiler::new()
+        .stop_at(Stage::Ir)
+        .opt_level(OptLevel::O2)
+        .compile(&source);
+    assert!(optimized.output.is_some());
+}
@@ -0,0 +1,38 @@
+int main(void) {
+    int result = 0;
+
+    // mov x, x: a self-assignment
+    int a = 5;
+    a = a;
+
+    // jmp to the next label: an empty else branch
+    if (a > 3) {
+        result = result + 1;
+    } else {
+    }
+
+    // store then reload of the same slot: a copy of a copy
+    int b = a;
+    int c = b;
+
+    // reload then store of the same slot
+    int d = c;
+    d = d;
+
+    // dead store: the first assignment is overwritten right away
+    int e = 1;
+    e = 2;
+
+    // load folded into the operation that uses it
+    int f = c + d;
+    int g = f - e;
+    if (g < c) result = result + 2;
+
+    // mov reg, 0 as xor when the flags are dead, but not between a cmp
+    // and its setcc
+    int zero = 0;
+    int less = a < b;
+    int more = !(a > b);
+
+    return result + a + b + c + d + e + f + g + zero + less + more;
+}
@@ -0,0 +1,36 @@
+int main(void) {
+    // More values live across the loop than there are registers
+    int a = 1;
+    int b = 2;
+    int c = 3;
+    int d = 4;
+    int e = 5;
+    int f = 6;
+    int g = 7;
+    int h = 8;
+    int i = 9;
+    int j = 10;
+    int k = 11;
+    int l = 12;
+
+    for (int n = 0; n < 20; n = n + 1) {
+        a = a + b % 7;
+        b = b + c / 3;
+        c = c + (d << 1);
+        d = d + (e >> 1);
+        e = e ^ f;
+        f = f + g * 3;
+        g = g - h / (n + 1);
+        h = h + i % (n + 2);
+        i = i + (j & 255);
+        j = j | k;
+        k = k + l;
+        l = l - a;
+    }
+
+    int t = a;
+    a = b;
+    b = t;
+
+    return (a + b * 2 + c + d + e + f + g + h + i + j + k + l) & 255;
+}
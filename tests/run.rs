@@ -0,0 +1,47 @@
+// Runs every program in `tests/` in-process with `crucible run`, which needs
+// an x86-64 host, and checks the exit statuses.
+#![cfg(target_arch = "x86_64")]
+
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+const ALLOCATORS: [&str; 3] = ["-fregalloc=linear", "-fregalloc=graph", "-fregalloc=none"];
+
+fn status(program: &Path, flags: &[&str]) -> Option<i32> {
+    let output = Command::new(env!("CARGO_BIN_EXE_crucible"))
+        .arg("run")
+        .args(flags)
+        .arg(program)
+        .output()
+        .unwrap();
+    output.status.code()
+}
+
+#[test]
+fn exit_statuses() {
+    let mut flags = Vec::new();
+    for level in ["-O0", "-O1", "-O2"] {
+        for allocator in ALLOCATORS {
+            flags.push([level, allocator]);
+        }
+    }
+    let flags: Vec<&[&str]> = flags.iter().map(|flags| &flags[..]).collect();
+    common::each_program(&flags, "wrong exit status", |program, flags| {
+        status(program, flags) == Some(common::expected_status(program))
+    });
+}
+
+// Every allocator has to produce the same program
+#[test]
+fn allocators_agree() {
+    let levels: [&[&str]; 3] = [&["-O0"], &["-O1"], &["-O2"]];
+    common::each_program(&levels, "the allocators disagree", |program, level| {
+        let statuses: Vec<_> = ALLOCATORS
+            .iter()
+            .map(|allocator| status(program, &[level, &[allocator]].concat()))
+            .collect();
+        statuses[0].is_some() && statuses.iter().all(|status| *status == statuses[0])
+    });
+}
@@ -0,0 +1,17 @@
+int main(void) {
+    int mode = 2;
+    int result = 0;
+    int limit = mode * 4;
+    if (mode == 2) {
+        result = limit + 1;
+    } else {
+        result = limit / 0;
+    }
+    for (int i = 0; i < 3; i++) {
+        if (mode != 2)
+            result = result * 100;
+        else
+            result = result + i;
+    }
+    return result;
+}
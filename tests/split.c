@@ -0,0 +1,41 @@
+int main(void) {
+    // Values live across branches and jumps in both directions, with more of
+    // them than there are registers, so intervals get split and reloaded
+    int a = 3;
+    int b = 5;
+    int c = 7;
+    int d = 11;
+    int e = 13;
+    int f = 17;
+    int g = 19;
+    int h = 23;
+    int i = 29;
+    int j = 31;
+    int k = 37;
+    int n = 0;
+
+again:
+    if (n % 3 == 0) {
+        a = a * b + c;
+        d = d + e * f;
+    } else if (n % 3 == 1) {
+        g = g ^ h;
+        i = i + j - k;
+        goto skip;
+    } else {
+        k = k + a;
+    }
+
+    b = b + d % 5;
+    c = c - g / 3;
+
+skip:
+    e = e + i;
+    f = f + j;
+    n = n + 1;
+    if (n < 25)
+        goto again;
+
+    int sum = a + b + c + d + e + f + g + h + i + j + k;
+    return sum & 255;
+}
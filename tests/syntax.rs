@@ -0,0 +1,61 @@
+// Checks that the Intel and AT&T emitters print the same instructions by
+// assembling both for every program in `tests/` and comparing the objects.
+// Needs an assembler on the PATH.
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn assemble(program: &Path, flags: &[&str], syntax: &str) -> Vec<u8> {
+    let output = Command::new(env!("CARGO_BIN_EXE_crucible"))
+        .args(flags)
+        .arg(format!("-masm={}", syntax))
+        .args(["-S", "-o", "-"])
+        .arg(program)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "failed to compile {}",
+        program.display()
+    );
+
+    let assembly = output.stdout;
+
+    let stem = program.file_stem().unwrap().to_str().unwrap();
+    let base = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!(
+        "{}{}-{}",
+        stem,
+        flags.concat(),
+        syntax
+    ));
+    let source = base.with_extension("s");
+    let object = base.with_extension("o");
+    fs::write(&source, assembly).unwrap();
+
+    let status = Command::new("as")
+        .arg("-o")
+        .arg(&object)
+        .arg(&source)
+        .status()
+        .expect("failed to run as");
+    assert!(status.success(), "failed to assemble {}", source.display());
+
+    fs::read(&object).unwrap()
+}
+
+#[test]
+fn intel_and_att_agree() {
+    let flags: [&[&str]; 4] = [
+        &["-O0"],
+        &["-O2"],
+        &["-O2", "-fregalloc=none"],
+        &["-O2", "-fomit-frame-pointer"],
+    ];
+    common::each_program(&flags, "objects differ", |program, flags| {
+        assemble(program, flags, "intel") == assemble(program, flags, "att")
+    });
+}